@@ -3,23 +3,37 @@ hello-start = Hello! Use /help to see available commands.
 help-text =
     <b>Available Commands:</b>
     /who - Show online users.
+    /seen &lt;username&gt; - Show when a user was last online.
+    /graph [day|week|month] - Chart of online users over time.
     /settings - Access the interactive settings menu (language, notifications, mute lists, Offline-only feature).
     /unsub - Unsubscribe from notifications.
+    /register &lt;username&gt; - Request a TeamTalk account (approved by an administrator).
     /help - Show this help message.
     (Note: <code>/start</code> is used to initiate the bot and process deeplinks.)
 
     <b>Admin Commands:</b>
     /kick - Kick a user from the server (via buttons).
     /ban - Ban a user from the server (via buttons).
+    /ban <IP or username> - Ban an address or account on the TeamTalk server, even if offline.
+    /move - Move a user to another channel (via buttons).
     /unban - Unban a user from the server (shows a list of banned users).
+    /serverbans - Show the TeamTalk server ban list.
+    /channels - Browse channels: create sub-channels, change topics and passwords, delete empty channels.
+    /accounts [search] - List, search, create, edit and delete TeamTalk user accounts.
+    /server - Show the server name, MOTD and limits, and edit the name or MOTD.
     /subscribers - View and manage subscribed users.
     /broadcast - Send a broadcast to all TeamTalk users.
     /message - Send a Telegram message to all subscribers.
+    /queue - Show and manage the stream queue.
+    /play &lt;search&gt; - Queue a track from the music library.
+    /playlist [list|show|add|play|delete] - Manage playlists.
     /exit - Shut down the bot.
 
 cmd-invalid-deeplink = Invalid or expired deeplink.
 cmd-success-sub = You have successfully subscribed to notifications.
 cmd-success-sub-guest = Subscribed as GUEST. Note: Offline-only is unavailable.
+cmd-success-sub-chat = This chat is now subscribed to notifications. Chat administrators can adjust them with /settings.
+cmd-chat-admin-only = Only chat administrators can change this chat's subscription and settings.
 cmd-success-unsub = You have successfully unsubscribed from notifications.
 cmd-relink = TeamTalk account linked successfully!
 cmd-fail-account = Your TeamTalk account must have a username to subscribe.
@@ -52,6 +66,17 @@ btn-no = No
 # Notifications
 event-join = { $nickname } joined server { $server }
 event-leave = { $nickname } left server { $server }
+event-channel-join = { $nickname } joined channel { $channel } on { $server }
+event-channel-leave = { $nickname } left channel { $channel } on { $server }
+event-nick-change = { $old } is now known as { $nickname } on { $server }
+event-status-change = { $nickname } changed status to { $status } on { $server }
+event-server-broadcast = 📢 Broadcast from { $nickname } on { $server }: { $text }
+event-file-upload = 📎 { $nickname } uploaded { $text } ({ $size }) to { $channel } on { $server }
+status-presence-available = available
+status-presence-away = away
+status-presence-question = question mode
+digest-title = <b>Activity summary</b> ({ $count } events):
+digest-more = …and { $count } more
 
 # Settings Menu
 settings-title = <b>Settings</b>
@@ -59,12 +84,36 @@ msg-choose-lang = Please choose your language:
 btn-lang = Language
 btn-sub-settings = Subscription Settings
 btn-notif-settings = Notification Settings
+btn-server-settings = Servers
+
+# Server Settings
+server-settings-title = <b>Servers</b>
+    Choose which TeamTalk servers send you join/leave notifications.
+resp-server-toggled = Notifications from { $server }: { $status }
 
 # Notification Settings
 notif-settings-title = <b>Notification Settings</b>
+delivery-settings-title = <b>Delivery Mode</b>
+btn-delivery-instant = { $marker } Every event
+btn-delivery-batched = { $marker } Batched every { $minutes } min
+btn-delivery-hourly = { $marker } Hourly summary
 btn-noon = Offline-only: { $status }
+btn-channel-notif = Channel moves: { $status }
+btn-nick-notif = Nickname changes: { $status }
+btn-status-notif = Status changes: { $status }
+btn-broadcast-notif = Server broadcasts: { $status }
+btn-file-notif = File uploads: { $status }
+btn-health-notif = Server down / back up: { $status }
+btn-delivery-settings = Delivery Mode
 btn-mute-manage = Manage Mute List
 resp-noon-updated = Offline-only updated: { $status }
+resp-channel-notif-updated = Channel move notifications: { $status }
+resp-nick-notif-updated = Nickname change notifications: { $status }
+resp-status-notif-updated = Status change notifications: { $status }
+resp-broadcast-notif-updated = Server broadcast notifications: { $status }
+resp-file-notif-updated = File upload notifications: { $status }
+resp-health-notif-updated = Server availability notifications: { $status }
+resp-delivery-updated = Delivery mode updated to: { $text }.
 
 # Mute Management
 mute-title = <b>Manage Mute List</b>
@@ -100,8 +149,11 @@ item-status-whitelist-in = ✅ { $name } (in whitelist)
 item-status-whitelist-out = 🚫 { $name } (not in whitelist)
 
 # Pagination / Lists
+server-picker-title = Choose a TeamTalk server:
 list-kick-title = Select a user to kick from { $server }:
 list-ban-title = Select a user to ban from { $server }:
+list-move-title = Select a user to move on { $server }:
+move-channel-title = Select a channel to move { $name } to:
 list-unban-title = Banned Users
 list-subs-title = Here is the list of subscribers.
 list-mute-title = Mute list for: { $name }
@@ -110,6 +162,18 @@ list-link-title = Select a TeamTalk account to link to subscriber { $id }:
 list-empty = The list is empty.
 list-subs-empty = No subscribers found.
 list-ban-empty = The ban list is empty.
+ban-term-title = How long should { $name } be banned?
+ban-reason-prompt = Banning { $name } ({ $term }). Reply to this message with the reason, or tap the button to ban without one.
+ban-done = { $name } has been banned ({ $term }).
+ban-done-reason = { $name } has been banned ({ $term }). Reason: { $reason }
+ban-term-hour = 1 hour
+ban-term-day = 1 day
+ban-term-week = 7 days
+ban-term-permanent = permanent
+ban-time-left = { $time } left
+ban-lifted = The ban on { $address } was lifted on the TeamTalk server.
+ban-lift-failed = The TeamTalk server rejected lifting the ban on { $address }. It is still in the ban list.
+ban-lift-unconfirmed = The TeamTalk server did not confirm lifting the ban on { $address }. It is still in the ban list; try again later.
 list-mute-empty = The mute list is currently empty.
 list-page = Page { $current }/{ $total }
 
@@ -121,6 +185,12 @@ btn-back-notif = Back to Notifications
 btn-back-mute = Back to Mute Management
 btn-back-menu = Back to Main Menu
 btn-back-subs = Back to Subscribers List
+btn-back-users = Back to Users
+btn-back-channels = Back to Channels
+btn-back-channel = Back to Channel
+btn-back-accounts = Back to Accounts
+btn-back-server = Back to Server
+btn-back-account = Back to Account
 btn-back-user-actions = Back to User Actions
 btn-back-manage-acc = Back to Manage Account
 
@@ -131,6 +201,11 @@ toast-lang-updated = Language has been changed.
 toast-command-sent = Command sent.
 toast-user-banned = User was banned and their profile was deleted.
 toast-user-unbanned = User has been successfully unbanned.
+toast-unban-sent = Lifting the ban on the TeamTalk server…
+server-bans-title = Bans on { $server } ({ $count }):
+server-bans-empty = There are no bans on { $server }.
+server-ban-usage = Usage: /ban <IP address or username>
+server-ban-sent = Ban for { $name } sent to the TeamTalk server.
 toast-subscriber-deleted = Subscriber deleted successfully.
 toast-account-unlinked = Account { $user } has been unlinked.
 toast-account-linked = Successfully linked TeamTalk account: { $user }.
@@ -163,6 +238,8 @@ tg-reply-offline = User is offline.
 tt-msg-sent = Message sent to Telegram.
 tt-msg-failed = Failed to send message to Telegram.
 tt-channel-reply = Reply to: { $msg } (duration { $duration })
+tt-channel-reply-track = Reply to: { $msg } — { $title } (duration { $duration })
+tt-channel-reply-track-untimed = Reply to: { $msg } — { $title }
 tt-channel-reply-text =
     Reply to: { $msg }
     { $reply }
@@ -197,6 +274,8 @@ tt-admin-help-cmds =
     /add_admin <Telegram ID> [<Telegram ID>...] - Add bot admin.
     /remove_admin <Telegram ID> [<Telegram ID>...] - Remove bot admin.
     /skip - Skip media playback.
+    /play <search> - Queue a track from the music library.
+    /playlist [list|show|add|play|delete] - Manage playlists.
 
 tt-report-header =
     There { $count ->
@@ -206,6 +285,7 @@ tt-report-header =
 tt-report-unauth = (not in a channel)
 tt-sub-fail-nouser = Your TeamTalk account must have a username to subscribe.
 tt-sub-link = Click this link to subscribe: { $link }
+tt-sub-group-link = To subscribe a Telegram group, open { $link }. For a channel, post /start { $token } in it.
 tt-unsub-link = Click this link to unsubscribe: { $link }
 tt-error-generic = Error. Try again.
 
@@ -235,6 +315,10 @@ btn-menu-settings = ⚙️ Settings
 btn-menu-help = ❓ Help
 btn-menu-kick = 👢 Kick User
 btn-menu-ban = 🚫 Ban User
+btn-menu-move = 🔀 Move User
+btn-menu-channels = 📁 Channels
+btn-menu-accounts = 👥 Accounts
+btn-menu-server = 🖥 Server
 btn-menu-unban = ✅ Unban User
 btn-menu-subs = 👥 Subscribers
 btn-menu-unsub = 🚪 Unsubscribe
@@ -261,19 +345,269 @@ btn-sub-notif = 🔔 Set Notification Prefs
 btn-sub-mute-mode = 🔇 Set Mute Mode
 btn-sub-view-mute = 📜 View Mute List
 btn-unban = ✅ Unban
+btn-server-unban = ✅ Unban { $name }
+btn-refresh = 🔄 Refresh
+btn-ban-hour = ⏱ 1 hour
+btn-ban-day = 📅 1 day
+btn-ban-week = 🗓 7 days
+btn-ban-permanent = ♾ Permanent
+btn-ban-no-reason = 🚫 Ban without a reason
 btn-unlink = ➖ Unlink { $user }
 btn-link-new = ➕ Link/Change TeamTalk Account
 
 # Bot Command Descriptions
 cmd-desc-menu = Show main menu with all commands
 cmd-desc-who = Show online users in TeamTalk
+cmd-desc-seen = Show when a TeamTalk user was last online
+cmd-desc-graph = Chart of online users over time
 cmd-desc-help = Show this help message
 cmd-desc-settings = Access interactive settings menu
 cmd-desc-kick = Kick TT user (admin, via buttons)
 cmd-desc-ban = Ban TT user (admin, via buttons)
+cmd-desc-move = Move TT user to another channel (admin, via buttons)
 cmd-desc-unban = Unban user (shows a list of banned users)
+cmd-desc-serverbans = TeamTalk server ban list (admin)
+cmd-desc-channels = Manage TT channels (admin)
+cmd-desc-accounts = Manage TT user accounts (admin)
+cmd-desc-server = View and edit TT server properties (admin)
 cmd-desc-subscribers = View and manage subscribed users
 cmd-desc-exit = Shut down the bot
 cmd-desc-broadcast = Send a TeamTalk broadcast message
 cmd-desc-message = Send a Telegram message to subscribers
+cmd-desc-queue = Show and manage the stream queue
+cmd-desc-play = Play a track from the music library
+cmd-desc-playlist = Manage playlists
 val-none = None
+
+# Seen
+seen-usage = Usage: /seen <username>
+seen-online = { $nickname } ({ $username }) is online now in { $channel } on { $server }.
+seen-last = { $nickname } ({ $username }) was last seen { $ago } ago on { $server }, stayed { $stayed }.
+seen-unknown = No sessions recorded for { $username }.
+
+# Channel Files
+btn-download-file = ⬇️ Download
+file-download-started = Downloading the file…
+file-download-not-found = The file is no longer available on the server.
+file-download-too-large = { $file } is larger than { $limit } MB and cannot be sent.
+file-download-failed = Could not download { $file } from the server.
+file-upload-started = Uploading the file to the channel…
+file-upload-progress = ⏳ Uploading { $file }: { $percent }% ({ $done } of { $size })
+file-upload-done = 📎 { $file } uploaded to { $channel }.
+file-upload-no-channel = Could not upload { $file }: the channel no longer exists.
+file-upload-not-allowed = Could not upload { $file }: the bot's TeamTalk account is not allowed to upload files.
+file-upload-quota = Could not upload { $file }: the channel's disk quota would be exceeded.
+file-upload-failed = Could not upload { $file } to the server.
+
+# User Moves
+move-done = Moved { $name } to { $channel }.
+move-user-offline = The user is no longer online.
+move-no-channel = The channel no longer exists, so { $name } was not moved.
+move-failed = Could not move { $name }. Check that the bot has the right to move users.
+
+# Channel Administration
+channels-title = Channels on { $server }:
+channel-details =
+    📁 { $channel }
+    Topic: { $topic }
+    Password: { $password }
+    Max users: { $max }
+    Users now: { $users }
+channel-no-topic = none
+channel-password-set = set
+channel-password-none = none
+channel-not-found = This channel no longer exists.
+channel-create-prompt = Reply to this message to create a sub-channel in { $channel }:
+    name | password | topic | max users
+    Only the name is required.
+channel-create-usage = Reply with: name | password | topic | max users. The name cannot contain "/" and max users must be a positive number.
+channel-topic-prompt = Reply to this message with the new topic for { $channel }, or "-" to clear it.
+channel-password-prompt = Reply to this message with the new password for { $channel }, or "-" to remove it.
+channel-delete-confirm = Delete { $channel }? Its sub-channels are deleted too.
+channel-created = Channel { $channel } created.
+channel-topic-updated = Topic of { $channel } updated.
+channel-password-updated = Password of { $channel } updated.
+channel-deleted = Channel { $channel } deleted.
+channel-change-not-found = The channel { $channel } no longer exists.
+channel-change-not-empty = { $channel } was not deleted: it is the root channel or someone is in it.
+channel-change-failed = The server rejected the change to { $channel }. Check that the bot's account is an administrator.
+channel-change-unconfirmed = The server did not confirm the change to { $channel }. Check the channel list before trying again.
+btn-channel-create = ➕ Create sub-channel
+btn-channel-topic = ✏️ Topic
+btn-channel-password = 🔑 Password
+btn-channel-delete = 🗑 Delete
+
+# Account Administration
+accounts-title = TeamTalk accounts on { $server } ({ $count }):
+accounts-search-title = Accounts on { $server } matching "{ $query }" ({ $count }):
+account-label-admin = { $username } (admin)
+account-details =
+    👤 { $username }
+    Type: { $account_type }
+    Note: { $note }
+    Status: { $status }
+account-type-admin = administrator
+account-type-default = regular user
+account-no-note = none
+account-status-online = online
+account-status-offline = offline
+account-not-found = This account no longer exists.
+account-search-prompt = Reply to this message with part of a username or note to search for.
+account-create-prompt = Reply to this message to create an account:
+    username | password | note
+    The note is optional.
+account-create-usage = Reply with: username | password | note. The username and password are required.
+account-password-prompt = Reply to this message with the new password for { $username }.
+account-password-usage = The password cannot be empty.
+account-note-prompt = Reply to this message with the new note for { $username }, or "-" to clear it.
+account-delete-confirm = Delete the account { $username }?
+account-created = Account { $username } created.
+account-password-updated = Password of { $username } changed.
+account-note-updated = Note of { $username } updated.
+account-type-updated = Type of { $username } changed.
+account-deleted = Account { $username } deleted.
+account-change-not-found = The account { $username } no longer exists.
+account-change-exists = An account named { $username } already exists.
+account-change-failed = The server rejected the change to { $username }. Check that the bot's account is an administrator.
+account-change-unconfirmed = The server did not confirm the change to { $username }. Check the account list before trying again.
+btn-account-search = 🔎 Search
+btn-account-create = ➕ New account
+btn-account-password = 🔑 Reset password
+btn-account-note = ✏️ Note
+btn-account-make-admin = 🛡 Make admin
+btn-account-make-default = 👤 Make regular user
+btn-account-delete = 🗑 Delete
+
+# Stream Queue
+queue-loading = Loading the stream queue…
+queue-title = 🎵 Stream queue on { $server }
+queue-now-playing = ▶️ { $title } — { $elapsed } / { $total }
+queue-now-paused = ⏸ { $title } — { $elapsed } / { $total }
+queue-idle = Nothing is playing.
+queue-empty = The queue is empty.
+queue-item = { $pos }. { $title } ({ $total })
+queue-item-voice = Voice message
+queue-more = …and { $count } more
+btn-queue-top = ⬆️ { $pos }
+btn-queue-remove = ❌ { $pos }
+btn-queue-pause = ⏸ Pause
+btn-queue-resume = ▶️ Resume
+btn-queue-skip = ⏭ Skip
+btn-queue-clear = 🗑 Clear
+btn-queue-refresh = 🔄 Refresh
+btn-queue-shuffle-on = 🔀 Shuffle: on
+btn-queue-shuffle-off = 🔀 Shuffle: off
+btn-queue-repeat-off = 🔁 Repeat: off
+btn-queue-repeat-all = 🔁 Repeat: all
+btn-queue-repeat-one = 🔂 Repeat: one
+
+# Music library
+music-no-library = The music library is not configured.
+music-play-usage = Usage: /play <search>
+music-not-found = Nothing in the library matches "{ $query }".
+music-queued = Queued: { $title }
+music-playlist-usage =
+    Usage:
+    /playlist list
+    /playlist show <name>
+    /playlist add <name> <search>
+    /playlist play <name>
+    /playlist delete <name>
+music-playlist-not-found = Playlist "{ $name }" not found.
+music-playlists-empty = There are no playlists yet.
+music-playlists-title = Playlists:
+music-playlist-line = • { $name } ({ $count })
+music-playlist-tracks-title = Playlist "{ $name }":
+music-playlist-added = Added { $title } to "{ $name }" ({ $count } tracks).
+music-playlist-queued = Queued { $count } tracks from "{ $name }".
+music-playlist-unavailable = None of the tracks in "{ $name }" are in the library any more.
+music-playlist-deleted = Playlist "{ $name }" deleted.
+
+# Graph
+graph-usage = Usage: /graph [day|week|month]
+graph-no-data = No online statistics recorded for this period yet.
+graph-caption = Online users for the { $period }: peak { $peak }, average { $average }.
+graph-period-day = last 24 hours
+graph-period-week = last 7 days
+graph-period-month = last 30 days
+graph-axis-time = Time (UTC)
+graph-axis-users = Users online
+duration-seconds = { $count }s
+duration-minutes = { $count }m
+duration-hours = { $count }h
+duration-days = { $count }d
+
+# Activity Report
+report-title-daily = Daily activity report
+report-title-weekly = Weekly activity report
+report-peak = Peak concurrent users: { $count }
+report-unique = Unique visitors: { $count }
+report-longest-header = Longest sessions:
+report-longest-row = • { $nickname } — { $duration }
+report-channels-header = Channels where sessions ended:
+report-channel-row = • { $channel } — { $count } sessions
+report-new-subs = New subscribers: { $count }
+report-bans = Bans issued: { $count }
+report-none = • none
+
+# Registration
+cmd-desc-register = Request a TeamTalk account
+register-usage = Usage: /register <username>. An administrator will review the request and the bot will send you the password.
+register-invalid-username = Usernames must be 2 to 32 characters long and may only contain Latin letters, digits, ".", "_" and "-".
+register-private-only = Please send /register to the bot in a private chat.
+register-already-linked = Your Telegram account is already linked to the TeamTalk account { $username }.
+register-username-taken = The username { $username } is already taken. Please choose another one.
+register-pending = You already have a registration request waiting for approval.
+register-submitted = Your request for the account { $username } has been sent to the administrators. You will get a message once it is reviewed.
+register-rejected = Your request for the TeamTalk account { $username } was declined.
+register-approved =
+    Your TeamTalk account on { $server } is ready.
+    Username: <code>{ $username }</code>
+    Password: <code>{ $password }</code>
+    Keep this password safe; you can ask an administrator to reset it.
+registration-request =
+    <b>New account request</b>
+    From: { $name } (<code>{ $id }</code>)
+    Server: { $server }
+    Username: <code>{ $username }</code>
+registration-account-note = Registered via Telegram (id { $id })
+registration-not-found = This request has already been handled.
+registration-in-progress = This request is already being approved.
+registration-rejected-admin = Request for { $username } rejected.
+registration-completed-admin = Account <code>{ $username }</code> created and the password sent to the applicant.
+btn-registration-approve = ✅ Approve
+btn-registration-reject = ❌ Reject
+
+# Server Properties
+server-details =
+    🖥 { $name }
+    MOTD: { $motd }
+    Max users: { $max }
+    Login delay: { $delay } ms
+    Server version: { $version }
+server-no-motd = none
+server-info-unavailable = The properties of { $server } are not available yet. The bot is not logged in to this server.
+server-name-prompt = Reply to this message with the new server name.
+server-motd-prompt = Reply to this message with the new MOTD, or "-" to clear it.
+server-name-usage = The server name cannot be empty.
+server-name-updated = Server name changed.
+server-motd-updated = MOTD updated.
+server-edit-failed = The server rejected the change. Check that the bot's account is an administrator.
+server-info-changed =
+    <b>Server properties of { $server } changed</b>
+    { $changes }
+server-change-name = Name: { $old } → { $new }
+server-change-motd = MOTD: { $old } → { $new }
+server-change-max-users = Max users: { $old } → { $new }
+server-change-login-delay = Login delay: { $old } → { $new } ms
+server-change-version = Version: { $old } → { $new }
+btn-server-name = ✏️ Name
+btn-server-motd = 📝 MOTD
+btn-server-refresh = 🔄 Refresh
+
+# Connection Health
+health-down-admin = ⚠️ Lost connection to { $server }. The bot keeps reconnecting.
+health-login-failing-admin = ⚠️ { $server } rejected the bot's login { $attempts } times in a row. Check the account name, password and server bans.
+health-restored-admin = ✅ Connection to { $server } restored after { $downtime }.
+health-down = ⚠️ { $server } is unreachable. Notifications are paused until the connection is back.
+health-restored = ✅ { $server } is back online (down for { $downtime }).
@@ -3,23 +3,37 @@ hello-start = Привет! Используйте /help для просмотр
 help-text =
     <b>Доступные команды:</b>
     /who - Показать онлайн пользователей.
+    /seen &lt;имя&gt; - Показать, когда пользователь был в сети.
+    /graph [day|week|month] - График онлайна пользователей.
     /settings - Доступ к интерактивному меню настроек (язык, уведомления, списки игнора, функция «Только офлайн»).
     /unsub - Отписаться от уведомлений.
+    /register &lt;имя&gt; - Запросить учётную запись TeamTalk (после одобрения администратором).
     /help - Показать это сообщение.
     (Примечание: <code>/start</code> используется для запуска бота и обработки deeplink-ссылок.)
 
     <b>Команды для администраторов:</b>
     /kick - Кикнуть пользователя с сервера (через кнопки).
     /ban - Забанить пользователя на сервере (через кнопки).
+    /ban <IP или имя> - Забанить адрес или учётную запись на сервере TeamTalk, даже если пользователь не в сети.
+    /move - Переместить пользователя в другой канал (через кнопки).
     /unban - Разбанить пользователя (показывает список забаненных).
+    /serverbans - Показать список банов сервера TeamTalk.
+    /channels - Каналы: создание подканалов, смена темы и пароля, удаление пустых каналов.
+    /accounts [поиск] - Учётные записи TeamTalk: список, поиск, создание, изменение и удаление.
+    /server - Показать имя сервера, MOTD и лимиты, изменить имя или MOTD.
     /subscribers - Просмотр и управление подписчиками.
     /broadcast - Отправить объявление всем пользователям TeamTalk.
     /message - Отправить сообщение всем подписчикам в Telegram.
+    /queue - Показать очередь воспроизведения и управлять ею.
+    /play &lt;поиск&gt; - Поставить в очередь трек из музыкальной библиотеки.
+    /playlist [list|show|add|play|delete] - Управление плейлистами.
     /exit - Выключить бота.
 
 cmd-invalid-deeplink = Недействительная или истекшая ссылка.
 cmd-success-sub = Вы успешно подписались на уведомления.
 cmd-success-sub-guest = Вы подписались как ГОСТЬ. Примечание: режим «Только офлайн» недоступен.
+cmd-success-sub-chat = Этот чат подписан на уведомления. Администраторы чата могут настроить их командой /settings.
+cmd-chat-admin-only = Менять подписку и настройки этого чата могут только его администраторы.
 cmd-success-unsub = Вы успешно отписались от уведомлений.
 cmd-relink = TeamTalk аккаунт успешно привязан!
 cmd-fail-account = У вашего аккаунта TeamTalk должен быть username для подписки.
@@ -52,6 +66,17 @@ btn-no = Нет
 # Notifications
 event-join = { $nickname } присоединился к серверу { $server }
 event-leave = { $nickname } покинул сервер { $server }
+event-channel-join = { $nickname } зашёл в канал { $channel } на сервере { $server }
+event-channel-leave = { $nickname } вышел из канала { $channel } на сервере { $server }
+event-nick-change = { $old } теперь известен как { $nickname } на сервере { $server }
+event-status-change = { $nickname } сменил статус на { $status } на сервере { $server }
+event-server-broadcast = 📢 Объявление от { $nickname } на сервере { $server }: { $text }
+event-file-upload = 📎 { $nickname } загрузил(а) { $text } ({ $size }) в канал { $channel } на сервере { $server }
+status-presence-available = доступен
+status-presence-away = отошёл
+status-presence-question = режим вопроса
+digest-title = <b>Сводка активности</b> (событий: { $count }):
+digest-more = …и ещё { $count }
 
 # Settings Menu
 settings-title = <b>Настройки</b>
@@ -59,12 +84,36 @@ msg-choose-lang = Пожалуйста, выберите ваш язык:
 btn-lang = Язык (Language)
 btn-sub-settings = Настройки подписки
 btn-notif-settings = Настройки уведомлений
+btn-server-settings = Серверы
+
+# Server Settings
+server-settings-title = <b>Серверы</b>
+    Выберите, с каких серверов TeamTalk присылать уведомления о входе и выходе.
+resp-server-toggled = Уведомления с сервера { $server }: { $status }
 
 # Notification Settings
 notif-settings-title = <b>Настройки уведомлений</b>
+delivery-settings-title = <b>Режим доставки</b>
+btn-delivery-instant = { $marker } Каждое событие
+btn-delivery-batched = { $marker } Пачкой раз в { $minutes } мин
+btn-delivery-hourly = { $marker } Сводка раз в час
 btn-noon = Только офлайн: { $status }
+btn-channel-notif = Перемещения по каналам: { $status }
+btn-nick-notif = Смена ников: { $status }
+btn-status-notif = Смена статуса: { $status }
+btn-broadcast-notif = Объявления сервера: { $status }
+btn-file-notif = Загрузки файлов: { $status }
+btn-health-notif = Сервер недоступен / снова в сети: { $status }
+btn-delivery-settings = Режим доставки
 btn-mute-manage = Управление списком игнора
 resp-noon-updated = Статус «Только офлайн» обновлён: { $status }
+resp-channel-notif-updated = Уведомления о перемещениях по каналам: { $status }
+resp-nick-notif-updated = Уведомления о смене ников: { $status }
+resp-status-notif-updated = Уведомления о смене статуса: { $status }
+resp-broadcast-notif-updated = Уведомления об объявлениях сервера: { $status }
+resp-file-notif-updated = Уведомления о загрузке файлов: { $status }
+resp-health-notif-updated = Уведомления о доступности сервера: { $status }
+resp-delivery-updated = Режим доставки изменён на: { $text }.
 
 # Mute Management
 mute-title = <b>Управление списком игнора</b>
@@ -100,8 +149,11 @@ item-status-whitelist-in = ✅ { $name } (в белом списке)
 item-status-whitelist-out = 🚫 { $name } (не в белом списке)
 
 # Pagination / Lists
+server-picker-title = Выберите сервер TeamTalk:
 list-kick-title = Выберите пользователя для кика с сервера { $server }:
 list-ban-title = Выберите пользователя для бана на сервере { $server }:
+list-move-title = Выберите пользователя для перемещения на сервере { $server }:
+move-channel-title = Выберите канал, куда переместить { $name }:
 list-unban-title = Забаненные пользователи
 list-subs-title = Вот список подписчиков.
 list-mute-title = Список игнора для: { $name }
@@ -110,6 +162,18 @@ list-link-title = Выберите учетную запись TeamTalk для 
 list-empty = Список пуст.
 list-subs-empty = Подписчики не найдены.
 list-ban-empty = Список забаненных пуст.
+ban-term-title = На какой срок забанить { $name }?
+ban-reason-prompt = Бан { $name } ({ $term }). Ответьте на это сообщение, указав причину, или нажмите кнопку, чтобы забанить без причины.
+ban-done = { $name } забанен ({ $term }).
+ban-done-reason = { $name } забанен ({ $term }). Причина: { $reason }
+ban-term-hour = 1 час
+ban-term-day = 1 день
+ban-term-week = 7 дней
+ban-term-permanent = навсегда
+ban-time-left = осталось { $time }
+ban-lifted = Бан { $address } снят на сервере TeamTalk.
+ban-lift-failed = Сервер TeamTalk отказался снимать бан { $address }. Бан остаётся в списке.
+ban-lift-unconfirmed = Сервер TeamTalk не подтвердил снятие бана { $address }. Бан остаётся в списке; попробуйте позже.
 list-mute-empty = Список игнора пуст.
 list-page = Страница { $current }/{ $total }
 
@@ -121,6 +185,12 @@ btn-back-notif = Назад в Уведомления
 btn-back-mute = Назад в меню игнора
 btn-back-menu = Назад в Главное меню
 btn-back-subs = Назад к списку подписчиков
+btn-back-users = Назад к пользователям
+btn-back-channels = Назад к каналам
+btn-back-channel = Назад к каналу
+btn-back-accounts = Назад к учётным записям
+btn-back-server = Назад к серверу
+btn-back-account = Назад к учётной записи
 btn-back-user-actions = Назад к действиям пользователя
 btn-back-manage-acc = Назад к управлению аккаунтом
 
@@ -131,6 +201,11 @@ toast-lang-updated = Язык был изменен.
 toast-command-sent = Команда отправлена.
 toast-user-banned = Пользователь был забанен, а его профиль удален.
 toast-user-unbanned = Пользователь успешно разбанен.
+toast-unban-sent = Снимаем бан на сервере TeamTalk…
+server-bans-title = Баны на { $server } ({ $count }):
+server-bans-empty = На { $server } нет банов.
+server-ban-usage = Использование: /ban <IP-адрес или имя пользователя>
+server-ban-sent = Бан для { $name } отправлен на сервер TeamTalk.
 toast-subscriber-deleted = Подписчик успешно удален.
 toast-account-unlinked = Аккаунт { $user } был отвязан.
 toast-account-linked = Аккаунт TeamTalk успешно привязан: { $user }.
@@ -163,6 +238,8 @@ tg-reply-offline = Пользователь не в сети.
 tt-msg-sent = Сообщение успешно отправлено в Telegram.
 tt-msg-failed = Не удалось доставить сообщение в Telegram.
 tt-channel-reply = Ответ на: { $msg } (длительность { $duration })
+tt-channel-reply-track = Ответ на: { $msg } — { $title } (длительность { $duration })
+tt-channel-reply-track-untimed = Ответ на: { $msg } — { $title }
 tt-channel-reply-text =
     Ответ на: { $msg }
     { $reply }
@@ -201,6 +278,8 @@ tt-admin-help-cmds =
     /add_admin <Telegram ID> [<Telegram ID>...] - Добавить админа бота.
     /remove_admin <Telegram ID> [<Telegram ID>...] - Удалить админа бота.
     /skip - Пропустить медиа.
+    /play <поиск> - Поставить в очередь трек из музыкальной библиотеки.
+    /playlist [list|show|add|play|delete] - Управление плейлистами.
 
 tt-report-header =
     На сервере { $server } сейчас { $count ->
@@ -211,6 +290,7 @@ tt-report-header =
 tt-report-unauth = (не в канале)
 tt-sub-fail-nouser = У вашего аккаунта TeamTalk должен быть установлен username для подписки.
 tt-sub-link = Нажмите на эту ссылку, чтобы подписаться на уведомления: { $link }
+tt-sub-group-link = Чтобы подписать группу Telegram, откройте { $link }. Для канала опубликуйте в нём /start { $token }.
 tt-unsub-link = Нажмите на эту ссылку, чтобы отписаться от уведомлений: { $link }
 tt-error-generic = Ошибка. Попробуйте позже.
 
@@ -240,6 +320,10 @@ btn-menu-settings = ⚙️ Настройки
 btn-menu-help = ❓ Помощь
 btn-menu-kick = 👢 Кикнуть пользователя
 btn-menu-ban = 🚫 Забанить пользователя
+btn-menu-move = 🔀 Переместить пользователя
+btn-menu-channels = 📁 Каналы
+btn-menu-accounts = 👥 Учётные записи
+btn-menu-server = 🖥 Сервер
 btn-menu-unban = ✅ Разбанить пользователя
 btn-menu-subs = 👥 Подписчики
 btn-menu-unsub = 🚪 Отписаться
@@ -266,19 +350,269 @@ btn-sub-notif = 🔔 Установить настройки уведомлен
 btn-sub-mute-mode = 🔇 Установить режим игнорирования
 btn-sub-view-mute = 📜 Просмотреть список игнорирования
 btn-unban = ✅ Разбанить
+btn-server-unban = ✅ Разбанить { $name }
+btn-refresh = 🔄 Обновить
+btn-ban-hour = ⏱ 1 час
+btn-ban-day = 📅 1 день
+btn-ban-week = 🗓 7 дней
+btn-ban-permanent = ♾ Навсегда
+btn-ban-no-reason = 🚫 Забанить без причины
 btn-unlink = ➖ Отвязать { $user }
 btn-link-new = ➕ Привязать/Изменить аккаунт TeamTalk
 
 # Bot Command Descriptions
 cmd-desc-menu = Показать главное меню со всеми командами
 cmd-desc-who = Показать онлайн пользователей в TeamTalk
+cmd-desc-seen = Показать, когда пользователь TeamTalk был в сети
+cmd-desc-graph = График онлайна пользователей
 cmd-desc-help = Показать это справочное сообщение
 cmd-desc-settings = Доступ к интерактивному меню настроек
 cmd-desc-kick = Кикнуть пользователя TT (админ, через кнопки)
 cmd-desc-ban = Забанить пользователя TT (админ, через кнопки)
+cmd-desc-move = Переместить пользователя TT в другой канал (админ, через кнопки)
 cmd-desc-unban = Разбанить пользователя (показывает список забаненных)
+cmd-desc-serverbans = Список банов сервера TeamTalk (админ)
+cmd-desc-channels = Управление каналами TT (админ)
+cmd-desc-accounts = Управление учётными записями TT (админ)
+cmd-desc-server = Свойства сервера TT (админ)
 cmd-desc-subscribers = Просмотр и управление подписанными пользователями
 cmd-desc-exit = Выключить бота
 cmd-desc-broadcast = Отправить объявление в TeamTalk
 cmd-desc-message = Отправить сообщение подписчикам в Telegram
+cmd-desc-queue = Очередь воспроизведения
+cmd-desc-play = Включить трек из музыкальной библиотеки
+cmd-desc-playlist = Управление плейлистами
 val-none = Нет
+
+# Seen
+seen-usage = Использование: /seen <имя пользователя>
+seen-online = { $nickname } ({ $username }) сейчас в сети, канал { $channel } на сервере { $server }.
+seen-last = { $nickname } ({ $username }) последний раз был в сети { $ago } назад на сервере { $server }, пробыл { $stayed }.
+seen-unknown = Нет записанных сессий для { $username }.
+
+# Channel Files
+btn-download-file = ⬇️ Скачать
+file-download-started = Скачиваю файл…
+file-download-not-found = Файла больше нет на сервере.
+file-download-too-large = { $file } больше { $limit } МБ и не может быть отправлен.
+file-download-failed = Не удалось скачать { $file } с сервера.
+file-upload-started = Загружаю файл в канал…
+file-upload-progress = ⏳ Загрузка { $file }: { $percent }% ({ $done } из { $size })
+file-upload-done = 📎 { $file } загружен в { $channel }.
+file-upload-no-channel = Не удалось загрузить { $file }: канал больше не существует.
+file-upload-not-allowed = Не удалось загрузить { $file }: учётной записи бота в TeamTalk запрещено загружать файлы.
+file-upload-quota = Не удалось загрузить { $file }: будет превышена дисковая квота канала.
+file-upload-failed = Не удалось загрузить { $file } на сервер.
+
+# User Moves
+move-done = { $name } перемещён в { $channel }.
+move-user-offline = Пользователь уже не в сети.
+move-no-channel = Канал больше не существует, { $name } не перемещён.
+move-failed = Не удалось переместить { $name }. Проверьте, есть ли у бота право перемещать пользователей.
+
+# Channel Administration
+channels-title = Каналы на сервере { $server }:
+channel-details =
+    📁 { $channel }
+    Тема: { $topic }
+    Пароль: { $password }
+    Макс. пользователей: { $max }
+    Сейчас в канале: { $users }
+channel-no-topic = нет
+channel-password-set = установлен
+channel-password-none = нет
+channel-not-found = Этот канал больше не существует.
+channel-create-prompt = Ответьте на это сообщение, чтобы создать подканал в { $channel }:
+    имя | пароль | тема | макс. пользователей
+    Обязательно только имя.
+channel-create-usage = Ответьте в формате: имя | пароль | тема | макс. пользователей. Имя не может содержать "/", а число пользователей должно быть положительным.
+channel-topic-prompt = Ответьте на это сообщение новой темой для { $channel } или "-", чтобы её убрать.
+channel-password-prompt = Ответьте на это сообщение новым паролем для { $channel } или "-", чтобы его убрать.
+channel-delete-confirm = Удалить { $channel }? Его подканалы тоже будут удалены.
+channel-created = Канал { $channel } создан.
+channel-topic-updated = Тема канала { $channel } обновлена.
+channel-password-updated = Пароль канала { $channel } обновлён.
+channel-deleted = Канал { $channel } удалён.
+channel-change-not-found = Канал { $channel } больше не существует.
+channel-change-not-empty = { $channel } не удалён: это корневой канал или в нём есть пользователи.
+channel-change-failed = Сервер отклонил изменение канала { $channel }. Проверьте, что учётная запись бота — администратор.
+channel-change-unconfirmed = Сервер не подтвердил изменение канала { $channel }. Проверьте список каналов, прежде чем повторять.
+btn-channel-create = ➕ Создать подканал
+btn-channel-topic = ✏️ Тема
+btn-channel-password = 🔑 Пароль
+btn-channel-delete = 🗑 Удалить
+
+# Account Administration
+accounts-title = Учётные записи TeamTalk на сервере { $server } ({ $count }):
+accounts-search-title = Учётные записи на сервере { $server } по запросу "{ $query }" ({ $count }):
+account-label-admin = { $username } (админ)
+account-details =
+    👤 { $username }
+    Тип: { $account_type }
+    Заметка: { $note }
+    Статус: { $status }
+account-type-admin = администратор
+account-type-default = обычный пользователь
+account-no-note = нет
+account-status-online = в сети
+account-status-offline = не в сети
+account-not-found = Эта учётная запись больше не существует.
+account-search-prompt = Ответьте на это сообщение частью имени пользователя или заметки для поиска.
+account-create-prompt = Ответьте на это сообщение, чтобы создать учётную запись:
+    имя пользователя | пароль | заметка
+    Заметка необязательна.
+account-create-usage = Ответьте в формате: имя пользователя | пароль | заметка. Имя пользователя и пароль обязательны.
+account-password-prompt = Ответьте на это сообщение новым паролем для { $username }.
+account-password-usage = Пароль не может быть пустым.
+account-note-prompt = Ответьте на это сообщение новой заметкой для { $username } или "-", чтобы её убрать.
+account-delete-confirm = Удалить учётную запись { $username }?
+account-created = Учётная запись { $username } создана.
+account-password-updated = Пароль { $username } изменён.
+account-note-updated = Заметка { $username } обновлена.
+account-type-updated = Тип учётной записи { $username } изменён.
+account-deleted = Учётная запись { $username } удалена.
+account-change-not-found = Учётная запись { $username } больше не существует.
+account-change-exists = Учётная запись { $username } уже существует.
+account-change-failed = Сервер отклонил изменение учётной записи { $username }. Проверьте, что учётная запись бота — администратор.
+account-change-unconfirmed = Сервер не подтвердил изменение учётной записи { $username }. Проверьте список учётных записей, прежде чем повторять.
+btn-account-search = 🔎 Поиск
+btn-account-create = ➕ Новая учётная запись
+btn-account-password = 🔑 Сбросить пароль
+btn-account-note = ✏️ Заметка
+btn-account-make-admin = 🛡 Сделать админом
+btn-account-make-default = 👤 Сделать обычным
+btn-account-delete = 🗑 Удалить
+
+# Stream Queue
+queue-loading = Загружаю очередь воспроизведения…
+queue-title = 🎵 Очередь воспроизведения на сервере { $server }
+queue-now-playing = ▶️ { $title } — { $elapsed } / { $total }
+queue-now-paused = ⏸ { $title } — { $elapsed } / { $total }
+queue-idle = Сейчас ничего не играет.
+queue-empty = Очередь пуста.
+queue-item = { $pos }. { $title } ({ $total })
+queue-item-voice = Голосовое сообщение
+queue-more = …и ещё { $count }
+btn-queue-top = ⬆️ { $pos }
+btn-queue-remove = ❌ { $pos }
+btn-queue-pause = ⏸ Пауза
+btn-queue-resume = ▶️ Продолжить
+btn-queue-skip = ⏭ Пропустить
+btn-queue-clear = 🗑 Очистить
+btn-queue-refresh = 🔄 Обновить
+btn-queue-shuffle-on = 🔀 Перемешать: вкл
+btn-queue-shuffle-off = 🔀 Перемешать: выкл
+btn-queue-repeat-off = 🔁 Повтор: выкл
+btn-queue-repeat-all = 🔁 Повтор: все
+btn-queue-repeat-one = 🔂 Повтор: один
+
+# Music library
+music-no-library = Музыкальная библиотека не настроена.
+music-play-usage = Использование: /play <поиск>
+music-not-found = В библиотеке нет ничего похожего на «{ $query }».
+music-queued = В очереди: { $title }
+music-playlist-usage =
+    Использование:
+    /playlist list
+    /playlist show <название>
+    /playlist add <название> <поиск>
+    /playlist play <название>
+    /playlist delete <название>
+music-playlist-not-found = Плейлист «{ $name }» не найден.
+music-playlists-empty = Плейлистов пока нет.
+music-playlists-title = Плейлисты:
+music-playlist-line = • { $name } ({ $count })
+music-playlist-tracks-title = Плейлист «{ $name }»:
+music-playlist-added = { $title } добавлен в «{ $name }» (треков: { $count }).
+music-playlist-queued = В очередь поставлено треков из «{ $name }»: { $count }.
+music-playlist-unavailable = Ни одного трека из «{ $name }» больше нет в библиотеке.
+music-playlist-deleted = Плейлист «{ $name }» удалён.
+
+# Graph
+graph-usage = Использование: /graph [day|week|month]
+graph-no-data = За этот период статистика онлайна ещё не собрана.
+graph-caption = Пользователи онлайн за { $period }: максимум { $peak }, в среднем { $average }.
+graph-period-day = последние 24 часа
+graph-period-week = последние 7 дней
+graph-period-month = последние 30 дней
+graph-axis-time = Время (UTC)
+graph-axis-users = Пользователей онлайн
+duration-seconds = { $count } с
+duration-minutes = { $count } мин
+duration-hours = { $count } ч
+duration-days = { $count } д
+
+# Activity Report
+report-title-daily = Ежедневный отчёт об активности
+report-title-weekly = Еженедельный отчёт об активности
+report-peak = Пик одновременных пользователей: { $count }
+report-unique = Уникальных посетителей: { $count }
+report-longest-header = Самые долгие сессии:
+report-longest-row = • { $nickname } — { $duration }
+report-channels-header = Каналы, в которых завершались сессии:
+report-channel-row = • { $channel } — сессий: { $count }
+report-new-subs = Новых подписчиков: { $count }
+report-bans = Выдано банов: { $count }
+report-none = • нет
+
+# Registration
+cmd-desc-register = Запросить учётную запись TeamTalk
+register-usage = Использование: /register <имя>. Администратор рассмотрит заявку, и бот пришлёт вам пароль.
+register-invalid-username = Имя должно быть длиной от 2 до 32 символов и может содержать только латинские буквы, цифры, «.», «_» и «-».
+register-private-only = Отправьте /register боту в личном чате.
+register-already-linked = Ваш Telegram уже привязан к учётной записи TeamTalk { $username }.
+register-username-taken = Имя { $username } уже занято. Выберите другое.
+register-pending = У вас уже есть заявка, ожидающая рассмотрения.
+register-submitted = Заявка на учётную запись { $username } отправлена администраторам. Вы получите сообщение, когда её рассмотрят.
+register-rejected = Ваша заявка на учётную запись TeamTalk { $username } отклонена.
+register-approved =
+    Ваша учётная запись TeamTalk на { $server } готова.
+    Имя: <code>{ $username }</code>
+    Пароль: <code>{ $password }</code>
+    Сохраните пароль; сбросить его может администратор.
+registration-request =
+    <b>Новая заявка на учётную запись</b>
+    От: { $name } (<code>{ $id }</code>)
+    Сервер: { $server }
+    Имя: <code>{ $username }</code>
+registration-account-note = Зарегистрирован через Telegram (id { $id })
+registration-not-found = Эта заявка уже обработана.
+registration-in-progress = Эта заявка уже одобряется.
+registration-rejected-admin = Заявка на { $username } отклонена.
+registration-completed-admin = Учётная запись <code>{ $username }</code> создана, пароль отправлен заявителю.
+btn-registration-approve = ✅ Одобрить
+btn-registration-reject = ❌ Отклонить
+
+# Server Properties
+server-details =
+    🖥 { $name }
+    MOTD: { $motd }
+    Максимум пользователей: { $max }
+    Задержка входа: { $delay } мс
+    Версия сервера: { $version }
+server-no-motd = нет
+server-info-unavailable = Свойства { $server } пока недоступны: бот не вошёл на этот сервер.
+server-name-prompt = Ответьте на это сообщение новым именем сервера.
+server-motd-prompt = Ответьте на это сообщение новым MOTD или «-», чтобы очистить его.
+server-name-usage = Имя сервера не может быть пустым.
+server-name-updated = Имя сервера изменено.
+server-motd-updated = MOTD обновлено.
+server-edit-failed = Сервер отклонил изменение. Убедитесь, что учётная запись бота — администратор.
+server-info-changed =
+    <b>Свойства сервера { $server } изменены</b>
+    { $changes }
+server-change-name = Имя: { $old } → { $new }
+server-change-motd = MOTD: { $old } → { $new }
+server-change-max-users = Максимум пользователей: { $old } → { $new }
+server-change-login-delay = Задержка входа: { $old } → { $new } мс
+server-change-version = Версия: { $old } → { $new }
+btn-server-name = ✏️ Имя
+btn-server-motd = 📝 MOTD
+btn-server-refresh = 🔄 Обновить
+
+# Connection Health
+health-down-admin = ⚠️ Потеряно соединение с { $server }. Бот продолжает переподключаться.
+health-login-failing-admin = ⚠️ { $server } отклонил вход бота { $attempts } раз подряд. Проверьте имя учётной записи, пароль и баны на сервере.
+health-restored-admin = ✅ Соединение с { $server } восстановлено спустя { $downtime }.
+health-down = ⚠️ { $server } недоступен. Уведомления приостановлены до восстановления соединения.
+health-restored = ✅ { $server } снова в сети (был недоступен { $downtime }).
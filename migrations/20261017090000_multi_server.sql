@@ -0,0 +1,13 @@
+-- Servers a subscriber opted out of; every configured server is followed by default.
+CREATE TABLE IF NOT EXISTS ignored_servers (
+    telegram_id INTEGER NOT NULL,
+    server_key TEXT NOT NULL,
+    PRIMARY KEY (telegram_id, server_key),
+    FOREIGN KEY(telegram_id) REFERENCES user_settings(telegram_id)
+);
+
+-- Remember which TeamTalk server a pending reply belongs to, by server key so that
+-- reordering the [[teamtalk]] blocks does not misroute stored replies.
+-- An empty key (rows from before this migration) means the first server.
+ALTER TABLE pending_replies ADD COLUMN server_key TEXT NOT NULL DEFAULT '';
+ALTER TABLE pending_channel_replies ADD COLUMN server_key TEXT NOT NULL DEFAULT '';
@@ -0,0 +1,2 @@
+ALTER TABLE user_settings ADD COLUMN nick_notifications_enabled BOOLEAN NOT NULL DEFAULT 0;
+ALTER TABLE user_settings ADD COLUMN status_notifications_enabled BOOLEAN NOT NULL DEFAULT 0;
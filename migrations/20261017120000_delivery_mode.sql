@@ -0,0 +1 @@
+ALTER TABLE user_settings ADD COLUMN delivery_mode TEXT NOT NULL DEFAULT 'instant';
@@ -0,0 +1,15 @@
+CREATE TABLE IF NOT EXISTS user_sessions (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    server_key TEXT NOT NULL,
+    username TEXT NOT NULL,
+    nickname TEXT NOT NULL,
+    channel TEXT,
+    login_time DATETIME NOT NULL,
+    logout_time DATETIME
+);
+
+CREATE INDEX IF NOT EXISTS idx_user_sessions_username
+    ON user_sessions(username, login_time);
+
+CREATE INDEX IF NOT EXISTS idx_user_sessions_open
+    ON user_sessions(server_key, username, logout_time);
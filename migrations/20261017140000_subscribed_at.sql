@@ -0,0 +1 @@
+ALTER TABLE subscribed_users ADD COLUMN subscribed_at DATETIME;
@@ -0,0 +1,9 @@
+CREATE TABLE IF NOT EXISTS online_samples (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    server_key TEXT NOT NULL,
+    sampled_at DATETIME NOT NULL,
+    user_count INTEGER NOT NULL CHECK (user_count >= 0)
+);
+
+CREATE INDEX IF NOT EXISTS idx_online_samples_time
+    ON online_samples(sampled_at);
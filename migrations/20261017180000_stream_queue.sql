@@ -0,0 +1,13 @@
+CREATE TABLE IF NOT EXISTS stream_queue (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    server_key TEXT NOT NULL,
+    position INTEGER NOT NULL CHECK (position >= 0),
+    channel_id INTEGER NOT NULL,
+    file_path TEXT NOT NULL,
+    duration_ms INTEGER NOT NULL CHECK (duration_ms >= 0),
+    announce_text TEXT,
+    title TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_stream_queue_server
+    ON stream_queue(server_key, position);
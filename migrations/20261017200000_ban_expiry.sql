@@ -0,0 +1,7 @@
+ALTER TABLE ban_list ADD COLUMN expires_at DATETIME;
+ALTER TABLE ban_list ADD COLUMN server_key TEXT;
+ALTER TABLE ban_list ADD COLUMN ip_address TEXT;
+ALTER TABLE ban_list ADD COLUMN channel_id INTEGER;
+
+CREATE INDEX IF NOT EXISTS idx_ban_list_expires_at
+    ON ban_list(expires_at) WHERE expires_at IS NOT NULL;
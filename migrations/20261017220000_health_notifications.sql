@@ -0,0 +1 @@
+ALTER TABLE user_settings ADD COLUMN health_notifications_enabled BOOLEAN NOT NULL DEFAULT 0;
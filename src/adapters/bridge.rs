@@ -1,37 +1,52 @@
+use crate::adapters::digest::{DigestBuffer, ReadyDigest};
+use crate::adapters::tg::keyboards::{callback_button, create_queue_keyboard};
+use crate::adapters::tt::{ServerShared, remove_temp_file};
+use crate::app::services::files as files_service;
+use crate::app::services::health as health_service;
+use crate::app::services::queue as queue_service;
+use crate::app::services::server_info as server_info_service;
+use crate::app::services::user_settings as user_settings_service;
 use crate::args;
 use crate::bootstrap::config::Config;
-use crate::core::types::{self, BridgeEvent, LanguageCode, LiteUser};
+use crate::core::callbacks::FileAction;
+use crate::core::types::{self, BridgeEvent, LanguageCode, ServerId};
 use crate::infra::db::{Database, types::UserSettings};
 use crate::infra::locales;
 use std::collections::HashMap;
-use std::sync::Arc;
-use std::sync::RwLock;
+use std::sync::{Arc, Mutex};
 use teloxide::ApiError;
 use teloxide::RequestError;
 use teloxide::sugar::request::RequestReplyExt;
+use teloxide::types::{InlineKeyboardMarkup, InputFile, MessageId};
 use teloxide::{prelude::*, utils::html};
 use tokio::sync::mpsc::Sender;
 use tokio::task::JoinSet;
 
 struct BridgeDeps<'a> {
     db: &'a Database,
-    online_users: &'a Arc<RwLock<HashMap<i32, LiteUser>>>,
+    config: &'a Config,
+    servers: &'a [ServerShared],
     event_bot: Option<&'a Bot>,
     msg_bot: Option<&'a Bot>,
     message_token_present: bool,
     default_lang: LanguageCode,
     admin_id: teloxide::types::ChatId,
     tx_tt_cmd: &'a Sender<types::TtCommand>,
+    digests: &'a DigestBuffer,
+    upload_status: &'a Mutex<HashMap<String, MessageId>>,
 }
 
 struct BroadcastData {
+    server_id: ServerId,
     event_type: types::NotificationType,
     nickname: String,
     server_name: String,
     related_tt_username: String,
+    detail: Option<types::BroadcastDetail>,
 }
 
 struct AdminData {
+    server_id: ServerId,
     user_id: i32,
     nick: String,
     tt_username: String,
@@ -40,6 +55,7 @@ struct AdminData {
 }
 
 struct AdminChannelData {
+    server_id: ServerId,
     channel_id: i32,
     channel_name: String,
     server_name: String,
@@ -52,15 +68,21 @@ struct WhoReportData {
     reply_to: Option<i32>,
 }
 
+struct ChatMirrorData {
+    chat_id: i64,
+    nickname: String,
+    text: String,
+}
+
 struct BroadcastTaskCtx {
     bot: Bot,
-    online_users: Arc<RwLock<HashMap<i32, LiteUser>>>,
+    servers: Vec<ServerShared>,
     db: Database,
 }
 
 pub struct BridgeContext {
     pub db: Database,
-    pub online_users: Arc<RwLock<HashMap<i32, LiteUser>>>,
+    pub servers: Vec<ServerShared>,
     pub config: Arc<Config>,
     pub event_bot: Option<Bot>,
     pub msg_bot: Option<Bot>,
@@ -75,7 +97,7 @@ pub async fn run_bridge(
 ) {
     let BridgeContext {
         db: db_clone,
-        online_users,
+        servers,
         config,
         event_bot,
         msg_bot,
@@ -85,23 +107,41 @@ pub async fn run_bridge(
     } = ctx;
     let default_lang = config.general.default_lang;
     let admin_id = teloxide::types::ChatId(config.telegram.admin_chat_id);
+    let digests = DigestBuffer::new(std::time::Duration::from_mins(
+        config.operational_parameters.digest_batch,
+    ));
+    let upload_status = Mutex::new(HashMap::new());
     let deps = BridgeDeps {
         db: &db_clone,
-        online_users: &online_users,
+        config: &config,
+        servers: &servers,
         event_bot: event_bot.as_ref(),
         msg_bot: msg_bot.as_ref(),
         message_token_present,
         default_lang,
         admin_id,
         tx_tt_cmd: &tx_tt_cmd,
+        digests: &digests,
+        upload_status: &upload_status,
     };
 
+    let mut digest_tick = tokio::time::interval(std::time::Duration::from_secs(30));
+    digest_tick.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
     tracing::info!(component = "bridge", "Bridge task started");
     loop {
         let event = tokio::select! {
             () = cancel_token.cancelled() => {
                 break;
             }
+            _ = digest_tick.tick() => {
+                let ready = digests.take_due(std::time::Instant::now());
+                if !ready.is_empty() {
+                    let set = spawn_digests(&deps, ready);
+                    tokio::spawn(join_notification_tasks(set));
+                }
+                continue;
+            }
             maybe_event = rx_bridge.recv() => {
                 match maybe_event {
                     Some(event) => event,
@@ -112,28 +152,77 @@ pub async fn run_bridge(
 
         handle_bridge_event(&deps, event).await;
     }
+
+    let pending = digests.take_all();
+    if !pending.is_empty() {
+        tracing::info!(
+            component = "bridge",
+            count = pending.len(),
+            "Flushing pending digests"
+        );
+        join_notification_tasks(spawn_digests(&deps, pending)).await;
+    }
+}
+
+fn spawn_digests(deps: &BridgeDeps<'_>, ready: Vec<ReadyDigest>) -> JoinSet<()> {
+    let mut set = JoinSet::new();
+    let Some(bot) = deps.event_bot else {
+        return set;
+    };
+    for digest in ready {
+        let task_ctx = BroadcastTaskCtx {
+            bot: bot.clone(),
+            servers: digest
+                .servers
+                .iter()
+                .filter_map(|server_id| deps.servers.get(server_id.0).cloned())
+                .collect(),
+            db: deps.db.clone(),
+        };
+        set.spawn(async move {
+            send_broadcast_to_recipient(task_ctx, digest.sub, digest.text, None).await;
+        });
+    }
+    set
+}
+
+async fn join_notification_tasks(mut set: JoinSet<()>) {
+    while let Some(res) = set.join_next().await {
+        if let Err(e) = res {
+            tracing::error!(
+                component = "bridge",
+                error = ?e,
+                "Notification task failed after join"
+            );
+        }
+    }
 }
 
 async fn handle_bridge_event(deps: &BridgeDeps<'_>, event: BridgeEvent) {
     match event {
         types::BridgeEvent::Broadcast {
+            server_id,
             event_type,
             nickname,
             server_name,
             related_tt_username,
+            detail,
         } => {
             handle_broadcast(
                 deps,
                 BroadcastData {
+                    server_id,
                     event_type,
                     nickname,
                     server_name,
                     related_tt_username,
+                    detail,
                 },
             )
             .await;
         }
         types::BridgeEvent::ToAdmin {
+            server_id,
             user_id,
             nick,
             tt_username,
@@ -143,6 +232,7 @@ async fn handle_bridge_event(deps: &BridgeDeps<'_>, event: BridgeEvent) {
             handle_to_admin(
                 deps,
                 AdminData {
+                    server_id,
                     user_id,
                     nick,
                     tt_username,
@@ -153,6 +243,7 @@ async fn handle_bridge_event(deps: &BridgeDeps<'_>, event: BridgeEvent) {
             .await;
         }
         types::BridgeEvent::ToAdminChannel {
+            server_id,
             channel_id,
             channel_name,
             server_name,
@@ -161,6 +252,7 @@ async fn handle_bridge_event(deps: &BridgeDeps<'_>, event: BridgeEvent) {
             handle_to_admin_channel(
                 deps,
                 AdminChannelData {
+                    server_id,
                     channel_id,
                     channel_name,
                     server_name,
@@ -184,6 +276,118 @@ async fn handle_bridge_event(deps: &BridgeDeps<'_>, event: BridgeEvent) {
             )
             .await;
         }
+        event @ types::BridgeEvent::ServerBroadcast { .. } => {
+            handle_server_broadcast(deps, event).await;
+        }
+        types::BridgeEvent::ChatMirror {
+            chat_id,
+            nickname,
+            text,
+        } => {
+            handle_chat_mirror(
+                deps,
+                ChatMirrorData {
+                    chat_id,
+                    nickname,
+                    text,
+                },
+            )
+            .await;
+        }
+        event => handle_command_result(deps, event).await,
+    }
+}
+
+async fn handle_command_result(deps: &BridgeDeps<'_>, event: BridgeEvent) {
+    match event {
+        event @ (types::BridgeEvent::FileDownloaded { .. }
+        | types::BridgeEvent::FileDownloadFailed { .. }) => {
+            handle_file_download(deps, event).await;
+        }
+        event @ (types::BridgeEvent::FileUploadProgress { .. }
+        | types::BridgeEvent::FileUploaded { .. }
+        | types::BridgeEvent::FileUploadFailed { .. }) => {
+            handle_file_upload(deps, event).await;
+        }
+        event @ (types::BridgeEvent::UserMoved { .. }
+        | types::BridgeEvent::UserMoveFailed { .. }) => {
+            handle_user_move(deps, event).await;
+        }
+        event @ (types::BridgeEvent::BanLifted { .. }
+        | types::BridgeEvent::BanLiftFailed { .. }) => {
+            handle_ban_lift(deps, event).await;
+        }
+        event @ (types::BridgeEvent::ChannelChanged { .. }
+        | types::BridgeEvent::ChannelChangeFailed { .. }) => {
+            handle_channel_change(deps, event).await;
+        }
+        event @ (types::BridgeEvent::AccountChanged { .. }
+        | types::BridgeEvent::AccountChangeFailed { .. }) => {
+            handle_account_change(deps, event).await;
+        }
+        types::BridgeEvent::AccountRegistered {
+            server_id,
+            chat_id,
+            lang,
+            registration,
+        } => handle_account_registered(deps, server_id, chat_id, lang, registration).await,
+        event @ (types::BridgeEvent::ServerEdited { .. }
+        | types::BridgeEvent::ServerEditFailed { .. }) => {
+            handle_server_edit(deps, event).await;
+        }
+        types::BridgeEvent::ServerInfoChanged {
+            server_id,
+            previous,
+            current,
+        } => handle_server_info_changed(deps, server_id, &previous, &current).await,
+        types::BridgeEvent::ServerHealth {
+            server_id,
+            alert,
+            notify_subscribers,
+        } => {
+            handle_server_health(deps, server_id, alert, notify_subscribers).await;
+        }
+        event @ types::BridgeEvent::QueueView { .. } => handle_queue_view(deps, event).await,
+        _ => {}
+    }
+}
+
+async fn handle_server_broadcast(deps: &BridgeDeps<'_>, event: BridgeEvent) {
+    let types::BridgeEvent::ServerBroadcast {
+        server_id,
+        server_name,
+        nickname,
+        tt_username,
+        text,
+    } = event
+    else {
+        return;
+    };
+    let data = BroadcastData {
+        server_id,
+        event_type: types::NotificationType::ServerBroadcast,
+        nickname,
+        server_name,
+        related_tt_username: tt_username,
+        detail: Some(types::BroadcastDetail::Message { text }),
+    };
+    handle_broadcast(deps, data).await;
+}
+
+fn render_status(lang: LanguageCode, detail: Option<&types::BroadcastDetail>) -> String {
+    let Some(types::BroadcastDetail::Status { presence, text }) = detail else {
+        return String::new();
+    };
+    let key = match presence {
+        types::Presence::Available => "status-presence-available",
+        types::Presence::Away => "status-presence-away",
+        types::Presence::Question => "status-presence-question",
+    };
+    let label = locales::get_text(lang.as_str(), key, None);
+    if text.is_empty() {
+        label
+    } else {
+        format!("{label} ({})", teloxide::utils::html::escape(text))
     }
 }
 
@@ -192,9 +396,16 @@ async fn handle_broadcast(deps: &BridgeDeps<'_>, data: BroadcastData) {
         return;
     };
 
+    let Some(server_config) = deps.config.server(data.server_id) else {
+        return;
+    };
     let recipients = match deps
         .db
-        .get_recipients_for_event(&data.related_tt_username, data.event_type)
+        .get_recipients_for_event(
+            &data.related_tt_username,
+            data.event_type,
+            &server_config.key(),
+        )
         .await
     {
         Ok(r) if !r.is_empty() => r,
@@ -202,6 +413,7 @@ async fn handle_broadcast(deps: &BridgeDeps<'_>, data: BroadcastData) {
         Err(e) => {
             tracing::error!(
                 component = "bridge",
+                server_id = %data.server_id,
                 event_type = ?data.event_type,
                 tt_username = %data.related_tt_username,
                 error = %e,
@@ -213,74 +425,147 @@ async fn handle_broadcast(deps: &BridgeDeps<'_>, data: BroadcastData) {
 
     let escaped_nick = teloxide::utils::html::escape(&data.nickname);
     let escaped_server = teloxide::utils::html::escape(&data.server_name);
+    let escaped_channel = match &data.detail {
+        Some(
+            types::BroadcastDetail::Channel { path } | types::BroadcastDetail::File { path, .. },
+        ) => teloxide::utils::html::escape(path),
+        _ => "/".to_string(),
+    };
+    let escaped_old = match &data.detail {
+        Some(types::BroadcastDetail::Nickname { previous }) => {
+            teloxide::utils::html::escape(previous)
+        }
+        _ => String::new(),
+    };
+    let escaped_text = match &data.detail {
+        Some(types::BroadcastDetail::Message { text }) => teloxide::utils::html::escape(text),
+        Some(types::BroadcastDetail::File { name, .. }) => teloxide::utils::html::escape(name),
+        _ => String::new(),
+    };
+    let size = match &data.detail {
+        Some(types::BroadcastDetail::File { size, .. }) => files_service::format_file_size(*size),
+        _ => String::new(),
+    };
 
     let key = match data.event_type {
         types::NotificationType::Join => "event-join",
         types::NotificationType::Leave => "event-leave",
+        types::NotificationType::ChannelJoin => "event-channel-join",
+        types::NotificationType::ChannelLeave => "event-channel-leave",
+        types::NotificationType::NickChange => "event-nick-change",
+        types::NotificationType::StatusChange => "event-status-change",
+        types::NotificationType::ServerBroadcast => "event-server-broadcast",
+        types::NotificationType::FileUpload => "event-file-upload",
     };
 
     let mut rendered_text_cache: HashMap<LanguageCode, String> = HashMap::new();
     let mut set = JoinSet::new();
+    let now = std::time::Instant::now();
 
     for sub in recipients {
-        let task_ctx = BroadcastTaskCtx {
-            bot: bot.clone(),
-            online_users: deps.online_users.clone(),
-            db: deps.db.clone(),
-        };
-
         let lang = LanguageCode::from_str_or_default(&sub.language_code, deps.default_lang);
         let text = rendered_text_cache
             .entry(lang)
             .or_insert_with(|| {
                 let args = args!(
                     nickname = escaped_nick.clone(),
-                    server = escaped_server.clone()
+                    server = escaped_server.clone(),
+                    channel = escaped_channel.clone(),
+                    old = escaped_old.clone(),
+                    text = escaped_text.clone(),
+                    size = size.clone(),
+                    status = render_status(lang, data.detail.as_ref())
                 );
                 locales::get_text(lang.as_str(), key, args.as_ref())
             })
             .clone();
 
-        set.spawn(async move {
-            send_broadcast_to_recipient(task_ctx, sub, text).await;
-        });
-    }
-
-    while let Some(res) = set.join_next().await {
-        if let Err(e) = res {
-            tracing::error!(
-                component = "bridge",
-                error = ?e,
-                "Notification task failed after join"
-            );
+        let mode = if matches!(
+            data.event_type,
+            types::NotificationType::ServerBroadcast | types::NotificationType::FileUpload
+        ) {
+            types::DeliveryMode::Instant
+        } else {
+            user_settings_service::parse_delivery_mode(&sub.delivery_mode)
+        };
+        if mode == types::DeliveryMode::Instant {
+            let task_ctx = BroadcastTaskCtx {
+                bot: bot.clone(),
+                servers: deps
+                    .servers
+                    .get(data.server_id.0)
+                    .cloned()
+                    .into_iter()
+                    .collect(),
+                db: deps.db.clone(),
+            };
+            let markup = download_keyboard(deps, lang, data.server_id, data.detail.as_ref());
+            set.spawn(async move {
+                send_broadcast_to_recipient(task_ctx, sub, text, markup).await;
+            });
+        } else {
+            deps.digests
+                .push(sub, data.server_id, mode, lang, text, now);
         }
     }
+
+    join_notification_tasks(set).await;
 }
 
-async fn send_broadcast_to_recipient(ctx: BroadcastTaskCtx, sub: UserSettings, text: String) {
-    let mut send_silent = false;
+fn download_keyboard(
+    deps: &BridgeDeps<'_>,
+    lang: LanguageCode,
+    server_id: ServerId,
+    detail: Option<&types::BroadcastDetail>,
+) -> Option<InlineKeyboardMarkup> {
+    let Some(types::BroadcastDetail::File {
+        channel_id,
+        file_id,
+        size,
+        ..
+    }) = detail
+    else {
+        return None;
+    };
+    if !files_service::fits_download_limit(
+        *size,
+        deps.config.operational_parameters.file_download_max,
+    ) {
+        return None;
+    }
+    Some(InlineKeyboardMarkup::new(vec![vec![callback_button(
+        locales::get_text(lang.as_str(), "btn-download-file", None),
+        FileAction::Download {
+            server_id,
+            channel_id: *channel_id,
+            file_id: *file_id,
+        },
+    )]]))
+}
 
-    if sub.not_on_online_enabled
+async fn send_broadcast_to_recipient(
+    ctx: BroadcastTaskCtx,
+    sub: UserSettings,
+    text: String,
+    markup: Option<InlineKeyboardMarkup>,
+) {
+    let send_silent = sub.not_on_online_enabled
         && sub.not_on_online_confirmed
-        && let Some(linked_tt) = &sub.teamtalk_username
-    {
-        let is_online = ctx
-            .online_users
-            .read()
-            .unwrap_or_else(std::sync::PoisonError::into_inner)
-            .values()
-            .any(|entry| entry.username == *linked_tt);
-        if is_online {
-            send_silent = true;
-        }
-    }
+        && sub.teamtalk_username.as_ref().is_some_and(|linked_tt| {
+            ctx.servers
+                .iter()
+                .any(|server| server.is_username_online(linked_tt))
+        });
 
-    let res = ctx
+    let req = ctx
         .bot
         .send_message(teloxide::types::ChatId(sub.telegram_id), text)
         .parse_mode(teloxide::types::ParseMode::Html)
-        .disable_notification(send_silent)
-        .await;
+        .disable_notification(send_silent);
+    let res = match markup {
+        Some(markup) => req.reply_markup(markup).await,
+        None => req.await,
+    };
 
     if let Err(e) = res {
         tracing::warn!(
@@ -369,7 +654,11 @@ async fn handle_to_admin(deps: &BridgeDeps<'_>, data: AdminData) {
     if let Ok(msg) = &res
         && let Err(e) = deps
             .db
-            .add_pending_reply(i64::from(msg.id.0), data.user_id)
+            .add_pending_reply(
+                i64::from(msg.id.0),
+                &deps.config.server_key(data.server_id),
+                data.user_id,
+            )
             .await
     {
         tracing::error!(
@@ -400,6 +689,7 @@ async fn handle_to_admin(deps: &BridgeDeps<'_>, data: AdminData) {
     if let Err(e) = deps
         .tx_tt_cmd
         .send(types::TtCommand::ReplyToUser {
+            server_id: data.server_id,
             user_id: data.user_id,
             text: reply_text,
         })
@@ -462,6 +752,7 @@ async fn handle_to_admin_channel(deps: &BridgeDeps<'_>, data: AdminChannelData)
             .db
             .add_pending_channel_reply(
                 i64::from(msg.id.0),
+                &deps.config.server_key(data.server_id),
                 data.channel_id,
                 &data.channel_name,
                 &data.server_name,
@@ -499,3 +790,729 @@ async fn handle_who_report(deps: &BridgeDeps<'_>, data: WhoReportData) {
         );
     }
 }
+
+async fn handle_chat_mirror(deps: &BridgeDeps<'_>, data: ChatMirrorData) {
+    let Some(bot) = deps.event_bot else {
+        return;
+    };
+    let text = format!("{}: {}", data.nickname, data.text);
+    if let Err(e) = bot
+        .send_message(teloxide::types::ChatId(data.chat_id), text)
+        .await
+    {
+        tracing::error!(
+            component = "bridge",
+            chat_id = data.chat_id,
+            error = %e,
+            "Failed to mirror channel message"
+        );
+    }
+}
+
+async fn handle_file_download(deps: &BridgeDeps<'_>, event: BridgeEvent) {
+    match event {
+        types::BridgeEvent::FileDownloaded {
+            chat_id,
+            file_path,
+            file_name,
+        } => {
+            let Some(bot) = deps.event_bot.cloned() else {
+                tokio::spawn(remove_temp_file(file_path));
+                return;
+            };
+            tokio::spawn(async move {
+                if let Err(e) = bot
+                    .send_document(
+                        teloxide::types::ChatId(chat_id),
+                        InputFile::file(&file_path).file_name(file_name),
+                    )
+                    .await
+                {
+                    tracing::error!(
+                        component = "bridge",
+                        chat_id,
+                        error = %e,
+                        "Failed to send downloaded file"
+                    );
+                }
+                remove_temp_file(file_path).await;
+            });
+        }
+        types::BridgeEvent::FileDownloadFailed {
+            chat_id,
+            lang,
+            file_name,
+            error,
+        } => {
+            let Some(bot) = deps.event_bot else {
+                return;
+            };
+            let (key, limit) = match error {
+                types::FileDownloadError::NotFound => ("file-download-not-found", 0),
+                types::FileDownloadError::TooLarge { limit_mb } => {
+                    ("file-download-too-large", limit_mb)
+                }
+                types::FileDownloadError::Failed => ("file-download-failed", 0),
+            };
+            let args = args!(file = html::escape(&file_name), limit = limit);
+            if let Err(e) = bot
+                .send_message(
+                    teloxide::types::ChatId(chat_id),
+                    locales::get_text(lang.as_str(), key, args.as_ref()),
+                )
+                .parse_mode(teloxide::types::ParseMode::Html)
+                .await
+            {
+                tracing::error!(
+                    component = "bridge",
+                    chat_id,
+                    error = %e,
+                    "Failed to report file download error"
+                );
+            }
+        }
+        _ => {}
+    }
+}
+
+async fn handle_file_upload(deps: &BridgeDeps<'_>, event: BridgeEvent) {
+    let Some(bot) = deps.msg_bot.or(deps.event_bot) else {
+        return;
+    };
+    let (chat_id, upload_id, text, finished) = match event {
+        types::BridgeEvent::FileUploadProgress {
+            chat_id,
+            lang,
+            upload_id,
+            file_name,
+            transferred,
+            total,
+        } => {
+            let args = args!(
+                file = html::escape(&file_name),
+                percent = files_service::transfer_percent(transferred, total),
+                done = files_service::format_file_size(transferred),
+                size = files_service::format_file_size(total)
+            );
+            (
+                chat_id,
+                upload_id,
+                locales::get_text(lang.as_str(), "file-upload-progress", args.as_ref()),
+                false,
+            )
+        }
+        types::BridgeEvent::FileUploaded {
+            chat_id,
+            lang,
+            upload_id,
+            file_name,
+            channel,
+        } => {
+            let args = args!(
+                file = html::escape(&file_name),
+                channel = html::escape(&channel)
+            );
+            (
+                chat_id,
+                upload_id,
+                locales::get_text(lang.as_str(), "file-upload-done", args.as_ref()),
+                true,
+            )
+        }
+        types::BridgeEvent::FileUploadFailed {
+            chat_id,
+            lang,
+            upload_id,
+            file_name,
+            error,
+        } => {
+            let key = match error {
+                types::FileUploadError::ChannelNotFound => "file-upload-no-channel",
+                types::FileUploadError::NotAllowed => "file-upload-not-allowed",
+                types::FileUploadError::QuotaExceeded => "file-upload-quota",
+                types::FileUploadError::Failed => "file-upload-failed",
+            };
+            let args = args!(file = html::escape(&file_name));
+            (
+                chat_id,
+                upload_id,
+                locales::get_text(lang.as_str(), key, args.as_ref()),
+                true,
+            )
+        }
+        _ => return,
+    };
+    report_upload_status(deps, bot, chat_id, upload_id, text, finished).await;
+}
+
+async fn report_upload_status(
+    deps: &BridgeDeps<'_>,
+    bot: &Bot,
+    chat_id: i64,
+    upload_id: String,
+    text: String,
+    finished: bool,
+) {
+    let chat = teloxide::types::ChatId(chat_id);
+    let status_message = deps.upload_status.lock().ok().and_then(|mut status| {
+        if finished {
+            status.remove(&upload_id)
+        } else {
+            status.get(&upload_id).copied()
+        }
+    });
+    if let Some(message_id) = status_message {
+        match bot
+            .edit_message_text(chat, message_id, text.clone())
+            .parse_mode(teloxide::types::ParseMode::Html)
+            .await
+        {
+            Ok(_) | Err(RequestError::Api(ApiError::MessageNotModified)) => return,
+            Err(e) => {
+                tracing::warn!(
+                    component = "bridge",
+                    chat_id,
+                    error = %e,
+                    "Failed to update file upload status"
+                );
+                if !finished {
+                    return;
+                }
+            }
+        }
+    }
+    match bot
+        .send_message(chat, text)
+        .parse_mode(teloxide::types::ParseMode::Html)
+        .await
+    {
+        Ok(sent) => {
+            if !finished && let Ok(mut status) = deps.upload_status.lock() {
+                status.insert(upload_id, sent.id);
+            }
+        }
+        Err(e) => {
+            tracing::error!(
+                component = "bridge",
+                chat_id,
+                error = %e,
+                "Failed to report file upload result"
+            );
+        }
+    }
+}
+
+async fn handle_user_move(deps: &BridgeDeps<'_>, event: BridgeEvent) {
+    let Some(bot) = deps.msg_bot.or(deps.event_bot) else {
+        return;
+    };
+    let (chat_id, text) = match event {
+        types::BridgeEvent::UserMoved {
+            chat_id,
+            lang,
+            nickname,
+            channel,
+        } => {
+            let args = args!(
+                name = html::escape(&nickname),
+                channel = html::escape(&channel)
+            );
+            (
+                chat_id,
+                locales::get_text(lang.as_str(), "move-done", args.as_ref()),
+            )
+        }
+        types::BridgeEvent::UserMoveFailed {
+            chat_id,
+            lang,
+            nickname,
+            error,
+        } => {
+            let key = match error {
+                types::MoveUserError::UserOffline => "move-user-offline",
+                types::MoveUserError::ChannelNotFound => "move-no-channel",
+                types::MoveUserError::Failed => "move-failed",
+            };
+            let args = args!(name = html::escape(&nickname));
+            (
+                chat_id,
+                locales::get_text(lang.as_str(), key, args.as_ref()),
+            )
+        }
+        _ => return,
+    };
+    if let Err(e) = bot
+        .send_message(teloxide::types::ChatId(chat_id), text)
+        .parse_mode(teloxide::types::ParseMode::Html)
+        .await
+    {
+        tracing::error!(
+            component = "bridge",
+            chat_id,
+            error = %e,
+            "Failed to report user move result"
+        );
+    }
+}
+
+async fn handle_ban_lift(deps: &BridgeDeps<'_>, event: BridgeEvent) {
+    let Some(bot) = deps.msg_bot.or(deps.event_bot) else {
+        return;
+    };
+    let (chat_id, lang, ip_address, key) = match event {
+        types::BridgeEvent::BanLifted {
+            chat_id,
+            lang,
+            ip_address,
+        } => (chat_id, lang, ip_address, "ban-lifted"),
+        types::BridgeEvent::BanLiftFailed {
+            chat_id,
+            lang,
+            ip_address,
+            error,
+        } => {
+            let key = match error {
+                types::BanLiftError::Failed => "ban-lift-failed",
+                types::BanLiftError::Unconfirmed => "ban-lift-unconfirmed",
+            };
+            (chat_id, lang, ip_address, key)
+        }
+        _ => return,
+    };
+    let args = args!(address = html::escape(&ip_address));
+    let text = locales::get_text(lang.as_str(), key, args.as_ref());
+    if let Err(e) = bot
+        .send_message(teloxide::types::ChatId(chat_id), text)
+        .parse_mode(teloxide::types::ParseMode::Html)
+        .await
+    {
+        tracing::error!(
+            component = "bridge",
+            chat_id,
+            error = %e,
+            "Failed to send unban result"
+        );
+    }
+}
+
+async fn handle_channel_change(deps: &BridgeDeps<'_>, event: BridgeEvent) {
+    let Some(bot) = deps.msg_bot.or(deps.event_bot) else {
+        return;
+    };
+    let (chat_id, text) = match event {
+        types::BridgeEvent::ChannelChanged {
+            chat_id,
+            lang,
+            op,
+            path,
+        } => {
+            let key = match op {
+                types::ChannelOp::Create => "channel-created",
+                types::ChannelOp::EditTopic => "channel-topic-updated",
+                types::ChannelOp::EditPassword => "channel-password-updated",
+                types::ChannelOp::Delete => "channel-deleted",
+            };
+            let args = args!(channel = html::escape(&path));
+            (
+                chat_id,
+                locales::get_text(lang.as_str(), key, args.as_ref()),
+            )
+        }
+        types::BridgeEvent::ChannelChangeFailed {
+            chat_id,
+            lang,
+            path,
+            error,
+            ..
+        } => {
+            let key = match error {
+                types::ChannelChangeError::NotFound => "channel-change-not-found",
+                types::ChannelChangeError::NotEmpty => "channel-change-not-empty",
+                types::ChannelChangeError::Failed => "channel-change-failed",
+                types::ChannelChangeError::Unconfirmed => "channel-change-unconfirmed",
+            };
+            let args = args!(channel = html::escape(&path));
+            (
+                chat_id,
+                locales::get_text(lang.as_str(), key, args.as_ref()),
+            )
+        }
+        _ => return,
+    };
+    if let Err(e) = bot
+        .send_message(teloxide::types::ChatId(chat_id), text)
+        .parse_mode(teloxide::types::ParseMode::Html)
+        .await
+    {
+        tracing::error!(
+            component = "bridge",
+            chat_id,
+            error = %e,
+            "Failed to report channel change result"
+        );
+    }
+}
+
+async fn handle_account_change(deps: &BridgeDeps<'_>, event: BridgeEvent) {
+    let Some(bot) = deps.msg_bot.or(deps.event_bot) else {
+        return;
+    };
+    let (chat_id, text) = match event {
+        types::BridgeEvent::AccountChanged {
+            chat_id,
+            lang,
+            op,
+            username,
+        } => {
+            let key = match op {
+                types::AccountOp::Create => "account-created",
+                types::AccountOp::Password => "account-password-updated",
+                types::AccountOp::Note => "account-note-updated",
+                types::AccountOp::Type => "account-type-updated",
+                types::AccountOp::Delete => "account-deleted",
+            };
+            let args = args!(username = html::escape(&username));
+            (
+                chat_id,
+                locales::get_text(lang.as_str(), key, args.as_ref()),
+            )
+        }
+        types::BridgeEvent::AccountChangeFailed {
+            chat_id,
+            lang,
+            username,
+            error,
+            ..
+        } => {
+            let key = match error {
+                types::AccountChangeError::NotFound => "account-change-not-found",
+                types::AccountChangeError::AlreadyExists => "account-change-exists",
+                types::AccountChangeError::Failed => "account-change-failed",
+                types::AccountChangeError::Unconfirmed => "account-change-unconfirmed",
+            };
+            let args = args!(username = html::escape(&username));
+            (
+                chat_id,
+                locales::get_text(lang.as_str(), key, args.as_ref()),
+            )
+        }
+        _ => return,
+    };
+    if let Err(e) = bot
+        .send_message(teloxide::types::ChatId(chat_id), text)
+        .parse_mode(teloxide::types::ParseMode::Html)
+        .await
+    {
+        tracing::error!(
+            component = "bridge",
+            chat_id,
+            error = %e,
+            "Failed to report account change result"
+        );
+    }
+}
+
+async fn handle_account_registered(
+    deps: &BridgeDeps<'_>,
+    server_id: ServerId,
+    chat_id: i64,
+    lang: LanguageCode,
+    registration: types::AccountRegistration,
+) {
+    let username = registration.username.clone();
+    match deps
+        .db
+        .take_registration_request(registration.request_id)
+        .await
+    {
+        Ok(Some(request)) => {
+            send_registration_credentials(deps, server_id, request.telegram_id, &registration)
+                .await;
+        }
+        Ok(None) => {
+            tracing::warn!(
+                component = "bridge",
+                request_id = registration.request_id,
+                "Registration request vanished before the account was created"
+            );
+        }
+        Err(e) => {
+            tracing::error!(
+                component = "bridge",
+                request_id = registration.request_id,
+                error = %e,
+                "Failed to close registration request"
+            );
+        }
+    }
+    let Some(bot) = deps.msg_bot.or(deps.event_bot) else {
+        return;
+    };
+    let text = locales::get_text(
+        lang.as_str(),
+        "registration-completed-admin",
+        args!(username = html::escape(&username)).as_ref(),
+    );
+    if let Err(e) = bot
+        .send_message(teloxide::types::ChatId(chat_id), text)
+        .parse_mode(teloxide::types::ParseMode::Html)
+        .await
+    {
+        tracing::error!(
+            component = "bridge",
+            chat_id,
+            error = %e,
+            "Failed to report registration result"
+        );
+    }
+}
+
+async fn send_registration_credentials(
+    deps: &BridgeDeps<'_>,
+    server_id: ServerId,
+    telegram_id: i64,
+    registration: &types::AccountRegistration,
+) {
+    if let Err(e) = deps
+        .db
+        .link_tt_account(telegram_id, &registration.username)
+        .await
+    {
+        tracing::error!(
+            component = "bridge",
+            telegram_id,
+            error = %e,
+            "Failed to link registered account"
+        );
+    }
+    let Some(bot) = deps.event_bot.or(deps.msg_bot) else {
+        return;
+    };
+    let lang = deps
+        .db
+        .get_or_create_user(telegram_id, deps.default_lang)
+        .await
+        .map_or(deps.default_lang, |u| {
+            LanguageCode::from_str_or_default(&u.language_code, deps.default_lang)
+        });
+    let text = locales::get_text(
+        lang.as_str(),
+        "register-approved",
+        args!(
+            username = html::escape(&registration.username),
+            password = html::escape(&registration.password),
+            server = html::escape(deps.config.server_display_name(server_id))
+        )
+        .as_ref(),
+    );
+    if let Err(e) = bot
+        .send_message(teloxide::types::ChatId(telegram_id), text)
+        .parse_mode(teloxide::types::ParseMode::Html)
+        .await
+    {
+        tracing::error!(
+            component = "bridge",
+            telegram_id,
+            error = %e,
+            "Failed to send registration credentials"
+        );
+    }
+}
+
+async fn handle_server_edit(deps: &BridgeDeps<'_>, event: BridgeEvent) {
+    let Some(bot) = deps.msg_bot.or(deps.event_bot) else {
+        return;
+    };
+    let (chat_id, lang, key) = match event {
+        types::BridgeEvent::ServerEdited {
+            chat_id,
+            lang,
+            field,
+        } => {
+            let key = match field {
+                types::ServerField::Name => "server-name-updated",
+                types::ServerField::Motd => "server-motd-updated",
+            };
+            (chat_id, lang, key)
+        }
+        types::BridgeEvent::ServerEditFailed { chat_id, lang, .. } => {
+            (chat_id, lang, "server-edit-failed")
+        }
+        _ => return,
+    };
+    if let Err(e) = bot
+        .send_message(
+            teloxide::types::ChatId(chat_id),
+            locales::get_text(lang.as_str(), key, None),
+        )
+        .await
+    {
+        tracing::error!(
+            component = "bridge",
+            chat_id,
+            error = %e,
+            "Failed to report server edit result"
+        );
+    }
+}
+
+async fn handle_server_info_changed(
+    deps: &BridgeDeps<'_>,
+    server_id: ServerId,
+    previous: &types::LiteServerInfo,
+    current: &types::LiteServerInfo,
+) {
+    let Some(bot) = deps.msg_bot.or(deps.event_bot) else {
+        return;
+    };
+    let server = deps.config.server_display_name(server_id);
+    for chat_id in admin_recipients(deps).await {
+        let lang = chat_lang(deps, chat_id).await;
+        let changes = server_info_service::describe_changes(lang, previous, current).join("\n");
+        let text = locales::get_text(
+            lang.as_str(),
+            "server-info-changed",
+            args!(
+                server = html::escape(server),
+                changes = html::escape(&changes)
+            )
+            .as_ref(),
+        );
+        if let Err(e) = bot
+            .send_message(teloxide::types::ChatId(chat_id), text)
+            .parse_mode(teloxide::types::ParseMode::Html)
+            .await
+        {
+            tracing::error!(
+                component = "bridge",
+                chat_id,
+                error = %e,
+                "Failed to announce server property change"
+            );
+        }
+    }
+}
+
+async fn admin_recipients(deps: &BridgeDeps<'_>) -> Vec<i64> {
+    let mut recipients = vec![deps.admin_id.0];
+    match deps.db.get_all_admins().await {
+        Ok(admins) => {
+            for admin in admins {
+                if !recipients.contains(&admin) {
+                    recipients.push(admin);
+                }
+            }
+        }
+        Err(e) => {
+            tracing::error!(component = "bridge", error = %e, "Failed to load admin list");
+        }
+    }
+    recipients
+}
+
+async fn chat_lang(deps: &BridgeDeps<'_>, chat_id: i64) -> LanguageCode {
+    deps.db
+        .get_or_create_user(chat_id, deps.default_lang)
+        .await
+        .map_or(deps.default_lang, |u| {
+            LanguageCode::from_str_or_default(&u.language_code, deps.default_lang)
+        })
+}
+
+async fn handle_server_health(
+    deps: &BridgeDeps<'_>,
+    server_id: ServerId,
+    alert: types::HealthAlert,
+    notify_subscribers: bool,
+) {
+    let server = deps.config.server_display_name(server_id);
+    let admins = admin_recipients(deps).await;
+    if let Some(bot) = deps.msg_bot.or(deps.event_bot) {
+        for &chat_id in &admins {
+            let lang = chat_lang(deps, chat_id).await;
+            let text = health_service::admin_alert_text(lang, server, alert);
+            send_health_message(bot, chat_id, text).await;
+        }
+    }
+    if !notify_subscribers {
+        return;
+    }
+    let (Some(bot), Some(server_config)) = (
+        deps.event_bot.or(deps.msg_bot),
+        deps.config.server(server_id),
+    ) else {
+        return;
+    };
+    let subscribers = match deps.db.get_health_recipients(&server_config.key()).await {
+        Ok(subscribers) => subscribers,
+        Err(e) => {
+            tracing::error!(
+                component = "bridge",
+                server_id = %server_id,
+                error = %e,
+                "Failed to load health notice recipients"
+            );
+            return;
+        }
+    };
+    for subscriber in subscribers {
+        if admins.contains(&subscriber.telegram_id) {
+            continue;
+        }
+        let lang = LanguageCode::from_str_or_default(&subscriber.language_code, deps.default_lang);
+        let text = health_service::subscriber_notice_text(lang, server, alert);
+        send_health_message(bot, subscriber.telegram_id, text).await;
+    }
+}
+
+async fn send_health_message(bot: &Bot, chat_id: i64, text: String) {
+    if let Err(e) = bot
+        .send_message(teloxide::types::ChatId(chat_id), text)
+        .await
+    {
+        tracing::error!(
+            component = "bridge",
+            chat_id,
+            error = %e,
+            "Failed to send connection health alert"
+        );
+    }
+}
+
+async fn handle_queue_view(deps: &BridgeDeps<'_>, event: BridgeEvent) {
+    let types::BridgeEvent::QueueView {
+        server_id,
+        chat_id,
+        message_id,
+        lang,
+        snapshot,
+    } = event
+    else {
+        return;
+    };
+    let Some(bot) = deps.event_bot else {
+        return;
+    };
+    let text =
+        queue_service::render_queue(lang, deps.config.server_display_name(server_id), &snapshot);
+    let res = bot
+        .edit_message_text(
+            teloxide::types::ChatId(chat_id),
+            teloxide::types::MessageId(message_id),
+            text,
+        )
+        .reply_markup(create_queue_keyboard(lang, server_id, &snapshot))
+        .await;
+    match res {
+        Ok(_) | Err(RequestError::Api(ApiError::MessageNotModified)) => {}
+        Err(e) => {
+            tracing::warn!(
+                component = "bridge",
+                chat_id,
+                message_id,
+                error = %e,
+                "Failed to update stream queue message"
+            );
+        }
+    }
+}
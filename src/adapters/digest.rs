@@ -0,0 +1,118 @@
+use crate::args;
+use crate::core::types::{DeliveryMode, LanguageCode, ServerId};
+use crate::infra::db::types::UserSettings;
+use crate::infra::locales;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+const MAX_DIGEST_LINES: usize = 50;
+
+struct PendingDigest {
+    sub: UserSettings,
+    lang: LanguageCode,
+    servers: Vec<ServerId>,
+    lines: Vec<String>,
+    due: Instant,
+}
+
+pub struct ReadyDigest {
+    pub sub: UserSettings,
+    pub servers: Vec<ServerId>,
+    pub text: String,
+}
+
+#[derive(Clone)]
+pub struct DigestBuffer {
+    batch: Duration,
+    pending: Arc<Mutex<HashMap<i64, PendingDigest>>>,
+}
+
+impl DigestBuffer {
+    pub fn new(batch: Duration) -> Self {
+        Self {
+            batch,
+            pending: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    pub fn push(
+        &self,
+        sub: UserSettings,
+        server_id: ServerId,
+        mode: DeliveryMode,
+        lang: LanguageCode,
+        line: String,
+        now: Instant,
+    ) {
+        let window = match mode {
+            DeliveryMode::Instant => Duration::ZERO,
+            DeliveryMode::Batched => self.batch,
+            DeliveryMode::Hourly => Duration::from_hours(1),
+        };
+        let Ok(mut pending) = self.pending.lock() else {
+            return;
+        };
+        let entry = pending
+            .entry(sub.telegram_id)
+            .or_insert_with(|| PendingDigest {
+                sub: sub.clone(),
+                lang,
+                servers: Vec::new(),
+                lines: Vec::new(),
+                due: now + window,
+            });
+        entry.sub = sub;
+        entry.lang = lang;
+        if !entry.servers.contains(&server_id) {
+            entry.servers.push(server_id);
+        }
+        entry.lines.push(line);
+    }
+
+    pub fn take_due(&self, now: Instant) -> Vec<ReadyDigest> {
+        let Ok(mut pending) = self.pending.lock() else {
+            return Vec::new();
+        };
+        pending
+            .extract_if(|_, d| d.due <= now)
+            .map(|(_, d)| render(d))
+            .collect()
+    }
+
+    pub fn take_all(&self) -> Vec<ReadyDigest> {
+        let Ok(mut pending) = self.pending.lock() else {
+            return Vec::new();
+        };
+        pending.drain().map(|(_, d)| render(d)).collect()
+    }
+}
+
+fn render(digest: PendingDigest) -> ReadyDigest {
+    let PendingDigest {
+        sub,
+        lang,
+        servers,
+        lines,
+        ..
+    } = digest;
+    let total = lines.len();
+    let mut text = locales::get_text(lang.as_str(), "digest-title", args!(count = total).as_ref());
+    for line in lines.iter().take(MAX_DIGEST_LINES) {
+        text.push('\n');
+        text.push_str(line);
+    }
+    if total > MAX_DIGEST_LINES {
+        text.push('\n');
+        text.push_str(&locales::get_text(
+            lang.as_str(),
+            "digest-more",
+            args!(count = total - MAX_DIGEST_LINES).as_ref(),
+        ));
+    }
+    ReadyDigest { sub, servers, text }
+}
+
+#[cfg(test)]
+#[path = "../../tests/unit/adapters_digest.rs"]
+mod tests;
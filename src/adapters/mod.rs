@@ -1,3 +1,4 @@
 pub mod bridge;
+pub mod digest;
 pub mod tg;
 pub mod tt;
@@ -0,0 +1,419 @@
+use crate::adapters::tg::keyboards::{
+    back_button_keyboard, callback_button, confirm_cancel_keyboard, create_account_keyboard,
+    create_user_list_keyboard,
+};
+use crate::adapters::tg::state::{AccountInput, AppState, PendingAccountInput};
+use crate::adapters::tg::utils::send_text_key;
+use crate::adapters::tt::ServerShared;
+use crate::adapters::tt::accounts::is_admin_account;
+use crate::app::services::accounts as accounts_service;
+use crate::args;
+use crate::core::callbacks::{AdminAction, CallbackAction};
+use crate::core::types::{
+    AccountEdit, AccountField, LanguageCode, ServerId, TtCommand, TtUsername,
+};
+use crate::infra::locales;
+use teamtalk::types::UserAccount;
+use teloxide::prelude::*;
+use teloxide::sugar::request::RequestReplyExt;
+use teloxide::types::InlineKeyboardMarkup;
+
+fn account_list_view(
+    state: &AppState,
+    server_id: ServerId,
+    page: usize,
+    filter: &str,
+    lang: LanguageCode,
+) -> (String, InlineKeyboardMarkup) {
+    let accounts = state
+        .servers
+        .get(server_id.0)
+        .map(ServerShared::sorted_accounts)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|a| accounts_service::matches_query(&a.username, &a.note, filter))
+        .collect::<Vec<_>>();
+    let server = state.config.server_display_name(server_id).to_string();
+    let text = if filter.is_empty() {
+        locales::get_text(
+            lang.as_str(),
+            "accounts-title",
+            args!(server = server, count = accounts.len()).as_ref(),
+        )
+    } else {
+        locales::get_text(
+            lang.as_str(),
+            "accounts-search-title",
+            args!(
+                server = server,
+                query = filter.to_string(),
+                count = accounts.len()
+            )
+            .as_ref(),
+        )
+    };
+    let keyboard = create_user_list_keyboard(
+        &accounts,
+        page,
+        |account| {
+            let label = if is_admin_account(account) {
+                locales::get_text(
+                    lang.as_str(),
+                    "account-label-admin",
+                    args!(username = account.username.clone()).as_ref(),
+                )
+            } else {
+                account.username.clone()
+            };
+            (
+                label,
+                CallbackAction::Admin(AdminAction::AccountView {
+                    server_id,
+                    username: TtUsername::from(account.username.clone()),
+                }),
+            )
+        },
+        |p| CallbackAction::Admin(AdminAction::AccountList { server_id, page: p }),
+        None,
+        lang,
+    )
+    .append_row(vec![
+        callback_button(
+            locales::get_text(lang.as_str(), "btn-account-search", None),
+            CallbackAction::Admin(AdminAction::AccountSearch { server_id }),
+        ),
+        callback_button(
+            locales::get_text(lang.as_str(), "btn-account-create", None),
+            CallbackAction::Admin(AdminAction::AccountCreate { server_id }),
+        ),
+    ]);
+    (text, keyboard)
+}
+
+fn find_account(state: &AppState, server_id: ServerId, username: &str) -> Option<UserAccount> {
+    state
+        .servers
+        .get(server_id.0)
+        .and_then(|server| server.account(username))
+}
+
+fn account_details(lang: LanguageCode, account: &UserAccount, online: bool) -> String {
+    let type_key = if is_admin_account(account) {
+        "account-type-admin"
+    } else {
+        "account-type-default"
+    };
+    let note = if account.note.is_empty() {
+        locales::get_text(lang.as_str(), "account-no-note", None)
+    } else {
+        account.note.clone()
+    };
+    let status_key = if online {
+        "account-status-online"
+    } else {
+        "account-status-offline"
+    };
+    locales::get_text(
+        lang.as_str(),
+        "account-details",
+        args!(
+            username = account.username.clone(),
+            account_type = locales::get_text(lang.as_str(), type_key, None),
+            note = note,
+            status = locales::get_text(lang.as_str(), status_key, None)
+        )
+        .as_ref(),
+    )
+}
+
+pub async fn request_accounts(state: &AppState, server_id: ServerId) {
+    if let Err(e) = state
+        .tx_tt
+        .send(TtCommand::LoadAccounts { server_id })
+        .await
+    {
+        tracing::error!(%server_id, error = %e, "Failed to request TT user accounts");
+    }
+}
+
+pub async fn send_account_list(
+    bot: &Bot,
+    chat_id: teloxide::types::ChatId,
+    state: &AppState,
+    server_id: ServerId,
+    filter: String,
+    lang: LanguageCode,
+    reply_to: Option<teloxide::types::MessageId>,
+) -> ResponseResult<()> {
+    let (text, keyboard) = account_list_view(state, server_id, 0, &filter, lang);
+    let req = bot.send_message(chat_id, text).reply_markup(keyboard);
+    let sent = if let Some(reply_to) = reply_to {
+        req.reply_to(reply_to).await?
+    } else {
+        req.await?
+    };
+    remember_filter(state, &sent, filter);
+    Ok(())
+}
+
+pub fn remember_filter(state: &AppState, msg: &Message, filter: String) {
+    if filter.is_empty() {
+        return;
+    }
+    if let Ok(mut filters) = state.account_filters.write() {
+        filters.insert((msg.chat.id.0, msg.id.0), filter);
+    }
+}
+
+pub async fn edit_account_list(
+    bot: &Bot,
+    msg: &Message,
+    state: &AppState,
+    server_id: ServerId,
+    page: usize,
+    lang: LanguageCode,
+) -> ResponseResult<()> {
+    let filter = state
+        .account_filters
+        .read()
+        .ok()
+        .and_then(|filters| filters.get(&(msg.chat.id.0, msg.id.0)).cloned())
+        .unwrap_or_default();
+    let (text, keyboard) = account_list_view(state, server_id, page, &filter, lang);
+    bot.edit_message_text(msg.chat.id, msg.id, text)
+        .reply_markup(keyboard)
+        .await?;
+    Ok(())
+}
+
+pub async fn edit_account_view(
+    bot: &Bot,
+    msg: &Message,
+    state: &AppState,
+    server_id: ServerId,
+    username: &TtUsername,
+    lang: LanguageCode,
+) -> ResponseResult<()> {
+    let Some(server) = state.servers.get(server_id.0) else {
+        return Ok(());
+    };
+    let Some(account) = server.account(username.as_str()) else {
+        return edit_account_gone(bot, msg, server_id, lang).await;
+    };
+    let online = server.is_username_online(&account.username);
+    bot.edit_message_text(msg.chat.id, msg.id, account_details(lang, &account, online))
+        .reply_markup(create_account_keyboard(
+            lang,
+            server_id,
+            username,
+            is_admin_account(&account),
+        ))
+        .await?;
+    Ok(())
+}
+
+pub async fn edit_account_prompt(
+    bot: &Bot,
+    msg: &Message,
+    state: &AppState,
+    pending: PendingAccountInput,
+    lang: LanguageCode,
+) -> ResponseResult<()> {
+    let server_id = pending.server_id;
+    let list = CallbackAction::Admin(AdminAction::AccountList { server_id, page: 0 });
+    let (text, back_key, back) = match &pending.input {
+        AccountInput::Search => (
+            locales::get_text(lang.as_str(), "account-search-prompt", None),
+            "btn-back-accounts",
+            list,
+        ),
+        AccountInput::Create => (
+            locales::get_text(lang.as_str(), "account-create-prompt", None),
+            "btn-back-accounts",
+            list,
+        ),
+        AccountInput::Field { username, field } => {
+            if find_account(state, server_id, username).is_none() {
+                return edit_account_gone(bot, msg, server_id, lang).await;
+            }
+            let key = match field {
+                AccountField::Password => "account-password-prompt",
+                AccountField::Note => "account-note-prompt",
+            };
+            (
+                locales::get_text(
+                    lang.as_str(),
+                    key,
+                    args!(username = username.clone()).as_ref(),
+                ),
+                "btn-back-account",
+                CallbackAction::Admin(AdminAction::AccountView {
+                    server_id,
+                    username: TtUsername::from(username.clone()),
+                }),
+            )
+        }
+    };
+    if let Ok(mut inputs) = state.pending_account_inputs.write() {
+        inputs.insert((msg.chat.id.0, msg.id.0), pending);
+    }
+    bot.edit_message_text(msg.chat.id, msg.id, text)
+        .reply_markup(back_button_keyboard(lang, back_key, back))
+        .await?;
+    Ok(())
+}
+
+pub async fn edit_account_delete_confirm(
+    bot: &Bot,
+    msg: &Message,
+    state: &AppState,
+    server_id: ServerId,
+    username: TtUsername,
+    lang: LanguageCode,
+) -> ResponseResult<()> {
+    if find_account(state, server_id, username.as_str()).is_none() {
+        return edit_account_gone(bot, msg, server_id, lang).await;
+    }
+    bot.edit_message_text(
+        msg.chat.id,
+        msg.id,
+        locales::get_text(
+            lang.as_str(),
+            "account-delete-confirm",
+            args!(username = username.to_string()).as_ref(),
+        ),
+    )
+    .reply_markup(confirm_cancel_keyboard(
+        lang,
+        "btn-yes",
+        CallbackAction::Admin(AdminAction::AccountDeleteConfirm {
+            server_id,
+            username: username.clone(),
+        }),
+        "btn-no",
+        CallbackAction::Admin(AdminAction::AccountView {
+            server_id,
+            username,
+        }),
+    ))
+    .await?;
+    Ok(())
+}
+
+async fn edit_account_gone(
+    bot: &Bot,
+    msg: &Message,
+    server_id: ServerId,
+    lang: LanguageCode,
+) -> ResponseResult<()> {
+    bot.edit_message_text(
+        msg.chat.id,
+        msg.id,
+        locales::get_text(lang.as_str(), "account-not-found", None),
+    )
+    .reply_markup(back_button_keyboard(
+        lang,
+        "btn-back-accounts",
+        CallbackAction::Admin(AdminAction::AccountList { server_id, page: 0 }),
+    ))
+    .await?;
+    Ok(())
+}
+
+fn account_command(
+    server_id: ServerId,
+    input: &AccountInput,
+    text: &str,
+    chat_id: i64,
+    lang: LanguageCode,
+) -> Option<TtCommand> {
+    let (username, edit) = match input {
+        AccountInput::Search => return None,
+        AccountInput::Create => {
+            let account = accounts_service::parse_new_account(text)?;
+            (
+                account.username,
+                AccountEdit::Create {
+                    password: account.password,
+                    note: account.note,
+                },
+            )
+        }
+        AccountInput::Field { username, field } => (
+            username.clone(),
+            accounts_service::parse_account_edit(*field, text)?,
+        ),
+    };
+    Some(TtCommand::EditAccount {
+        server_id,
+        username,
+        edit,
+        chat_id,
+        lang,
+    })
+}
+
+async fn execute_account_input(
+    state: &AppState,
+    server_id: ServerId,
+    input: &AccountInput,
+    text: &str,
+    chat_id: i64,
+    lang: LanguageCode,
+) -> Option<String> {
+    let cmd = account_command(server_id, input, text, chat_id, lang)?;
+    if let Err(e) = state.tx_tt.send(cmd).await {
+        tracing::error!(%server_id, error = %e, "Failed to send account command");
+        return Some(locales::get_text(lang.as_str(), "cmd-error", None));
+    }
+    Some(locales::get_text(lang.as_str(), "toast-command-sent", None))
+}
+
+pub async fn handle_account_reply(
+    bot: &Bot,
+    msg: &Message,
+    prompt: &Message,
+    state: &AppState,
+    pending: PendingAccountInput,
+    text: &str,
+    lang: LanguageCode,
+) -> ResponseResult<()> {
+    let PendingAccountInput { server_id, input } = pending;
+    let prompt_key = (msg.chat.id.0, prompt.id.0);
+    if matches!(input, AccountInput::Search) {
+        if let Ok(mut inputs) = state.pending_account_inputs.write() {
+            inputs.remove(&prompt_key);
+        }
+        let _ = bot.edit_message_reply_markup(msg.chat.id, prompt.id).await;
+        return send_account_list(
+            bot,
+            msg.chat.id,
+            state,
+            server_id,
+            text.trim().to_string(),
+            lang,
+            Some(msg.id),
+        )
+        .await;
+    }
+    let Some(result) =
+        execute_account_input(state, server_id, &input, text, msg.chat.id.0, lang).await
+    else {
+        let usage_key = match input {
+            AccountInput::Field {
+                field: AccountField::Password,
+                ..
+            } => "account-password-usage",
+            _ => "account-create-usage",
+        };
+        return send_text_key(bot, msg.chat.id, lang, usage_key, Some(msg.id)).await;
+    };
+    if let Ok(mut inputs) = state.pending_account_inputs.write() {
+        inputs.remove(&prompt_key);
+    }
+    let _ = bot.edit_message_reply_markup(msg.chat.id, prompt.id).await;
+    bot.send_message(msg.chat.id, result)
+        .reply_to(msg.id)
+        .await?;
+    Ok(())
+}
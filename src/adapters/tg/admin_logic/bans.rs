@@ -1,7 +1,14 @@
 use crate::adapters::tg::keyboards::{back_btn, create_user_list_keyboard};
+use crate::adapters::tg::state::{AppState, PendingBan};
+use crate::adapters::tg::utils::notify_admin_error;
+use crate::app::services::admin_cleanup as admin_cleanup_service;
+use crate::app::services::bans as bans_service;
+use crate::args;
+use crate::bootstrap::config::TeamTalkConfig;
 use crate::core::callbacks::{AdminAction, CallbackAction, MenuAction};
-use crate::core::types::LanguageCode;
+use crate::core::types::{AdminErrorContext, LanguageCode, TtCommand};
 use crate::infra::db::Database;
+use crate::infra::db::types::NewBan;
 use crate::infra::locales;
 use teloxide::prelude::*;
 use teloxide::sugar::request::RequestReplyExt;
@@ -14,6 +21,7 @@ pub async fn send_unban_list(
     page: usize,
     reply_to: Option<teloxide::types::MessageId>,
 ) -> ResponseResult<()> {
+    let now = chrono::Utc::now().naive_utc();
     let entries = match db.get_banned_users().await {
         Ok(list) => list,
         Err(e) => {
@@ -39,16 +47,8 @@ pub async fn send_unban_list(
         &entries,
         page,
         |e| {
-            let name = e.telegram_id.map_or_else(
-                || {
-                    e.teamtalk_username
-                        .clone()
-                        .unwrap_or_else(|| "Unknown".to_string())
-                },
-                |tg| format!("{tg}"),
-            );
             (
-                name,
+                bans_service::ban_label(lang, e, now),
                 CallbackAction::Admin(AdminAction::UnbanPerform {
                     ban_db_id: e.id,
                     page,
@@ -85,6 +85,7 @@ pub async fn edit_unban_list(
     lang: LanguageCode,
     page: usize,
 ) -> ResponseResult<()> {
+    let now = chrono::Utc::now().naive_utc();
     let entries = match db.get_banned_users().await {
         Ok(list) => list,
         Err(e) => {
@@ -107,16 +108,8 @@ pub async fn edit_unban_list(
         &entries,
         page,
         |e| {
-            let name = e.telegram_id.map_or_else(
-                || {
-                    e.teamtalk_username
-                        .clone()
-                        .unwrap_or_else(|| "Unknown".to_string())
-                },
-                |tg| format!("{tg}"),
-            );
             (
-                name,
+                bans_service::ban_label(lang, e, now),
                 CallbackAction::Admin(AdminAction::UnbanPerform {
                     ban_db_id: e.id,
                     page,
@@ -141,3 +134,92 @@ pub async fn edit_unban_list(
     .await?;
     Ok(())
 }
+
+pub async fn execute_ban(
+    bot: &Bot,
+    state: &AppState,
+    admin_id: i64,
+    lang: LanguageCode,
+    pending: PendingBan,
+    reason: Option<String>,
+) -> String {
+    let PendingBan {
+        server_id,
+        user_id,
+        term,
+    } = pending;
+    let Some(user) = state
+        .servers
+        .get(server_id.0)
+        .and_then(|server| server.online_user(user_id))
+    else {
+        return locales::get_text(lang.as_str(), "cmd-no-users", None);
+    };
+    let expires_at = bans_service::expires_at(term, chrono::Utc::now().naive_utc());
+    let server_key = state.config.server(server_id).map(TeamTalkConfig::key);
+
+    let result = async {
+        let telegram_id =
+            admin_cleanup_service::get_telegram_id_by_tt_user(&state.db, &user.username).await;
+        if let Some(tg_id) = telegram_id
+            && let Err(e) =
+                admin_cleanup_service::cleanup_deleted_banned_user(&state.db, tg_id).await
+        {
+            tracing::error!(
+                tt_username = %user.username,
+                error = %e,
+                "Failed to delete user profile during ban"
+            );
+        }
+        state
+            .db
+            .add_ban_entry(&NewBan {
+                telegram_id,
+                teamtalk_username: Some(user.username.clone()),
+                reason: reason.clone(),
+                expires_at,
+                server_key,
+                ip_address: Some(user.ip_address.clone()),
+            })
+            .await?;
+        state
+            .tx_tt
+            .send(TtCommand::BanUser { server_id, user_id })
+            .await?;
+        anyhow::Ok(())
+    }
+    .await;
+
+    if let Err(e) = result {
+        tracing::error!(
+            user_id,
+            tt_username = %user.username,
+            error = %e,
+            "Failed to ban user"
+        );
+        notify_admin_error(
+            bot,
+            &state.config,
+            admin_id,
+            AdminErrorContext::Callback,
+            &e.to_string(),
+            lang,
+        )
+        .await;
+        return locales::get_text(lang.as_str(), "cmd-error", None);
+    }
+
+    let term = bans_service::term_label(lang, term);
+    match reason {
+        Some(reason) => locales::get_text(
+            lang.as_str(),
+            "ban-done-reason",
+            args!(name = user.nickname, term = term, reason = reason).as_ref(),
+        ),
+        None => locales::get_text(
+            lang.as_str(),
+            "ban-done",
+            args!(name = user.nickname, term = term).as_ref(),
+        ),
+    }
+}
@@ -0,0 +1,270 @@
+use crate::adapters::tg::keyboards::{
+    back_btn, back_button_keyboard, confirm_cancel_keyboard, create_channel_keyboard,
+    create_user_list_keyboard,
+};
+use crate::adapters::tg::state::{AppState, PendingChannelEdit};
+use crate::adapters::tt::ServerShared;
+use crate::app::services::channels as channels_service;
+use crate::args;
+use crate::core::callbacks::{AdminAction, CallbackAction, MenuAction};
+use crate::core::types::{ChannelField, LanguageCode, LiteChannel, ServerId, TtCommand};
+use crate::infra::locales;
+use teloxide::prelude::*;
+use teloxide::sugar::request::RequestReplyExt;
+use teloxide::types::InlineKeyboardMarkup;
+
+fn channel_list_view(
+    state: &AppState,
+    server_id: ServerId,
+    page: usize,
+    lang: LanguageCode,
+) -> (String, InlineKeyboardMarkup) {
+    let channels = state
+        .servers
+        .get(server_id.0)
+        .map(ServerShared::channels)
+        .unwrap_or_default();
+    let text = locales::get_text(
+        lang.as_str(),
+        "channels-title",
+        args!(server = state.config.server_display_name(server_id).to_string()).as_ref(),
+    );
+    let keyboard = create_user_list_keyboard(
+        &channels,
+        page,
+        |channel| {
+            (
+                channel.path.clone(),
+                CallbackAction::Admin(AdminAction::ChannelView {
+                    server_id,
+                    channel_id: channel.id,
+                }),
+            )
+        },
+        |p| CallbackAction::Admin(AdminAction::ChannelList { server_id, page: p }),
+        Some(back_btn(
+            lang,
+            "btn-back-menu",
+            CallbackAction::Menu(MenuAction::Who),
+        )),
+        lang,
+    );
+    (text, keyboard)
+}
+
+fn occupants(server: &ServerShared, channel_id: i32) -> usize {
+    let users = server.sorted_online_users();
+    channels_service::occupants(
+        channel_id,
+        &server.channels(),
+        users.iter().map(|u| u.channel_id),
+    )
+}
+
+fn find_channel(state: &AppState, server_id: ServerId, channel_id: i32) -> Option<LiteChannel> {
+    state
+        .servers
+        .get(server_id.0)
+        .and_then(|server| server.channel(channel_id))
+}
+
+pub async fn send_channel_list(
+    bot: &Bot,
+    chat_id: teloxide::types::ChatId,
+    state: &AppState,
+    server_id: ServerId,
+    lang: LanguageCode,
+    reply_to: Option<teloxide::types::MessageId>,
+) -> ResponseResult<()> {
+    let (text, keyboard) = channel_list_view(state, server_id, 0, lang);
+    let req = bot.send_message(chat_id, text).reply_markup(keyboard);
+    if let Some(reply_to) = reply_to {
+        req.reply_to(reply_to).await?;
+    } else {
+        req.await?;
+    }
+    Ok(())
+}
+
+pub async fn edit_channel_list(
+    bot: &Bot,
+    msg: &Message,
+    state: &AppState,
+    server_id: ServerId,
+    page: usize,
+    lang: LanguageCode,
+) -> ResponseResult<()> {
+    let (text, keyboard) = channel_list_view(state, server_id, page, lang);
+    bot.edit_message_text(msg.chat.id, msg.id, text)
+        .reply_markup(keyboard)
+        .await?;
+    Ok(())
+}
+
+pub async fn edit_channel_view(
+    bot: &Bot,
+    msg: &Message,
+    state: &AppState,
+    server_id: ServerId,
+    channel_id: i32,
+    lang: LanguageCode,
+) -> ResponseResult<()> {
+    let Some(server) = state.servers.get(server_id.0) else {
+        return Ok(());
+    };
+    let Some(channel) = server.channel(channel_id) else {
+        return edit_channel_gone(bot, msg, server_id, lang).await;
+    };
+    let occupants = occupants(server, channel_id);
+    let deletable = channel.parent_id != 0 && occupants == 0;
+    bot.edit_message_text(
+        msg.chat.id,
+        msg.id,
+        channels_service::channel_details(lang, &channel, occupants),
+    )
+    .reply_markup(create_channel_keyboard(
+        lang, server_id, channel_id, deletable,
+    ))
+    .await?;
+    Ok(())
+}
+
+pub async fn edit_channel_prompt(
+    bot: &Bot,
+    msg: &Message,
+    state: &AppState,
+    pending: PendingChannelEdit,
+    lang: LanguageCode,
+) -> ResponseResult<()> {
+    let Some(channel) = find_channel(state, pending.server_id, pending.channel_id) else {
+        return edit_channel_gone(bot, msg, pending.server_id, lang).await;
+    };
+    let key = match pending.field {
+        ChannelField::SubChannel => "channel-create-prompt",
+        ChannelField::Topic => "channel-topic-prompt",
+        ChannelField::Password => "channel-password-prompt",
+    };
+    if let Ok(mut edits) = state.pending_channel_edits.write() {
+        edits.insert((msg.chat.id.0, msg.id.0), pending);
+    }
+    bot.edit_message_text(
+        msg.chat.id,
+        msg.id,
+        locales::get_text(lang.as_str(), key, args!(channel = channel.path).as_ref()),
+    )
+    .reply_markup(back_button_keyboard(
+        lang,
+        "btn-back-channel",
+        CallbackAction::Admin(AdminAction::ChannelView {
+            server_id: pending.server_id,
+            channel_id: pending.channel_id,
+        }),
+    ))
+    .await?;
+    Ok(())
+}
+
+pub async fn edit_delete_confirm(
+    bot: &Bot,
+    msg: &Message,
+    state: &AppState,
+    server_id: ServerId,
+    channel_id: i32,
+    lang: LanguageCode,
+) -> ResponseResult<()> {
+    let Some(channel) = find_channel(state, server_id, channel_id) else {
+        return edit_channel_gone(bot, msg, server_id, lang).await;
+    };
+    bot.edit_message_text(
+        msg.chat.id,
+        msg.id,
+        locales::get_text(
+            lang.as_str(),
+            "channel-delete-confirm",
+            args!(channel = channel.path).as_ref(),
+        ),
+    )
+    .reply_markup(confirm_cancel_keyboard(
+        lang,
+        "btn-yes",
+        CallbackAction::Admin(AdminAction::ChannelDeleteConfirm {
+            server_id,
+            channel_id,
+        }),
+        "btn-no",
+        CallbackAction::Admin(AdminAction::ChannelView {
+            server_id,
+            channel_id,
+        }),
+    ))
+    .await?;
+    Ok(())
+}
+
+async fn edit_channel_gone(
+    bot: &Bot,
+    msg: &Message,
+    server_id: ServerId,
+    lang: LanguageCode,
+) -> ResponseResult<()> {
+    bot.edit_message_text(
+        msg.chat.id,
+        msg.id,
+        locales::get_text(lang.as_str(), "channel-not-found", None),
+    )
+    .reply_markup(back_button_keyboard(
+        lang,
+        "btn-back-channels",
+        CallbackAction::Admin(AdminAction::ChannelList { server_id, page: 0 }),
+    ))
+    .await?;
+    Ok(())
+}
+
+fn channel_command(
+    pending: PendingChannelEdit,
+    input: &str,
+    chat_id: i64,
+    lang: LanguageCode,
+) -> Option<TtCommand> {
+    let PendingChannelEdit {
+        server_id,
+        channel_id,
+        field,
+    } = pending;
+    if field == ChannelField::SubChannel {
+        return Some(TtCommand::CreateChannel {
+            server_id,
+            parent_id: channel_id,
+            channel: channels_service::parse_new_channel(input)?,
+            chat_id,
+            lang,
+        });
+    }
+    Some(TtCommand::EditChannel {
+        server_id,
+        channel_id,
+        edit: channels_service::parse_channel_edit(field, input)?,
+        chat_id,
+        lang,
+    })
+}
+
+pub async fn execute_channel_edit(
+    state: &AppState,
+    pending: PendingChannelEdit,
+    input: &str,
+    chat_id: i64,
+    lang: LanguageCode,
+) -> Option<String> {
+    let cmd = channel_command(pending, input, chat_id, lang)?;
+    if let Err(e) = state.tx_tt.send(cmd).await {
+        tracing::error!(
+            channel_id = pending.channel_id,
+            error = %e,
+            "Failed to send channel command"
+        );
+        return Some(locales::get_text(lang.as_str(), "cmd-error", None));
+    }
+    Some(locales::get_text(lang.as_str(), "toast-command-sent", None))
+}
@@ -1,4 +1,9 @@
+pub mod accounts;
 pub mod bans;
+pub mod channels;
+pub mod registrations;
+pub mod server_bans;
+pub mod server_info;
 pub mod subscriber_settings;
 pub mod subscribers;
 pub mod utils;
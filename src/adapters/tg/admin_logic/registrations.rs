@@ -0,0 +1,247 @@
+use crate::adapters::tg::keyboards::confirm_cancel_keyboard;
+use crate::adapters::tg::state::AppState;
+use crate::app::services::registration::{self as registration_service, SubmitOutcome};
+use crate::app::services::user_settings as user_settings_service;
+use crate::args;
+use crate::bootstrap::config::TeamTalkConfig;
+use crate::core::callbacks::{AdminAction, CallbackAction};
+use crate::core::types::{AccountRegistration, LanguageCode, ServerId, TtCommand};
+use crate::infra::db::types::RegistrationRequest;
+use crate::infra::locales;
+use teloxide::prelude::*;
+use teloxide::utils::html;
+
+pub struct Applicant {
+    pub telegram_id: i64,
+    pub name: String,
+    pub lang: LanguageCode,
+}
+
+impl Applicant {
+    pub fn from_user(user: &teloxide::types::User, lang: LanguageCode) -> Self {
+        let name = match &user.username {
+            Some(username) => format!("{} (@{username})", user.full_name()),
+            None => user.full_name(),
+        };
+        Self {
+            telegram_id: i64::try_from(user.id.0).unwrap_or(i64::MAX),
+            name,
+            lang,
+        }
+    }
+}
+
+pub async fn submit_registration(
+    bot: &Bot,
+    state: &AppState,
+    applicant: &Applicant,
+    server_id: ServerId,
+    username: &str,
+) -> String {
+    let lang = applicant.lang;
+    let Some(server_key) = state.config.server(server_id).map(TeamTalkConfig::key) else {
+        return locales::get_text(lang.as_str(), "cmd-error", None);
+    };
+    let exists = state
+        .servers
+        .get(server_id.0)
+        .is_some_and(|server| server.account(username).is_some());
+    if exists {
+        return locales::get_text(
+            lang.as_str(),
+            "register-username-taken",
+            args!(username = username.to_string()).as_ref(),
+        );
+    }
+    let outcome =
+        registration_service::submit(&state.db, applicant.telegram_id, &server_key, username).await;
+    let key = match outcome {
+        Ok(SubmitOutcome::Filed(request_id)) => {
+            notify_admins(bot, state, request_id, applicant, server_id, username).await;
+            "register-submitted"
+        }
+        Ok(SubmitOutcome::AlreadyPending) => "register-pending",
+        Ok(SubmitOutcome::UsernameTaken) => "register-username-taken",
+        Err(e) => {
+            tracing::error!(
+                telegram_id = applicant.telegram_id,
+                error = %e,
+                "Failed to file registration request"
+            );
+            "cmd-error"
+        }
+    };
+    locales::get_text(
+        lang.as_str(),
+        key,
+        args!(username = username.to_string()).as_ref(),
+    )
+}
+
+async fn user_lang(state: &AppState, telegram_id: i64) -> LanguageCode {
+    let default_lang = state.config.general.default_lang;
+    user_settings_service::get_or_create(&state.db, telegram_id, default_lang)
+        .await
+        .map_or(default_lang, |u| {
+            LanguageCode::from_str_or_default(&u.language_code, default_lang)
+        })
+}
+
+async fn admin_recipients(state: &AppState) -> Vec<i64> {
+    let mut recipients = vec![state.config.telegram.admin_chat_id];
+    match state.db.get_all_admins().await {
+        Ok(admins) => {
+            for admin in admins {
+                if !recipients.contains(&admin) {
+                    recipients.push(admin);
+                }
+            }
+        }
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to load admin list for registration");
+        }
+    }
+    recipients
+}
+
+async fn notify_admins(
+    bot: &Bot,
+    state: &AppState,
+    request_id: i64,
+    applicant: &Applicant,
+    server_id: ServerId,
+    username: &str,
+) {
+    for admin_id in admin_recipients(state).await {
+        let lang = user_lang(state, admin_id).await;
+        let text = locales::get_text(
+            lang.as_str(),
+            "registration-request",
+            args!(
+                name = html::escape(&applicant.name),
+                id = applicant.telegram_id,
+                username = username.to_string(),
+                server = html::escape(state.config.server_display_name(server_id))
+            )
+            .as_ref(),
+        );
+        let keyboard = confirm_cancel_keyboard(
+            lang,
+            "btn-registration-approve",
+            CallbackAction::Admin(AdminAction::RegistrationApprove { request_id }),
+            "btn-registration-reject",
+            CallbackAction::Admin(AdminAction::RegistrationReject { request_id }),
+        );
+        if let Err(e) = bot
+            .send_message(teloxide::types::ChatId(admin_id), text)
+            .parse_mode(teloxide::types::ParseMode::Html)
+            .reply_markup(keyboard)
+            .await
+        {
+            tracing::error!(admin_id, request_id, error = %e, "Failed to send registration request");
+        }
+    }
+}
+
+pub enum ApproveOutcome {
+    Sent,
+    InProgress,
+    NotFound,
+    Failed,
+}
+
+pub async fn approve_registration(
+    state: &AppState,
+    request_id: i64,
+    chat_id: i64,
+    lang: LanguageCode,
+) -> ApproveOutcome {
+    let request = match state.db.claim_registration_request(request_id).await {
+        Ok(Some(request)) => request,
+        Ok(None) => {
+            return match state.db.get_registration_request(request_id).await {
+                Ok(Some(_)) => ApproveOutcome::InProgress,
+                Ok(None) => ApproveOutcome::NotFound,
+                Err(e) => {
+                    tracing::error!(request_id, error = %e, "Failed to load registration request");
+                    ApproveOutcome::Failed
+                }
+            };
+        }
+        Err(e) => {
+            tracing::error!(request_id, error = %e, "Failed to claim registration request");
+            return ApproveOutcome::Failed;
+        }
+    };
+    let Some(server_id) = state.config.server_id_by_key(&request.server_key) else {
+        tracing::warn!(
+            request_id,
+            server_key = %request.server_key,
+            "Registration request points to an unknown server"
+        );
+        release_claim(state, request_id).await;
+        return ApproveOutcome::Failed;
+    };
+    let default_lang = state.config.general.default_lang;
+    let registration = AccountRegistration {
+        request_id,
+        username: request.username,
+        password: registration_service::generate_password(),
+        note: locales::get_text(
+            default_lang.as_str(),
+            "registration-account-note",
+            args!(id = request.telegram_id).as_ref(),
+        ),
+    };
+    let cmd = TtCommand::RegisterAccount {
+        server_id,
+        registration,
+        chat_id,
+        lang,
+    };
+    if let Err(e) = state.tx_tt.send(cmd).await {
+        tracing::error!(request_id, error = %e, "Failed to send registration command");
+        release_claim(state, request_id).await;
+        return ApproveOutcome::Failed;
+    }
+    ApproveOutcome::Sent
+}
+
+async fn release_claim(state: &AppState, request_id: i64) {
+    if let Err(e) = state.db.release_registration_request(request_id).await {
+        tracing::error!(request_id, error = %e, "Failed to release registration request");
+    }
+}
+
+pub async fn reject_registration(
+    bot: &Bot,
+    state: &AppState,
+    request_id: i64,
+) -> Option<RegistrationRequest> {
+    let request = match state.db.withdraw_registration_request(request_id).await {
+        Ok(request) => request?,
+        Err(e) => {
+            tracing::error!(request_id, error = %e, "Failed to remove registration request");
+            return None;
+        }
+    };
+    let lang = user_lang(state, request.telegram_id).await;
+    if let Err(e) = bot
+        .send_message(
+            teloxide::types::ChatId(request.telegram_id),
+            locales::get_text(
+                lang.as_str(),
+                "register-rejected",
+                args!(username = request.username.clone()).as_ref(),
+            ),
+        )
+        .await
+    {
+        tracing::error!(
+            telegram_id = request.telegram_id,
+            error = %e,
+            "Failed to notify rejected applicant"
+        );
+    }
+    Some(request)
+}
@@ -0,0 +1,145 @@
+use crate::adapters::tg::keyboards::{
+    USERS_PER_PAGE, back_btn, back_button_keyboard, create_user_list_keyboard,
+};
+use crate::adapters::tg::state::AppState;
+use crate::adapters::tt::{ServerShared, server_ban_key};
+use crate::args;
+use crate::core::callbacks::{AdminAction, CallbackAction};
+use crate::core::types::{LanguageCode, ServerId, TtCommand};
+use crate::infra::locales;
+use teamtalk::types::BannedUser;
+use teloxide::prelude::*;
+use teloxide::sugar::request::RequestReplyExt;
+use teloxide::types::InlineKeyboardMarkup;
+
+fn ban_line(index: usize, ban: &BannedUser) -> String {
+    let parts = [
+        ban.username.as_str(),
+        ban.nickname.as_str(),
+        ban.ip_address.as_str(),
+        ban.channel_path.as_str(),
+        ban.ban_time.as_str(),
+    ];
+    let details = parts
+        .iter()
+        .filter(|part| !part.is_empty())
+        .copied()
+        .collect::<Vec<_>>()
+        .join(" · ");
+    format!("{}. {details}", index + 1)
+}
+
+fn ban_name(ban: &BannedUser) -> String {
+    if ban.username.is_empty() {
+        ban.ip_address.clone()
+    } else {
+        ban.username.clone()
+    }
+}
+
+fn server_bans_view(
+    state: &AppState,
+    server_id: ServerId,
+    page: usize,
+    skip_key: Option<u64>,
+    lang: LanguageCode,
+) -> (String, InlineKeyboardMarkup) {
+    let server = state.config.server_display_name(server_id).to_string();
+    let bans = state
+        .servers
+        .get(server_id.0)
+        .map(ServerShared::sorted_server_bans)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|ban| Some(server_ban_key(ban)) != skip_key)
+        .collect::<Vec<_>>();
+    let refresh = CallbackAction::Admin(AdminAction::ServerBanList { server_id, page });
+    if bans.is_empty() {
+        let text = locales::get_text(
+            lang.as_str(),
+            "server-bans-empty",
+            args!(server = server).as_ref(),
+        );
+        return (text, back_button_keyboard(lang, "btn-refresh", refresh));
+    }
+
+    let total_pages = bans.len().div_ceil(USERS_PER_PAGE);
+    let page = page.min(total_pages - 1);
+    let start = page * USERS_PER_PAGE;
+    let mut lines = vec![locales::get_text(
+        lang.as_str(),
+        "server-bans-title",
+        args!(server = server, count = bans.len()).as_ref(),
+    )];
+    lines.extend(
+        bans.iter()
+            .enumerate()
+            .skip(start)
+            .take(USERS_PER_PAGE)
+            .map(|(idx, ban)| ban_line(idx, ban)),
+    );
+
+    let keyboard = create_user_list_keyboard(
+        &bans,
+        page,
+        |ban| {
+            (
+                locales::get_text(
+                    lang.as_str(),
+                    "btn-server-unban",
+                    args!(name = ban_name(ban)).as_ref(),
+                ),
+                CallbackAction::Admin(AdminAction::ServerUnban {
+                    server_id,
+                    page,
+                    ban_key: server_ban_key(ban),
+                }),
+            )
+        },
+        |p| CallbackAction::Admin(AdminAction::ServerBanList { server_id, page: p }),
+        Some(back_btn(lang, "btn-refresh", refresh)),
+        lang,
+    );
+    (lines.join("\n"), keyboard)
+}
+
+pub async fn request_server_bans(state: &AppState, server_id: ServerId) {
+    if let Err(e) = state.tx_tt.send(TtCommand::LoadBans { server_id }).await {
+        tracing::error!(%server_id, error = %e, "Failed to request TT server bans");
+    }
+}
+
+pub async fn send_server_bans(
+    bot: &Bot,
+    chat_id: teloxide::types::ChatId,
+    state: &AppState,
+    server_id: ServerId,
+    lang: LanguageCode,
+    reply_to: Option<teloxide::types::MessageId>,
+) -> ResponseResult<()> {
+    let (text, keyboard) = server_bans_view(state, server_id, 0, None, lang);
+    let req = bot.send_message(chat_id, text).reply_markup(keyboard);
+    if let Some(reply_to) = reply_to {
+        req.reply_to(reply_to).await?;
+    } else {
+        req.await?;
+    }
+    request_server_bans(state, server_id).await;
+    Ok(())
+}
+
+pub async fn edit_server_bans(
+    bot: &Bot,
+    msg: &Message,
+    state: &AppState,
+    server_id: ServerId,
+    page: usize,
+    skip_key: Option<u64>,
+    lang: LanguageCode,
+) -> ResponseResult<()> {
+    let (text, keyboard) = server_bans_view(state, server_id, page, skip_key, lang);
+    bot.edit_message_text(msg.chat.id, msg.id, text)
+        .reply_markup(keyboard)
+        .await?;
+    Ok(())
+}
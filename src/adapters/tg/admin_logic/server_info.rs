@@ -0,0 +1,137 @@
+use crate::adapters::tg::keyboards::{back_button_keyboard, create_server_info_keyboard};
+use crate::adapters::tg::state::{AppState, PendingServerEdit};
+use crate::adapters::tg::utils::send_text_key;
+use crate::adapters::tt::ServerShared;
+use crate::app::services::server_info as server_info_service;
+use crate::args;
+use crate::core::callbacks::{AdminAction, CallbackAction};
+use crate::core::types::{LanguageCode, ServerField, ServerId, TtCommand};
+use crate::infra::locales;
+use teloxide::prelude::*;
+use teloxide::sugar::request::RequestReplyExt;
+use teloxide::types::InlineKeyboardMarkup;
+use teloxide::{ApiError, RequestError};
+
+fn server_info_view(
+    state: &AppState,
+    server_id: ServerId,
+    lang: LanguageCode,
+) -> (String, InlineKeyboardMarkup) {
+    let text = state
+        .servers
+        .get(server_id.0)
+        .and_then(ServerShared::server_info)
+        .map_or_else(
+            || {
+                locales::get_text(
+                    lang.as_str(),
+                    "server-info-unavailable",
+                    args!(server = state.config.server_display_name(server_id).to_string())
+                        .as_ref(),
+                )
+            },
+            |info| server_info_service::server_details(lang, &info),
+        );
+    (text, create_server_info_keyboard(lang, server_id))
+}
+
+pub async fn send_server_info(
+    bot: &Bot,
+    chat_id: teloxide::types::ChatId,
+    state: &AppState,
+    server_id: ServerId,
+    lang: LanguageCode,
+    reply_to: Option<teloxide::types::MessageId>,
+) -> ResponseResult<()> {
+    let (text, keyboard) = server_info_view(state, server_id, lang);
+    let req = bot.send_message(chat_id, text).reply_markup(keyboard);
+    if let Some(reply_to) = reply_to {
+        req.reply_to(reply_to).await?;
+    } else {
+        req.await?;
+    }
+    Ok(())
+}
+
+pub async fn edit_server_info(
+    bot: &Bot,
+    msg: &Message,
+    state: &AppState,
+    server_id: ServerId,
+    lang: LanguageCode,
+) -> ResponseResult<()> {
+    if let Ok(mut edits) = state.pending_server_edits.write() {
+        edits.remove(&(msg.chat.id.0, msg.id.0));
+    }
+    let (text, keyboard) = server_info_view(state, server_id, lang);
+    match bot
+        .edit_message_text(msg.chat.id, msg.id, text)
+        .reply_markup(keyboard)
+        .await
+    {
+        Ok(_) | Err(RequestError::Api(ApiError::MessageNotModified)) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn edit_server_prompt(
+    bot: &Bot,
+    msg: &Message,
+    state: &AppState,
+    pending: PendingServerEdit,
+    lang: LanguageCode,
+) -> ResponseResult<()> {
+    let key = match pending.field {
+        ServerField::Name => "server-name-prompt",
+        ServerField::Motd => "server-motd-prompt",
+    };
+    if let Ok(mut edits) = state.pending_server_edits.write() {
+        edits.insert((msg.chat.id.0, msg.id.0), pending);
+    }
+    bot.edit_message_text(
+        msg.chat.id,
+        msg.id,
+        locales::get_text(lang.as_str(), key, None),
+    )
+    .reply_markup(back_button_keyboard(
+        lang,
+        "btn-back-server",
+        CallbackAction::Admin(AdminAction::ServerInfo {
+            server_id: pending.server_id,
+        }),
+    ))
+    .await?;
+    Ok(())
+}
+
+pub async fn handle_server_reply(
+    bot: &Bot,
+    msg: &Message,
+    prompt: &Message,
+    state: &AppState,
+    pending: PendingServerEdit,
+    text: &str,
+    lang: LanguageCode,
+) -> ResponseResult<()> {
+    let Some(edit) = server_info_service::parse_server_edit(pending.field, text) else {
+        return send_text_key(bot, msg.chat.id, lang, "server-name-usage", Some(msg.id)).await;
+    };
+    let cmd = TtCommand::EditServer {
+        server_id: pending.server_id,
+        edit,
+        chat_id: msg.chat.id.0,
+        lang,
+    };
+    let result_key = match state.tx_tt.send(cmd).await {
+        Ok(()) => "toast-command-sent",
+        Err(e) => {
+            tracing::error!(server_id = %pending.server_id, error = %e, "Failed to send server edit");
+            "cmd-error"
+        }
+    };
+    if let Ok(mut edits) = state.pending_server_edits.write() {
+        edits.remove(&(msg.chat.id.0, prompt.id.0));
+    }
+    let _ = bot.edit_message_reply_markup(msg.chat.id, prompt.id).await;
+    send_text_key(bot, msg.chat.id, lang, result_key, Some(msg.id)).await
+}
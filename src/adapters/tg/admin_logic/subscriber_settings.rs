@@ -1,13 +1,13 @@
 use crate::adapters::tg::keyboards::{
     back_btn, back_button, callback_button, create_user_list_keyboard,
 };
+use crate::adapters::tt::{ServerShared, merged_user_accounts};
 use crate::app::services::user_settings as user_settings_service;
 use crate::args;
 use crate::core::callbacks::{CallbackAction, SubAction};
 use crate::core::types::{LanguageCode, MuteListMode, NotificationSetting, TtUsername};
 use crate::infra::db::Database;
 use crate::infra::locales;
-use teamtalk::types::UserAccount;
 use teloxide::prelude::*;
 use teloxide::types::InlineKeyboardMarkup;
 
@@ -78,21 +78,13 @@ pub async fn send_sub_manage_tt_menu(
 pub async fn send_sub_link_account_list(
     bot: &Bot,
     msg: &Message,
-    user_accounts: &std::sync::Arc<
-        std::sync::RwLock<std::collections::HashMap<String, UserAccount>>,
-    >,
+    servers: &[ServerShared],
     lang: LanguageCode,
     target_id: i64,
     sub_page: usize,
     page: usize,
 ) -> ResponseResult<()> {
-    let mut accounts: Vec<UserAccount> = user_accounts
-        .read()
-        .unwrap_or_else(std::sync::PoisonError::into_inner)
-        .values()
-        .cloned()
-        .collect();
-    accounts.sort_by(|a, b| a.username.to_lowercase().cmp(&b.username.to_lowercase()));
+    let accounts = merged_user_accounts(servers);
 
     let keyboard = create_user_list_keyboard(
         &accounts,
@@ -224,6 +224,13 @@ async fn load_subscriber_settings(db: &Database, sub_id: i64) -> UserSettings {
                 teamtalk_username: None,
                 not_on_online_enabled: false,
                 not_on_online_confirmed: false,
+                channel_notifications_enabled: false,
+                nick_notifications_enabled: false,
+                status_notifications_enabled: false,
+                broadcast_notifications_enabled: true,
+                file_notifications_enabled: true,
+                health_notifications_enabled: false,
+                delivery_mode: "instant".to_string(),
             }
         })
 }
@@ -1,14 +1,37 @@
-use crate::adapters::tg::admin_logic::bans::{edit_unban_list, send_unban_list};
+use crate::adapters::tg::admin_logic::accounts::{
+    edit_account_delete_confirm, edit_account_list, edit_account_prompt, edit_account_view,
+    request_accounts, send_account_list,
+};
+use crate::adapters::tg::admin_logic::bans::{edit_unban_list, execute_ban, send_unban_list};
+use crate::adapters::tg::admin_logic::channels::{
+    edit_channel_list, edit_channel_prompt, edit_channel_view, edit_delete_confirm,
+    send_channel_list,
+};
+use crate::adapters::tg::admin_logic::registrations::{
+    ApproveOutcome, approve_registration, reject_registration,
+};
+use crate::adapters::tg::admin_logic::server_bans::{edit_server_bans, request_server_bans};
+use crate::adapters::tg::admin_logic::server_info::{
+    edit_server_info, edit_server_prompt, send_server_info,
+};
 use crate::adapters::tg::admin_logic::subscribers::{edit_subscribers_list, send_subscribers_list};
-use crate::adapters::tg::keyboards::create_user_list_keyboard;
-use crate::adapters::tg::state::AppState;
+use crate::adapters::tg::commands::{enqueue_tracks, send_media_to_server};
+use crate::adapters::tg::keyboards::{
+    back_btn, back_button_keyboard, create_ban_term_keyboard, create_server_picker_keyboard,
+    create_user_list_keyboard,
+};
+use crate::adapters::tg::state::{
+    AccountInput, AppState, PendingAccountInput, PendingBan, PendingChannelEdit, PendingServerEdit,
+};
 use crate::adapters::tg::utils::{
-    answer_callback, answer_callback_empty, check_db_err, notify_admin_error,
+    answer_callback, answer_callback_empty, check_db_err, notify_admin_error, server_names,
 };
-use crate::app::services::admin_cleanup as admin_cleanup_service;
+use crate::adapters::tt::ServerShared;
+use crate::app::services::bans as bans_service;
+use crate::app::services::music::{self as music_service, MusicCommand};
 use crate::args;
 use crate::core::callbacks::{AdminAction, CallbackAction};
-use crate::core::types::{AdminErrorContext, LanguageCode, LiteUser, TtCommand};
+use crate::core::types::{AccountEdit, AdminErrorContext, LanguageCode, ServerId, TtCommand};
 use crate::infra::locales;
 use teloxide::prelude::*;
 
@@ -24,17 +47,15 @@ pub async fn handle_admin(
     };
     let msg = msg.as_ref();
     match action {
-        AdminAction::KickList { page } => {
-            handle_kick_list(&bot, &q, &state, msg, page, lang).await?;
-        }
-        AdminAction::BanList { page } => {
-            handle_ban_list(&bot, &q, &state, msg, page, lang).await?;
-        }
-        AdminAction::KickPerform { user_id } => {
-            handle_kick_perform(&bot, &q, &state, user_id, lang).await?;
-        }
-        AdminAction::BanPerform { user_id } => {
-            handle_ban_perform(&bot, &q, &state, user_id, lang).await?;
+        action @ (AdminAction::KickServers
+        | AdminAction::KickList { .. }
+        | AdminAction::KickPerform { .. }
+        | AdminAction::BanServers
+        | AdminAction::BanList { .. }
+        | AdminAction::BanPerform { .. }
+        | AdminAction::BanTerm { .. }
+        | AdminAction::BanConfirm { .. }) => {
+            handle_kick_ban_action(&bot, &q, &state, msg, action, lang).await?;
         }
         AdminAction::UnbanList { page } => {
             handle_unban_list(&bot, &q, &state, msg, page, lang).await?;
@@ -42,23 +63,537 @@ pub async fn handle_admin(
         AdminAction::UnbanPerform { ban_db_id, page } => {
             handle_unban_perform(&bot, &q, &state, msg, ban_db_id, page, lang).await?;
         }
+        AdminAction::ServerBanList { server_id, page } => {
+            edit_server_bans(&bot, msg, &state, server_id, page, None, lang).await?;
+            request_server_bans(&state, server_id).await;
+            answer_callback_empty(&bot, &q.id).await?;
+        }
+        AdminAction::ServerUnban {
+            server_id,
+            page,
+            ban_key,
+        } => {
+            handle_server_unban(&bot, &q, &state, msg, server_id, page, ban_key, lang).await?;
+        }
         AdminAction::SubsList { page } => {
             handle_subs_list(&bot, &q, &state, msg, page, lang).await?;
         }
+        action @ (AdminAction::MoveServers
+        | AdminAction::MoveList { .. }
+        | AdminAction::MoveChannels { .. }
+        | AdminAction::MovePerform { .. }) => {
+            handle_move_action(&bot, &q, &state, msg, action, lang).await?;
+        }
+        action @ (AdminAction::ChannelServers
+        | AdminAction::ChannelList { .. }
+        | AdminAction::ChannelView { .. }
+        | AdminAction::ChannelPrompt { .. }
+        | AdminAction::ChannelDelete { .. }
+        | AdminAction::ChannelDeleteConfirm { .. }) => {
+            handle_channel_action(&bot, &q, &state, msg, action, lang).await?;
+        }
+        action @ (AdminAction::AccountServers
+        | AdminAction::AccountList { .. }
+        | AdminAction::AccountView { .. }
+        | AdminAction::AccountSearch { .. }
+        | AdminAction::AccountCreate { .. }
+        | AdminAction::AccountPrompt { .. }
+        | AdminAction::AccountSetType { .. }
+        | AdminAction::AccountDelete { .. }
+        | AdminAction::AccountDeleteConfirm { .. }) => {
+            handle_account_action(&bot, &q, &state, msg, action, lang).await?;
+        }
+        action @ (AdminAction::ServerInfoServers
+        | AdminAction::ServerInfo { .. }
+        | AdminAction::ServerPrompt { .. }) => {
+            handle_server_info_action(&bot, &q, &state, msg, action, lang).await?;
+        }
+        AdminAction::RegistrationApprove { request_id } => {
+            handle_registration_approve(&bot, &q, &state, msg, request_id, lang).await?;
+        }
+        AdminAction::RegistrationReject { request_id } => {
+            handle_registration_reject(&bot, &q, &state, msg, request_id, lang).await?;
+        }
+        AdminAction::MediaServer { server_id } => {
+            handle_media_server(&bot, &q, &state, msg, server_id, lang).await?;
+        }
+        AdminAction::MusicServer { server_id } => {
+            handle_music_server(&bot, &q, &state, msg, server_id, lang).await?;
+        }
     }
     Ok(())
 }
 
+async fn handle_media_server(
+    bot: &Bot,
+    q: &CallbackQuery,
+    state: &AppState,
+    msg: &Message,
+    server_id: ServerId,
+    lang: LanguageCode,
+) -> ResponseResult<()> {
+    let Some(media) = msg.reply_to_message() else {
+        answer_callback(
+            bot,
+            &q.id,
+            locales::get_text(lang.as_str(), "cmd-error", None),
+            true,
+        )
+        .await?;
+        return Ok(());
+    };
+    answer_callback_empty(bot, &q.id).await?;
+    let _ = bot.edit_message_reply_markup(msg.chat.id, msg.id).await;
+    let telegram_id = tg_user_id_i64(q.from.id.0);
+    send_media_to_server(bot, media, state, telegram_id, lang, server_id).await
+}
+
+async fn handle_music_server(
+    bot: &Bot,
+    q: &CallbackQuery,
+    state: &AppState,
+    msg: &Message,
+    server_id: ServerId,
+    lang: LanguageCode,
+) -> ResponseResult<()> {
+    let Some(command) = msg
+        .reply_to_message()
+        .and_then(Message::text)
+        .and_then(music_service::parse_music_command)
+    else {
+        answer_callback(
+            bot,
+            &q.id,
+            locales::get_text(lang.as_str(), "cmd-error", None),
+            true,
+        )
+        .await?;
+        return Ok(());
+    };
+    answer_callback_empty(bot, &q.id).await?;
+    let library = state.config.music.library_dir();
+    let reply = match command {
+        MusicCommand::Play { query } => Ok(music_service::play(library, lang, query).await),
+        MusicCommand::Playlist { args } => {
+            music_service::playlist(&state.db, library, lang, args).await
+        }
+    };
+    let res = match reply {
+        Ok(reply) => enqueue_tracks(state, &reply.enqueue, server_id)
+            .await
+            .map(|()| reply.text)
+            .map_err(|e| (AdminErrorContext::TtCommand, e)),
+        Err(e) => Err((AdminErrorContext::Command, e.to_string())),
+    };
+    let text = match res {
+        Ok(text) => text,
+        Err((context, e)) => {
+            tracing::error!(error = %e, "Failed to run music request");
+            notify_admin_error(
+                bot,
+                &state.config,
+                tg_user_id_i64(q.from.id.0),
+                context,
+                &e,
+                lang,
+            )
+            .await;
+            locales::get_text(lang.as_str(), "cmd-error", None)
+        }
+    };
+    bot.edit_message_text(msg.chat.id, msg.id, text).await?;
+    Ok(())
+}
+
+async fn handle_kick_ban_action(
+    bot: &Bot,
+    q: &CallbackQuery,
+    state: &AppState,
+    msg: &Message,
+    action: AdminAction,
+    lang: LanguageCode,
+) -> ResponseResult<()> {
+    match action {
+        AdminAction::KickServers => {
+            if state.config.is_multi_server() {
+                send_server_picker(bot, q, state, msg, lang, |server_id| {
+                    AdminAction::KickList { server_id, page: 0 }
+                })
+                .await?;
+            } else {
+                handle_kick_list(bot, q, state, msg, ServerId(0), 0, lang).await?;
+            }
+        }
+        AdminAction::BanServers => {
+            if state.config.is_multi_server() {
+                send_server_picker(bot, q, state, msg, lang, |server_id| AdminAction::BanList {
+                    server_id,
+                    page: 0,
+                })
+                .await?;
+            } else {
+                handle_ban_list(bot, q, state, msg, ServerId(0), 0, lang).await?;
+            }
+        }
+        AdminAction::KickList { server_id, page } => {
+            handle_kick_list(bot, q, state, msg, server_id, page, lang).await?;
+        }
+        AdminAction::BanList { server_id, page } => {
+            handle_ban_list(bot, q, state, msg, server_id, page, lang).await?;
+        }
+        AdminAction::KickPerform { server_id, user_id } => {
+            handle_kick_perform(bot, q, state, server_id, user_id, lang).await?;
+        }
+        AdminAction::BanPerform { server_id, user_id } => {
+            handle_ban_perform(bot, q, state, msg, server_id, user_id, lang).await?;
+        }
+        AdminAction::BanTerm {
+            server_id,
+            user_id,
+            term,
+        } => {
+            let pending = PendingBan {
+                server_id,
+                user_id,
+                term,
+            };
+            handle_ban_term(bot, q, state, msg, pending, lang).await?;
+        }
+        AdminAction::BanConfirm {
+            server_id,
+            user_id,
+            term,
+        } => {
+            let pending = PendingBan {
+                server_id,
+                user_id,
+                term,
+            };
+            handle_ban_confirm(bot, q, state, msg, pending, lang).await?;
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+async fn handle_move_action(
+    bot: &Bot,
+    q: &CallbackQuery,
+    state: &AppState,
+    msg: &Message,
+    action: AdminAction,
+    lang: LanguageCode,
+) -> ResponseResult<()> {
+    match action {
+        AdminAction::MoveServers => {
+            if state.config.is_multi_server() {
+                send_server_picker(bot, q, state, msg, lang, |server_id| {
+                    AdminAction::MoveList { server_id, page: 0 }
+                })
+                .await?;
+            } else {
+                handle_move_list(bot, q, state, msg, ServerId(0), 0, lang).await?;
+            }
+        }
+        AdminAction::MoveList { server_id, page } => {
+            handle_move_list(bot, q, state, msg, server_id, page, lang).await?;
+        }
+        AdminAction::MoveChannels {
+            server_id,
+            user_id,
+            page,
+        } => {
+            handle_move_channels(bot, q, state, msg, server_id, user_id, page, lang).await?;
+        }
+        AdminAction::MovePerform {
+            server_id,
+            user_id,
+            channel_id,
+        } => {
+            handle_move_perform(bot, q, state, msg, server_id, user_id, channel_id, lang).await?;
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+async fn handle_channel_action(
+    bot: &Bot,
+    q: &CallbackQuery,
+    state: &AppState,
+    msg: &Message,
+    action: AdminAction,
+    lang: LanguageCode,
+) -> ResponseResult<()> {
+    match action {
+        AdminAction::ChannelServers => {
+            if state.config.is_multi_server() {
+                send_server_picker(bot, q, state, msg, lang, |server_id| {
+                    AdminAction::ChannelList { server_id, page: 0 }
+                })
+                .await?;
+            } else {
+                send_channel_list(bot, msg.chat.id, state, ServerId(0), lang, None).await?;
+                answer_callback_empty(bot, &q.id).await?;
+            }
+        }
+        AdminAction::ChannelList { server_id, page } => {
+            edit_channel_list(bot, msg, state, server_id, page, lang).await?;
+            answer_callback_empty(bot, &q.id).await?;
+        }
+        AdminAction::ChannelView {
+            server_id,
+            channel_id,
+        } => {
+            if let Ok(mut edits) = state.pending_channel_edits.write() {
+                edits.remove(&(msg.chat.id.0, msg.id.0));
+            }
+            edit_channel_view(bot, msg, state, server_id, channel_id, lang).await?;
+            answer_callback_empty(bot, &q.id).await?;
+        }
+        AdminAction::ChannelPrompt {
+            server_id,
+            channel_id,
+            field,
+        } => {
+            let pending = PendingChannelEdit {
+                server_id,
+                channel_id,
+                field,
+            };
+            edit_channel_prompt(bot, msg, state, pending, lang).await?;
+            answer_callback_empty(bot, &q.id).await?;
+        }
+        AdminAction::ChannelDelete {
+            server_id,
+            channel_id,
+        } => {
+            edit_delete_confirm(bot, msg, state, server_id, channel_id, lang).await?;
+            answer_callback_empty(bot, &q.id).await?;
+        }
+        AdminAction::ChannelDeleteConfirm {
+            server_id,
+            channel_id,
+        } => {
+            handle_channel_delete(bot, q, state, msg, server_id, channel_id, lang).await?;
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+async fn handle_account_action(
+    bot: &Bot,
+    q: &CallbackQuery,
+    state: &AppState,
+    msg: &Message,
+    action: AdminAction,
+    lang: LanguageCode,
+) -> ResponseResult<()> {
+    let prompt_key = (msg.chat.id.0, msg.id.0);
+    match action {
+        AdminAction::AccountServers => {
+            if state.config.is_multi_server() {
+                send_server_picker(bot, q, state, msg, lang, |server_id| {
+                    AdminAction::AccountList { server_id, page: 0 }
+                })
+                .await?;
+            } else {
+                send_account_list(
+                    bot,
+                    msg.chat.id,
+                    state,
+                    ServerId(0),
+                    String::new(),
+                    lang,
+                    None,
+                )
+                .await?;
+                request_accounts(state, ServerId(0)).await;
+                answer_callback_empty(bot, &q.id).await?;
+            }
+        }
+        AdminAction::AccountList { server_id, page } => {
+            if let Ok(mut inputs) = state.pending_account_inputs.write() {
+                inputs.remove(&prompt_key);
+            }
+            edit_account_list(bot, msg, state, server_id, page, lang).await?;
+            if page == 0 {
+                request_accounts(state, server_id).await;
+            }
+            answer_callback_empty(bot, &q.id).await?;
+        }
+        AdminAction::AccountView {
+            server_id,
+            username,
+        } => {
+            if let Ok(mut inputs) = state.pending_account_inputs.write() {
+                inputs.remove(&prompt_key);
+            }
+            edit_account_view(bot, msg, state, server_id, &username, lang).await?;
+            answer_callback_empty(bot, &q.id).await?;
+        }
+        action @ (AdminAction::AccountSearch { .. }
+        | AdminAction::AccountCreate { .. }
+        | AdminAction::AccountPrompt { .. }) => {
+            if let Some(pending) = account_input(action) {
+                edit_account_prompt(bot, msg, state, pending, lang).await?;
+            }
+            answer_callback_empty(bot, &q.id).await?;
+        }
+        AdminAction::AccountSetType {
+            server_id,
+            username,
+            account_type,
+        } => {
+            let cmd = TtCommand::EditAccount {
+                server_id,
+                username: username.to_string(),
+                edit: AccountEdit::Type(account_type),
+                chat_id: msg.chat.id.0,
+                lang,
+            };
+            send_account_command(bot, q, state, cmd, lang).await?;
+        }
+        AdminAction::AccountDelete {
+            server_id,
+            username,
+        } => {
+            edit_account_delete_confirm(bot, msg, state, server_id, username, lang).await?;
+            answer_callback_empty(bot, &q.id).await?;
+        }
+        AdminAction::AccountDeleteConfirm {
+            server_id,
+            username,
+        } => {
+            let cmd = TtCommand::DeleteAccount {
+                server_id,
+                username: username.to_string(),
+                chat_id: msg.chat.id.0,
+                lang,
+            };
+            edit_account_list(bot, msg, state, server_id, 0, lang).await?;
+            send_account_command(bot, q, state, cmd, lang).await?;
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+async fn handle_server_info_action(
+    bot: &Bot,
+    q: &CallbackQuery,
+    state: &AppState,
+    msg: &Message,
+    action: AdminAction,
+    lang: LanguageCode,
+) -> ResponseResult<()> {
+    match action {
+        AdminAction::ServerInfoServers => {
+            if state.config.is_multi_server() {
+                send_server_picker(bot, q, state, msg, lang, |server_id| {
+                    AdminAction::ServerInfo { server_id }
+                })
+                .await?;
+            } else {
+                send_server_info(bot, msg.chat.id, state, ServerId(0), lang, None).await?;
+                answer_callback_empty(bot, &q.id).await?;
+            }
+        }
+        AdminAction::ServerInfo { server_id } => {
+            edit_server_info(bot, msg, state, server_id, lang).await?;
+            answer_callback_empty(bot, &q.id).await?;
+        }
+        AdminAction::ServerPrompt { server_id, field } => {
+            let pending = PendingServerEdit { server_id, field };
+            edit_server_prompt(bot, msg, state, pending, lang).await?;
+            answer_callback_empty(bot, &q.id).await?;
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+async fn handle_registration_approve(
+    bot: &Bot,
+    q: &CallbackQuery,
+    state: &AppState,
+    msg: &Message,
+    request_id: i64,
+    lang: LanguageCode,
+) -> ResponseResult<()> {
+    let (key, alert) = match approve_registration(state, request_id, msg.chat.id.0, lang).await {
+        ApproveOutcome::Sent => ("toast-command-sent", false),
+        ApproveOutcome::InProgress => ("registration-in-progress", true),
+        ApproveOutcome::NotFound => {
+            let _ = bot.edit_message_reply_markup(msg.chat.id, msg.id).await;
+            ("registration-not-found", true)
+        }
+        ApproveOutcome::Failed => ("cmd-error", true),
+    };
+    answer_callback(
+        bot,
+        &q.id,
+        locales::get_text(lang.as_str(), key, None),
+        alert,
+    )
+    .await
+}
+
+async fn handle_registration_reject(
+    bot: &Bot,
+    q: &CallbackQuery,
+    state: &AppState,
+    msg: &Message,
+    request_id: i64,
+    lang: LanguageCode,
+) -> ResponseResult<()> {
+    let Some(request) = reject_registration(bot, state, request_id).await else {
+        let key = if matches!(
+            state.db.get_registration_request(request_id).await,
+            Ok(Some(_))
+        ) {
+            "registration-in-progress"
+        } else {
+            let _ = bot.edit_message_reply_markup(msg.chat.id, msg.id).await;
+            "registration-not-found"
+        };
+        return answer_callback(
+            bot,
+            &q.id,
+            locales::get_text(lang.as_str(), key, None),
+            true,
+        )
+        .await;
+    };
+    bot.edit_message_text(
+        msg.chat.id,
+        msg.id,
+        locales::get_text(
+            lang.as_str(),
+            "registration-rejected-admin",
+            args!(username = request.username).as_ref(),
+        ),
+    )
+    .await?;
+    answer_callback_empty(bot, &q.id).await
+}
+
 async fn handle_kick_list(
     bot: &Bot,
     q: &CallbackQuery,
     state: &AppState,
     msg: &Message,
+    server_id: ServerId,
     page: usize,
     lang: LanguageCode,
 ) -> ResponseResult<()> {
-    let users = sorted_online_users(&state.online_users);
-    let args = args!(server = state.config.teamtalk.display_name().to_string());
+    let users = state
+        .servers
+        .get(server_id.0)
+        .map(ServerShared::sorted_online_users)
+        .unwrap_or_default();
+    let args = args!(server = state.config.server_display_name(server_id).to_string());
     let title = locales::get_text(lang.as_str(), "list-kick-title", args.as_ref());
     let keyboard = create_user_list_keyboard(
         &users,
@@ -66,10 +601,13 @@ async fn handle_kick_list(
         |u| {
             (
                 u.nickname.clone(),
-                CallbackAction::Admin(AdminAction::KickPerform { user_id: u.id }),
+                CallbackAction::Admin(AdminAction::KickPerform {
+                    server_id,
+                    user_id: u.id,
+                }),
             )
         },
-        |p| CallbackAction::Admin(AdminAction::KickList { page: p }),
+        |p| CallbackAction::Admin(AdminAction::KickList { server_id, page: p }),
         None,
         lang,
     );
@@ -82,11 +620,16 @@ async fn handle_ban_list(
     q: &CallbackQuery,
     state: &AppState,
     msg: &Message,
+    server_id: ServerId,
     page: usize,
     lang: LanguageCode,
 ) -> ResponseResult<()> {
-    let users = sorted_online_users(&state.online_users);
-    let args = args!(server = state.config.teamtalk.display_name().to_string());
+    let users = state
+        .servers
+        .get(server_id.0)
+        .map(ServerShared::sorted_online_users)
+        .unwrap_or_default();
+    let args = args!(server = state.config.server_display_name(server_id).to_string());
     let title = locales::get_text(lang.as_str(), "list-ban-title", args.as_ref());
     let keyboard = create_user_list_keyboard(
         &users,
@@ -94,10 +637,13 @@ async fn handle_ban_list(
         |u| {
             (
                 u.nickname.clone(),
-                CallbackAction::Admin(AdminAction::BanPerform { user_id: u.id }),
+                CallbackAction::Admin(AdminAction::BanPerform {
+                    server_id,
+                    user_id: u.id,
+                }),
             )
         },
-        |p| CallbackAction::Admin(AdminAction::BanList { page: p }),
+        |p| CallbackAction::Admin(AdminAction::BanList { server_id, page: p }),
         None,
         lang,
     );
@@ -109,10 +655,15 @@ async fn handle_kick_perform(
     bot: &Bot,
     q: &CallbackQuery,
     state: &AppState,
+    server_id: ServerId,
     user_id: i32,
     lang: LanguageCode,
 ) -> ResponseResult<()> {
-    if let Err(e) = state.tx_tt.send(TtCommand::KickUser { user_id }).await {
+    if let Err(e) = state
+        .tx_tt
+        .send(TtCommand::KickUser { server_id, user_id })
+        .await
+    {
         tracing::error!(user_id, error = %e, "Failed to send kick command");
         notify_admin_error(
             bot,
@@ -133,20 +684,56 @@ async fn handle_kick_perform(
     .await
 }
 
-async fn handle_ban_perform(
+async fn handle_move_list(
     bot: &Bot,
     q: &CallbackQuery,
     state: &AppState,
+    msg: &Message,
+    server_id: ServerId,
+    page: usize,
+    lang: LanguageCode,
+) -> ResponseResult<()> {
+    let users = state
+        .servers
+        .get(server_id.0)
+        .map(ServerShared::sorted_online_users)
+        .unwrap_or_default();
+    let args = args!(server = state.config.server_display_name(server_id).to_string());
+    let title = locales::get_text(lang.as_str(), "list-move-title", args.as_ref());
+    let keyboard = create_user_list_keyboard(
+        &users,
+        page,
+        |u| {
+            (
+                u.nickname.clone(),
+                CallbackAction::Admin(AdminAction::MoveChannels {
+                    server_id,
+                    user_id: u.id,
+                    page: 0,
+                }),
+            )
+        },
+        |p| CallbackAction::Admin(AdminAction::MoveList { server_id, page: p }),
+        None,
+        lang,
+    );
+    send_or_edit_list(bot, msg, page, title, keyboard).await?;
+    answer_callback_empty(bot, &q.id).await
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn handle_move_channels(
+    bot: &Bot,
+    q: &CallbackQuery,
+    state: &AppState,
+    msg: &Message,
+    server_id: ServerId,
     user_id: i32,
+    page: usize,
     lang: LanguageCode,
 ) -> ResponseResult<()> {
-    let user = state
-        .online_users
-        .read()
-        .unwrap_or_else(std::sync::PoisonError::into_inner)
-        .get(&user_id)
-        .cloned();
-    let Some(u) = user else {
+    let server = state.servers.get(server_id.0);
+    let Some(user) = server.and_then(|server| server.online_user(user_id)) else {
         return answer_callback(
             bot,
             &q.id,
@@ -155,69 +742,158 @@ async fn handle_ban_perform(
         )
         .await;
     };
+    let channels = server
+        .map(ServerShared::channels)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|channel| channel.id != user.channel_id)
+        .collect::<Vec<_>>();
+    let keyboard = create_user_list_keyboard(
+        &channels,
+        page,
+        |channel| {
+            (
+                channel.path.clone(),
+                CallbackAction::Admin(AdminAction::MovePerform {
+                    server_id,
+                    user_id,
+                    channel_id: channel.id,
+                }),
+            )
+        },
+        |p| {
+            CallbackAction::Admin(AdminAction::MoveChannels {
+                server_id,
+                user_id,
+                page: p,
+            })
+        },
+        Some(back_btn(
+            lang,
+            "btn-back-users",
+            CallbackAction::Admin(AdminAction::MoveList { server_id, page: 0 }),
+        )),
+        lang,
+    );
+    bot.edit_message_text(
+        msg.chat.id,
+        msg.id,
+        locales::get_text(
+            lang.as_str(),
+            "move-channel-title",
+            args!(name = user.nickname).as_ref(),
+        ),
+    )
+    .reply_markup(keyboard)
+    .await?;
+    answer_callback_empty(bot, &q.id).await
+}
 
-    if let Err(e) = state
-        .db
-        .add_ban(
-            None,
-            Some(u.username.clone()),
-            Some("Banned via Telegram".to_string()),
-        )
-        .await
-    {
-        tracing::error!(tt_username = %u.username, error = %e, "Failed to add ban");
+#[allow(clippy::too_many_arguments)]
+async fn handle_move_perform(
+    bot: &Bot,
+    q: &CallbackQuery,
+    state: &AppState,
+    msg: &Message,
+    server_id: ServerId,
+    user_id: i32,
+    channel_id: i32,
+    lang: LanguageCode,
+) -> ResponseResult<()> {
+    let cmd = TtCommand::MoveUser {
+        server_id,
+        user_id,
+        channel_id,
+        chat_id: msg.chat.id.0,
+        lang,
+    };
+    if let Err(e) = state.tx_tt.send(cmd).await {
+        tracing::error!(user_id, channel_id, error = %e, "Failed to send move command");
         notify_admin_error(
             bot,
             &state.config,
             tg_user_id_i64(q.from.id.0),
-            AdminErrorContext::Callback,
+            AdminErrorContext::TtCommand,
             &e.to_string(),
             lang,
         )
         .await;
-        answer_callback(
+    }
+    answer_callback(
+        bot,
+        &q.id,
+        locales::get_text(lang.as_str(), "toast-command-sent", None),
+        false,
+    )
+    .await
+}
+
+async fn handle_channel_delete(
+    bot: &Bot,
+    q: &CallbackQuery,
+    state: &AppState,
+    msg: &Message,
+    server_id: ServerId,
+    channel_id: i32,
+    lang: LanguageCode,
+) -> ResponseResult<()> {
+    let cmd = TtCommand::DeleteChannel {
+        server_id,
+        channel_id,
+        chat_id: msg.chat.id.0,
+        lang,
+    };
+    if let Err(e) = state.tx_tt.send(cmd).await {
+        tracing::error!(channel_id, error = %e, "Failed to send channel delete command");
+        notify_admin_error(
             bot,
-            &q.id,
-            locales::get_text(lang.as_str(), "cmd-error", None),
-            true,
+            &state.config,
+            tg_user_id_i64(q.from.id.0),
+            AdminErrorContext::TtCommand,
+            &e.to_string(),
+            lang,
         )
-        .await?;
-        return Ok(());
+        .await;
     }
+    edit_channel_list(bot, msg, state, server_id, 0, lang).await?;
+    answer_callback(
+        bot,
+        &q.id,
+        locales::get_text(lang.as_str(), "toast-command-sent", None),
+        false,
+    )
+    .await
+}
 
-    if let Some(tg_id) =
-        admin_cleanup_service::get_telegram_id_by_tt_user(&state.db, &u.username).await
-    {
-        if let Err(e) = admin_cleanup_service::cleanup_deleted_banned_user(&state.db, tg_id).await {
-            tracing::error!(
-                tt_username = %u.username,
-                error = %e,
-                "Failed to delete user profile during ban"
-            );
-        }
-        if let Err(e) = state
-            .db
-            .add_ban(
-                Some(tg_id),
-                Some(u.username.clone()),
-                Some("TG+TT Ban".to_string()),
-            )
-            .await
-        {
-            tracing::error!(
-                tt_username = %u.username,
-                error = %e,
-                "Failed to add second ban record"
-            );
-        }
-    }
-    if let Err(e) = state.tx_tt.send(TtCommand::BanUser { user_id }).await {
-        tracing::error!(
-            user_id,
-            tt_username = %u.username,
-            error = %e,
-            "Failed to send ban command"
-        );
+fn account_input(action: AdminAction) -> Option<PendingAccountInput> {
+    let (server_id, input) = match action {
+        AdminAction::AccountSearch { server_id } => (server_id, AccountInput::Search),
+        AdminAction::AccountCreate { server_id } => (server_id, AccountInput::Create),
+        AdminAction::AccountPrompt {
+            server_id,
+            username,
+            field,
+        } => (
+            server_id,
+            AccountInput::Field {
+                username: username.to_string(),
+                field,
+            },
+        ),
+        _ => return None,
+    };
+    Some(PendingAccountInput { server_id, input })
+}
+
+async fn send_account_command(
+    bot: &Bot,
+    q: &CallbackQuery,
+    state: &AppState,
+    cmd: TtCommand,
+    lang: LanguageCode,
+) -> ResponseResult<()> {
+    if let Err(e) = state.tx_tt.send(cmd).await {
+        tracing::error!(error = %e, "Failed to send account command");
         notify_admin_error(
             bot,
             &state.config,
@@ -237,6 +913,119 @@ async fn handle_ban_perform(
     .await
 }
 
+async fn handle_ban_perform(
+    bot: &Bot,
+    q: &CallbackQuery,
+    state: &AppState,
+    msg: &Message,
+    server_id: ServerId,
+    user_id: i32,
+    lang: LanguageCode,
+) -> ResponseResult<()> {
+    let user = state
+        .servers
+        .get(server_id.0)
+        .and_then(|server| server.online_user(user_id));
+    let Some(u) = user else {
+        return answer_callback(
+            bot,
+            &q.id,
+            locales::get_text(lang.as_str(), "cmd-no-users", None),
+            true,
+        )
+        .await;
+    };
+    bot.edit_message_text(
+        msg.chat.id,
+        msg.id,
+        locales::get_text(
+            lang.as_str(),
+            "ban-term-title",
+            args!(name = u.nickname).as_ref(),
+        ),
+    )
+    .reply_markup(create_ban_term_keyboard(lang, server_id, user_id))
+    .await?;
+    answer_callback_empty(bot, &q.id).await
+}
+
+async fn handle_ban_term(
+    bot: &Bot,
+    q: &CallbackQuery,
+    state: &AppState,
+    msg: &Message,
+    pending: PendingBan,
+    lang: LanguageCode,
+) -> ResponseResult<()> {
+    let user = state
+        .servers
+        .get(pending.server_id.0)
+        .and_then(|server| server.online_user(pending.user_id));
+    let Some(u) = user else {
+        return answer_callback(
+            bot,
+            &q.id,
+            locales::get_text(lang.as_str(), "cmd-no-users", None),
+            true,
+        )
+        .await;
+    };
+    if let Ok(mut pending_bans) = state.pending_bans.write() {
+        pending_bans.insert((msg.chat.id.0, msg.id.0), pending);
+    }
+    let PendingBan {
+        server_id,
+        user_id,
+        term,
+    } = pending;
+    bot.edit_message_text(
+        msg.chat.id,
+        msg.id,
+        locales::get_text(
+            lang.as_str(),
+            "ban-reason-prompt",
+            args!(
+                name = u.nickname,
+                term = bans_service::term_label(lang, term)
+            )
+            .as_ref(),
+        ),
+    )
+    .reply_markup(back_button_keyboard(
+        lang,
+        "btn-ban-no-reason",
+        CallbackAction::Admin(AdminAction::BanConfirm {
+            server_id,
+            user_id,
+            term,
+        }),
+    ))
+    .await?;
+    answer_callback_empty(bot, &q.id).await
+}
+
+async fn handle_ban_confirm(
+    bot: &Bot,
+    q: &CallbackQuery,
+    state: &AppState,
+    msg: &Message,
+    pending: PendingBan,
+    lang: LanguageCode,
+) -> ResponseResult<()> {
+    if let Ok(mut pending_bans) = state.pending_bans.write() {
+        pending_bans.remove(&(msg.chat.id.0, msg.id.0));
+    }
+    let text = execute_ban(bot, state, tg_user_id_i64(q.from.id.0), lang, pending, None).await;
+    bot.edit_message_text(msg.chat.id, msg.id, text).await?;
+    answer_callback(
+        bot,
+        &q.id,
+        locales::get_text(lang.as_str(), "toast-command-sent", None),
+        false,
+    )
+    .await
+}
+
 async fn handle_unban_list(
     bot: &Bot,
     q: &CallbackQuery,
@@ -262,12 +1051,17 @@ async fn handle_unban_perform(
     page: usize,
     lang: LanguageCode,
 ) -> ResponseResult<()> {
+    let admin_id = tg_user_id_i64(q.from.id.0);
+    let ban = state.db.get_ban_by_id(ban_db_id).await;
+    let unban = ban.as_ref().ok().and_then(Option::as_ref).and_then(|ban| {
+        bans_service::unban_command(&state.config, ban, Some((msg.chat.id.0, lang)))
+    });
     if check_db_err(
         bot,
         &q.id.0,
-        state.db.remove_ban_by_id(ban_db_id).await,
+        ban.map(|_| ()),
         &state.config,
-        tg_user_id_i64(q.from.id.0),
+        admin_id,
         AdminErrorContext::Callback,
         lang,
     )
@@ -275,16 +1069,86 @@ async fn handle_unban_perform(
     {
         return Ok(());
     }
+    let key = match unban {
+        Some(cmd) => match state.tx_tt.send(cmd).await {
+            Ok(()) => "toast-unban-sent",
+            Err(e) => {
+                tracing::error!(ban_db_id, error = %e, "Failed to send unban command");
+                "cmd-error"
+            }
+        },
+        None => {
+            let removed = state.db.remove_ban_by_id(ban_db_id).await;
+            if check_db_err(
+                bot,
+                &q.id.0,
+                removed.map(|_| ()),
+                &state.config,
+                admin_id,
+                AdminErrorContext::Callback,
+                lang,
+            )
+            .await?
+            {
+                return Ok(());
+            }
+            "toast-user-unbanned"
+        }
+    };
     answer_callback(
         bot,
         &q.id,
-        locales::get_text(lang.as_str(), "toast-user-unbanned", None),
+        locales::get_text(lang.as_str(), key, None),
         false,
     )
     .await?;
     edit_unban_list(bot, msg, &state.db, lang, page).await
 }
 
+#[allow(clippy::too_many_arguments)]
+async fn handle_server_unban(
+    bot: &Bot,
+    q: &CallbackQuery,
+    state: &AppState,
+    msg: &Message,
+    server_id: ServerId,
+    page: usize,
+    ban_key: u64,
+    lang: LanguageCode,
+) -> ResponseResult<()> {
+    if let Err(e) = state
+        .tx_tt
+        .send(TtCommand::LiftServerBan { server_id, ban_key })
+        .await
+    {
+        tracing::error!(%server_id, error = %e, "Failed to send server unban command");
+        notify_admin_error(
+            bot,
+            &state.config,
+            tg_user_id_i64(q.from.id.0),
+            AdminErrorContext::TtCommand,
+            &e.to_string(),
+            lang,
+        )
+        .await;
+        return answer_callback(
+            bot,
+            &q.id,
+            locales::get_text(lang.as_str(), "cmd-error", None),
+            true,
+        )
+        .await;
+    }
+    answer_callback(
+        bot,
+        &q.id,
+        locales::get_text(lang.as_str(), "toast-user-unbanned", None),
+        false,
+    )
+    .await?;
+    edit_server_bans(bot, msg, state, server_id, page, Some(ban_key), lang).await
+}
+
 async fn handle_subs_list(
     bot: &Bot,
     q: &CallbackQuery,
@@ -301,17 +1165,27 @@ async fn handle_subs_list(
     answer_callback_empty(bot, &q.id).await
 }
 
-fn sorted_online_users(
-    online_users: &std::sync::RwLock<std::collections::HashMap<i32, LiteUser>>,
-) -> Vec<LiteUser> {
-    let mut users: Vec<LiteUser> = online_users
-        .read()
-        .unwrap_or_else(std::sync::PoisonError::into_inner)
-        .values()
-        .cloned()
-        .collect();
-    users.sort_by(|a, b| a.nickname.to_lowercase().cmp(&b.nickname.to_lowercase()));
-    users
+async fn send_server_picker<F>(
+    bot: &Bot,
+    q: &CallbackQuery,
+    state: &AppState,
+    msg: &Message,
+    lang: LanguageCode,
+    action_builder: F,
+) -> ResponseResult<()>
+where
+    F: Fn(ServerId) -> AdminAction,
+{
+    let keyboard = create_server_picker_keyboard(&server_names(&state.config), |server_id| {
+        CallbackAction::Admin(action_builder(server_id))
+    });
+    bot.send_message(
+        msg.chat.id,
+        locales::get_text(lang.as_str(), "server-picker-title", None),
+    )
+    .reply_markup(keyboard)
+    .await?;
+    answer_callback_empty(bot, &q.id).await
 }
 
 async fn send_or_edit_list(
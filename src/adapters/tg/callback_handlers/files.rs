@@ -0,0 +1,61 @@
+use crate::adapters::tg::state::AppState;
+use crate::adapters::tg::utils::{answer_callback, notify_admin_error};
+use crate::core::callbacks::FileAction;
+use crate::core::types::{AdminErrorContext, LanguageCode, TtCommand};
+use crate::infra::locales;
+use teloxide::prelude::*;
+
+pub async fn handle_file_action(
+    bot: Bot,
+    q: CallbackQuery,
+    state: AppState,
+    action: FileAction,
+    lang: LanguageCode,
+) -> ResponseResult<()> {
+    let Some(teloxide::types::MaybeInaccessibleMessage::Regular(msg)) = q.message else {
+        return Ok(());
+    };
+    let chat_id = msg.chat.id.0;
+
+    match action {
+        FileAction::Download {
+            server_id,
+            channel_id,
+            file_id,
+        } => {
+            let key = if let Err(e) = state
+                .tx_tt
+                .send(TtCommand::DownloadFile {
+                    server_id,
+                    channel_id,
+                    file_id,
+                    chat_id,
+                    lang,
+                })
+                .await
+            {
+                tracing::error!(%server_id, error = %e, "Failed to send TT download command");
+                notify_admin_error(
+                    &bot,
+                    &state.config,
+                    chat_id,
+                    AdminErrorContext::TtCommand,
+                    &e.to_string(),
+                    lang,
+                )
+                .await;
+                "cmd-error"
+            } else {
+                "file-download-started"
+            };
+            answer_callback(
+                &bot,
+                &q.id,
+                locales::get_text(lang.as_str(), key, None),
+                false,
+            )
+            .await?;
+        }
+    }
+    Ok(())
+}
@@ -1,8 +1,9 @@
-use crate::adapters::tg::keyboards::confirm_cancel_keyboard;
+use crate::adapters::tg::admin_logic::registrations::{Applicant, submit_registration};
+use crate::adapters::tg::keyboards::{confirm_cancel_keyboard, create_server_picker_keyboard};
 use crate::adapters::tg::state::AppState;
-use crate::adapters::tg::utils::{answer_callback_empty, notify_admin_error};
+use crate::adapters::tg::utils::{answer_callback_empty, notify_admin_error, server_names};
 use crate::core::callbacks::{CallbackAction, MenuAction, UnsubAction};
-use crate::core::types::{AdminErrorContext, LanguageCode, TtCommand};
+use crate::core::types::{AdminErrorContext, LanguageCode, ServerId, TtCommand};
 use crate::infra::locales;
 use teloxide::prelude::*;
 use teloxide::types::ParseMode;
@@ -14,35 +15,43 @@ pub async fn handle_menu(
     action: MenuAction,
     lang: LanguageCode,
 ) -> ResponseResult<()> {
-    let Some(teloxide::types::MaybeInaccessibleMessage::Regular(msg)) = q.message else {
+    let Some(teloxide::types::MaybeInaccessibleMessage::Regular(ref msg)) = q.message else {
         return Ok(());
     };
     let chat_id = msg.chat.id;
 
     match action {
         MenuAction::Who => {
-            if let Err(e) = state
-                .tx_tt
-                .send(TtCommand::Who {
-                    chat_id: chat_id.0,
-                    lang,
-                    reply_to: None,
-                })
-                .await
-            {
-                tracing::error!(error = %e, "Failed to send TT who command");
-                notify_admin_error(
-                    &bot,
-                    &state.config,
-                    tg_user_id_i64(q.from.id.0),
-                    AdminErrorContext::TtCommand,
-                    &e.to_string(),
-                    lang,
+            if state.config.is_multi_server() {
+                let keyboard =
+                    create_server_picker_keyboard(&server_names(&state.config), |server_id| {
+                        CallbackAction::Menu(MenuAction::WhoServer { server_id })
+                    });
+                bot.send_message(
+                    chat_id,
+                    locales::get_text(lang.as_str(), "server-picker-title", None),
                 )
-                .await;
+                .reply_markup(keyboard)
+                .await?;
+            } else {
+                request_who(&bot, &q, &state, chat_id, lang, ServerId(0)).await;
             }
             answer_callback_empty(&bot, &q.id).await?;
         }
+        MenuAction::WhoServer { server_id } => {
+            request_who(&bot, &q, &state, chat_id, lang, server_id).await;
+            answer_callback_empty(&bot, &q.id).await?;
+        }
+        MenuAction::RegisterServer {
+            server_id,
+            username,
+        } => {
+            let applicant = Applicant::from_user(&q.from, lang);
+            let text =
+                submit_registration(&bot, &state, &applicant, server_id, username.as_str()).await;
+            bot.edit_message_text(chat_id, msg.id, text).await?;
+            answer_callback_empty(&bot, &q.id).await?;
+        }
         MenuAction::Help => {
             bot.send_message(chat_id, locales::get_text(lang.as_str(), "help-text", None))
                 .parse_mode(ParseMode::Html)
@@ -70,6 +79,37 @@ pub async fn handle_menu(
     Ok(())
 }
 
+async fn request_who(
+    bot: &Bot,
+    q: &CallbackQuery,
+    state: &AppState,
+    chat_id: ChatId,
+    lang: LanguageCode,
+    server_id: ServerId,
+) {
+    if let Err(e) = state
+        .tx_tt
+        .send(TtCommand::Who {
+            server_id,
+            chat_id: chat_id.0,
+            lang,
+            reply_to: None,
+        })
+        .await
+    {
+        tracing::error!(%server_id, error = %e, "Failed to send TT who command");
+        notify_admin_error(
+            bot,
+            &state.config,
+            tg_user_id_i64(q.from.id.0),
+            AdminErrorContext::TtCommand,
+            &e.to_string(),
+            lang,
+        )
+        .await;
+    }
+}
+
 fn tg_user_id_i64(user_id: u64) -> i64 {
     i64::try_from(user_id).unwrap_or(i64::MAX)
 }
@@ -1,6 +1,8 @@
 pub mod admin;
+pub mod files;
 pub mod menu;
 pub mod mute;
+pub mod queue;
 pub mod settings;
 pub mod subscriber;
 pub mod unsub;
@@ -4,11 +4,11 @@ use crate::adapters::tg::settings_logic::{
 };
 use crate::adapters::tg::state::AppState;
 use crate::adapters::tg::utils::{answer_callback, check_db_err, notify_admin_error};
+use crate::adapters::tt::merged_user_accounts;
 use crate::args;
 use crate::core::callbacks::MuteAction;
 use crate::core::types::{AdminErrorContext, LanguageCode, MuteListMode, TtCommand};
 use crate::infra::locales;
-use teamtalk::types::UserAccount;
 use teloxide::prelude::*;
 
 pub async fn handle_mute(
@@ -21,7 +21,7 @@ pub async fn handle_mute(
     let Some(teloxide::types::MaybeInaccessibleMessage::Regular(msg)) = &q.message else {
         return Ok(());
     };
-    let telegram_id = tg_user_id_i64(q.from.id.0);
+    let telegram_id = msg.chat.id.0;
     let ctx = MuteCtx {
         bot: &bot,
         q: &q,
@@ -36,7 +36,7 @@ pub async fn handle_mute(
             handle_mode_set(&bot, &q, &state, msg, telegram_id, lang, mode).await?;
         }
         MuteAction::Menu { mode } => {
-            let has_guest = state.config.teamtalk.guest_username.is_some();
+            let has_guest = !state.config.guest_usernames().is_empty();
             send_mute_menu(&bot, msg, lang, mode, has_guest).await?;
         }
         MuteAction::List { mode, page } => {
@@ -97,7 +97,7 @@ async fn handle_mode_set(
         false,
     )
     .await?;
-    let has_guest = state.config.teamtalk.guest_username.is_some();
+    let has_guest = !state.config.guest_usernames().is_empty();
     send_mute_menu(bot, msg, lang, mode, has_guest).await
 }
 
@@ -111,7 +111,7 @@ async fn handle_list(
     page: usize,
 ) -> ResponseResult<()> {
     let muted = load_muted_users(&state.db, telegram_id, mode.clone()).await;
-    let guest_username = state.config.teamtalk.guest_username.as_deref();
+    let guest_usernames = state.config.guest_usernames();
     render_mute_list_strings(RenderMuteListStringsArgs {
         bot,
         msg,
@@ -119,7 +119,7 @@ async fn handle_list(
         items: &muted,
         page,
         title_key: "list-mute-title",
-        guest_username,
+        guest_usernames: &guest_usernames,
         mode,
     })
     .await
@@ -160,7 +160,7 @@ async fn handle_toggle(
     .await?;
 
     let muted = load_muted_users(&ctx.state.db, ctx.telegram_id, mode.clone()).await;
-    let guest_username = ctx.state.config.teamtalk.guest_username.as_deref();
+    let guest_usernames = ctx.state.config.guest_usernames();
     render_mute_list_strings(RenderMuteListStringsArgs {
         bot: ctx.bot,
         msg: ctx.msg,
@@ -168,7 +168,7 @@ async fn handle_toggle(
         items: &muted,
         page,
         title_key: "list-mute-title",
-        guest_username,
+        guest_usernames: &guest_usernames,
         mode,
     })
     .await
@@ -185,8 +185,8 @@ async fn handle_server_list(
 ) -> ResponseResult<()> {
     request_accounts(bot, state, telegram_id, lang).await;
 
-    let accounts = load_accounts(&state.user_accounts);
-    let guest_username = state.config.teamtalk.guest_username.as_deref();
+    let accounts = merged_user_accounts(&state.servers);
+    let guest_usernames = state.config.guest_usernames();
     render_mute_list(RenderMuteListArgs {
         bot,
         msg,
@@ -196,7 +196,7 @@ async fn handle_server_list(
         accounts: &accounts,
         page,
         title_key: "list-all-accs-title",
-        guest_username,
+        guest_usernames: &guest_usernames,
         mode,
     })
     .await
@@ -236,8 +236,8 @@ async fn handle_server_toggle(
     )
     .await?;
 
-    let accounts = load_accounts(&ctx.state.user_accounts);
-    let guest_username = ctx.state.config.teamtalk.guest_username.as_deref();
+    let accounts = merged_user_accounts(&ctx.state.servers);
+    let guest_usernames = ctx.state.config.guest_usernames();
     render_mute_list(RenderMuteListArgs {
         bot: ctx.bot,
         msg: ctx.msg,
@@ -247,7 +247,7 @@ async fn handle_server_toggle(
         accounts: &accounts,
         page,
         title_key: "list-all-accs-title",
-        guest_username,
+        guest_usernames: &guest_usernames,
         mode,
     })
     .await
@@ -263,17 +263,23 @@ struct MuteCtx<'a> {
 }
 
 async fn request_accounts(bot: &Bot, state: &AppState, telegram_id: i64, lang: LanguageCode) {
-    if let Err(e) = state.tx_tt.send(TtCommand::LoadAccounts).await {
-        tracing::error!(error = %e, "Failed to request TT accounts");
-        notify_admin_error(
-            bot,
-            &state.config,
-            telegram_id,
-            AdminErrorContext::TtCommand,
-            &e.to_string(),
-            lang,
-        )
-        .await;
+    for server_id in state.config.server_ids() {
+        if let Err(e) = state
+            .tx_tt
+            .send(TtCommand::LoadAccounts { server_id })
+            .await
+        {
+            tracing::error!(%server_id, error = %e, "Failed to request TT accounts");
+            notify_admin_error(
+                bot,
+                &state.config,
+                telegram_id,
+                AdminErrorContext::TtCommand,
+                &e.to_string(),
+                lang,
+            )
+            .await;
+        }
     }
 }
 
@@ -289,20 +295,3 @@ async fn load_muted_users(
             Vec::new()
         })
 }
-
-fn load_accounts(
-    user_accounts: &std::sync::RwLock<std::collections::HashMap<String, UserAccount>>,
-) -> Vec<UserAccount> {
-    let mut accounts: Vec<UserAccount> = user_accounts
-        .read()
-        .unwrap_or_else(std::sync::PoisonError::into_inner)
-        .values()
-        .cloned()
-        .collect();
-    accounts.sort_by(|a, b| a.username.to_lowercase().cmp(&b.username.to_lowercase()));
-    accounts
-}
-
-fn tg_user_id_i64(user_id: u64) -> i64 {
-    i64::try_from(user_id).unwrap_or(i64::MAX)
-}
@@ -0,0 +1,56 @@
+use crate::adapters::tg::state::AppState;
+use crate::adapters::tg::utils::{answer_callback, answer_callback_empty, notify_admin_error};
+use crate::core::callbacks::QueueAction;
+use crate::core::types::{AdminErrorContext, LanguageCode, TtCommand};
+use crate::infra::locales;
+use teloxide::prelude::*;
+
+pub async fn handle_queue_action(
+    bot: Bot,
+    q: CallbackQuery,
+    state: AppState,
+    action: QueueAction,
+    lang: LanguageCode,
+) -> ResponseResult<()> {
+    let Some(teloxide::types::MaybeInaccessibleMessage::Regular(msg)) = &q.message else {
+        return Ok(());
+    };
+    let chat_id = msg.chat.id.0;
+
+    match action {
+        QueueAction::Control { server_id, op } => {
+            if let Err(e) = state
+                .tx_tt
+                .send(TtCommand::QueueControl {
+                    server_id,
+                    chat_id,
+                    message_id: msg.id.0,
+                    lang,
+                    op,
+                })
+                .await
+            {
+                tracing::error!(%server_id, error = %e, "Failed to send TT queue command");
+                notify_admin_error(
+                    &bot,
+                    &state.config,
+                    chat_id,
+                    AdminErrorContext::TtCommand,
+                    &e.to_string(),
+                    lang,
+                )
+                .await;
+                answer_callback(
+                    &bot,
+                    &q.id,
+                    locales::get_text(lang.as_str(), "cmd-error", None),
+                    true,
+                )
+                .await?;
+                return Ok(());
+            }
+            answer_callback_empty(&bot, &q.id).await?;
+        }
+    }
+    Ok(())
+}
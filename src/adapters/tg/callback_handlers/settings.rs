@@ -1,13 +1,16 @@
 use crate::adapters::tg::keyboards::{back_button, callback_button};
 use crate::adapters::tg::settings_logic::{
-    send_main_settings_edit, send_mute_menu, send_notif_settings, send_sub_settings,
+    delivery_mode_label, send_delivery_settings, send_main_settings_edit, send_mute_menu,
+    send_notif_settings, send_server_settings, send_sub_settings,
 };
 use crate::adapters::tg::state::AppState;
 use crate::adapters::tg::utils::{answer_callback, check_db_err};
 use crate::app::services::user_settings as user_settings_service;
 use crate::args;
 use crate::core::callbacks::{CallbackAction, SettingsAction};
-use crate::core::types::{AdminErrorContext, LanguageCode, NotificationSetting};
+use crate::core::types::{
+    AdminErrorContext, DeliveryMode, EventOptIn, LanguageCode, NotificationSetting, ServerId,
+};
 use crate::infra::locales;
 use teloxide::prelude::*;
 use teloxide::types::InlineKeyboardMarkup;
@@ -23,11 +26,11 @@ pub async fn handle_settings(
         return Ok(());
     };
     let msg = msg.as_ref();
-    let telegram_id = tg_user_id_i64(q.from.id.0);
+    let telegram_id = msg.chat.id.0;
 
     match action {
         SettingsAction::Main => {
-            send_main_settings_edit(&bot, msg, lang).await?;
+            send_main_settings_edit(&bot, msg, lang, state.config.is_multi_server()).await?;
         }
         SettingsAction::LangSelect => {
             handle_lang_select(&bot, msg, lang).await?;
@@ -50,6 +53,21 @@ pub async fn handle_settings(
         SettingsAction::MuteManage => {
             handle_mute_manage(&bot, &q, &state, msg, telegram_id, lang).await?;
         }
+        SettingsAction::ServersSelect => {
+            send_server_settings(&bot, msg, &state.db, &state.config, telegram_id, lang).await?;
+        }
+        SettingsAction::ServerToggle { server_id } => {
+            handle_server_toggle(&bot, &q, &state, msg, telegram_id, lang, server_id).await?;
+        }
+        SettingsAction::EventOptInToggle { kind } => {
+            handle_event_opt_in_toggle(&bot, &q, &state, msg, telegram_id, lang, kind).await?;
+        }
+        SettingsAction::DeliverySelect => {
+            send_delivery_settings(&bot, msg, &state.db, &state.config, telegram_id, lang).await?;
+        }
+        SettingsAction::DeliverySet { mode } => {
+            handle_delivery_set(&bot, &q, &state, msg, telegram_id, lang, mode).await?;
+        }
     }
     Ok(())
 }
@@ -113,7 +131,7 @@ async fn handle_lang_set(
         false,
     )
     .await?;
-    send_main_settings_edit(bot, msg, new_lang).await
+    send_main_settings_edit(bot, msg, new_lang, state.config.is_multi_server()).await
 }
 
 async fn handle_sub_set(
@@ -162,6 +180,48 @@ async fn handle_sub_set(
     send_sub_settings(bot, msg, &state.db, telegram_id, lang).await
 }
 
+async fn handle_delivery_set(
+    bot: &Bot,
+    q: &CallbackQuery,
+    state: &AppState,
+    msg: &Message,
+    telegram_id: i64,
+    lang: LanguageCode,
+    mode: DeliveryMode,
+) -> ResponseResult<()> {
+    if check_db_err(
+        bot,
+        &q.id.0,
+        state.db.update_delivery_mode(telegram_id, mode).await,
+        &state.config,
+        telegram_id,
+        AdminErrorContext::Callback,
+        lang,
+    )
+    .await?
+    {
+        return Ok(());
+    }
+    let mode_text = delivery_mode_label(
+        lang,
+        mode,
+        "",
+        state.config.operational_parameters.digest_batch,
+    );
+    answer_callback(
+        bot,
+        &q.id,
+        locales::get_text(
+            lang.as_str(),
+            "resp-delivery-updated",
+            args!(text = mode_text.trim()).as_ref(),
+        ),
+        false,
+    )
+    .await?;
+    send_delivery_settings(bot, msg, &state.db, &state.config, telegram_id, lang).await
+}
+
 async fn handle_noon_toggle(
     bot: &Bot,
     q: &CallbackQuery,
@@ -244,6 +304,55 @@ async fn handle_noon_toggle(
     Ok(())
 }
 
+async fn handle_event_opt_in_toggle(
+    bot: &Bot,
+    q: &CallbackQuery,
+    state: &AppState,
+    msg: &Message,
+    telegram_id: i64,
+    lang: LanguageCode,
+    kind: EventOptIn,
+) -> ResponseResult<()> {
+    let resp_key = match kind {
+        EventOptIn::ChannelMoves => "resp-channel-notif-updated",
+        EventOptIn::NickChanges => "resp-nick-notif-updated",
+        EventOptIn::StatusChanges => "resp-status-notif-updated",
+        EventOptIn::ServerBroadcasts => "resp-broadcast-notif-updated",
+        EventOptIn::FileUploads => "resp-file-notif-updated",
+        EventOptIn::ServerHealth => "resp-health-notif-updated",
+    };
+    match state.db.toggle_event_opt_in(telegram_id, kind).await {
+        Ok(new_val) => {
+            let status = if new_val {
+                locales::get_text(lang.as_str(), "status-enabled", None)
+            } else {
+                locales::get_text(lang.as_str(), "status-disabled", None)
+            };
+            answer_callback(
+                bot,
+                &q.id,
+                locales::get_text(lang.as_str(), resp_key, args!(status = status).as_ref()),
+                false,
+            )
+            .await?;
+            send_notif_settings(bot, msg, &state.db, telegram_id, lang).await?;
+        }
+        Err(e) => {
+            check_db_err(
+                bot,
+                &q.id.0,
+                Err(e),
+                &state.config,
+                telegram_id,
+                AdminErrorContext::Callback,
+                lang,
+            )
+            .await?;
+        }
+    }
+    Ok(())
+}
+
 async fn handle_mute_manage(
     bot: &Bot,
     q: &CallbackQuery,
@@ -255,7 +364,7 @@ async fn handle_mute_manage(
     match user_settings_service::get_or_create(&state.db, telegram_id, LanguageCode::En).await {
         Ok(u) => {
             let mode = user_settings_service::parse_mute_list_mode(&u.mute_list_mode);
-            let has_guest = state.config.teamtalk.guest_username.is_some();
+            let has_guest = !state.config.guest_usernames().is_empty();
             send_mute_menu(bot, msg, lang, mode, has_guest).await?;
         }
         Err(e) => {
@@ -274,6 +383,58 @@ async fn handle_mute_manage(
     Ok(())
 }
 
-fn tg_user_id_i64(user_id: u64) -> i64 {
-    i64::try_from(user_id).unwrap_or(i64::MAX)
+async fn handle_server_toggle(
+    bot: &Bot,
+    q: &CallbackQuery,
+    state: &AppState,
+    msg: &Message,
+    telegram_id: i64,
+    lang: LanguageCode,
+    server_id: ServerId,
+) -> ResponseResult<()> {
+    let Some(server) = state.config.server(server_id) else {
+        return answer_callback(
+            bot,
+            &q.id,
+            locales::get_text(lang.as_str(), "cmd-error", None),
+            true,
+        )
+        .await;
+    };
+
+    match state
+        .db
+        .toggle_ignored_server(telegram_id, &server.key())
+        .await
+    {
+        Ok(ignored) => {
+            let status = if ignored {
+                locales::get_text(lang.as_str(), "status-disabled", None)
+            } else {
+                locales::get_text(lang.as_str(), "status-enabled", None)
+            };
+            let args = args!(server = server.display_name().to_string(), status = status);
+            answer_callback(
+                bot,
+                &q.id,
+                locales::get_text(lang.as_str(), "resp-server-toggled", args.as_ref()),
+                false,
+            )
+            .await?;
+            send_server_settings(bot, msg, &state.db, &state.config, telegram_id, lang).await?;
+        }
+        Err(e) => {
+            check_db_err(
+                bot,
+                &q.id.0,
+                Err(e),
+                &state.config,
+                telegram_id,
+                AdminErrorContext::Callback,
+                lang,
+            )
+            .await?;
+        }
+    }
+    Ok(())
 }
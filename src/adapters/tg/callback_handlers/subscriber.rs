@@ -9,6 +9,7 @@ use crate::adapters::tg::state::AppState;
 use crate::adapters::tg::utils::{
     answer_callback, answer_callback_empty, check_db_err, notify_admin_error,
 };
+use crate::adapters::tt::ServerShared;
 use crate::app::services::subscriber_actions as subscriber_actions_service;
 use crate::args;
 use crate::core::callbacks::SubAction;
@@ -22,9 +23,7 @@ struct SubCtx<'a> {
     msg: &'a Message,
     db: &'a Database,
     config: &'a crate::bootstrap::config::Config,
-    user_accounts: &'a std::sync::Arc<
-        std::sync::RwLock<std::collections::HashMap<String, teamtalk::types::UserAccount>>,
-    >,
+    servers: &'a [ServerShared],
     tx_tt: &'a tokio::sync::mpsc::Sender<TtCommand>,
     lang: LanguageCode,
     q_id: &'a teloxide::types::CallbackQueryId,
@@ -42,7 +41,7 @@ pub async fn handle_subscriber_actions(
         return Ok(());
     };
     let db = &state.db;
-    let user_accounts = &state.user_accounts;
+    let servers = &state.servers;
     let tx_tt = &state.tx_tt;
     let config = &state.config;
     let admin_chat_id = tg_user_id_i64(q.from.id.0);
@@ -52,7 +51,7 @@ pub async fn handle_subscriber_actions(
         msg: &msg,
         db,
         config,
-        user_accounts,
+        servers,
         tx_tt,
         lang,
         q_id: &q.id,
@@ -216,22 +215,24 @@ impl SubCtx<'_> {
     }
 
     async fn link_list(&self, sub_id: i64, page: usize, list_page: usize) -> ResponseResult<()> {
-        if let Err(e) = self.tx_tt.send(TtCommand::LoadAccounts).await {
-            tracing::error!(error = %e, "Failed to request TT accounts");
-            notify_admin_error(
-                self.bot,
-                self.config,
-                self.admin_chat_id,
-                AdminErrorContext::TtCommand,
-                &e.to_string(),
-                self.lang,
-            )
-            .await;
+        for server_id in self.config.server_ids() {
+            if let Err(e) = self.tx_tt.send(TtCommand::LoadAccounts { server_id }).await {
+                tracing::error!(%server_id, error = %e, "Failed to request TT accounts");
+                notify_admin_error(
+                    self.bot,
+                    self.config,
+                    self.admin_chat_id,
+                    AdminErrorContext::TtCommand,
+                    &e.to_string(),
+                    self.lang,
+                )
+                .await;
+            }
         }
         send_sub_link_account_list(
             self.bot,
             self.msg,
-            self.user_accounts,
+            self.servers,
             self.lang,
             sub_id,
             page,
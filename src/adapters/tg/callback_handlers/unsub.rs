@@ -16,7 +16,7 @@ pub async fn handle_unsub_action(
     let Some(teloxide::types::MaybeInaccessibleMessage::Regular(msg)) = q.message else {
         return Ok(());
     };
-    let telegram_id = tg_user_id_i64(q.from.id.0);
+    let telegram_id = msg.chat.id.0;
     let db = &state.db;
     let config = &state.config;
 
@@ -72,7 +72,3 @@ pub async fn handle_unsub_action(
     }
     Ok(())
 }
-
-fn tg_user_id_i64(user_id: u64) -> i64 {
-    i64::try_from(user_id).unwrap_or(i64::MAX)
-}
@@ -1,6 +1,9 @@
-use crate::adapters::tg::callback_handlers::{admin, menu, mute, settings, subscriber, unsub};
+use crate::adapters::tg::callback_handlers::{
+    admin, files, menu, mute, queue, settings, subscriber, unsub,
+};
+use crate::adapters::tg::commands::is_admin;
 use crate::adapters::tg::state::AppState;
-use crate::adapters::tg::utils::notify_admin_error;
+use crate::adapters::tg::utils::{self, is_chat_admin, notify_admin_error};
 use crate::app::services::subscription as subscriptions_service;
 use crate::app::services::user_settings as user_settings_service;
 use crate::core::callbacks::CallbackAction;
@@ -12,22 +15,53 @@ use teloxide::types::MaybeInaccessibleMessage;
 
 pub async fn answer_callback(bot: Bot, q: CallbackQuery, state: AppState) -> ResponseResult<()> {
     let query_id = q.id.clone();
-    let telegram_id = tg_user_id_i64(q.from.id.0);
     let callback_data_str = q.data.clone().unwrap_or_default();
 
     let db = &state.db;
     let config = &state.config;
     let default_lang = config.general.default_lang;
 
-    let Some(MaybeInaccessibleMessage::Regular(_msg)) = &q.message else {
+    let Some(MaybeInaccessibleMessage::Regular(msg)) = &q.message else {
         return Ok(());
     };
 
+    let action = parse_action(&callback_data_str);
+    let changes_chat_settings = action.changes_chat_settings();
+    let telegram_id = if action.acts_for_chat(msg.chat.is_private()) {
+        msg.chat.id.0
+    } else {
+        tg_user_id_i64(q.from.id.0)
+    };
+
+    let known_lang = db.get_user_lang(telegram_id).await.unwrap_or(default_lang);
+    if !ensure_subscribed(&bot, db, config, telegram_id, known_lang, query_id.clone()).await? {
+        return Ok(());
+    }
     let lang = load_user_lang(&bot, db, config, telegram_id, default_lang, q.id.clone()).await?;
-    if !ensure_subscribed(&bot, db, config, telegram_id, lang, query_id.clone()).await? {
+    if changes_chat_settings && !is_chat_admin(&bot, &msg.chat, Some(&q.from)).await {
+        utils::answer_callback(
+            &bot,
+            &query_id,
+            locales::get_text(lang.as_str(), "cmd-chat-admin-only", None),
+            true,
+        )
+        .await?;
+        return Ok(());
+    }
+    let admin_only = matches!(
+        action,
+        CallbackAction::Admin(_) | CallbackAction::Queue(_) | CallbackAction::Subscriber(_)
+    );
+    if admin_only && !is_admin(db, config, tg_user_id_i64(q.from.id.0)).await {
+        utils::answer_callback(
+            &bot,
+            &query_id,
+            locales::get_text(lang.as_str(), "cmd-unauth", None),
+            true,
+        )
+        .await?;
         return Ok(());
     }
-    let action = parse_action(&callback_data_str);
     dispatch_action(bot, q, state, action, lang).await?;
 
     Ok(())
@@ -153,6 +187,12 @@ async fn dispatch_action(
         CallbackAction::Unsub(unsub_act) => {
             unsub::handle_unsub_action(bot, q, state, unsub_act, lang).await
         }
+        CallbackAction::File(file_act) => {
+            files::handle_file_action(bot, q, state, file_act, lang).await
+        }
+        CallbackAction::Queue(queue_act) => {
+            queue::handle_queue_action(bot, q, state, queue_act, lang).await
+        }
         CallbackAction::NoOp => {
             bot.answer_callback_query(q.id).await?;
             Ok(())
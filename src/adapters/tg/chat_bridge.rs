@@ -0,0 +1,48 @@
+use crate::adapters::tg::state::AppState;
+use crate::core::types::TtCommand;
+use teloxide::prelude::*;
+
+pub async fn answer_bridge_message(msg: Message, state: AppState) -> ResponseResult<()> {
+    let Some(user) = msg.from.as_ref().filter(|u| !u.is_bot) else {
+        return Ok(());
+    };
+    let Some(text) = msg.text().map(str::trim).filter(|t| !t.is_empty()) else {
+        return Ok(());
+    };
+    if text.starts_with('/') {
+        return Ok(());
+    }
+    let Some((server_id, bridge)) = state.config.chat_bridge_for_chat(msg.chat.id.0) else {
+        return Ok(());
+    };
+    let Some(channel_id) = state
+        .servers
+        .get(server_id.0)
+        .and_then(|server| server.bridge_channel_id(&bridge.channel))
+    else {
+        tracing::warn!(
+            chat_id = msg.chat.id.0,
+            channel = %bridge.channel,
+            "Chat bridge channel is not resolved yet; message dropped"
+        );
+        return Ok(());
+    };
+
+    let text = format!("{}: {}", user.full_name(), text);
+    if let Err(e) = state
+        .tx_tt
+        .send(TtCommand::SendToChannel {
+            server_id,
+            channel_id,
+            text,
+        })
+        .await
+    {
+        tracing::error!(
+            chat_id = msg.chat.id.0,
+            error = %e,
+            "Failed to forward bridged message to TeamTalk"
+        );
+    }
+    Ok(())
+}
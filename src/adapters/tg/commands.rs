@@ -1,27 +1,52 @@
-use crate::adapters::tg::admin_logic::bans::send_unban_list;
+use crate::adapters::tg::admin_logic::accounts::{
+    handle_account_reply, remember_filter, request_accounts, send_account_list,
+};
+use crate::adapters::tg::admin_logic::bans::{execute_ban, send_unban_list};
+use crate::adapters::tg::admin_logic::channels::{execute_channel_edit, send_channel_list};
+use crate::adapters::tg::admin_logic::registrations::{Applicant, submit_registration};
+use crate::adapters::tg::admin_logic::server_bans::send_server_bans;
+use crate::adapters::tg::admin_logic::server_info::{handle_server_reply, send_server_info};
 use crate::adapters::tg::admin_logic::subscribers::send_subscribers_list;
 use crate::adapters::tg::keyboards::{
-    confirm_cancel_keyboard, create_main_menu_keyboard, create_user_list_keyboard,
+    confirm_cancel_keyboard, create_main_menu_keyboard, create_server_picker_keyboard,
+    create_user_list_keyboard,
 };
 use crate::adapters::tg::settings_logic::send_main_settings;
 use crate::adapters::tg::state::AppState;
-use crate::adapters::tg::utils::{ensure_subscribed, notify_admin_error, send_text_key};
+use crate::adapters::tg::utils::{
+    ensure_subscribed, is_chat_admin, notify_admin_error, send_text_key, server_names,
+};
+use crate::adapters::tt::ServerShared;
+use crate::app::services::bans as bans_service;
 use crate::app::services::deeplink as deeplink_service;
+use crate::app::services::files as files_service;
+use crate::app::services::graph as graph_service;
+use crate::app::services::music::{self as music_service, MusicReply, Track};
 use crate::app::services::pending as pending_service;
+use crate::app::services::queue as queue_service;
+use crate::app::services::registration as registration_service;
+use crate::app::services::seen as seen_service;
 use crate::app::services::subscription as subscription_service;
 use crate::app::services::user_settings as user_settings_service;
 use crate::args;
-use crate::core::callbacks::{AdminAction, CallbackAction, UnsubAction};
-use crate::core::types::{AdminErrorContext, DeeplinkAction, LanguageCode, LiteUser, TtCommand};
+use crate::core::callbacks::{AdminAction, CallbackAction, MenuAction, QueueAction, UnsubAction};
+use crate::core::types::{
+    AdminErrorContext, BanTarget, DeeplinkAction, LanguageCode, QueueOp, ServerId, TtCommand,
+    TtUsername,
+};
+use crate::infra::chart;
 use crate::infra::locales;
 use std::time::{SystemTime, UNIX_EPOCH};
 use teloxide::net::Download;
 use teloxide::prelude::*;
 use teloxide::sugar::request::RequestReplyExt;
-use teloxide::types::{ParseMode, Voice};
+use teloxide::types::{Document, FileMeta, InputFile, ParseMode};
 use teloxide::utils::command::BotCommands;
 use tokio::fs::File;
 
+const GRAPH_WIDTH: u32 = 800;
+const GRAPH_HEIGHT: u32 = 400;
+
 #[derive(BotCommands, Clone, Debug)]
 #[command(rename_rule = "lowercase", description = "Available Commands:")]
 pub enum Command {
@@ -33,16 +58,32 @@ pub enum Command {
     Help,
     #[command(description = "Who is online")]
     Who,
+    #[command(description = "Last seen")]
+    Seen(String),
+    #[command(description = "Online graph")]
+    Graph(String),
     #[command(description = "Settings")]
     Settings,
     #[command(description = "Unsubscribe")]
     Unsub,
+    #[command(description = "Register a TeamTalk account")]
+    Register(String),
     #[command(description = "Kick (Admin)")]
     Kick,
     #[command(description = "Ban (Admin)")]
-    Ban,
+    Ban(String),
+    #[command(description = "Move (Admin)")]
+    Move,
     #[command(description = "Unban (Admin)")]
     Unban,
+    #[command(description = "TeamTalk server bans (Admin)")]
+    ServerBans,
+    #[command(description = "Channels (Admin)")]
+    Channels,
+    #[command(description = "User accounts (Admin)")]
+    Accounts(String),
+    #[command(description = "Server properties (Admin)")]
+    Server,
     #[command(description = "Subscribers (Admin)")]
     Subscribers,
     #[command(description = "Exit (Admin)")]
@@ -51,6 +92,12 @@ pub enum Command {
     Broadcast(String),
     #[command(description = "Message (Admin)")]
     Message(String),
+    #[command(description = "Stream queue (Admin)")]
+    Queue,
+    #[command(description = "Play from the music library (Admin)")]
+    Play(String),
+    #[command(description = "Playlists (Admin)")]
+    Playlist(String),
 }
 
 pub async fn answer_command(
@@ -59,10 +106,11 @@ pub async fn answer_command(
     cmd: Command,
     state: AppState,
 ) -> ResponseResult<()> {
-    let Some(user) = &msg.from else {
-        return Ok(());
+    let telegram_id = match &msg.from {
+        Some(user) => tg_user_id_i64(user.id.0),
+        None if msg.chat.is_channel() => msg.chat.id.0,
+        None => return Ok(()),
     };
-    let telegram_id = tg_user_id_i64(user.id.0);
     let Some(ctx) = CommandCtx::new(&bot, &msg, &state, telegram_id).await? else {
         return Ok(());
     };
@@ -80,7 +128,6 @@ struct CommandCtx<'a> {
     state: &'a AppState,
     db: &'a crate::infra::db::Database,
     config: &'a crate::bootstrap::config::Config,
-    online_users: &'a std::sync::Arc<std::sync::RwLock<std::collections::HashMap<i32, LiteUser>>>,
     tx_tt: &'a tokio::sync::mpsc::Sender<TtCommand>,
     telegram_id: i64,
     lang: LanguageCode,
@@ -97,12 +144,12 @@ impl<'a> CommandCtx<'a> {
         let db = &state.db;
         let config = &state.config;
         let default_lang = config.general.default_lang;
-        let settings = match user_settings_service::get_or_create(db, telegram_id, default_lang)
+        let settings = match user_settings_service::get_or_create(db, msg.chat.id.0, default_lang)
             .await
         {
             Ok(s) => s,
             Err(e) => {
-                tracing::error!(telegram_id, error = %e, "Failed to get or create user");
+                tracing::error!(telegram_id, chat_id = msg.chat.id.0, error = %e, "Failed to get or create user");
                 notify_admin_error(
                     bot,
                     config,
@@ -135,7 +182,6 @@ impl<'a> CommandCtx<'a> {
             state,
             db,
             config,
-            online_users: &state.online_users,
             tx_tt: &state.tx_tt,
             telegram_id,
             lang,
@@ -143,20 +189,52 @@ impl<'a> CommandCtx<'a> {
         }))
     }
 
+    async fn ensure_chat_admin(&self) -> ResponseResult<bool> {
+        let on_behalf_of_chat = self
+            .msg
+            .sender_chat
+            .as_ref()
+            .is_some_and(|chat| chat.id == self.msg.chat.id);
+        let user = self.msg.from.as_ref().filter(|_| !on_behalf_of_chat);
+        if is_chat_admin(self.bot, &self.msg.chat, user).await {
+            return Ok(true);
+        }
+        send_text_key(
+            self.bot,
+            self.msg.chat.id,
+            self.lang,
+            "cmd-chat-admin-only",
+            Some(self.msg.id),
+        )
+        .await?;
+        Ok(false)
+    }
+
     async fn dispatch(&self, cmd: Command) -> ResponseResult<()> {
         match cmd {
             Command::Start(token) => self.start(token).await,
             Command::Menu => self.menu().await,
             Command::Help => self.help().await,
             Command::Who => self.who().await,
+            Command::Seen(username) => self.seen(username).await,
+            Command::Graph(range) => self.graph(range).await,
             Command::Settings => self.settings().await,
             Command::Unsub => self.unsub().await,
-            Command::Kick | Command::Ban => self.kick_or_ban(cmd).await,
+            Command::Register(username) => self.register(username).await,
+            Command::Ban(target) if !target.trim().is_empty() => self.server_ban(&target).await,
+            Command::Kick | Command::Ban(_) | Command::Move => self.user_action(cmd).await,
             Command::Unban => self.unban().await,
+            Command::ServerBans => self.server_bans().await,
+            Command::Channels => self.channels().await,
+            Command::Accounts(query) => self.accounts(query).await,
+            Command::Server => self.server().await,
             Command::Subscribers => self.subscribers().await,
             Command::Exit => self.exit().await,
             Command::Broadcast(text) => self.broadcast(text).await,
             Command::Message(text) => self.message(text).await,
+            Command::Queue => self.queue().await,
+            Command::Play(query) => self.play(query).await,
+            Command::Playlist(args) => self.playlist(args).await,
         }
     }
 
@@ -171,7 +249,15 @@ impl<'a> CommandCtx<'a> {
             )
             .await;
         }
-        match deeplink_service::resolve_for_user(self.db, &token, self.telegram_id).await {
+        let resolved = if self.msg.chat.is_private() {
+            deeplink_service::resolve_for_user(self.db, &token, self.telegram_id).await
+        } else {
+            if !self.ensure_chat_admin().await? {
+                return Ok(());
+            }
+            deeplink_service::resolve_for_chat(self.db, &token).await
+        };
+        match resolved {
             Ok(Some(deeplink)) => match deeplink.action {
                 DeeplinkAction::Subscribe => self.handle_subscribe(deeplink.payload).await,
                 DeeplinkAction::Unsubscribe => self.handle_unsubscribe().await,
@@ -210,8 +296,12 @@ impl<'a> CommandCtx<'a> {
     }
 
     async fn handle_subscribe(&self, payload: Option<String>) -> ResponseResult<()> {
-        match subscription_service::subscribe_via_deeplink(self.db, self.telegram_id, payload).await
-        {
+        let outcome = if self.msg.chat.is_private() {
+            subscription_service::subscribe_via_deeplink(self.db, self.telegram_id, payload).await
+        } else {
+            subscription_service::subscribe_chat(self.db, self.msg.chat.id.0).await
+        };
+        match outcome {
             Ok(subscription_service::SubscribeOutcome::BannedUser) => {
                 send_text_key(
                     self.bot,
@@ -243,6 +333,16 @@ impl<'a> CommandCtx<'a> {
                 )
                 .await
             }
+            Ok(subscription_service::SubscribeOutcome::SubscribedChat) => {
+                send_text_key(
+                    self.bot,
+                    self.msg.chat.id,
+                    self.lang,
+                    "cmd-success-sub-chat",
+                    Some(self.msg.id),
+                )
+                .await
+            }
             Ok(subscription_service::SubscribeOutcome::SubscribedGuest) => {
                 self.bot
                     .send_message(
@@ -278,7 +378,7 @@ impl<'a> CommandCtx<'a> {
     }
 
     async fn handle_unsubscribe(&self) -> ResponseResult<()> {
-        if let Err(e) = subscription_service::unsubscribe(self.db, self.telegram_id).await {
+        if let Err(e) = subscription_service::unsubscribe(self.db, self.msg.chat.id.0).await {
             tracing::error!(error = %e, "DB error unsubscribing");
             notify_admin_error(
                 self.bot,
@@ -344,9 +444,16 @@ impl<'a> CommandCtx<'a> {
         if !ensure_subscribed(self.bot, self.msg, self.db, self.config, self.lang).await {
             return Ok(());
         }
+        if self.config.is_multi_server() {
+            let keyboard = create_server_picker_keyboard(&server_names(self.config), |server_id| {
+                CallbackAction::Menu(MenuAction::WhoServer { server_id })
+            });
+            return self.send_server_picker(keyboard).await;
+        }
         if let Err(e) = self
             .tx_tt
             .send(TtCommand::Who {
+                server_id: ServerId(0),
                 chat_id: self.msg.chat.id.0,
                 lang: self.lang,
                 reply_to: Some(self.msg.id.0),
@@ -367,17 +474,183 @@ impl<'a> CommandCtx<'a> {
         Ok(())
     }
 
+    async fn seen(&self, username: String) -> ResponseResult<()> {
+        if !ensure_subscribed(self.bot, self.msg, self.db, self.config, self.lang).await {
+            return Ok(());
+        }
+        let username = username.trim();
+        if username.is_empty() {
+            return send_text_key(
+                self.bot,
+                self.msg.chat.id,
+                self.lang,
+                "seen-usage",
+                Some(self.msg.id),
+            )
+            .await;
+        }
+
+        let online = self
+            .config
+            .server_ids()
+            .zip(self.state.servers.iter())
+            .find_map(|(server_id, server)| {
+                server
+                    .online_user_by_username(username)
+                    .map(|u| seen_service::OnlineSighting {
+                        nickname: u.nickname,
+                        channel: u.channel_name,
+                        server: self.config.server_display_name(server_id).to_string(),
+                    })
+            });
+        let last = if online.is_some() {
+            None
+        } else {
+            match seen_service::last_session(self.db, username).await {
+                Ok(last) => last,
+                Err(e) => {
+                    tracing::error!(tt_username = %username, error = %e, "Failed to load last session");
+                    return send_text_key(
+                        self.bot,
+                        self.msg.chat.id,
+                        self.lang,
+                        "cmd-error",
+                        Some(self.msg.id),
+                    )
+                    .await;
+                }
+            }
+        };
+
+        let text = seen_service::render_seen(
+            self.lang,
+            self.config,
+            username,
+            online,
+            last.as_ref(),
+            chrono::Utc::now().naive_utc(),
+        );
+        self.bot
+            .send_message(self.msg.chat.id, text)
+            .reply_to(self.msg.id)
+            .await?;
+        Ok(())
+    }
+
+    async fn graph(&self, range: String) -> ResponseResult<()> {
+        if !ensure_subscribed(self.bot, self.msg, self.db, self.config, self.lang).await {
+            return Ok(());
+        }
+        let Some(range) = graph_service::GraphRange::parse(&range) else {
+            return send_text_key(
+                self.bot,
+                self.msg.chat.id,
+                self.lang,
+                "graph-usage",
+                Some(self.msg.id),
+            )
+            .await;
+        };
+
+        let now = chrono::Utc::now().naive_utc();
+        let graph = match graph_service::load_graph(self.db, range, now).await {
+            Ok(Some(graph)) => graph,
+            Ok(None) => {
+                return send_text_key(
+                    self.bot,
+                    self.msg.chat.id,
+                    self.lang,
+                    "graph-no-data",
+                    Some(self.msg.id),
+                )
+                .await;
+            }
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to load online samples");
+                return send_text_key(
+                    self.bot,
+                    self.msg.chat.id,
+                    self.lang,
+                    "cmd-error",
+                    Some(self.msg.id),
+                )
+                .await;
+            }
+        };
+
+        let caption = graph_service::render_caption(self.lang, range, &graph);
+        let points = graph.points;
+        let x_label = locales::get_text(self.lang.as_str(), "graph-axis-time", None);
+        let y_label = locales::get_text(self.lang.as_str(), "graph-axis-users", None);
+        let png = match tokio::task::spawn_blocking(move || {
+            let labels = chart::AxisLabels {
+                x: &x_label,
+                y: &y_label,
+            };
+            chart::render_line_chart(&points, &labels, GRAPH_WIDTH, GRAPH_HEIGHT)
+        })
+        .await
+        {
+            Ok(Ok(png)) => png,
+            Ok(Err(e)) => {
+                tracing::error!(error = %e, "Failed to render online graph");
+                return send_text_key(
+                    self.bot,
+                    self.msg.chat.id,
+                    self.lang,
+                    "cmd-error",
+                    Some(self.msg.id),
+                )
+                .await;
+            }
+            Err(e) => {
+                tracing::error!(error = %e, "Online graph render task failed");
+                return send_text_key(
+                    self.bot,
+                    self.msg.chat.id,
+                    self.lang,
+                    "cmd-error",
+                    Some(self.msg.id),
+                )
+                .await;
+            }
+        };
+
+        self.bot
+            .send_photo(
+                self.msg.chat.id,
+                InputFile::memory(png).file_name("graph.png"),
+            )
+            .caption(caption)
+            .reply_to(self.msg.id)
+            .await?;
+        Ok(())
+    }
+
     async fn settings(&self) -> ResponseResult<()> {
         if !ensure_subscribed(self.bot, self.msg, self.db, self.config, self.lang).await {
             return Ok(());
         }
-        send_main_settings(self.bot, self.msg.chat.id, self.lang, Some(self.msg.id)).await
+        if !self.ensure_chat_admin().await? {
+            return Ok(());
+        }
+        send_main_settings(
+            self.bot,
+            self.msg.chat.id,
+            self.lang,
+            self.config.is_multi_server(),
+            Some(self.msg.id),
+        )
+        .await
     }
 
     async fn unsub(&self) -> ResponseResult<()> {
         if !ensure_subscribed(self.bot, self.msg, self.db, self.config, self.lang).await {
             return Ok(());
         }
+        if !self.ensure_chat_admin().await? {
+            return Ok(());
+        }
         let text = locales::get_text(self.lang.as_str(), "unsub-confirm-text", None);
         let keyboard = confirm_cancel_keyboard(
             self.lang,
@@ -394,7 +667,78 @@ impl<'a> CommandCtx<'a> {
         Ok(())
     }
 
-    async fn kick_or_ban(&self, cmd: Command) -> ResponseResult<()> {
+    async fn register(&self, username: String) -> ResponseResult<()> {
+        if !ensure_subscribed(self.bot, self.msg, self.db, self.config, self.lang).await {
+            return Ok(());
+        }
+        let Some(user) = self
+            .msg
+            .from
+            .as_ref()
+            .filter(|_| self.msg.chat.is_private())
+        else {
+            return self.reply_key("register-private-only").await;
+        };
+        match self
+            .db
+            .get_tt_username_by_telegram_id(self.telegram_id)
+            .await
+        {
+            Ok(Some(linked)) => {
+                let text = locales::get_text(
+                    self.lang.as_str(),
+                    "register-already-linked",
+                    args!(username = linked).as_ref(),
+                );
+                self.bot
+                    .send_message(self.msg.chat.id, text)
+                    .reply_to(self.msg.id)
+                    .await?;
+                return Ok(());
+            }
+            Ok(None) => {}
+            Err(e) => {
+                tracing::error!(telegram_id = self.telegram_id, error = %e, "Failed to load linked account");
+                return self.reply_key("cmd-error").await;
+            }
+        }
+        if username.trim().is_empty() {
+            return self.reply_key("register-usage").await;
+        }
+        let Some(username) = registration_service::normalize_username(&username) else {
+            return self.reply_key("register-invalid-username").await;
+        };
+        if self.config.is_multi_server() {
+            let keyboard = create_server_picker_keyboard(&server_names(self.config), |server_id| {
+                CallbackAction::Menu(MenuAction::RegisterServer {
+                    server_id,
+                    username: TtUsername::from(username.clone()),
+                })
+            });
+            return self.send_server_picker(keyboard).await;
+        }
+        let applicant = Applicant::from_user(user, self.lang);
+        let text =
+            submit_registration(self.bot, self.state, &applicant, ServerId(0), &username).await;
+        self.bot
+            .send_message(self.msg.chat.id, text)
+            .reply_to(self.msg.id)
+            .await?;
+        Ok(())
+    }
+
+    async fn reply_key(&self, key: &str) -> ResponseResult<()> {
+        send_text_key(
+            self.bot,
+            self.msg.chat.id,
+            self.lang,
+            key,
+            Some(self.msg.id),
+        )
+        .await
+    }
+
+    async fn user_action(&self, cmd: Command) -> ResponseResult<()> {
         if !self.is_admin {
             send_text_key(
                 self.bot,
@@ -406,44 +750,59 @@ impl<'a> CommandCtx<'a> {
             .await?;
             return Ok(());
         }
-        let mut users: Vec<LiteUser> = self
-            .online_users
-            .read()
-            .unwrap_or_else(std::sync::PoisonError::into_inner)
-            .values()
-            .cloned()
-            .collect();
-        users.sort_by(|a, b| a.nickname.to_lowercase().cmp(&b.nickname.to_lowercase()));
-
-        let is_kick = matches!(cmd, Command::Kick);
-        let title_key = if is_kick {
-            "list-kick-title"
-        } else {
-            "list-ban-title"
+        let (title_key, list, perform): (
+            &str,
+            fn(ServerId, usize) -> AdminAction,
+            fn(ServerId, i32) -> AdminAction,
+        ) = match cmd {
+            Command::Kick => (
+                "list-kick-title",
+                |server_id, page| AdminAction::KickList { server_id, page },
+                |server_id, user_id| AdminAction::KickPerform { server_id, user_id },
+            ),
+            Command::Move => (
+                "list-move-title",
+                |server_id, page| AdminAction::MoveList { server_id, page },
+                |server_id, user_id| AdminAction::MoveChannels {
+                    server_id,
+                    user_id,
+                    page: 0,
+                },
+            ),
+            _ => (
+                "list-ban-title",
+                |server_id, page| AdminAction::BanList { server_id, page },
+                |server_id, user_id| AdminAction::BanPerform { server_id, user_id },
+            ),
         };
+        if self.config.is_multi_server() {
+            let keyboard = create_server_picker_keyboard(&server_names(self.config), |server_id| {
+                CallbackAction::Admin(list(server_id, 0))
+            });
+            return self.send_server_picker(keyboard).await;
+        }
 
-        let args = args!(server = self.config.teamtalk.display_name().to_string());
+        let server_id = ServerId(0);
+        let users = self
+            .state
+            .servers
+            .get(server_id.0)
+            .map(ServerShared::sorted_online_users)
+            .unwrap_or_default();
+
+        let args = args!(server = self.config.server_display_name(server_id).to_string());
         let title = locales::get_text(self.lang.as_str(), title_key, args.as_ref());
 
         let keyboard = create_user_list_keyboard(
             &users,
             0,
-            move |u| {
-                let action = if is_kick {
-                    AdminAction::KickPerform { user_id: u.id }
-                } else {
-                    AdminAction::BanPerform { user_id: u.id }
-                };
-                (u.nickname.clone(), CallbackAction::Admin(action))
-            },
-            move |p| {
-                let action = if is_kick {
-                    AdminAction::KickList { page: p }
-                } else {
-                    AdminAction::BanList { page: p }
-                };
-                CallbackAction::Admin(action)
+            |u| {
+                (
+                    u.nickname.clone(),
+                    CallbackAction::Admin(perform(server_id, u.id)),
+                )
             },
+            |p| CallbackAction::Admin(list(server_id, p)),
             None,
             self.lang,
         );
@@ -456,6 +815,21 @@ impl<'a> CommandCtx<'a> {
         Ok(())
     }
 
+    async fn send_server_picker(
+        &self,
+        keyboard: teloxide::types::InlineKeyboardMarkup,
+    ) -> ResponseResult<()> {
+        self.bot
+            .send_message(
+                self.msg.chat.id,
+                locales::get_text(self.lang.as_str(), "server-picker-title", None),
+            )
+            .reply_to(self.msg.id)
+            .reply_markup(keyboard)
+            .await?;
+        Ok(())
+    }
+
     async fn unban(&self) -> ResponseResult<()> {
         if !self.is_admin {
             send_text_key(
@@ -479,7 +853,7 @@ impl<'a> CommandCtx<'a> {
         .await
     }
 
-    async fn subscribers(&self) -> ResponseResult<()> {
+    async fn server_ban(&self, target: &str) -> ResponseResult<()> {
         if !self.is_admin {
             send_text_key(
                 self.bot,
@@ -491,42 +865,63 @@ impl<'a> CommandCtx<'a> {
             .await?;
             return Ok(());
         }
-        send_subscribers_list(
-            self.bot,
-            self.msg.chat.id,
-            self.db,
-            self.lang,
-            0,
-            Some(self.msg.id),
-        )
-        .await
-    }
-
-    async fn exit(&self) -> ResponseResult<()> {
-        if !self.is_admin {
-            send_text_key(
+        let Some(target) = bans_service::parse_ban_target(target) else {
+            return send_text_key(
                 self.bot,
                 self.msg.chat.id,
                 self.lang,
-                "cmd-unauth",
+                "server-ban-usage",
                 Some(self.msg.id),
             )
-            .await?;
-            return Ok(());
+            .await;
+        };
+        for server_id in self.config.server_ids() {
+            if let Err(e) = self
+                .tx_tt
+                .send(TtCommand::ServerBan {
+                    server_id,
+                    target: target.clone(),
+                })
+                .await
+            {
+                tracing::error!(%server_id, error = %e, "Failed to send server ban command");
+                notify_admin_error(
+                    self.bot,
+                    self.config,
+                    self.telegram_id,
+                    AdminErrorContext::TtCommand,
+                    &e.to_string(),
+                    self.lang,
+                )
+                .await;
+                return send_text_key(
+                    self.bot,
+                    self.msg.chat.id,
+                    self.lang,
+                    "cmd-error",
+                    Some(self.msg.id),
+                )
+                .await;
+            }
         }
+        let name = match target {
+            BanTarget::IpAddress(name) | BanTarget::Username(name) => name,
+        };
         self.bot
             .send_message(
                 self.msg.chat.id,
-                locales::get_text(self.lang.as_str(), "cmd-shutting-down", None),
+                locales::get_text(
+                    self.lang.as_str(),
+                    "server-ban-sent",
+                    args!(name = name).as_ref(),
+                ),
             )
             .reply_to(self.msg.id)
             .await?;
-        let _ = self.state.tx_tt.send(TtCommand::Shutdown).await;
-        self.state.cancel_token.cancel();
         Ok(())
     }
 
-    async fn broadcast(&self, text: String) -> ResponseResult<()> {
+    async fn server_bans(&self) -> ResponseResult<()> {
         if !self.is_admin {
             send_text_key(
                 self.bot,
@@ -538,25 +933,212 @@ impl<'a> CommandCtx<'a> {
             .await?;
             return Ok(());
         }
+        if self.config.is_multi_server() {
+            let keyboard = create_server_picker_keyboard(&server_names(self.config), |server_id| {
+                CallbackAction::Admin(AdminAction::ServerBanList { server_id, page: 0 })
+            });
+            return self.send_server_picker(keyboard).await;
+        }
+        send_server_bans(
+            self.bot,
+            self.msg.chat.id,
+            self.state,
+            ServerId(0),
+            self.lang,
+            Some(self.msg.id),
+        )
+        .await
+    }
 
-        let text = text.trim().to_string();
-        if text.is_empty() {
+    async fn channels(&self) -> ResponseResult<()> {
+        if !self.is_admin {
             send_text_key(
                 self.bot,
                 self.msg.chat.id,
                 self.lang,
-                "cmd-broadcast-empty",
+                "cmd-unauth",
                 Some(self.msg.id),
             )
             .await?;
             return Ok(());
         }
+        if self.config.is_multi_server() {
+            let keyboard = create_server_picker_keyboard(&server_names(self.config), |server_id| {
+                CallbackAction::Admin(AdminAction::ChannelList { server_id, page: 0 })
+            });
+            return self.send_server_picker(keyboard).await;
+        }
+        send_channel_list(
+            self.bot,
+            self.msg.chat.id,
+            self.state,
+            ServerId(0),
+            self.lang,
+            Some(self.msg.id),
+        )
+        .await
+    }
 
-        if let Err(e) = self.tx_tt.send(TtCommand::Broadcast { text }).await {
-            tracing::error!(error = %e, "Failed to send broadcast command");
-            notify_admin_error(
+    async fn server(&self) -> ResponseResult<()> {
+        if !self.is_admin {
+            send_text_key(
                 self.bot,
-                self.config,
+                self.msg.chat.id,
+                self.lang,
+                "cmd-unauth",
+                Some(self.msg.id),
+            )
+            .await?;
+            return Ok(());
+        }
+        if self.config.is_multi_server() {
+            let keyboard = create_server_picker_keyboard(&server_names(self.config), |server_id| {
+                CallbackAction::Admin(AdminAction::ServerInfo { server_id })
+            });
+            return self.send_server_picker(keyboard).await;
+        }
+        send_server_info(
+            self.bot,
+            self.msg.chat.id,
+            self.state,
+            ServerId(0),
+            self.lang,
+            Some(self.msg.id),
+        )
+        .await
+    }
+
+    async fn accounts(&self, query: String) -> ResponseResult<()> {
+        if !self.is_admin {
+            send_text_key(
+                self.bot,
+                self.msg.chat.id,
+                self.lang,
+                "cmd-unauth",
+                Some(self.msg.id),
+            )
+            .await?;
+            return Ok(());
+        }
+        let query = query.trim().to_string();
+        if self.config.is_multi_server() {
+            let keyboard = create_server_picker_keyboard(&server_names(self.config), |server_id| {
+                CallbackAction::Admin(AdminAction::AccountList { server_id, page: 0 })
+            });
+            let picker = self
+                .bot
+                .send_message(
+                    self.msg.chat.id,
+                    locales::get_text(self.lang.as_str(), "server-picker-title", None),
+                )
+                .reply_to(self.msg.id)
+                .reply_markup(keyboard)
+                .await?;
+            remember_filter(self.state, &picker, query);
+            return Ok(());
+        }
+        send_account_list(
+            self.bot,
+            self.msg.chat.id,
+            self.state,
+            ServerId(0),
+            query,
+            self.lang,
+            Some(self.msg.id),
+        )
+        .await?;
+        request_accounts(self.state, ServerId(0)).await;
+        Ok(())
+    }
+
+    async fn subscribers(&self) -> ResponseResult<()> {
+        if !self.is_admin {
+            send_text_key(
+                self.bot,
+                self.msg.chat.id,
+                self.lang,
+                "cmd-unauth",
+                Some(self.msg.id),
+            )
+            .await?;
+            return Ok(());
+        }
+        send_subscribers_list(
+            self.bot,
+            self.msg.chat.id,
+            self.db,
+            self.lang,
+            0,
+            Some(self.msg.id),
+        )
+        .await
+    }
+
+    async fn exit(&self) -> ResponseResult<()> {
+        if !self.is_admin {
+            send_text_key(
+                self.bot,
+                self.msg.chat.id,
+                self.lang,
+                "cmd-unauth",
+                Some(self.msg.id),
+            )
+            .await?;
+            return Ok(());
+        }
+        self.bot
+            .send_message(
+                self.msg.chat.id,
+                locales::get_text(self.lang.as_str(), "cmd-shutting-down", None),
+            )
+            .reply_to(self.msg.id)
+            .await?;
+        let _ = self.state.tx_tt.send(TtCommand::Shutdown).await;
+        self.state.cancel_token.cancel();
+        Ok(())
+    }
+
+    async fn broadcast(&self, text: String) -> ResponseResult<()> {
+        if !self.is_admin {
+            send_text_key(
+                self.bot,
+                self.msg.chat.id,
+                self.lang,
+                "cmd-unauth",
+                Some(self.msg.id),
+            )
+            .await?;
+            return Ok(());
+        }
+
+        let text = text.trim().to_string();
+        if text.is_empty() {
+            send_text_key(
+                self.bot,
+                self.msg.chat.id,
+                self.lang,
+                "cmd-broadcast-empty",
+                Some(self.msg.id),
+            )
+            .await?;
+            return Ok(());
+        }
+
+        let mut send_err = None;
+        for server_id in self.config.server_ids() {
+            let cmd = TtCommand::Broadcast {
+                server_id,
+                text: text.clone(),
+            };
+            if let Err(e) = self.tx_tt.send(cmd).await {
+                tracing::error!(%server_id, error = %e, "Failed to send broadcast command");
+                send_err = Some(e);
+            }
+        }
+        if let Some(e) = send_err {
+            notify_admin_error(
+                self.bot,
+                self.config,
                 self.telegram_id,
                 AdminErrorContext::Command,
                 &e.to_string(),
@@ -663,6 +1245,172 @@ impl<'a> CommandCtx<'a> {
             .await?;
         Ok(())
     }
+
+    async fn queue(&self) -> ResponseResult<()> {
+        if !self.is_admin {
+            send_text_key(
+                self.bot,
+                self.msg.chat.id,
+                self.lang,
+                "cmd-unauth",
+                Some(self.msg.id),
+            )
+            .await?;
+            return Ok(());
+        }
+        if self.config.is_multi_server() {
+            let keyboard = create_server_picker_keyboard(&server_names(self.config), |server_id| {
+                CallbackAction::Queue(QueueAction::Control {
+                    server_id,
+                    op: QueueOp::Show,
+                })
+            });
+            return self.send_server_picker(keyboard).await;
+        }
+
+        let sent = self
+            .bot
+            .send_message(
+                self.msg.chat.id,
+                locales::get_text(self.lang.as_str(), "queue-loading", None),
+            )
+            .reply_to(self.msg.id)
+            .await?;
+        if let Err(e) = self
+            .tx_tt
+            .send(TtCommand::QueueControl {
+                server_id: ServerId(0),
+                chat_id: self.msg.chat.id.0,
+                message_id: sent.id.0,
+                lang: self.lang,
+                op: QueueOp::Show,
+            })
+            .await
+        {
+            tracing::error!(error = %e, "Failed to send TT queue command");
+            notify_admin_error(
+                self.bot,
+                self.config,
+                self.telegram_id,
+                AdminErrorContext::TtCommand,
+                &e.to_string(),
+                self.lang,
+            )
+            .await;
+        }
+        Ok(())
+    }
+
+    async fn play(&self, query: String) -> ResponseResult<()> {
+        if !self.is_admin {
+            send_text_key(
+                self.bot,
+                self.msg.chat.id,
+                self.lang,
+                "cmd-unauth",
+                Some(self.msg.id),
+            )
+            .await?;
+            return Ok(());
+        }
+        let reply = music_service::play(self.config.music.library_dir(), self.lang, &query).await;
+        self.send_music_reply(reply).await
+    }
+
+    async fn playlist(&self, args: String) -> ResponseResult<()> {
+        if !self.is_admin {
+            send_text_key(
+                self.bot,
+                self.msg.chat.id,
+                self.lang,
+                "cmd-unauth",
+                Some(self.msg.id),
+            )
+            .await?;
+            return Ok(());
+        }
+        let reply = match music_service::playlist(
+            self.db,
+            self.config.music.library_dir(),
+            self.lang,
+            &args,
+        )
+        .await
+        {
+            Ok(reply) => reply,
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to run playlist command");
+                notify_admin_error(
+                    self.bot,
+                    self.config,
+                    self.telegram_id,
+                    AdminErrorContext::Command,
+                    &e.to_string(),
+                    self.lang,
+                )
+                .await;
+                send_text_key(
+                    self.bot,
+                    self.msg.chat.id,
+                    self.lang,
+                    "cmd-error",
+                    Some(self.msg.id),
+                )
+                .await?;
+                return Ok(());
+            }
+        };
+        self.send_music_reply(reply).await
+    }
+
+    async fn send_music_reply(&self, reply: MusicReply) -> ResponseResult<()> {
+        if !reply.enqueue.is_empty() && self.config.is_multi_server() {
+            let keyboard = create_server_picker_keyboard(&server_names(self.config), |server_id| {
+                CallbackAction::Admin(AdminAction::MusicServer { server_id })
+            });
+            return self.send_server_picker(keyboard).await;
+        }
+        if let Err(e) = enqueue_tracks(self.state, &reply.enqueue, ServerId(0)).await {
+            tracing::error!(error = %e, "Failed to send TT enqueue command");
+            notify_admin_error(
+                self.bot,
+                self.config,
+                self.telegram_id,
+                AdminErrorContext::TtCommand,
+                &e,
+                self.lang,
+            )
+            .await;
+            return send_text_key(
+                self.bot,
+                self.msg.chat.id,
+                self.lang,
+                "cmd-error",
+                Some(self.msg.id),
+            )
+            .await;
+        }
+        self.bot
+            .send_message(self.msg.chat.id, reply.text)
+            .reply_to(self.msg.id)
+            .await?;
+        Ok(())
+    }
+}
+
+pub async fn enqueue_tracks(
+    state: &AppState,
+    tracks: &[Track],
+    server_id: ServerId,
+) -> Result<(), String> {
+    for track in tracks {
+        state
+            .tx_tt
+            .send(track.enqueue_command(server_id))
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+    Ok(())
 }
 
 pub async fn answer_message(bot: Bot, msg: Message, state: AppState) -> ResponseResult<()> {
@@ -686,7 +1434,7 @@ pub async fn answer_message(bot: Bot, msg: Message, state: AppState) -> Response
     handle_admin_reply(&bot, &msg, &state, telegram_id, admin_lang).await
 }
 
-async fn is_admin(
+pub async fn is_admin(
     db: &crate::infra::db::Database,
     config: &crate::bootstrap::config::Config,
     telegram_id: i64,
@@ -703,6 +1451,59 @@ async fn is_admin(
     }
 }
 
+pub async fn send_media_to_server(
+    bot: &Bot,
+    msg: &Message,
+    state: &AppState,
+    telegram_id: i64,
+    admin_lang: LanguageCode,
+    server_id: ServerId,
+) -> ResponseResult<()> {
+    let config = &state.config;
+    let audio = AudioClip::from_message(msg);
+    let document = msg.document().filter(|_| audio.is_none());
+    let res = if let Some(audio) = &audio {
+        stream_audio(bot, state, server_id, None, audio)
+            .await
+            .map(|()| "tg-reply-sent")
+    } else if let Some(document) = document {
+        upload_document(
+            bot,
+            state,
+            msg.chat.id.0,
+            admin_lang,
+            server_id,
+            0,
+            document,
+        )
+        .await
+        .map(|()| "file-upload-started")
+    } else {
+        return Ok(());
+    };
+    let reply_key = match res {
+        Ok(key) => key,
+        Err(e) => {
+            notify_admin_error(
+                bot,
+                config,
+                telegram_id,
+                AdminErrorContext::Command,
+                &e,
+                admin_lang,
+            )
+            .await;
+            "tg-reply-failed"
+        }
+    };
+    let reply_text = locales::get_text(admin_lang.as_str(), reply_key, None);
+    let _ = bot
+        .send_message(msg.chat.id, reply_text)
+        .reply_to(msg.id)
+        .await;
+    Ok(())
+}
+
 async fn handle_admin_reply(
     bot: &Bot,
     msg: &Message,
@@ -713,39 +1514,31 @@ async fn handle_admin_reply(
     let config = &state.config;
     let reply_to = msg.reply_to_message();
     let text = msg.text();
-    let voice = msg.voice();
-
-    if reply_to.is_none() {
-        if let Some(voice) = voice {
-            let reply_key = match stream_voice(bot, state, None, voice).await {
-                Ok(()) => "tg-reply-sent",
-                Err(e) => {
-                    notify_admin_error(
-                        bot,
-                        config,
-                        telegram_id,
-                        AdminErrorContext::Command,
-                        &e,
-                        admin_lang,
-                    )
-                    .await;
-                    "tg-reply-failed"
-                }
-            };
-            let reply_text = locales::get_text(admin_lang.as_str(), reply_key, None);
-            let _ = bot
-                .send_message(msg.chat.id, reply_text)
-                .reply_to(msg.id)
-                .await;
-        }
-        return Ok(());
-    }
+    let audio = AudioClip::from_message(msg);
+    let document = msg.document().filter(|_| audio.is_none());
 
     let Some(reply_to) = reply_to else {
-        return Ok(());
+        if (audio.is_some() || document.is_some()) && config.is_multi_server() {
+            let keyboard = create_server_picker_keyboard(&server_names(config), |server_id| {
+                CallbackAction::Admin(AdminAction::MediaServer { server_id })
+            });
+            bot.send_message(
+                msg.chat.id,
+                locales::get_text(admin_lang.as_str(), "server-picker-title", None),
+            )
+            .reply_to(msg.id)
+            .reply_markup(keyboard)
+            .await?;
+            return Ok(());
+        }
+        return send_media_to_server(bot, msg, state, telegram_id, admin_lang, ServerId(0)).await;
     };
     let reply_id = i64::from(reply_to.id.0);
 
+    if handle_prompt_reply(bot, msg, reply_to, state, telegram_id, admin_lang).await? {
+        return Ok(());
+    }
+
     if handle_channel_reply(
         ChannelReplyCtx {
             bot,
@@ -757,7 +1550,8 @@ async fn handle_admin_reply(
         ChannelReplyInput {
             reply_id,
             text,
-            voice,
+            audio,
+            document,
         },
     )
     .await?
@@ -771,6 +1565,97 @@ async fn handle_admin_reply(
     handle_user_reply(bot, msg, state, telegram_id, admin_lang, reply_id, text).await
 }
 
+async fn handle_prompt_reply(
+    bot: &Bot,
+    msg: &Message,
+    reply_to: &Message,
+    state: &AppState,
+    telegram_id: i64,
+    admin_lang: LanguageCode,
+) -> ResponseResult<bool> {
+    let text = msg.text();
+    if let Some(reason) = text.map(str::trim).filter(|t| !t.is_empty())
+        && let Some(pending) = state
+            .pending_bans
+            .write()
+            .ok()
+            .and_then(|mut pending| pending.remove(&(msg.chat.id.0, reply_to.id.0)))
+    {
+        let result = execute_ban(
+            bot,
+            state,
+            telegram_id,
+            admin_lang,
+            pending,
+            Some(reason.to_string()),
+        )
+        .await;
+        let _ = bot
+            .edit_message_reply_markup(msg.chat.id, reply_to.id)
+            .await;
+        bot.send_message(msg.chat.id, result)
+            .reply_to(msg.id)
+            .await?;
+        return Ok(true);
+    }
+
+    let prompt_key = (msg.chat.id.0, reply_to.id.0);
+    if let Some(input) = text.filter(|t| !t.trim().is_empty())
+        && let Some(pending) = state
+            .pending_account_inputs
+            .read()
+            .ok()
+            .and_then(|pending| pending.get(&prompt_key).cloned())
+    {
+        handle_account_reply(bot, msg, reply_to, state, pending, input, admin_lang).await?;
+        return Ok(true);
+    }
+
+    if let Some(input) = text.filter(|t| !t.trim().is_empty())
+        && let Some(pending) = state
+            .pending_server_edits
+            .read()
+            .ok()
+            .and_then(|pending| pending.get(&prompt_key).copied())
+    {
+        handle_server_reply(bot, msg, reply_to, state, pending, input, admin_lang).await?;
+        return Ok(true);
+    }
+
+    if let Some(input) = text.filter(|t| !t.trim().is_empty())
+        && let Some(pending) = state
+            .pending_channel_edits
+            .read()
+            .ok()
+            .and_then(|pending| pending.get(&prompt_key).copied())
+    {
+        let Some(result) =
+            execute_channel_edit(state, pending, input, msg.chat.id.0, admin_lang).await
+        else {
+            send_text_key(
+                bot,
+                msg.chat.id,
+                admin_lang,
+                "channel-create-usage",
+                Some(msg.id),
+            )
+            .await?;
+            return Ok(true);
+        };
+        if let Ok(mut pending) = state.pending_channel_edits.write() {
+            pending.remove(&prompt_key);
+        }
+        let _ = bot
+            .edit_message_reply_markup(msg.chat.id, reply_to.id)
+            .await;
+        bot.send_message(msg.chat.id, result)
+            .reply_to(msg.id)
+            .await?;
+        return Ok(true);
+    }
+    Ok(false)
+}
+
 struct ChannelReplyCtx<'a> {
     bot: &'a Bot,
     msg: &'a Message,
@@ -782,7 +1667,8 @@ struct ChannelReplyCtx<'a> {
 struct ChannelReplyInput<'a> {
     reply_id: i64,
     text: Option<&'a str>,
-    voice: Option<&'a Voice>,
+    audio: Option<AudioClip<'a>>,
+    document: Option<&'a Document>,
 }
 
 async fn handle_channel_reply(
@@ -791,17 +1677,46 @@ async fn handle_channel_reply(
 ) -> ResponseResult<bool> {
     let db = &ctx.state.db;
     let config = &ctx.state.config;
-    if let Ok(Some((channel_id, _channel_name, _server_name, original_text))) =
-        pending_service::get_pending_channel_reply(db, input.reply_id).await
+    if let Ok(Some((server_id, reply))) =
+        pending_service::get_pending_channel_reply(db, config, input.reply_id).await
     {
+        let channel_id = reply.channel_id;
+        let original_text = reply.original_text;
         let mut reply_key = "tg-reply-sent";
-        if let Some(voice) = input.voice {
-            let duration = format_duration(voice.duration.seconds());
-            let args = args!(msg = original_text.clone(), duration = duration);
-            let announce_text =
-                locales::get_text(ctx.admin_lang.as_str(), "tt-channel-reply", args.as_ref());
-            if let Err(e) =
-                stream_voice(ctx.bot, ctx.state, Some((channel_id, announce_text)), voice).await
+        if let Some(audio) = &input.audio {
+            let announce_text = audio.announce_text(ctx.admin_lang, &original_text);
+            if let Err(e) = stream_audio(
+                ctx.bot,
+                ctx.state,
+                server_id,
+                Some((channel_id, announce_text)),
+                audio,
+            )
+            .await
+            {
+                notify_admin_error(
+                    ctx.bot,
+                    config,
+                    ctx.telegram_id,
+                    AdminErrorContext::Command,
+                    &e,
+                    ctx.admin_lang,
+                )
+                .await;
+                reply_key = "tg-reply-failed";
+            }
+        } else if let Some(document) = input.document {
+            reply_key = "file-upload-started";
+            if let Err(e) = upload_document(
+                ctx.bot,
+                ctx.state,
+                ctx.msg.chat.id.0,
+                ctx.admin_lang,
+                server_id,
+                channel_id,
+                document,
+            )
+            .await
             {
                 notify_admin_error(
                     ctx.bot,
@@ -825,6 +1740,7 @@ async fn handle_channel_reply(
                 .state
                 .tx_tt
                 .send(TtCommand::SendToChannel {
+                    server_id,
                     channel_id,
                     text: channel_text,
                 })
@@ -877,33 +1793,34 @@ async fn handle_user_reply(
 ) -> ResponseResult<()> {
     let db = &state.db;
     let config = &state.config;
-    let tt_user_id = match pending_service::get_pending_reply_user_id(db, reply_id).await {
-        Ok(Some(id)) => id,
-        Ok(None) => return Ok(()),
-        Err(e) => {
-            tracing::error!(reply_id, error = %e, "Failed to load pending reply");
-            notify_admin_error(
-                bot,
-                config,
-                telegram_id,
-                AdminErrorContext::Command,
-                &e.to_string(),
-                config.general.default_lang,
-            )
-            .await;
-            return Ok(());
-        }
-    };
+    let (server_id, tt_user_id) =
+        match pending_service::get_pending_reply(db, config, reply_id).await {
+            Ok(Some(target)) => target,
+            Ok(None) => return Ok(()),
+            Err(e) => {
+                tracing::error!(reply_id, error = %e, "Failed to load pending reply");
+                notify_admin_error(
+                    bot,
+                    config,
+                    telegram_id,
+                    AdminErrorContext::Command,
+                    &e.to_string(),
+                    config.general.default_lang,
+                )
+                .await;
+                return Ok(());
+            }
+        };
 
     let is_online = state
-        .online_users
-        .read()
-        .unwrap_or_else(std::sync::PoisonError::into_inner)
-        .contains_key(&tt_user_id);
+        .servers
+        .get(server_id.0)
+        .is_some_and(|server| server.online_user(tt_user_id).is_some());
     let reply_key = if is_online {
         let send_res = state
             .tx_tt
             .send(TtCommand::ReplyToUser {
+                server_id,
                 user_id: tt_user_id,
                 text: text.to_string(),
             })
@@ -945,38 +1862,184 @@ fn format_duration(duration_secs: u32) -> String {
     format!("{minutes:02}:{seconds:02}")
 }
 
-async fn stream_voice(
+struct AudioClip<'a> {
+    file: &'a FileMeta,
+    extension: String,
+    duration_secs: Option<u32>,
+    title: Option<String>,
+}
+
+impl<'a> AudioClip<'a> {
+    fn from_message(msg: &'a Message) -> Option<Self> {
+        if let Some(voice) = msg.voice() {
+            return Some(Self {
+                file: &voice.file,
+                extension: "ogg".to_string(),
+                duration_secs: Some(voice.duration.seconds()),
+                title: None,
+            });
+        }
+        if let Some(audio) = msg.audio() {
+            let file_name = audio.file_name.as_deref();
+            return Some(Self {
+                file: &audio.file,
+                extension: queue_service::file_extension(file_name)
+                    .unwrap_or_else(|| "mp3".to_string()),
+                duration_secs: Some(audio.duration.seconds()),
+                title: queue_service::track_title(
+                    audio.performer.as_deref(),
+                    audio.title.as_deref(),
+                    file_name,
+                ),
+            });
+        }
+        if let Some(note) = msg.video_note() {
+            return Some(Self {
+                file: &note.file,
+                extension: "mp4".to_string(),
+                duration_secs: Some(note.duration.seconds()),
+                title: None,
+            });
+        }
+        let document = msg.document()?;
+        let file_name = document.file_name.as_deref();
+        let mime_type = document.mime_type.as_ref().map(|mime| mime.essence_str());
+        if !queue_service::is_audio_file(mime_type, file_name) {
+            return None;
+        }
+        Some(Self {
+            file: &document.file,
+            extension: queue_service::file_extension(file_name)
+                .unwrap_or_else(|| "audio".to_string()),
+            duration_secs: None,
+            title: queue_service::track_title(None, None, file_name),
+        })
+    }
+
+    fn announce_text(&self, lang: LanguageCode, original_text: &str) -> String {
+        let duration = self.duration_secs.map(format_duration);
+        let (key, args) = match (&self.title, duration) {
+            (Some(title), Some(duration)) => (
+                "tt-channel-reply-track",
+                args!(
+                    msg = original_text.to_string(),
+                    title = title.clone(),
+                    duration = duration
+                ),
+            ),
+            (Some(title), None) => (
+                "tt-channel-reply-track-untimed",
+                args!(msg = original_text.to_string(), title = title.clone()),
+            ),
+            (None, Some(duration)) => (
+                "tt-channel-reply",
+                args!(msg = original_text.to_string(), duration = duration),
+            ),
+            (None, None) => (
+                "tt-channel-reply-track-untimed",
+                args!(
+                    msg = original_text.to_string(),
+                    title = locales::get_text(lang.as_str(), "queue-item-voice", None)
+                ),
+            ),
+        };
+        locales::get_text(lang.as_str(), key, args.as_ref())
+    }
+}
+
+async fn stream_audio(
     bot: &Bot,
     state: &AppState,
+    server_id: ServerId,
     announce: Option<(i32, String)>,
-    voice: &Voice,
+    audio: &AudioClip<'_>,
 ) -> Result<(), String> {
     let file_info = bot
-        .get_file(voice.file.id.clone())
+        .get_file(audio.file.id.clone())
+        .await
+        .map_err(|e| e.to_string())?;
+    let spool_dir = state.config.database.spool_dir();
+    tokio::fs::create_dir_all(&spool_dir)
         .await
         .map_err(|e| e.to_string())?;
-    let mut temp_path = std::env::temp_dir();
     let now = SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .map(|d| d.as_millis())
         .unwrap_or(0);
-    temp_path.push(format!("tg-voice-{}-{}.ogg", voice.file.id, now));
+    let temp_path = spool_dir.join(format!(
+        "tg-audio-{}-{}.{}",
+        audio.file.unique_id, now, audio.extension
+    ));
     let mut dst = File::create(&temp_path).await.map_err(|e| e.to_string())?;
-    bot.download_file(&file_info.path, &mut dst)
-        .await
-        .map_err(|e| e.to_string())?;
+    if let Err(e) = bot.download_file(&file_info.path, &mut dst).await {
+        let _ = tokio::fs::remove_file(&temp_path).await;
+        return Err(e.to_string());
+    }
 
-    let duration_ms = voice.duration.seconds().saturating_mul(1000);
+    let duration_ms = audio.duration_secs.unwrap_or(0).saturating_mul(1000);
     let (channel_id, announce_text) = announce.map_or((0, None), |(id, text)| (id, Some(text)));
     state
         .tx_tt
         .send(TtCommand::EnqueueStream {
+            server_id,
             channel_id,
             file_path: temp_path.to_string_lossy().to_string(),
             duration_ms,
             announce_text,
+            title: audio.title.clone(),
         })
         .await
         .map_err(|e| e.to_string())?;
     Ok(())
 }
+
+async fn upload_document(
+    bot: &Bot,
+    state: &AppState,
+    chat_id: i64,
+    lang: LanguageCode,
+    server_id: ServerId,
+    channel_id: i32,
+    document: &Document,
+) -> Result<(), String> {
+    let file_info = bot
+        .get_file(document.file.id.clone())
+        .await
+        .map_err(|e| e.to_string())?;
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis())
+        .unwrap_or(0);
+    let mut temp_dir = std::env::temp_dir();
+    temp_dir.push(format!("tg-upload-{}-{}", document.file.unique_id, now));
+    tokio::fs::create_dir_all(&temp_dir)
+        .await
+        .map_err(|e| e.to_string())?;
+    let temp_path = temp_dir.join(files_service::upload_file_name(
+        document.file_name.as_deref(),
+        &document.file.unique_id.to_string(),
+    ));
+
+    let res = async {
+        let mut dst = File::create(&temp_path).await.map_err(|e| e.to_string())?;
+        bot.download_file(&file_info.path, &mut dst)
+            .await
+            .map_err(|e| e.to_string())?;
+        state
+            .tx_tt
+            .send(TtCommand::UploadFile {
+                server_id,
+                channel_id,
+                path: temp_path.to_string_lossy().to_string(),
+                chat_id,
+                lang,
+            })
+            .await
+            .map_err(|e| e.to_string())
+    }
+    .await;
+    if res.is_err() {
+        let _ = tokio::fs::remove_dir_all(&temp_dir).await;
+    }
+    res
+}
@@ -1,5 +1,10 @@
-use crate::core::callbacks::{AsCallbackData, CallbackAction};
-use crate::core::types::LanguageCode;
+use crate::app::services::queue::QUEUE_ITEMS_SHOWN;
+use crate::args;
+use crate::core::callbacks::{AsCallbackData, CallbackAction, QueueAction};
+use crate::core::types::{
+    AccountField, AccountType, BanTerm, ChannelField, LanguageCode, QueueOp, QueueSnapshot,
+    RepeatMode, ServerField, ServerId, TtUsername,
+};
 use crate::infra::locales;
 use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
 
@@ -140,6 +145,170 @@ where
     InlineKeyboardMarkup::new(final_buttons)
 }
 
+pub fn create_server_picker_keyboard<F>(
+    servers: &[(ServerId, String)],
+    action_builder: F,
+) -> InlineKeyboardMarkup
+where
+    F: Fn(ServerId) -> CallbackAction,
+{
+    let buttons = servers
+        .iter()
+        .map(|(id, name)| vec![callback_button(name.clone(), action_builder(*id))])
+        .collect::<Vec<_>>();
+    InlineKeyboardMarkup::new(buttons)
+}
+
+pub fn create_ban_term_keyboard(
+    lang: LanguageCode,
+    server_id: ServerId,
+    user_id: i32,
+) -> InlineKeyboardMarkup {
+    use crate::core::callbacks::AdminAction;
+
+    let buttons = BanTerm::ALL
+        .iter()
+        .map(|&term| {
+            let key = match term {
+                BanTerm::Hour => "btn-ban-hour",
+                BanTerm::Day => "btn-ban-day",
+                BanTerm::Week => "btn-ban-week",
+                BanTerm::Permanent => "btn-ban-permanent",
+            };
+            callback_button(
+                locales::get_text(lang.as_str(), key, None),
+                CallbackAction::Admin(AdminAction::BanTerm {
+                    server_id,
+                    user_id,
+                    term,
+                }),
+            )
+        })
+        .collect::<Vec<_>>();
+    InlineKeyboardMarkup::new(buttons.chunks(2).map(<[_]>::to_vec).collect::<Vec<_>>())
+}
+
+pub fn create_channel_keyboard(
+    lang: LanguageCode,
+    server_id: ServerId,
+    channel_id: i32,
+    deletable: bool,
+) -> InlineKeyboardMarkup {
+    use crate::core::callbacks::AdminAction;
+
+    let button = |key: &str, action: AdminAction| {
+        callback_button(
+            locales::get_text(lang.as_str(), key, None),
+            CallbackAction::Admin(action),
+        )
+    };
+    let prompt = |field| AdminAction::ChannelPrompt {
+        server_id,
+        channel_id,
+        field,
+    };
+    let mut buttons = vec![
+        vec![button(
+            "btn-channel-create",
+            prompt(ChannelField::SubChannel),
+        )],
+        vec![
+            button("btn-channel-topic", prompt(ChannelField::Topic)),
+            button("btn-channel-password", prompt(ChannelField::Password)),
+        ],
+    ];
+    if deletable {
+        buttons.push(vec![button(
+            "btn-channel-delete",
+            AdminAction::ChannelDelete {
+                server_id,
+                channel_id,
+            },
+        )]);
+    }
+    buttons.push(vec![button(
+        "btn-back-channels",
+        AdminAction::ChannelList { server_id, page: 0 },
+    )]);
+    InlineKeyboardMarkup::new(buttons)
+}
+
+pub fn create_server_info_keyboard(
+    lang: LanguageCode,
+    server_id: ServerId,
+) -> InlineKeyboardMarkup {
+    use crate::core::callbacks::AdminAction;
+
+    let button = |key: &str, action: AdminAction| {
+        callback_button(
+            locales::get_text(lang.as_str(), key, None),
+            CallbackAction::Admin(action),
+        )
+    };
+    let prompt = |field| AdminAction::ServerPrompt { server_id, field };
+    InlineKeyboardMarkup::new(vec![
+        vec![
+            button("btn-server-name", prompt(ServerField::Name)),
+            button("btn-server-motd", prompt(ServerField::Motd)),
+        ],
+        vec![button(
+            "btn-server-refresh",
+            AdminAction::ServerInfo { server_id },
+        )],
+    ])
+}
+
+pub fn create_account_keyboard(
+    lang: LanguageCode,
+    server_id: ServerId,
+    username: &TtUsername,
+    is_admin: bool,
+) -> InlineKeyboardMarkup {
+    use crate::core::callbacks::AdminAction;
+
+    let button = |key: &str, action: AdminAction| {
+        callback_button(
+            locales::get_text(lang.as_str(), key, None),
+            CallbackAction::Admin(action),
+        )
+    };
+    let prompt = |field| AdminAction::AccountPrompt {
+        server_id,
+        username: username.clone(),
+        field,
+    };
+    let (type_key, account_type) = if is_admin {
+        ("btn-account-make-default", AccountType::Default)
+    } else {
+        ("btn-account-make-admin", AccountType::Admin)
+    };
+    InlineKeyboardMarkup::new(vec![
+        vec![
+            button("btn-account-password", prompt(AccountField::Password)),
+            button("btn-account-note", prompt(AccountField::Note)),
+        ],
+        vec![button(
+            type_key,
+            AdminAction::AccountSetType {
+                server_id,
+                username: username.clone(),
+                account_type,
+            },
+        )],
+        vec![button(
+            "btn-account-delete",
+            AdminAction::AccountDelete {
+                server_id,
+                username: username.clone(),
+            },
+        )],
+        vec![button(
+            "btn-back-accounts",
+            AdminAction::AccountList { server_id, page: 0 },
+        )],
+    ])
+}
+
 pub fn create_main_menu_keyboard(lang: LanguageCode, is_admin: bool) -> InlineKeyboardMarkup {
     use crate::core::callbacks::{AdminAction, MenuAction};
 
@@ -165,11 +334,27 @@ pub fn create_main_menu_keyboard(lang: LanguageCode, is_admin: bool) -> InlineKe
     if is_admin {
         buttons.push(vec![callback_button(
             locales::get_text(lang.as_str(), "btn-menu-kick", None),
-            CallbackAction::Admin(AdminAction::KickList { page: 0 }),
+            CallbackAction::Admin(AdminAction::KickServers),
         )]);
         buttons.push(vec![callback_button(
             locales::get_text(lang.as_str(), "btn-menu-ban", None),
-            CallbackAction::Admin(AdminAction::BanList { page: 0 }),
+            CallbackAction::Admin(AdminAction::BanServers),
+        )]);
+        buttons.push(vec![callback_button(
+            locales::get_text(lang.as_str(), "btn-menu-move", None),
+            CallbackAction::Admin(AdminAction::MoveServers),
+        )]);
+        buttons.push(vec![callback_button(
+            locales::get_text(lang.as_str(), "btn-menu-channels", None),
+            CallbackAction::Admin(AdminAction::ChannelServers),
+        )]);
+        buttons.push(vec![callback_button(
+            locales::get_text(lang.as_str(), "btn-menu-accounts", None),
+            CallbackAction::Admin(AdminAction::AccountServers),
+        )]);
+        buttons.push(vec![callback_button(
+            locales::get_text(lang.as_str(), "btn-menu-server", None),
+            CallbackAction::Admin(AdminAction::ServerInfoServers),
         )]);
         buttons.push(vec![callback_button(
             locales::get_text(lang.as_str(), "btn-menu-unban", None),
@@ -184,6 +369,73 @@ pub fn create_main_menu_keyboard(lang: LanguageCode, is_admin: bool) -> InlineKe
     InlineKeyboardMarkup::new(buttons)
 }
 
+pub fn create_queue_keyboard(
+    lang: LanguageCode,
+    server_id: ServerId,
+    snapshot: &QueueSnapshot,
+) -> InlineKeyboardMarkup {
+    let button = |key: &str, pos: Option<usize>, op: QueueOp| {
+        let args = pos.and_then(|pos| args!(pos = pos));
+        callback_button(
+            locales::get_text(lang.as_str(), key, args.as_ref()),
+            CallbackAction::Queue(QueueAction::Control { server_id, op }),
+        )
+    };
+
+    let mut buttons = snapshot
+        .pending
+        .iter()
+        .take(QUEUE_ITEMS_SHOWN)
+        .enumerate()
+        .map(|(idx, entry)| {
+            let stream_id = entry.stream_id;
+            vec![
+                button(
+                    "btn-queue-top",
+                    Some(idx + 1),
+                    QueueOp::MoveTop { stream_id },
+                ),
+                button(
+                    "btn-queue-remove",
+                    Some(idx + 1),
+                    QueueOp::Remove { stream_id },
+                ),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    if let Some(playing) = &snapshot.now_playing {
+        let toggle = if playing.paused {
+            button("btn-queue-resume", None, QueueOp::Resume)
+        } else {
+            button("btn-queue-pause", None, QueueOp::Pause)
+        };
+        buttons.push(vec![toggle, button("btn-queue-skip", None, QueueOp::Skip)]);
+    }
+    let shuffle_key = if snapshot.shuffle {
+        "btn-queue-shuffle-on"
+    } else {
+        "btn-queue-shuffle-off"
+    };
+    let repeat_key = match snapshot.repeat {
+        RepeatMode::Off => "btn-queue-repeat-off",
+        RepeatMode::All => "btn-queue-repeat-all",
+        RepeatMode::One => "btn-queue-repeat-one",
+    };
+    buttons.push(vec![
+        button(shuffle_key, None, QueueOp::ToggleShuffle),
+        button(repeat_key, None, QueueOp::CycleRepeat),
+    ]);
+    let mut last_row = Vec::new();
+    if !snapshot.pending.is_empty() {
+        last_row.push(button("btn-queue-clear", None, QueueOp::Clear));
+    }
+    last_row.push(button("btn-queue-refresh", None, QueueOp::Show));
+    buttons.push(last_row);
+
+    InlineKeyboardMarkup::new(buttons)
+}
+
 #[cfg(test)]
 #[path = "../../../tests/unit/tg_keyboards.rs"]
 mod tests;
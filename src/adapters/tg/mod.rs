@@ -1,6 +1,7 @@
 pub mod admin_logic;
 pub mod callback_handlers;
 pub mod callbacks;
+pub mod chat_bridge;
 pub mod commands;
 pub mod keyboards;
 pub mod settings_logic;
@@ -8,15 +9,13 @@ pub mod state;
 pub mod utils;
 
 use crate::adapters::tg::utils::notify_admin_error;
+use crate::adapters::tt::ServerShared;
 use crate::app::services::user_settings as user_settings_service;
 use crate::bootstrap::config::Config;
-use crate::core::types::{AdminErrorContext, LanguageCode, LiteUser, TtCommand};
+use crate::core::types::{AdminErrorContext, LanguageCode, TtCommand};
 use crate::infra::db::Database;
 use crate::infra::locales;
-use std::collections::HashMap;
 use std::sync::Arc;
-use std::sync::RwLock;
-use teamtalk::types::UserAccount;
 use teloxide::error_handlers::ErrorHandler;
 use teloxide::{
     prelude::*,
@@ -31,8 +30,7 @@ pub struct TgRunArgs {
     pub event_bot: Bot,
     pub message_bot: Option<Bot>,
     pub db: Database,
-    pub online_users: Arc<RwLock<HashMap<i32, LiteUser>>>,
-    pub user_accounts: Arc<RwLock<HashMap<String, UserAccount>>>,
+    pub servers: Vec<ServerShared>,
     pub tx_tt_cmd: Sender<TtCommand>,
     pub config: Arc<Config>,
     pub cancel_token: tokio_util::sync::CancellationToken,
@@ -43,20 +41,12 @@ pub async fn run_tg_bot(args: TgRunArgs) {
         event_bot,
         message_bot,
         db,
-        online_users,
-        user_accounts,
+        servers,
         tx_tt_cmd,
         config,
         cancel_token,
     } = args;
-    let state = build_state(
-        db.clone(),
-        online_users,
-        user_accounts,
-        tx_tt_cmd,
-        &config,
-        &cancel_token,
-    );
+    let state = build_state(db.clone(), servers, tx_tt_cmd, &config, &cancel_token);
 
     if let Err(e) = set_bot_commands(&event_bot, &db, &config).await {
         tracing::error!(error = %e, "Failed to set bot commands");
@@ -73,19 +63,22 @@ pub async fn run_tg_bot(args: TgRunArgs) {
 
 fn build_state(
     db: Database,
-    online_users: Arc<RwLock<HashMap<i32, LiteUser>>>,
-    user_accounts: Arc<RwLock<HashMap<String, UserAccount>>>,
+    servers: Vec<ServerShared>,
     tx_tt_cmd: Sender<TtCommand>,
     config: &Arc<Config>,
     cancel_token: &tokio_util::sync::CancellationToken,
 ) -> AppState {
     AppState {
         db,
-        online_users,
-        user_accounts,
+        servers,
         tx_tt: tx_tt_cmd,
         config: config.clone(),
         cancel_token: cancel_token.clone(),
+        pending_bans: Arc::default(),
+        pending_channel_edits: Arc::default(),
+        pending_account_inputs: Arc::default(),
+        account_filters: Arc::default(),
+        pending_server_edits: Arc::default(),
     }
 }
 
@@ -150,7 +143,19 @@ async fn run_event_bot(
                 .filter_command::<Command>()
                 .endpoint(commands::answer_command),
         )
+        .branch(
+            Update::filter_message()
+                .filter(|msg: Message, state: AppState| {
+                    state.config.chat_bridge_for_chat(msg.chat.id.0).is_some()
+                })
+                .endpoint(chat_bridge::answer_bridge_message),
+        )
         .branch(Update::filter_message().endpoint(commands::answer_message))
+        .branch(
+            Update::filter_channel_post()
+                .filter_command::<Command>()
+                .endpoint(commands::answer_command),
+        )
         .branch(Update::filter_callback_query().endpoint(callbacks::answer_callback));
     let mut dispatcher = Dispatcher::builder(event_bot.clone(), handler)
         .dependencies(dptree::deps![state])
@@ -230,6 +235,13 @@ async fn set_bot_commands(
                     teamtalk_username: None,
                     not_on_online_enabled: false,
                     not_on_online_confirmed: false,
+                    channel_notifications_enabled: false,
+                    nick_notifications_enabled: false,
+                    status_notifications_enabled: false,
+                    broadcast_notifications_enabled: true,
+                    file_notifications_enabled: true,
+                    health_notifications_enabled: false,
+                    delivery_mode: "instant".to_string(),
                 }
             });
 
@@ -265,6 +277,14 @@ fn get_user_commands(lang: LanguageCode) -> Vec<BotCommand> {
             "who",
             locales::get_text(lang.as_str(), "cmd-desc-who", None),
         ),
+        BotCommand::new(
+            "seen",
+            locales::get_text(lang.as_str(), "cmd-desc-seen", None),
+        ),
+        BotCommand::new(
+            "graph",
+            locales::get_text(lang.as_str(), "cmd-desc-graph", None),
+        ),
         BotCommand::new(
             "settings",
             locales::get_text(lang.as_str(), "cmd-desc-settings", None),
@@ -273,6 +293,10 @@ fn get_user_commands(lang: LanguageCode) -> Vec<BotCommand> {
             "unsub",
             locales::get_text(lang.as_str(), "cmd-desc-unsub", None),
         ),
+        BotCommand::new(
+            "register",
+            locales::get_text(lang.as_str(), "cmd-desc-register", None),
+        ),
         BotCommand::new(
             "help",
             locales::get_text(lang.as_str(), "cmd-desc-help", None),
@@ -291,10 +315,30 @@ fn get_admin_commands(lang: LanguageCode) -> Vec<BotCommand> {
             "ban",
             locales::get_text(lang.as_str(), "cmd-desc-ban", None),
         ),
+        BotCommand::new(
+            "move",
+            locales::get_text(lang.as_str(), "cmd-desc-move", None),
+        ),
         BotCommand::new(
             "unban",
             locales::get_text(lang.as_str(), "cmd-desc-unban", None),
         ),
+        BotCommand::new(
+            "serverbans",
+            locales::get_text(lang.as_str(), "cmd-desc-serverbans", None),
+        ),
+        BotCommand::new(
+            "channels",
+            locales::get_text(lang.as_str(), "cmd-desc-channels", None),
+        ),
+        BotCommand::new(
+            "accounts",
+            locales::get_text(lang.as_str(), "cmd-desc-accounts", None),
+        ),
+        BotCommand::new(
+            "server",
+            locales::get_text(lang.as_str(), "cmd-desc-server", None),
+        ),
         BotCommand::new(
             "subscribers",
             locales::get_text(lang.as_str(), "cmd-desc-subscribers", None),
@@ -311,6 +355,18 @@ fn get_admin_commands(lang: LanguageCode) -> Vec<BotCommand> {
             "message",
             locales::get_text(lang.as_str(), "cmd-desc-message", None),
         ),
+        BotCommand::new(
+            "queue",
+            locales::get_text(lang.as_str(), "cmd-desc-queue", None),
+        ),
+        BotCommand::new(
+            "play",
+            locales::get_text(lang.as_str(), "cmd-desc-play", None),
+        ),
+        BotCommand::new(
+            "playlist",
+            locales::get_text(lang.as_str(), "cmd-desc-playlist", None),
+        ),
     ]);
     cmds
 }
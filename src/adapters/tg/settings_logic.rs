@@ -3,23 +3,27 @@ use crate::adapters::tg::keyboards::{
 };
 use crate::app::services::user_settings as user_settings_service;
 use crate::args;
+use crate::bootstrap::config::Config;
 use crate::core::callbacks::{CallbackAction, MuteAction, SettingsAction};
-use crate::core::types::{LanguageCode, MuteListMode, NotificationSetting, TtUsername};
+use crate::core::types::{
+    DeliveryMode, EventOptIn, LanguageCode, MuteListMode, NotificationSetting, TtUsername,
+};
 use crate::infra::db::Database;
 use crate::infra::locales;
 use teamtalk::types::UserAccount;
 use teloxide::prelude::*;
 use teloxide::sugar::request::RequestReplyExt;
-use teloxide::types::{InlineKeyboardMarkup, ParseMode};
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup, ParseMode};
 
 pub async fn send_main_settings(
     bot: &Bot,
     chat_id: teloxide::types::ChatId,
     lang: LanguageCode,
+    show_servers: bool,
     reply_to: Option<teloxide::types::MessageId>,
 ) -> ResponseResult<()> {
     let text = locales::get_text(lang.as_str(), "settings-title", None);
-    let keyboard = main_settings_keyboard(lang);
+    let keyboard = main_settings_keyboard(lang, show_servers);
     let req = bot
         .send_message(chat_id, text)
         .reply_markup(keyboard)
@@ -36,9 +40,10 @@ pub async fn send_main_settings_edit(
     bot: &Bot,
     msg: &Message,
     lang: LanguageCode,
+    show_servers: bool,
 ) -> ResponseResult<()> {
     let text = locales::get_text(lang.as_str(), "settings-title", None);
-    let keyboard = main_settings_keyboard(lang);
+    let keyboard = main_settings_keyboard(lang, show_servers);
     bot.edit_message_text(msg.chat.id, msg.id, text)
         .reply_markup(keyboard)
         .parse_mode(ParseMode::Html)
@@ -46,8 +51,8 @@ pub async fn send_main_settings_edit(
     Ok(())
 }
 
-fn main_settings_keyboard(lang: LanguageCode) -> InlineKeyboardMarkup {
-    InlineKeyboardMarkup::new(vec![
+fn main_settings_keyboard(lang: LanguageCode, show_servers: bool) -> InlineKeyboardMarkup {
+    let mut buttons = vec![
         vec![callback_button(
             locales::get_text(lang.as_str(), "btn-lang", None),
             CallbackAction::Settings(SettingsAction::LangSelect),
@@ -60,7 +65,71 @@ fn main_settings_keyboard(lang: LanguageCode) -> InlineKeyboardMarkup {
             locales::get_text(lang.as_str(), "btn-notif-settings", None),
             CallbackAction::Settings(SettingsAction::NotifSelect),
         )],
-    ])
+    ];
+    if show_servers {
+        buttons.push(vec![callback_button(
+            locales::get_text(lang.as_str(), "btn-server-settings", None),
+            CallbackAction::Settings(SettingsAction::ServersSelect),
+        )]);
+    }
+    InlineKeyboardMarkup::new(buttons)
+}
+
+pub async fn send_server_settings(
+    bot: &Bot,
+    msg: &Message,
+    db: &Database,
+    config: &Config,
+    telegram_id: i64,
+    lang: LanguageCode,
+) -> ResponseResult<()> {
+    let ignored = match db.get_ignored_servers(telegram_id).await {
+        Ok(list) => list,
+        Err(e) => {
+            tracing::error!(telegram_id, error = %e, "Failed to load ignored servers");
+            bot.edit_message_text(
+                msg.chat.id,
+                msg.id,
+                locales::get_text(lang.as_str(), "cmd-error", None),
+            )
+            .await?;
+            return Ok(());
+        }
+    };
+
+    let icon_checked = locales::get_text(lang.as_str(), "icon-checked", None);
+    let icon_unchecked = locales::get_text(lang.as_str(), "icon-unchecked", None);
+
+    let mut buttons: Vec<Vec<InlineKeyboardButton>> = config
+        .server_ids()
+        .filter_map(|server_id| {
+            let server = config.server(server_id)?;
+            let icon = if ignored.contains(&server.key()) {
+                &icon_unchecked
+            } else {
+                &icon_checked
+            };
+            Some(vec![callback_button(
+                format!("{icon} {}", server.display_name()),
+                CallbackAction::Settings(SettingsAction::ServerToggle { server_id }),
+            )])
+        })
+        .collect();
+    buttons.push(vec![back_button(
+        lang,
+        "btn-back-settings",
+        CallbackAction::Settings(SettingsAction::Main),
+    )]);
+
+    bot.edit_message_text(
+        msg.chat.id,
+        msg.id,
+        locales::get_text(lang.as_str(), "server-settings-title", None),
+    )
+    .reply_markup(InlineKeyboardMarkup::new(buttons))
+    .parse_mode(ParseMode::Html)
+    .await?;
+    Ok(())
 }
 
 pub async fn send_sub_settings(
@@ -205,22 +274,68 @@ pub async fn send_notif_settings(
         "btn-noon",
         args!(status = status_text).as_ref(),
     );
-
-    let keyboard = InlineKeyboardMarkup::new(vec![
-        vec![callback_button(
-            noon_text,
-            CallbackAction::Settings(SettingsAction::NoonToggle),
-        )],
-        vec![callback_button(
-            locales::get_text(lang.as_str(), "btn-mute-manage", None),
-            CallbackAction::Settings(SettingsAction::MuteManage),
-        )],
-        vec![back_button(
-            lang,
-            "btn-back-settings",
-            CallbackAction::Settings(SettingsAction::Main),
-        )],
-    ]);
+    let opt_ins = [
+        (
+            EventOptIn::ChannelMoves,
+            "btn-channel-notif",
+            settings.channel_notifications_enabled,
+        ),
+        (
+            EventOptIn::NickChanges,
+            "btn-nick-notif",
+            settings.nick_notifications_enabled,
+        ),
+        (
+            EventOptIn::StatusChanges,
+            "btn-status-notif",
+            settings.status_notifications_enabled,
+        ),
+        (
+            EventOptIn::ServerBroadcasts,
+            "btn-broadcast-notif",
+            settings.broadcast_notifications_enabled,
+        ),
+        (
+            EventOptIn::FileUploads,
+            "btn-file-notif",
+            settings.file_notifications_enabled,
+        ),
+        (
+            EventOptIn::ServerHealth,
+            "btn-health-notif",
+            settings.health_notifications_enabled,
+        ),
+    ];
+
+    let mut rows = vec![vec![callback_button(
+        noon_text,
+        CallbackAction::Settings(SettingsAction::NoonToggle),
+    )]];
+    for (kind, key, enabled) in opt_ins {
+        let status = if enabled {
+            locales::get_text(lang.as_str(), "status-enabled", None)
+        } else {
+            locales::get_text(lang.as_str(), "status-disabled", None)
+        };
+        rows.push(vec![callback_button(
+            locales::get_text(lang.as_str(), key, args!(status = status).as_ref()),
+            CallbackAction::Settings(SettingsAction::EventOptInToggle { kind }),
+        )]);
+    }
+    rows.push(vec![callback_button(
+        locales::get_text(lang.as_str(), "btn-delivery-settings", None),
+        CallbackAction::Settings(SettingsAction::DeliverySelect),
+    )]);
+    rows.push(vec![callback_button(
+        locales::get_text(lang.as_str(), "btn-mute-manage", None),
+        CallbackAction::Settings(SettingsAction::MuteManage),
+    )]);
+    rows.push(vec![back_button(
+        lang,
+        "btn-back-settings",
+        CallbackAction::Settings(SettingsAction::Main),
+    )]);
+    let keyboard = InlineKeyboardMarkup::new(rows);
 
     bot.edit_message_text(
         msg.chat.id,
@@ -233,6 +348,89 @@ pub async fn send_notif_settings(
     Ok(())
 }
 
+pub async fn send_delivery_settings(
+    bot: &Bot,
+    msg: &Message,
+    db: &Database,
+    config: &Config,
+    telegram_id: i64,
+    lang: LanguageCode,
+) -> ResponseResult<()> {
+    let settings =
+        match user_settings_service::get_or_create(db, telegram_id, LanguageCode::En).await {
+            Ok(s) => s,
+            Err(e) => {
+                tracing::error!(
+                    telegram_id,
+                    error = %e,
+                    "Failed to get or create user"
+                );
+                bot.edit_message_text(
+                    msg.chat.id,
+                    msg.id,
+                    locales::get_text(lang.as_str(), "cmd-error", None),
+                )
+                .await?;
+                return Ok(());
+            }
+        };
+    let current = user_settings_service::parse_delivery_mode(&settings.delivery_mode);
+    let check_icon = locales::get_text(lang.as_str(), "icon-check-simple", None);
+    let minutes = config.operational_parameters.digest_batch;
+
+    let mut rows: Vec<Vec<InlineKeyboardButton>> = [
+        DeliveryMode::Instant,
+        DeliveryMode::Batched,
+        DeliveryMode::Hourly,
+    ]
+    .into_iter()
+    .map(|mode| {
+        let marker = if mode == current {
+            check_icon.clone()
+        } else {
+            String::new()
+        };
+        vec![callback_button(
+            delivery_mode_label(lang, mode, &marker, minutes),
+            CallbackAction::Settings(SettingsAction::DeliverySet { mode }),
+        )]
+    })
+    .collect();
+    rows.push(vec![back_button(
+        lang,
+        "btn-back-notif",
+        CallbackAction::Settings(SettingsAction::NotifSelect),
+    )]);
+
+    bot.edit_message_text(
+        msg.chat.id,
+        msg.id,
+        locales::get_text(lang.as_str(), "delivery-settings-title", None),
+    )
+    .reply_markup(InlineKeyboardMarkup::new(rows))
+    .parse_mode(ParseMode::Html)
+    .await?;
+    Ok(())
+}
+
+pub fn delivery_mode_label(
+    lang: LanguageCode,
+    mode: DeliveryMode,
+    marker: &str,
+    minutes: u64,
+) -> String {
+    let key = match mode {
+        DeliveryMode::Instant => "btn-delivery-instant",
+        DeliveryMode::Batched => "btn-delivery-batched",
+        DeliveryMode::Hourly => "btn-delivery-hourly",
+    };
+    locales::get_text(
+        lang.as_str(),
+        key,
+        args!(marker = marker, minutes = minutes).as_ref(),
+    )
+}
+
 pub async fn send_mute_menu(
     bot: &Bot,
     msg: &Message,
@@ -351,7 +549,7 @@ pub struct RenderMuteListArgs<'a> {
     pub accounts: &'a [UserAccount],
     pub page: usize,
     pub title_key: &'a str,
-    pub guest_username: Option<&'a str>,
+    pub guest_usernames: &'a [String],
     pub mode: MuteListMode,
 }
 
@@ -362,7 +560,7 @@ pub struct RenderMuteListStringsArgs<'a> {
     pub items: &'a [String],
     pub page: usize,
     pub title_key: &'a str,
-    pub guest_username: Option<&'a str>,
+    pub guest_usernames: &'a [String],
     pub mode: MuteListMode,
 }
 
@@ -399,7 +597,7 @@ pub async fn render_mute_list(args: RenderMuteListArgs<'_>) -> ResponseResult<()
                 (MuteListMode::Whitelist, false) => "item-status-whitelist-out",
             };
 
-            let display_name = if Some(acc.username.as_str()) == args.guest_username {
+            let display_name = if args.guest_usernames.contains(&acc.username) {
                 locales::get_text(args.lang.as_str(), "display-guest-account", None)
             } else {
                 acc.username.clone()
@@ -460,7 +658,7 @@ pub async fn render_mute_list_strings(args: RenderMuteListStringsArgs<'_>) -> Re
         &sorted_items,
         args.page,
         |username| {
-            let display_name = if Some(username.as_str()) == args.guest_username {
+            let display_name = if args.guest_usernames.contains(username) {
                 locales::get_text(args.lang.as_str(), "display-guest-account", None)
             } else {
                 username.clone()
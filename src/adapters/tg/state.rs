@@ -1,18 +1,57 @@
+use crate::adapters::tt::ServerShared;
 use crate::bootstrap::config::Config;
-use crate::core::types::{LiteUser, TtCommand};
+use crate::core::types::{AccountField, BanTerm, ChannelField, ServerField, ServerId, TtCommand};
 use crate::infra::db::Database;
 use std::collections::HashMap;
-use std::sync::Arc;
-use std::sync::RwLock;
-use teamtalk::types::UserAccount;
+use std::sync::{Arc, RwLock};
 use tokio::sync::mpsc::Sender;
 
 #[derive(Clone)]
 pub struct AppState {
     pub db: Database,
-    pub online_users: Arc<RwLock<HashMap<i32, LiteUser>>>,
-    pub user_accounts: Arc<RwLock<HashMap<String, UserAccount>>>,
+    pub servers: Vec<ServerShared>,
     pub tx_tt: Sender<TtCommand>,
     pub config: Arc<Config>,
     pub cancel_token: tokio_util::sync::CancellationToken,
+    pub pending_bans: Arc<RwLock<HashMap<(i64, i32), PendingBan>>>,
+    pub pending_channel_edits: Arc<RwLock<HashMap<(i64, i32), PendingChannelEdit>>>,
+    pub pending_account_inputs: Arc<RwLock<HashMap<(i64, i32), PendingAccountInput>>>,
+    pub account_filters: Arc<RwLock<HashMap<(i64, i32), String>>>,
+    pub pending_server_edits: Arc<RwLock<HashMap<(i64, i32), PendingServerEdit>>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PendingBan {
+    pub server_id: ServerId,
+    pub user_id: i32,
+    pub term: BanTerm,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PendingChannelEdit {
+    pub server_id: ServerId,
+    pub channel_id: i32,
+    pub field: ChannelField,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PendingServerEdit {
+    pub server_id: ServerId,
+    pub field: ServerField,
+}
+
+#[derive(Debug, Clone)]
+pub struct PendingAccountInput {
+    pub server_id: ServerId,
+    pub input: AccountInput,
+}
+
+#[derive(Debug, Clone)]
+pub enum AccountInput {
+    Search,
+    Create,
+    Field {
+        username: String,
+        field: AccountField,
+    },
 }
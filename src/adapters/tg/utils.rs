@@ -1,12 +1,43 @@
 use crate::app::services::subscription as subscriptions_service;
 use crate::bootstrap::config::Config;
-use crate::core::types::{AdminErrorContext, LanguageCode};
+use crate::core::types::{AdminErrorContext, LanguageCode, ServerId};
 use crate::infra::db::Database;
 use crate::infra::locales;
 use teloxide::prelude::*;
 use teloxide::sugar::request::RequestReplyExt;
 use teloxide::types::ParseMode;
 
+pub fn server_names(config: &Config) -> Vec<(ServerId, String)> {
+    config
+        .server_ids()
+        .map(|id| (id, config.server_display_name(id).to_string()))
+        .collect()
+}
+
+pub async fn is_chat_admin(
+    bot: &Bot,
+    chat: &teloxide::types::Chat,
+    user: Option<&teloxide::types::User>,
+) -> bool {
+    if chat.is_private() {
+        return true;
+    }
+    let Some(user) = user else {
+        return true;
+    };
+    match bot.get_chat_member(chat.id, user.id).await {
+        Ok(member) => member.is_privileged(),
+        Err(e) => {
+            tracing::warn!(
+                chat_id = chat.id.0,
+                error = %e,
+                "Failed to check chat administrator status"
+            );
+            false
+        }
+    }
+}
+
 pub async fn ensure_subscribed(
     bot: &Bot,
     msg: &Message,
@@ -0,0 +1,286 @@
+#![allow(clippy::pedantic, clippy::nursery)]
+
+use crate::adapters::tt::confirmations::{self, Confirmation};
+use crate::adapters::tt::{WorkerContext, send_to_bridge};
+use crate::core::types::{
+    AccountChangeError, AccountEdit, AccountOp, AccountRegistration, AccountType, BridgeEvent,
+    LanguageCode,
+};
+use teamtalk::Client;
+use teamtalk::client::ffi;
+use teamtalk::types::UserAccount;
+
+pub struct PendingAccountOp {
+    pub chat_id: i64,
+    pub lang: LanguageCode,
+    pub op: AccountOp,
+    pub registration: Option<AccountRegistration>,
+}
+
+pub fn is_admin_account(account: &UserAccount) -> bool {
+    account.user_type & ffi::UserType::USERTYPE_ADMIN as u32 != 0
+}
+
+fn user_type_bits(account_type: AccountType) -> u32 {
+    match account_type {
+        AccountType::Default => ffi::UserType::USERTYPE_DEFAULT as u32,
+        AccountType::Admin => ffi::UserType::USERTYPE_ADMIN as u32,
+    }
+}
+
+pub(super) fn edit_account(
+    client: &Client,
+    ctx: &WorkerContext,
+    username: String,
+    edit: AccountEdit,
+    chat_id: i64,
+    lang: LanguageCode,
+) {
+    let cached = ctx
+        .user_accounts
+        .read()
+        .ok()
+        .and_then(|accounts| accounts.get(&username).cloned());
+    let op = match &edit {
+        AccountEdit::Create { .. } => AccountOp::Create,
+        AccountEdit::Password(_) => AccountOp::Password,
+        AccountEdit::Note(_) => AccountOp::Note,
+        AccountEdit::Type(_) => AccountOp::Type,
+    };
+    let account = match (edit, cached) {
+        (AccountEdit::Create { .. }, Some(_)) => {
+            fail_change(
+                ctx,
+                chat_id,
+                lang,
+                op,
+                username,
+                AccountChangeError::AlreadyExists,
+            );
+            return;
+        }
+        (AccountEdit::Create { password, note }, None) => UserAccount {
+            username: username.clone(),
+            password,
+            note,
+            user_type: user_type_bits(AccountType::Default),
+            ..UserAccount::default()
+        },
+        (_, None) => {
+            fail_change(
+                ctx,
+                chat_id,
+                lang,
+                op,
+                username,
+                AccountChangeError::NotFound,
+            );
+            return;
+        }
+        (AccountEdit::Password(password), Some(account)) => UserAccount {
+            password,
+            ..account
+        },
+        (AccountEdit::Note(note), Some(account)) => UserAccount { note, ..account },
+        (AccountEdit::Type(account_type), Some(account)) => UserAccount {
+            user_type: user_type_bits(account_type),
+            ..account
+        },
+    };
+    let pending = PendingAccountOp {
+        chat_id,
+        lang,
+        op,
+        registration: None,
+    };
+    send_account(client, ctx, &account, pending);
+}
+
+pub(super) fn register_account(
+    client: &Client,
+    ctx: &WorkerContext,
+    registration: AccountRegistration,
+    chat_id: i64,
+    lang: LanguageCode,
+) {
+    let op = AccountOp::Create;
+    let exists = ctx
+        .user_accounts
+        .read()
+        .is_ok_and(|accounts| accounts.contains_key(&registration.username));
+    if exists {
+        release_registration(ctx, registration.request_id);
+        fail_change(
+            ctx,
+            chat_id,
+            lang,
+            op,
+            registration.username,
+            AccountChangeError::AlreadyExists,
+        );
+        return;
+    }
+    let account = UserAccount {
+        username: registration.username.clone(),
+        password: registration.password.clone(),
+        note: registration.note.clone(),
+        user_type: user_type_bits(AccountType::Default),
+        ..UserAccount::default()
+    };
+    let pending = PendingAccountOp {
+        chat_id,
+        lang,
+        op,
+        registration: Some(registration),
+    };
+    send_account(client, ctx, &account, pending);
+}
+
+fn send_account(
+    client: &Client,
+    ctx: &WorkerContext,
+    account: &UserAccount,
+    pending: PendingAccountOp,
+) {
+    let username = account.username.clone();
+    let cmd_id = client.new_user_account(account);
+    if cmd_id <= 0 {
+        tracing::error!(
+            component = "tt_worker",
+            username = %username,
+            op = ?pending.op,
+            "Failed to send account change"
+        );
+        fail_pending(ctx, username, pending, AccountChangeError::Failed);
+        return;
+    }
+    await_confirmation(ctx, cmd_id, username, pending);
+}
+
+pub(super) fn delete_account(
+    client: &Client,
+    ctx: &WorkerContext,
+    username: String,
+    chat_id: i64,
+    lang: LanguageCode,
+) {
+    let op = AccountOp::Delete;
+    let exists = ctx
+        .user_accounts
+        .read()
+        .is_ok_and(|accounts| accounts.contains_key(&username));
+    if !exists {
+        fail_change(
+            ctx,
+            chat_id,
+            lang,
+            op,
+            username,
+            AccountChangeError::NotFound,
+        );
+        return;
+    }
+    let cmd_id = client.delete_user_account(&username);
+    if cmd_id <= 0 {
+        tracing::error!(
+            component = "tt_worker",
+            username = %username,
+            "Failed to send account removal"
+        );
+        fail_change(ctx, chat_id, lang, op, username, AccountChangeError::Failed);
+        return;
+    }
+    let pending = PendingAccountOp {
+        chat_id,
+        lang,
+        op,
+        registration: None,
+    };
+    await_confirmation(ctx, cmd_id, username, pending);
+}
+
+fn await_confirmation(
+    ctx: &WorkerContext,
+    cmd_id: i32,
+    username: String,
+    pending: PendingAccountOp,
+) {
+    tracing::info!(
+        component = "tt_worker",
+        username = %username,
+        op = ?pending.op,
+        "Account change sent"
+    );
+    confirmations::track(ctx, cmd_id, Confirmation::Account { username, pending });
+}
+
+pub(super) fn confirm_change(ctx: &WorkerContext, username: String, pending: PendingAccountOp) {
+    let PendingAccountOp {
+        chat_id,
+        lang,
+        op,
+        registration,
+    } = pending;
+    let event = match registration {
+        Some(registration) => BridgeEvent::AccountRegistered {
+            server_id: ctx.server_id,
+            chat_id,
+            lang,
+            registration,
+        },
+        None => BridgeEvent::AccountChanged {
+            chat_id,
+            lang,
+            op,
+            username,
+        },
+    };
+    send_to_bridge(ctx, event);
+}
+
+pub(super) fn fail_pending(
+    ctx: &WorkerContext,
+    username: String,
+    pending: PendingAccountOp,
+    error: AccountChangeError,
+) {
+    let PendingAccountOp {
+        chat_id,
+        lang,
+        op,
+        registration,
+    } = pending;
+    if let Some(registration) = registration {
+        release_registration(ctx, registration.request_id);
+    }
+    fail_change(ctx, chat_id, lang, op, username, error);
+}
+
+fn release_registration(ctx: &WorkerContext, request_id: i64) {
+    let db = ctx.db.clone();
+    tokio::task::spawn_local(async move {
+        if let Err(e) = db.release_registration_request(request_id).await {
+            tracing::error!(request_id, error = %e, "Failed to release registration request");
+        }
+    });
+}
+
+pub(super) fn fail_change(
+    ctx: &WorkerContext,
+    chat_id: i64,
+    lang: LanguageCode,
+    op: AccountOp,
+    username: String,
+    error: AccountChangeError,
+) {
+    send_to_bridge(
+        ctx,
+        BridgeEvent::AccountChangeFailed {
+            chat_id,
+            lang,
+            op,
+            username,
+            error,
+        },
+    );
+}
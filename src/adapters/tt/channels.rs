@@ -0,0 +1,268 @@
+#![allow(clippy::pedantic, clippy::nursery)]
+
+use crate::adapters::tt::confirmations::{self, Confirmation};
+use crate::adapters::tt::{WorkerContext, resolve_channel_path, send_to_bridge};
+use crate::app::services::channels as channels_service;
+use crate::core::types::{
+    BridgeEvent, ChannelChangeError, ChannelEdit, ChannelOp, LanguageCode, LiteChannel,
+    MoveUserError, NewChannel,
+};
+use teamtalk::Client;
+use teamtalk::types::{Channel, ChannelId, UserId};
+
+pub(super) fn refresh_channels(client: &Client, ctx: &WorkerContext) {
+    let mut channels: Vec<LiteChannel> = client
+        .get_server_channels()
+        .into_iter()
+        .map(|channel| LiteChannel {
+            id: channel.id.0,
+            parent_id: channel.parent_id.0,
+            path: resolve_channel_path(client, channel.id),
+            topic: channel.topic,
+            password_protected: !channel.password.is_empty(),
+            max_users: channel.max_users,
+        })
+        .collect();
+    channels.sort_by_key(|c| c.path.to_lowercase());
+    if let Ok(mut cache) = ctx.channels.write() {
+        *cache = channels;
+    }
+}
+
+pub(super) fn move_user(
+    client: &Client,
+    ctx: &WorkerContext,
+    user_id: i32,
+    channel_id: i32,
+    chat_id: i64,
+    lang: LanguageCode,
+) {
+    let nickname = ctx
+        .online_users
+        .read()
+        .ok()
+        .and_then(|users| users.get(&user_id).map(|u| u.nickname.clone()));
+    let Some(nickname) = nickname else {
+        fail_move(
+            ctx,
+            chat_id,
+            lang,
+            String::new(),
+            MoveUserError::UserOffline,
+        );
+        return;
+    };
+    if client.get_channel(ChannelId(channel_id)).is_none() {
+        fail_move(ctx, chat_id, lang, nickname, MoveUserError::ChannelNotFound);
+        return;
+    }
+    let channel = resolve_channel_path(client, ChannelId(channel_id));
+    if client.move_user(UserId(user_id), ChannelId(channel_id)) <= 0 {
+        tracing::error!(
+            component = "tt_worker",
+            user_id,
+            channel = %channel,
+            "Failed to move user"
+        );
+        fail_move(ctx, chat_id, lang, nickname, MoveUserError::Failed);
+        return;
+    }
+    tracing::info!(
+        component = "tt_worker",
+        user_id,
+        nickname = %nickname,
+        channel = %channel,
+        "Moved user"
+    );
+    send_to_bridge(
+        ctx,
+        BridgeEvent::UserMoved {
+            chat_id,
+            lang,
+            nickname,
+            channel,
+        },
+    );
+}
+
+fn fail_move(
+    ctx: &WorkerContext,
+    chat_id: i64,
+    lang: LanguageCode,
+    nickname: String,
+    error: MoveUserError,
+) {
+    send_to_bridge(
+        ctx,
+        BridgeEvent::UserMoveFailed {
+            chat_id,
+            lang,
+            nickname,
+            error,
+        },
+    );
+}
+
+pub(super) fn create_channel(
+    client: &Client,
+    ctx: &WorkerContext,
+    parent_id: i32,
+    new_channel: NewChannel,
+    chat_id: i64,
+    lang: LanguageCode,
+) {
+    let Some(parent) = client.get_channel(ChannelId(parent_id)) else {
+        let path = new_channel.name;
+        fail_change(
+            ctx,
+            chat_id,
+            lang,
+            ChannelOp::Create,
+            path,
+            ChannelChangeError::NotFound,
+        );
+        return;
+    };
+    let parent_path = resolve_channel_path(client, ChannelId(parent_id));
+    let path = format!("{}/{}", parent_path.trim_end_matches('/'), new_channel.name);
+    let channel = Channel {
+        parent_id: ChannelId(parent_id),
+        name: new_channel.name,
+        password: new_channel.password,
+        topic: new_channel.topic,
+        max_users: new_channel.max_users.unwrap_or(parent.max_users),
+        ..Channel::default()
+    };
+    let cmd_id = client.make_channel(&channel);
+    send_change(ctx, cmd_id, chat_id, lang, ChannelOp::Create, path);
+}
+
+pub(super) fn edit_channel(
+    client: &Client,
+    ctx: &WorkerContext,
+    channel_id: i32,
+    edit: ChannelEdit,
+    chat_id: i64,
+    lang: LanguageCode,
+) {
+    let op = match edit {
+        ChannelEdit::Topic(_) => ChannelOp::EditTopic,
+        ChannelEdit::Password(_) => ChannelOp::EditPassword,
+    };
+    let path = resolve_channel_path(client, ChannelId(channel_id));
+    let Some(mut channel) = client.get_channel(ChannelId(channel_id)) else {
+        fail_change(ctx, chat_id, lang, op, path, ChannelChangeError::NotFound);
+        return;
+    };
+    match edit {
+        ChannelEdit::Topic(topic) => channel.topic = topic,
+        ChannelEdit::Password(password) => channel.password = password,
+    }
+    let cmd_id = client.update_channel(&channel);
+    send_change(ctx, cmd_id, chat_id, lang, op, path);
+}
+
+pub(super) fn delete_channel(
+    client: &Client,
+    ctx: &WorkerContext,
+    channel_id: i32,
+    chat_id: i64,
+    lang: LanguageCode,
+) {
+    let op = ChannelOp::Delete;
+    let path = resolve_channel_path(client, ChannelId(channel_id));
+    let Some(channel) = client.get_channel(ChannelId(channel_id)) else {
+        fail_change(ctx, chat_id, lang, op, path, ChannelChangeError::NotFound);
+        return;
+    };
+    if channel.parent_id.0 == 0 || channel_occupants(ctx, channel_id) > 0 {
+        fail_change(ctx, chat_id, lang, op, path, ChannelChangeError::NotEmpty);
+        return;
+    }
+    let cmd_id = client.remove_channel(ChannelId(channel_id));
+    send_change(ctx, cmd_id, chat_id, lang, op, path);
+}
+
+fn channel_occupants(ctx: &WorkerContext, channel_id: i32) -> usize {
+    let channels = ctx.channels.read().map(|c| c.clone()).unwrap_or_default();
+    let user_channels = ctx
+        .online_users
+        .read()
+        .map(|users| users.values().map(|u| u.channel_id).collect::<Vec<_>>())
+        .unwrap_or_default();
+    channels_service::occupants(channel_id, &channels, user_channels)
+}
+
+fn send_change(
+    ctx: &WorkerContext,
+    cmd_id: i32,
+    chat_id: i64,
+    lang: LanguageCode,
+    op: ChannelOp,
+    path: String,
+) {
+    if cmd_id <= 0 {
+        tracing::error!(
+            component = "tt_worker",
+            op = ?op,
+            channel = %path,
+            "Failed to send channel change"
+        );
+        fail_change(ctx, chat_id, lang, op, path, ChannelChangeError::Failed);
+        return;
+    }
+    tracing::info!(
+        component = "tt_worker",
+        op = ?op,
+        channel = %path,
+        "Channel change sent"
+    );
+    confirmations::track(
+        ctx,
+        cmd_id,
+        Confirmation::Channel {
+            chat_id,
+            lang,
+            op,
+            path,
+        },
+    );
+}
+
+pub(super) fn confirm_change(
+    ctx: &WorkerContext,
+    chat_id: i64,
+    lang: LanguageCode,
+    op: ChannelOp,
+    path: String,
+) {
+    send_to_bridge(
+        ctx,
+        BridgeEvent::ChannelChanged {
+            chat_id,
+            lang,
+            op,
+            path,
+        },
+    );
+}
+
+pub(super) fn fail_change(
+    ctx: &WorkerContext,
+    chat_id: i64,
+    lang: LanguageCode,
+    op: ChannelOp,
+    path: String,
+    error: ChannelChangeError,
+) {
+    send_to_bridge(
+        ctx,
+        BridgeEvent::ChannelChangeFailed {
+            chat_id,
+            lang,
+            op,
+            path,
+            error,
+        },
+    );
+}
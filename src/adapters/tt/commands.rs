@@ -1,14 +1,92 @@
 #![allow(clippy::pedantic, clippy::nursery)]
 
-use crate::adapters::tt::{WorkerContext, resolve_channel_name, resolve_server_name};
+use crate::adapters::tt::{
+    WorkerContext, resolve_channel_name, resolve_channel_path, resolve_server_name, send_to_bridge,
+};
+use crate::app::services::music as music_service;
+use crate::app::services::seen as seen_service;
 use crate::args;
-use crate::core::types::{DeeplinkAction, LanguageCode, TtCommand};
+use crate::core::types::{BridgeEvent, DeeplinkAction, LanguageCode, TtCommand};
+use crate::infra::db::Database;
 use crate::infra::locales;
 use teamtalk::Client;
 use teamtalk::types::TextMessage;
 use tokio::task::spawn_local;
 use uuid::Uuid;
 
+pub(super) fn forward_server_broadcast(
+    client: &Client,
+    ctx: &WorkerContext,
+    nickname: String,
+    tt_username: String,
+    text: &str,
+) {
+    if text.is_empty() {
+        return;
+    }
+    let real_name = client.get_server_properties().map(|p| p.name);
+    let event = BridgeEvent::ServerBroadcast {
+        server_id: ctx.server_id,
+        server_name: resolve_server_name(ctx.tt_config(), real_name.as_deref()),
+        nickname,
+        tt_username,
+        text: text.to_string(),
+    };
+    send_to_bridge(ctx, event);
+}
+
+async fn is_bot_admin(db: &Database, admin_username: Option<&String>, username: &str) -> bool {
+    if username.is_empty() {
+        return false;
+    }
+    if admin_username.is_some_and(|u| u == username) {
+        return true;
+    }
+    match db.get_telegram_id_by_tt_user(username).await {
+        Some(tg_id) => db
+            .get_all_admins()
+            .await
+            .map(|admins| admins.contains(&tg_id))
+            .unwrap_or(false),
+        None => false,
+    }
+}
+
+fn mirror_to_chat(client: &Client, ctx: &WorkerContext, msg: &TextMessage, content: &str) {
+    if content.is_empty() {
+        return;
+    }
+    let channel_path = resolve_channel_path(client, msg.channel_id);
+    let Some(bridge) = ctx
+        .config
+        .chat_bridge_for_channel(ctx.server_id, &channel_path)
+    else {
+        return;
+    };
+    if let Ok(mut channels) = ctx.bridge_channels.write() {
+        channels.insert(bridge.channel.clone(), msg.channel_id.0);
+    }
+    let nickname = ctx
+        .online_users
+        .read()
+        .ok()
+        .and_then(|users| users.get(&msg.from_id.0).map(|u| u.nickname.clone()))
+        .unwrap_or_else(|| "Unknown".to_string());
+    let event = BridgeEvent::ChatMirror {
+        chat_id: bridge.chat_id,
+        nickname,
+        text: content.to_string(),
+    };
+    let tx_bridge = ctx.tx_bridge.clone();
+    let tt_msg_sem = ctx.tt_msg_sem.clone();
+    spawn_local(async move {
+        let _permit = tt_msg_sem.acquire_owned().await;
+        if let Err(e) = tx_bridge.send(event).await {
+            tracing::error!(error = %e, "Failed to send chat mirror event");
+        }
+    });
+}
+
 pub(super) fn handle_text_message(client: &Client, ctx: &WorkerContext, msg: TextMessage) {
     if msg.from_id == client.my_id() {
         return;
@@ -22,7 +100,8 @@ pub(super) fn handle_text_message(client: &Client, ctx: &WorkerContext, msg: Tex
 
     let default_lang = ctx.config.general.default_lang;
     let admin_username = ctx.config.general.admin_username.clone();
-    let tt_config = ctx.config.teamtalk.clone();
+    let tt_config = ctx.tt_config().clone();
+    let server_id = ctx.server_id;
     let deeplink_ttl = ctx.config.operational_parameters.deeplink_ttl;
 
     let bot_username = ctx.bot_username.clone();
@@ -32,6 +111,21 @@ pub(super) fn handle_text_message(client: &Client, ctx: &WorkerContext, msg: Tex
     let tt_tg_cache = ctx.tt_tg_cache.clone();
     let tt_cache_stats = ctx.tt_cache_stats.clone();
 
+    if msg.msg_type == teamtalk::client::ffi::TextMsgType::MSGTYPE_BROADCAST {
+        let (nickname, username) = ctx
+            .online_users
+            .read()
+            .ok()
+            .and_then(|users| {
+                users
+                    .get(&msg.from_id.0)
+                    .map(|u| (u.nickname.clone(), u.username.clone()))
+            })
+            .unwrap_or_else(|| ("Unknown".to_string(), String::new()));
+        forward_server_broadcast(client, ctx, nickname, username, msg.text.trim());
+        return;
+    }
+
     if msg.msg_type == teamtalk::client::ffi::TextMsgType::MSGTYPE_CHANNEL {
         let content = msg.text.trim();
         let cmd = content
@@ -39,6 +133,10 @@ pub(super) fn handle_text_message(client: &Client, ctx: &WorkerContext, msg: Tex
             .next()
             .unwrap_or("")
             .to_lowercase();
+        if !cmd.starts_with('/') {
+            mirror_to_chat(client, ctx, &msg, content);
+            return;
+        }
         if cmd == "/skip" {
             let from_uid = msg.from_id.0;
             let channel_id = msg.channel_id.0;
@@ -123,7 +221,7 @@ pub(super) fn handle_text_message(client: &Client, ctx: &WorkerContext, msg: Tex
                     false
                 };
                 let text_key = if is_admin {
-                    if let Err(e) = tx_tt_cmd.send(TtCommand::SkipStream).await {
+                    if let Err(e) = tx_tt_cmd.send(TtCommand::SkipStream { server_id }).await {
                         tracing::error!(
                             tt_username = %username,
                             error = %e,
@@ -138,7 +236,11 @@ pub(super) fn handle_text_message(client: &Client, ctx: &WorkerContext, msg: Tex
                 };
                 let text = locales::get_text(reply_lang.as_str(), text_key, None);
                 if let Err(e) = tx_tt_cmd
-                    .send(TtCommand::SendToChannel { channel_id, text })
+                    .send(TtCommand::SendToChannel {
+                        server_id,
+                        channel_id,
+                        text,
+                    })
                     .await
                 {
                     tracing::error!(
@@ -164,6 +266,7 @@ pub(super) fn handle_text_message(client: &Client, ctx: &WorkerContext, msg: Tex
                 let _permit = tt_msg_sem.acquire_owned().await;
                 if let Err(e) = tx_bridge
                     .send(crate::core::types::BridgeEvent::ToAdminChannel {
+                        server_id,
                         channel_id,
                         channel_name,
                         server_name,
@@ -180,6 +283,8 @@ pub(super) fn handle_text_message(client: &Client, ctx: &WorkerContext, msg: Tex
 
     let tt_lang_cache = tt_lang_cache.clone();
     let tt_cache_stats = tt_cache_stats.clone();
+    let config = ctx.config.clone();
+    let current_server_name = resolve_server_name(&tt_config, real_name_from_client.as_deref());
     spawn_local(async move {
         if msg.msg_type == teamtalk::client::ffi::TextMsgType::MSGTYPE_USER {
             let content = msg.text.trim();
@@ -234,7 +339,7 @@ pub(super) fn handle_text_message(client: &Client, ctx: &WorkerContext, msg: Tex
             let cmd = parts[0].to_lowercase();
             let needs_heavy = matches!(
                 cmd.as_str(),
-                "/sub" | "/unsub" | "/skip" | "/help" | "/start"
+                "/sub" | "/unsub" | "/skip" | "/help" | "/start" | "/seen" | "/play" | "/playlist"
             );
             let _permit = if needs_heavy {
                 Some(tt_msg_sem.acquire_owned().await)
@@ -245,6 +350,7 @@ pub(super) fn handle_text_message(client: &Client, ctx: &WorkerContext, msg: Tex
             let send_reply = |text: String| async {
                 if let Err(e) = tx_tt_cmd
                     .send(TtCommand::ReplyToUser {
+                        server_id,
                         user_id: from_uid,
                         text,
                     })
@@ -293,11 +399,19 @@ pub(super) fn handle_text_message(client: &Client, ctx: &WorkerContext, msg: Tex
                     match res {
                         Ok(_) => {
                             let link = format!("https://t.me/{}?start={}", bot_user, token);
-                            let text = locales::get_text(
+                            let group_link =
+                                format!("https://t.me/{}?startgroup={}", bot_user, token);
+                            let mut text = locales::get_text(
                                 reply_lang.as_str(),
                                 "tt-sub-link",
                                 args!(link = link).as_ref(),
                             );
+                            text.push('\n');
+                            text.push_str(&locales::get_text(
+                                reply_lang.as_str(),
+                                "tt-sub-group-link",
+                                args!(link = group_link, token = token.clone()).as_ref(),
+                            ));
                             send_reply(text).await;
                         }
                         Err(_) => {
@@ -368,29 +482,51 @@ pub(super) fn handle_text_message(client: &Client, ctx: &WorkerContext, msg: Tex
                     help_msg.push_str(&cmds);
                 }
                 send_reply(help_msg).await;
-            } else if cmd == "/skip" {
-                let is_admin = if username.is_empty() {
-                    false
-                } else if admin_username
-                    .as_ref()
-                    .map(|u| u == &username)
-                    .unwrap_or(false)
-                {
-                    true
-                } else if let Some(tg_id) = db.get_telegram_id_by_tt_user(&username).await {
-                    db.get_all_admins()
-                        .await
-                        .map(|admins| admins.contains(&tg_id))
-                        .unwrap_or(false)
+            } else if cmd == "/seen" {
+                let Some(target) = parts.get(1).copied() else {
+                    let text = locales::get_text(reply_lang.as_str(), "seen-usage", None);
+                    send_reply(text).await;
+                    return;
+                };
+                let online = online_users.read().ok().and_then(|users| {
+                    users.values().find(|u| u.username == target).map(|u| {
+                        seen_service::OnlineSighting {
+                            nickname: u.nickname.clone(),
+                            channel: u.channel_name.clone(),
+                            server: current_server_name.clone(),
+                        }
+                    })
+                });
+                let last = if online.is_some() {
+                    None
                 } else {
-                    false
+                    match seen_service::last_session(&db, target).await {
+                        Ok(last) => last,
+                        Err(e) => {
+                            tracing::error!(tt_username = %target, error = %e, "Failed to load last session");
+                            let text =
+                                locales::get_text(reply_lang.as_str(), "tt-error-generic", None);
+                            send_reply(text).await;
+                            return;
+                        }
+                    }
                 };
-                if !is_admin {
+                let text = seen_service::render_seen(
+                    reply_lang,
+                    &config,
+                    target,
+                    online,
+                    last.as_ref(),
+                    chrono::Utc::now().naive_utc(),
+                );
+                send_reply(text).await;
+            } else if cmd == "/skip" {
+                if !is_bot_admin(&db, admin_username.as_ref(), &username).await {
                     let text = locales::get_text(reply_lang.as_str(), "cmd-unauth", None);
                     send_reply(text).await;
                     return;
                 }
-                if let Err(e) = tx_tt_cmd.send(TtCommand::SkipStream).await {
+                if let Err(e) = tx_tt_cmd.send(TtCommand::SkipStream { server_id }).await {
                     tracing::error!(
                         tt_username = %username,
                         error = %e,
@@ -402,6 +538,43 @@ pub(super) fn handle_text_message(client: &Client, ctx: &WorkerContext, msg: Tex
                 }
                 let text = locales::get_text(reply_lang.as_str(), "tt-skip-sent", None);
                 send_reply(text).await;
+            } else if cmd == "/play" || cmd == "/playlist" {
+                if !is_bot_admin(&db, admin_username.as_ref(), &username).await {
+                    let text = locales::get_text(reply_lang.as_str(), "cmd-unauth", None);
+                    send_reply(text).await;
+                    return;
+                }
+                let args = content
+                    .split_once(char::is_whitespace)
+                    .map_or("", |(_, rest)| rest.trim());
+                let library = config.music.library_dir();
+                let reply = if cmd == "/play" {
+                    music_service::play(library, reply_lang, args).await
+                } else {
+                    match music_service::playlist(&db, library, reply_lang, args).await {
+                        Ok(reply) => reply,
+                        Err(e) => {
+                            tracing::error!(tt_username = %username, error = %e, "Failed to run playlist command");
+                            let text =
+                                locales::get_text(reply_lang.as_str(), "tt-error-generic", None);
+                            send_reply(text).await;
+                            return;
+                        }
+                    }
+                };
+                for track in &reply.enqueue {
+                    if let Err(e) = tx_tt_cmd.send(track.enqueue_command(server_id)).await {
+                        tracing::error!(
+                            tt_username = %username,
+                            error = %e,
+                            "Failed to send TT enqueue command"
+                        );
+                        let text = locales::get_text(reply_lang.as_str(), "tt-error-generic", None);
+                        send_reply(text).await;
+                        return;
+                    }
+                }
+                send_reply(reply.text).await;
             } else if cmd == "/add_admin" {
                 let is_main_admin = admin_username
                     .as_ref()
@@ -510,6 +683,7 @@ pub(super) fn handle_text_message(client: &Client, ctx: &WorkerContext, msg: Tex
 
                 if let Err(e) = tx_bridge
                     .send(crate::core::types::BridgeEvent::ToAdmin {
+                        server_id,
                         user_id: from_uid,
                         nick,
                         tt_username: username,
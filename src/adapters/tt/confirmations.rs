@@ -0,0 +1,264 @@
+#![allow(clippy::pedantic, clippy::nursery)]
+
+use crate::adapters::tt::accounts::{self, PendingAccountOp};
+use crate::adapters::tt::{WorkerContext, channels, send_to_bridge};
+use crate::core::types::{
+    AccountChangeError, BanLiftError, BridgeEvent, ChannelChangeError, ChannelOp, LanguageCode,
+};
+use std::time::{Duration, Instant};
+
+pub const CONFIRMATION_SWEEP_INTERVAL: Duration = Duration::from_secs(5);
+const CONFIRMATION_TIMEOUT: Duration = Duration::from_secs(30);
+
+pub enum Confirmation {
+    Unban {
+        ban_id: i64,
+        ip_address: String,
+        reply_to: Option<(i64, LanguageCode)>,
+    },
+    Channel {
+        chat_id: i64,
+        lang: LanguageCode,
+        op: ChannelOp,
+        path: String,
+    },
+    Account {
+        username: String,
+        pending: PendingAccountOp,
+    },
+}
+
+pub struct PendingConfirmation {
+    confirmation: Confirmation,
+    sent_at: Instant,
+}
+
+enum Outcome {
+    Confirmed,
+    Rejected,
+    Lost,
+}
+
+pub(super) fn track(ctx: &WorkerContext, cmd_id: i32, confirmation: Confirmation) {
+    if let Ok(mut pending) = ctx.confirmations.write() {
+        pending.insert(
+            cmd_id,
+            PendingConfirmation {
+                confirmation,
+                sent_at: Instant::now(),
+            },
+        );
+    }
+}
+
+pub(super) fn join_pending_unban(
+    ctx: &WorkerContext,
+    ban_id: i64,
+    reply: Option<(i64, LanguageCode)>,
+) -> bool {
+    let Ok(mut pending) = ctx.confirmations.write() else {
+        return false;
+    };
+    let Some(reply_to) = pending
+        .values_mut()
+        .find_map(|p| match &mut p.confirmation {
+            Confirmation::Unban {
+                ban_id: id,
+                reply_to,
+                ..
+            } if *id == ban_id => Some(reply_to),
+            _ => None,
+        })
+    else {
+        return false;
+    };
+    if reply.is_some() {
+        *reply_to = reply;
+    }
+    true
+}
+
+pub(super) fn command_succeeded(ctx: &WorkerContext, cmd_id: i32) {
+    resolve(ctx, cmd_id, Outcome::Confirmed);
+}
+
+pub(super) fn command_failed(ctx: &WorkerContext, cmd_id: i32) {
+    resolve(ctx, cmd_id, Outcome::Rejected);
+}
+
+pub(super) fn expire_stale(ctx: &WorkerContext) {
+    let stale: Vec<PendingConfirmation> = match ctx.confirmations.write() {
+        Ok(mut pending) => {
+            let ids: Vec<i32> = pending
+                .iter()
+                .filter(|(_, p)| p.sent_at.elapsed() >= CONFIRMATION_TIMEOUT)
+                .map(|(id, _)| *id)
+                .collect();
+            ids.iter().filter_map(|id| pending.remove(id)).collect()
+        }
+        Err(_) => return,
+    };
+    for p in stale {
+        finish(ctx, p.confirmation, Outcome::Lost);
+    }
+}
+
+pub(super) fn abort_all(ctx: &WorkerContext) {
+    let aborted: Vec<PendingConfirmation> = match ctx.confirmations.write() {
+        Ok(mut pending) => pending.drain().map(|(_, p)| p).collect(),
+        Err(_) => return,
+    };
+    for p in aborted {
+        finish(ctx, p.confirmation, Outcome::Lost);
+    }
+}
+
+fn resolve(ctx: &WorkerContext, cmd_id: i32, outcome: Outcome) {
+    let pending = ctx
+        .confirmations
+        .write()
+        .ok()
+        .and_then(|mut pending| pending.remove(&cmd_id));
+    if let Some(p) = pending {
+        finish(ctx, p.confirmation, outcome);
+    }
+}
+
+fn finish(ctx: &WorkerContext, confirmation: Confirmation, outcome: Outcome) {
+    match confirmation {
+        Confirmation::Unban {
+            ban_id,
+            ip_address,
+            reply_to,
+        } => finish_unban(ctx, ban_id, ip_address, reply_to, outcome),
+        Confirmation::Channel {
+            chat_id,
+            lang,
+            op,
+            path,
+        } => match outcome {
+            Outcome::Confirmed => channels::confirm_change(ctx, chat_id, lang, op, path),
+            Outcome::Rejected => {
+                tracing::error!(
+                    component = "tt_worker",
+                    op = ?op,
+                    channel = %path,
+                    "Channel change rejected"
+                );
+                channels::fail_change(ctx, chat_id, lang, op, path, ChannelChangeError::Failed);
+            }
+            Outcome::Lost => {
+                channels::fail_change(
+                    ctx,
+                    chat_id,
+                    lang,
+                    op,
+                    path,
+                    ChannelChangeError::Unconfirmed,
+                );
+            }
+        },
+        Confirmation::Account { username, pending } => {
+            let error = match outcome {
+                Outcome::Confirmed => {
+                    accounts::confirm_change(ctx, username, pending);
+                    return;
+                }
+                Outcome::Rejected => AccountChangeError::Failed,
+                Outcome::Lost => AccountChangeError::Unconfirmed,
+            };
+            tracing::error!(
+                component = "tt_worker",
+                username = %username,
+                op = ?pending.op,
+                error = ?error,
+                "Account change not confirmed"
+            );
+            accounts::fail_pending(ctx, username, pending, error);
+        }
+    }
+}
+
+fn finish_unban(
+    ctx: &WorkerContext,
+    ban_id: i64,
+    ip_address: String,
+    reply_to: Option<(i64, LanguageCode)>,
+    outcome: Outcome,
+) {
+    let error = match outcome {
+        Outcome::Confirmed => {
+            if let Ok(mut bans) = ctx.server_bans.write() {
+                bans.retain(|b| b.ip_address != ip_address);
+            }
+            None
+        }
+        Outcome::Rejected => {
+            let still_banned = ctx
+                .server_bans
+                .read()
+                .is_ok_and(|bans| bans.iter().any(|b| b.ip_address == ip_address));
+            tracing::warn!(
+                component = "tt_worker",
+                server_id = %ctx.server_id,
+                ip_address = %ip_address,
+                still_banned,
+                "Server rejected unban"
+            );
+            still_banned.then_some(BanLiftError::Failed)
+        }
+        Outcome::Lost => {
+            tracing::warn!(
+                component = "tt_worker",
+                server_id = %ctx.server_id,
+                ip_address = %ip_address,
+                "Unban was not confirmed; will retry"
+            );
+            Some(BanLiftError::Unconfirmed)
+        }
+    };
+    if let Some((chat_id, lang)) = reply_to {
+        report_unban(ctx, chat_id, lang, ip_address, error);
+    }
+    if error.is_some() {
+        return;
+    }
+    let db = ctx.db.clone();
+    tokio::task::spawn_local(async move {
+        match db.remove_ban_by_id(ban_id).await {
+            Ok(Some(ban)) => {
+                tracing::info!(
+                    tt_username = ban.teamtalk_username.as_deref().unwrap_or_default(),
+                    "Ban lifted"
+                );
+            }
+            Ok(None) => {}
+            Err(e) => {
+                tracing::error!(ban_id, error = %e, "Failed to remove lifted ban");
+            }
+        }
+    });
+}
+
+pub(super) fn report_unban(
+    ctx: &WorkerContext,
+    chat_id: i64,
+    lang: LanguageCode,
+    ip_address: String,
+    error: Option<BanLiftError>,
+) {
+    let event = match error {
+        None => BridgeEvent::BanLifted {
+            chat_id,
+            lang,
+            ip_address,
+        },
+        Some(error) => BridgeEvent::BanLiftFailed {
+            chat_id,
+            lang,
+            ip_address,
+            error,
+        },
+    };
+    send_to_bridge(ctx, event);
+}
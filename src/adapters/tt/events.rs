@@ -1,13 +1,21 @@
 #![allow(clippy::pedantic, clippy::nursery)]
 
-use crate::adapters::tt::commands;
-use crate::adapters::tt::{WorkerContext, resolve_channel_name, resolve_server_name};
+use crate::adapters::tt::notices::{self, Notice};
+use crate::adapters::tt::sessions::{self, SessionWrite};
+use crate::adapters::tt::{
+    WorkerContext, refresh_server_bans, resolve_channel_name, resolve_channel_path,
+    resolve_server_name, server_ban_key,
+};
+use crate::adapters::tt::{channels, commands, confirmations, files, health, server};
 use crate::bootstrap::config::GenderConfig;
-use crate::core::types::{BridgeEvent, LanguageCode, LiteUser, NotificationType};
+use crate::core::types::{
+    BridgeEvent, BroadcastDetail, LanguageCode, LiteUser, NotificationType, Presence,
+};
+use std::collections::HashMap;
 use std::time::{Duration, Instant};
 use teamtalk::client::ReconnectHandler;
 use teamtalk::client::ffi;
-use teamtalk::types::{UserGender, UserStatus};
+use teamtalk::types::{ChannelId, UserGender, UserPresence, UserStatus};
 use teamtalk::{Client, Event, Message};
 
 pub(super) fn handle_sdk_event(
@@ -20,7 +28,12 @@ pub(super) fn handle_sdk_event(
     ready_time: &mut Option<Instant>,
 ) {
     tracing::trace!(component = "tt_worker", event = ?event, "Event received");
-    let tt_config = &ctx.config.teamtalk;
+    let tt_config = ctx.tt_config();
+
+    if matches!(event, Event::CmdError) && *is_connected && ready_time.is_none() {
+        tracing::warn!(component = "tt_worker", "Login rejected by server");
+        health::login_failed(ctx);
+    }
 
     match event {
         Event::ConnectSuccess => {
@@ -39,12 +52,17 @@ pub(super) fn handle_sdk_event(
             if let Ok(mut users) = ctx.online_users.write() {
                 users.clear();
             }
+            notices::send(ctx, Notice::Reset);
+            sessions::record(ctx, SessionWrite::CloseAll);
+            files::abort_transfers(ctx);
+            confirmations::abort_all(ctx);
             *ready_time = None;
             tracing::warn!(
                 component = "tt_worker",
                 event = ?e,
                 "Disconnection event; reconnect pending"
             );
+            health::disconnected(ctx);
         }
         Event::MySelfLoggedIn => {
             let gender = parse_gender(ctx.config.general.gender);
@@ -66,11 +84,16 @@ pub(super) fn handle_sdk_event(
                     );
                 }
             }
+            refresh_bridge_channels(client, ctx);
+            channels::refresh_channels(client, ctx);
+            server::refresh_server_info(client, ctx);
             *ready_time = Some(std::time::Instant::now());
+            health::logged_in(ctx);
             if let Ok(mut accounts) = ctx.user_accounts.write() {
                 accounts.clear();
             }
             client.list_user_accounts(0, 1000);
+            refresh_server_bans(client, ctx);
         }
 
         Event::UserAccount => {
@@ -81,6 +104,22 @@ pub(super) fn handle_sdk_event(
                 accounts.insert(account.username.clone(), account);
             }
         }
+        Event::BannedUser => {
+            if let Some(ban) = msg.banned_user()
+                && let Ok(mut bans) = ctx.server_bans.write()
+                && !bans
+                    .iter()
+                    .any(|b| server_ban_key(b) == server_ban_key(&ban))
+            {
+                bans.push(ban);
+            }
+        }
+        Event::CmdSuccess => confirmations::command_succeeded(ctx, msg.source()),
+        Event::CmdError => confirmations::command_failed(ctx, msg.source()),
+        Event::ChannelCreated | Event::ChannelUpdated | Event::ChannelRemoved => {
+            channels::refresh_channels(client, ctx);
+        }
+        Event::ServerUpdate => server::server_updated(client, ctx),
         Event::UserAccountCreated | Event::UserAccountRemoved => {
             if let Ok(mut accounts) = ctx.user_accounts.write() {
                 accounts.clear();
@@ -89,6 +128,7 @@ pub(super) fn handle_sdk_event(
         }
 
         Event::UserUpdate => {
+            let mut changes = Vec::new();
             if let Some(user) = msg.user()
                 && let Ok(mut users) = ctx.online_users.write()
                 && let Some(existing_lite_user) = users.get_mut(&user.id.0)
@@ -113,7 +153,44 @@ pub(super) fn handle_sdk_event(
                         new_nick = %user.nickname,
                         "Nickname changed"
                     );
-                    existing_lite_user.nickname = user.nickname.clone();
+                    let previous =
+                        std::mem::replace(&mut existing_lite_user.nickname, user.nickname.clone());
+                    changes.push((
+                        NotificationType::NickChange,
+                        BroadcastDetail::Nickname { previous },
+                    ));
+                }
+
+                let presence = map_presence(user.status.presence);
+                if existing_lite_user.presence != presence
+                    || existing_lite_user.status_text != user.status_msg
+                {
+                    existing_lite_user.presence = presence;
+                    existing_lite_user.status_text = user.status_msg.clone();
+                    changes.push((
+                        NotificationType::StatusChange,
+                        BroadcastDetail::Status {
+                            presence,
+                            text: user.status_msg.clone(),
+                        },
+                    ));
+                }
+            }
+
+            if let Some(user) = msg.user()
+                && user.id != client.my_id()
+                && is_ready(*ready_time)
+                && !tt_config.global_ignore_usernames.contains(&user.username)
+            {
+                for (event_type, detail) in changes {
+                    send_broadcast(
+                        client,
+                        ctx,
+                        event_type,
+                        user.nickname.clone(),
+                        user.username.clone(),
+                        Some(detail),
+                    );
                 }
             }
         }
@@ -135,7 +212,7 @@ pub(super) fn handle_sdk_event(
                         streaming: false,
                         ..UserStatus::default()
                     };
-                    client.set_status(status, &ctx.config.teamtalk.status_text);
+                    client.set_status(status, &tt_config.status_text);
                 }
                 ffi::MediaFileStatus::MFS_PAUSED => {
                     if ctx.is_streaming.load(std::sync::atomic::Ordering::Relaxed) {
@@ -145,7 +222,7 @@ pub(super) fn handle_sdk_event(
                             media_paused: true,
                             ..UserStatus::default()
                         };
-                        client.set_status(status, &ctx.config.teamtalk.status_text);
+                        client.set_status(status, &tt_config.status_text);
                     }
                 }
                 ffi::MediaFileStatus::MFS_STARTED | ffi::MediaFileStatus::MFS_PLAYING => {
@@ -155,7 +232,7 @@ pub(super) fn handle_sdk_event(
                             streaming: true,
                             ..UserStatus::default()
                         };
-                        client.set_status(status, &ctx.config.teamtalk.status_text);
+                        client.set_status(status, &tt_config.status_text);
                     }
                 }
             }
@@ -172,7 +249,11 @@ pub(super) fn handle_sdk_event(
                     id: user.id.0,
                     nickname: nickname.clone(),
                     username: user.username.clone(),
+                    ip_address: user.ip_address.clone(),
+                    channel_id: user.channel_id.0,
                     channel_name,
+                    presence: map_presence(user.status.presence),
+                    status_text: user.status_msg.clone(),
                 };
                 if let Ok(mut by_username) = ctx.online_users_by_username.write()
                     && !lite_user.username.is_empty()
@@ -182,31 +263,33 @@ pub(super) fn handle_sdk_event(
                 if let Ok(mut users) = ctx.online_users.write() {
                     users.insert(user.id.0, lite_user.clone());
                 }
+                sessions::record(
+                    ctx,
+                    SessionWrite::Open {
+                        username: user.username.clone(),
+                        nickname: nickname.clone(),
+                    },
+                );
 
-                let is_ready = ready_time
-                    .map(|t| t.elapsed() >= Duration::from_secs(2))
-                    .unwrap_or(false);
-
-                if is_ready && !tt_config.global_ignore_usernames.contains(&user.username) {
-                    let real_name = client.get_server_properties().map(|p| p.name);
-                    let server_name = resolve_server_name(tt_config, real_name.as_deref());
-
-                    let tx_bridge = ctx.tx_bridge.clone();
-                    let related_tt_username = user.username.clone();
-                    tokio::task::spawn_local(async move {
-                        if let Err(e) = tx_bridge
-                            .send(BridgeEvent::Broadcast {
-                                event_type: NotificationType::Join,
-                                nickname,
-                                server_name,
-                                related_tt_username,
-                            })
-                            .await
-                        {
-                            tracing::error!(error = %e, "Failed to send join broadcast");
-                        }
-                    });
-                }
+                let join = (is_ready(*ready_time)
+                    && !tt_config.global_ignore_usernames.contains(&user.username))
+                .then(|| {
+                    broadcast_event(
+                        client,
+                        ctx,
+                        NotificationType::Join,
+                        nickname,
+                        user.username.clone(),
+                        None,
+                    )
+                });
+                notices::send(
+                    ctx,
+                    Notice::Login {
+                        username: user.username.clone(),
+                        event: join,
+                    },
+                );
             }
         }
         Event::UserJoined => {
@@ -218,9 +301,13 @@ pub(super) fn handle_sdk_event(
 
                 let lite_user = LiteUser {
                     id: user.id.0,
-                    nickname,
+                    nickname: nickname.clone(),
                     username: user.username.clone(),
+                    ip_address: user.ip_address.clone(),
+                    channel_id: user.channel_id.0,
                     channel_name,
+                    presence: map_presence(user.status.presence),
+                    status_text: user.status_msg.clone(),
                 };
                 if let Ok(mut by_username) = ctx.online_users_by_username.write()
                     && !lite_user.username.is_empty()
@@ -230,6 +317,38 @@ pub(super) fn handle_sdk_event(
                 if let Ok(mut users) = ctx.online_users.write() {
                     users.insert(user.id.0, lite_user);
                 }
+                if user.channel_id.0 > 0 {
+                    sessions::record(
+                        ctx,
+                        SessionWrite::Channel {
+                            username: user.username.clone(),
+                            path: resolve_channel_path(client, user.channel_id),
+                        },
+                    );
+                }
+
+                if user.channel_id.0 > 0
+                    && is_ready(*ready_time)
+                    && !tt_config.global_ignore_usernames.contains(&user.username)
+                {
+                    let event = broadcast_event(
+                        client,
+                        ctx,
+                        NotificationType::ChannelJoin,
+                        nickname,
+                        user.username.clone(),
+                        Some(BroadcastDetail::Channel {
+                            path: resolve_channel_path(client, user.channel_id),
+                        }),
+                    );
+                    notices::send(
+                        ctx,
+                        Notice::ChannelJoin {
+                            username: user.username.clone(),
+                            event,
+                        },
+                    );
+                }
             }
         }
 
@@ -246,31 +365,17 @@ pub(super) fn handle_sdk_event(
                     {
                         by_username.remove(&u.username);
                     }
-                    if user.id != client.my_id() {
-                        let is_ready = ready_time
-                            .map(|t| t.elapsed() >= Duration::from_secs(2))
-                            .unwrap_or(false);
-                        if is_ready && !tt_config.global_ignore_usernames.contains(&u.username) {
-                            let real_name = client.get_server_properties().map(|p| p.name);
-                            let server_name = resolve_server_name(tt_config, real_name.as_deref());
-
-                            let tx_bridge = ctx.tx_bridge.clone();
-                            let nickname = u.nickname.clone();
-                            let related_tt_username = u.username.clone();
-                            tokio::task::spawn_local(async move {
-                                if let Err(e) = tx_bridge
-                                    .send(BridgeEvent::Broadcast {
-                                        event_type: NotificationType::Leave,
-                                        nickname,
-                                        server_name,
-                                        related_tt_username,
-                                    })
-                                    .await
-                                {
-                                    tracing::error!(error = %e, "Failed to send leave broadcast");
-                                }
-                            });
-                        }
+                    sessions::record(
+                        ctx,
+                        SessionWrite::Close {
+                            username: u.username.clone(),
+                        },
+                    );
+                    if user.id != client.my_id()
+                        && is_ready(*ready_time)
+                        && !tt_config.global_ignore_usernames.contains(&u.username)
+                    {
+                        send_leave(client, ctx, u.nickname, u.username);
                     }
                 }
             }
@@ -279,10 +384,34 @@ pub(super) fn handle_sdk_event(
             if let Some(user) = msg.user() {
                 let channel_name = resolve_channel_name(client, user.channel_id, LanguageCode::En);
 
-                if let Ok(mut users) = ctx.online_users.write()
+                let left = if let Ok(mut users) = ctx.online_users.write()
                     && let Some(u) = users.get_mut(&user.id.0)
                 {
+                    let previous_channel = u.channel_id;
+                    u.channel_id = user.channel_id.0;
                     u.channel_name = channel_name;
+                    Some((previous_channel, u.nickname.clone(), u.username.clone()))
+                } else {
+                    None
+                };
+
+                if let Some((previous_channel, nickname, username)) = left
+                    && previous_channel > 0
+                    && user.id != client.my_id()
+                    && is_ready(*ready_time)
+                    && !tt_config.global_ignore_usernames.contains(&username)
+                {
+                    let event = broadcast_event(
+                        client,
+                        ctx,
+                        NotificationType::ChannelLeave,
+                        nickname,
+                        username.clone(),
+                        Some(BroadcastDetail::Channel {
+                            path: resolve_channel_path(client, ChannelId(previous_channel)),
+                        }),
+                    );
+                    notices::send(ctx, Notice::ChannelLeave { username, event });
                 }
             }
         }
@@ -293,10 +422,139 @@ pub(super) fn handle_sdk_event(
             }
         }
 
+        Event::FileNew => {
+            if let Some(file) = msg.remote_file()
+                && is_ready(*ready_time)
+                && file.username != tt_config.user_name
+                && !tt_config.global_ignore_usernames.contains(&file.username)
+            {
+                let nickname = ctx
+                    .online_users
+                    .read()
+                    .ok()
+                    .and_then(|users| {
+                        users
+                            .values()
+                            .find(|u| u.username == file.username)
+                            .map(|u| u.nickname.clone())
+                    })
+                    .unwrap_or_else(|| file.username.clone());
+                send_broadcast(
+                    client,
+                    ctx,
+                    NotificationType::FileUpload,
+                    nickname,
+                    file.username.clone(),
+                    Some(BroadcastDetail::File {
+                        path: resolve_channel_path(client, file.channel_id),
+                        channel_id: file.channel_id.0,
+                        file_id: file.id,
+                        name: file.name,
+                        size: file.size,
+                    }),
+                );
+            }
+        }
+        Event::FileTransfer => {
+            if let Some(transfer) = msg.file_transfer() {
+                files::handle_file_transfer(ctx, transfer);
+            }
+        }
+
         _ => {}
     }
 }
 
+fn is_ready(ready_time: Option<Instant>) -> bool {
+    ready_time
+        .map(|t| t.elapsed() >= Duration::from_secs(2))
+        .unwrap_or(false)
+}
+
+fn refresh_bridge_channels(client: &Client, ctx: &WorkerContext) {
+    let resolved: HashMap<String, i32> = ctx
+        .config
+        .chat_bridges
+        .iter()
+        .filter(|b| ctx.config.chat_bridge_server(b) == Some(ctx.server_id))
+        .filter_map(|b| {
+            let id = client.get_channel_id_from_path(&b.channel).0;
+            if id > 0 {
+                Some((b.channel.clone(), id))
+            } else {
+                tracing::warn!(
+                    component = "tt_worker",
+                    channel = %b.channel,
+                    "Chat bridge channel not found on server"
+                );
+                None
+            }
+        })
+        .collect();
+    if let Ok(mut channels) = ctx.bridge_channels.write() {
+        *channels = resolved;
+    }
+}
+
+fn send_leave(client: &Client, ctx: &WorkerContext, nickname: String, username: String) {
+    let event = broadcast_event(
+        client,
+        ctx,
+        NotificationType::Leave,
+        nickname,
+        username.clone(),
+        None,
+    );
+    notices::send(ctx, Notice::Leave { username, event });
+}
+
+fn broadcast_event(
+    client: &Client,
+    ctx: &WorkerContext,
+    event_type: NotificationType,
+    nickname: String,
+    related_tt_username: String,
+    detail: Option<BroadcastDetail>,
+) -> BridgeEvent {
+    let real_name = client.get_server_properties().map(|p| p.name);
+    let server_name = resolve_server_name(ctx.tt_config(), real_name.as_deref());
+    BridgeEvent::Broadcast {
+        server_id: ctx.server_id,
+        event_type,
+        nickname,
+        server_name,
+        related_tt_username,
+        detail,
+    }
+}
+
+fn send_broadcast(
+    client: &Client,
+    ctx: &WorkerContext,
+    event_type: NotificationType,
+    nickname: String,
+    related_tt_username: String,
+    detail: Option<BroadcastDetail>,
+) {
+    let event = broadcast_event(
+        client,
+        ctx,
+        event_type,
+        nickname,
+        related_tt_username,
+        detail,
+    );
+    notices::send(ctx, Notice::Event(event));
+}
+
+fn map_presence(presence: UserPresence) -> Presence {
+    match presence {
+        UserPresence::Available => Presence::Available,
+        UserPresence::Away => Presence::Away,
+        UserPresence::Question => Presence::Question,
+    }
+}
+
 fn parse_gender(cfg: GenderConfig) -> UserGender {
     cfg.to_user_gender()
 }
@@ -0,0 +1,325 @@
+#![allow(clippy::pedantic, clippy::nursery)]
+
+use crate::adapters::tt::{
+    PendingDownload, PendingUpload, WorkerContext, remove_temp_file, resolve_channel_path,
+    send_to_bridge,
+};
+use crate::app::services::files::{fits_channel_quota, fits_download_limit};
+use crate::core::types::{BridgeEvent, FileDownloadError, FileUploadError, LanguageCode};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use teamtalk::Client;
+use teamtalk::client::ffi::{self, FileTransferStatus};
+use teamtalk::types::{ChannelId, FileTransfer};
+
+const UPLOAD_PROGRESS_INTERVAL: Duration = Duration::from_secs(5);
+
+pub(super) fn start_download(
+    client: &Client,
+    ctx: &WorkerContext,
+    channel_id: i32,
+    file_id: i32,
+    chat_id: i64,
+    lang: LanguageCode,
+) {
+    let Some(file) = client.get_channel_file(ChannelId(channel_id), file_id) else {
+        send_to_bridge(
+            ctx,
+            BridgeEvent::FileDownloadFailed {
+                chat_id,
+                lang,
+                file_name: String::new(),
+                error: FileDownloadError::NotFound,
+            },
+        );
+        return;
+    };
+    let limit_mb = ctx.config.operational_parameters.file_download_max;
+    if !fits_download_limit(file.size, limit_mb) {
+        send_to_bridge(
+            ctx,
+            BridgeEvent::FileDownloadFailed {
+                chat_id,
+                lang,
+                file_name: file.name,
+                error: FileDownloadError::TooLarge { limit_mb },
+            },
+        );
+        return;
+    }
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis())
+        .unwrap_or(0);
+    let mut temp_path = std::env::temp_dir();
+    temp_path.push(format!("tt-file-{}-{}-{}", ctx.server_id, file_id, now));
+    let local_path = temp_path.to_string_lossy().to_string();
+
+    if client.recv_file(ChannelId(channel_id), file_id, &local_path) <= 0 {
+        tracing::error!(
+            component = "tt_worker",
+            channel_id,
+            file_id,
+            "Failed to start file download"
+        );
+        send_to_bridge(
+            ctx,
+            BridgeEvent::FileDownloadFailed {
+                chat_id,
+                lang,
+                file_name: file.name,
+                error: FileDownloadError::Failed,
+            },
+        );
+        return;
+    }
+    if let Ok(mut downloads) = ctx.file_downloads.write() {
+        downloads.insert(
+            local_path,
+            PendingDownload {
+                chat_id,
+                lang,
+                file_name: file.name,
+            },
+        );
+    }
+}
+
+pub(super) fn start_upload(
+    client: &Client,
+    ctx: &WorkerContext,
+    channel_id: i32,
+    local_path: String,
+    chat_id: i64,
+    lang: LanguageCode,
+) {
+    let file_name = std::path::Path::new(&local_path)
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let channel_id = if channel_id == 0 {
+        client.my_channel_id()
+    } else {
+        ChannelId(channel_id)
+    };
+    let pending = PendingUpload {
+        chat_id,
+        lang,
+        file_name,
+        channel: resolve_channel_path(client, channel_id),
+        last_progress: Instant::now(),
+    };
+
+    let Some(channel) = client.get_channel(channel_id) else {
+        fail_upload(ctx, local_path, pending, FileUploadError::ChannelNotFound);
+        return;
+    };
+    let is_admin = client.my_user_type() & ffi::UserType::USERTYPE_ADMIN as u32 != 0;
+    if !is_admin && client.my_user_rights() & ffi::UserRight::USERRIGHT_UPLOAD_FILES as u32 == 0 {
+        fail_upload(ctx, local_path, pending, FileUploadError::NotAllowed);
+        return;
+    }
+    let size = std::fs::metadata(&local_path)
+        .map(|meta| i64::try_from(meta.len()).unwrap_or(i64::MAX))
+        .unwrap_or(0);
+    let used: i64 = client
+        .get_channel_files(channel_id)
+        .iter()
+        .map(|file| file.size)
+        .sum();
+    if !is_admin && !fits_channel_quota(used, size, channel.disk_quota) {
+        fail_upload(ctx, local_path, pending, FileUploadError::QuotaExceeded);
+        return;
+    }
+
+    if client.send_file(channel_id, &local_path) <= 0 {
+        tracing::error!(
+            component = "tt_worker",
+            channel_id = channel_id.0,
+            file_name = %pending.file_name,
+            "Failed to start file upload"
+        );
+        fail_upload(ctx, local_path, pending, FileUploadError::Failed);
+        return;
+    }
+    tracing::info!(
+        component = "tt_worker",
+        channel = %pending.channel,
+        file_name = %pending.file_name,
+        size,
+        "File upload started"
+    );
+    if let Ok(mut uploads) = ctx.file_uploads.write() {
+        uploads.insert(local_path, pending);
+    }
+}
+
+pub(super) fn handle_file_transfer(ctx: &WorkerContext, transfer: FileTransfer) {
+    if !transfer.inbound {
+        handle_upload_transfer(ctx, transfer);
+        return;
+    }
+    let finished = match transfer.status {
+        FileTransferStatus::FILETX_FINISHED => true,
+        FileTransferStatus::FILETX_ERROR | FileTransferStatus::FILETX_CLOSED => false,
+        FileTransferStatus::FILETX_ACTIVE => return,
+    };
+    let Some(pending) = ctx
+        .file_downloads
+        .write()
+        .ok()
+        .and_then(|mut downloads| downloads.remove(&transfer.local_path))
+    else {
+        return;
+    };
+
+    if finished {
+        send_to_bridge(
+            ctx,
+            BridgeEvent::FileDownloaded {
+                chat_id: pending.chat_id,
+                file_path: transfer.local_path,
+                file_name: pending.file_name,
+            },
+        );
+    } else {
+        tracing::warn!(
+            component = "tt_worker",
+            file_name = %pending.file_name,
+            "File download failed"
+        );
+        fail_download(ctx, transfer.local_path, pending);
+    }
+}
+
+fn handle_upload_transfer(ctx: &WorkerContext, transfer: FileTransfer) {
+    let finished = match transfer.status {
+        FileTransferStatus::FILETX_FINISHED => true,
+        FileTransferStatus::FILETX_ERROR | FileTransferStatus::FILETX_CLOSED => false,
+        FileTransferStatus::FILETX_ACTIVE => {
+            report_upload_progress(ctx, &transfer);
+            return;
+        }
+    };
+    let Some(pending) = ctx
+        .file_uploads
+        .write()
+        .ok()
+        .and_then(|mut uploads| uploads.remove(&transfer.local_path))
+    else {
+        return;
+    };
+
+    if finished {
+        tokio::task::spawn_local(remove_upload_file(transfer.local_path.clone()));
+        send_to_bridge(
+            ctx,
+            BridgeEvent::FileUploaded {
+                chat_id: pending.chat_id,
+                lang: pending.lang,
+                upload_id: transfer.local_path,
+                file_name: pending.file_name,
+                channel: pending.channel,
+            },
+        );
+    } else {
+        tracing::warn!(
+            component = "tt_worker",
+            file_name = %pending.file_name,
+            "File upload failed"
+        );
+        fail_upload(ctx, transfer.local_path, pending, FileUploadError::Failed);
+    }
+}
+
+fn report_upload_progress(ctx: &WorkerContext, transfer: &FileTransfer) {
+    let Ok(mut uploads) = ctx.file_uploads.write() else {
+        return;
+    };
+    let Some(pending) = uploads.get_mut(&transfer.local_path) else {
+        return;
+    };
+    if pending.last_progress.elapsed() < UPLOAD_PROGRESS_INTERVAL
+        || transfer.transferred >= transfer.file_size
+    {
+        return;
+    }
+    pending.last_progress = Instant::now();
+    let event = BridgeEvent::FileUploadProgress {
+        chat_id: pending.chat_id,
+        lang: pending.lang,
+        upload_id: transfer.local_path.clone(),
+        file_name: pending.file_name.clone(),
+        transferred: transfer.transferred,
+        total: transfer.file_size,
+    };
+    drop(uploads);
+    send_to_bridge(ctx, event);
+}
+
+pub(super) fn abort_transfers(ctx: &WorkerContext) {
+    let pending: Vec<(String, PendingDownload)> = ctx
+        .file_downloads
+        .write()
+        .map(|mut downloads| downloads.drain().collect())
+        .unwrap_or_default();
+    for (local_path, download) in pending {
+        fail_download(ctx, local_path, download);
+    }
+    let pending: Vec<(String, PendingUpload)> = ctx
+        .file_uploads
+        .write()
+        .map(|mut uploads| uploads.drain().collect())
+        .unwrap_or_default();
+    for (local_path, upload) in pending {
+        fail_upload(ctx, local_path, upload, FileUploadError::Failed);
+    }
+}
+
+fn fail_download(ctx: &WorkerContext, local_path: String, pending: PendingDownload) {
+    tokio::task::spawn_local(remove_temp_file(local_path));
+    send_to_bridge(
+        ctx,
+        BridgeEvent::FileDownloadFailed {
+            chat_id: pending.chat_id,
+            lang: pending.lang,
+            file_name: pending.file_name,
+            error: FileDownloadError::Failed,
+        },
+    );
+}
+
+fn fail_upload(
+    ctx: &WorkerContext,
+    local_path: String,
+    pending: PendingUpload,
+    error: FileUploadError,
+) {
+    tokio::task::spawn_local(remove_upload_file(local_path.clone()));
+    send_to_bridge(
+        ctx,
+        BridgeEvent::FileUploadFailed {
+            chat_id: pending.chat_id,
+            lang: pending.lang,
+            upload_id: local_path,
+            file_name: pending.file_name,
+            error,
+        },
+    );
+}
+
+async fn remove_upload_file(local_path: String) {
+    let dir = std::path::Path::new(&local_path)
+        .parent()
+        .map(std::path::Path::to_path_buf);
+    remove_temp_file(local_path).await;
+    if let Some(dir) = dir
+        && let Err(e) = tokio::fs::remove_dir(&dir).await
+    {
+        tracing::warn!(
+            dir = %dir.display(),
+            error = %e,
+            "Failed to remove upload directory"
+        );
+    }
+}
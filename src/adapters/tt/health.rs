@@ -0,0 +1,43 @@
+#![allow(clippy::pedantic, clippy::nursery)]
+
+use crate::adapters::tt::{WorkerContext, send_to_bridge};
+use crate::app::services::health::HealthTracker;
+use crate::core::types::{BridgeEvent, HealthAlert};
+use std::time::{Duration, Instant};
+
+pub const DEFERRED_ALERT_CHECK_INTERVAL: Duration = Duration::from_secs(10);
+
+pub(super) fn disconnected(ctx: &WorkerContext) {
+    track(ctx, |health, now| health.disconnected(now));
+}
+
+pub(super) fn login_failed(ctx: &WorkerContext) {
+    track(ctx, |health, now| health.login_failed(now));
+}
+
+pub(super) fn check_deferred(ctx: &WorkerContext) {
+    track(ctx, |health, now| health.deferred_alert(now));
+}
+
+pub(super) fn logged_in(ctx: &WorkerContext) {
+    track(ctx, |health, now| health.logged_in(now));
+}
+
+fn track(
+    ctx: &WorkerContext,
+    update: impl FnOnce(&mut HealthTracker, Instant) -> Option<HealthAlert>,
+) {
+    let Some((alert, notify_subscribers)) = ctx.health.write().ok().and_then(|mut health| {
+        let alert = update(&mut health, Instant::now())?;
+        Some((alert, health.notifies_subscribers(alert)))
+    }) else {
+        return;
+    };
+    tracing::info!(component = "tt_worker", alert = ?alert, "Connection health alert");
+    let event = BridgeEvent::ServerHealth {
+        server_id: ctx.server_id,
+        alert,
+        notify_subscribers,
+    };
+    send_to_bridge(ctx, event);
+}
@@ -1,32 +1,45 @@
 #![allow(clippy::pedantic, clippy::nursery)]
 
+pub mod accounts;
+pub mod channels;
 pub mod commands;
+pub mod confirmations;
 pub mod events;
+pub mod files;
+pub mod health;
+pub mod notices;
 pub mod reports;
+pub mod server;
+pub mod sessions;
+pub mod stream;
 
-use crate::bootstrap::config::Config;
-use crate::core::types::{BridgeEvent, LanguageCode, LiteUser, TtCommand};
+use crate::app::services::health::HealthTracker;
+use crate::bootstrap::config::{Config, TeamTalkConfig, same_channel_path};
+use crate::core::types::{
+    BanLiftError, BanTarget, BridgeEvent, LanguageCode, LiteChannel, LiteServerInfo, LiteUser,
+    QueueOp, ServerId, TtCommand,
+};
 use crate::infra::db::Database;
 use crate::infra::locales;
 use futures_util::StreamExt;
-use std::collections::{HashMap, VecDeque};
+use std::collections::HashMap;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, RwLock};
-use std::time::Duration;
+use std::time::{Duration, Instant};
+use stream::{QueueWatcher, StreamQueue};
 use teamtalk::Client;
 use teamtalk::client::media::MediaPlayback;
 use teamtalk::client::{ConnectParams, ReconnectConfig, ReconnectHandler};
 use teamtalk::types::{AudioPreprocessor, ChannelId, UserStatus};
-use teamtalk::types::{UserAccount, UserId};
+use teamtalk::types::{BanTypes, BannedUser, UserAccount, UserId};
 use tokio::sync::Semaphore;
 use tokio::sync::mpsc::{Receiver, Sender};
 use tokio::sync::oneshot;
 use tokio::time::interval;
 
-pub(super) fn resolve_server_name(
-    tt_config: &crate::bootstrap::config::TeamTalkConfig,
-    real_name: Option<&str>,
-) -> String {
+const ONLINE_SAMPLE_RETENTION_DAYS: i64 = 35;
+
+pub(super) fn resolve_server_name(tt_config: &TeamTalkConfig, real_name: Option<&str>) -> String {
     tt_config
         .server_name
         .as_deref()
@@ -51,11 +64,161 @@ pub(super) fn resolve_channel_name(
     }
 }
 
+pub(super) fn resolve_channel_path(client: &Client, channel_id: ChannelId) -> String {
+    client
+        .get_channel_path(channel_id)
+        .filter(|path| !path.is_empty())
+        .unwrap_or_else(|| "/".to_string())
+}
+
+#[derive(Clone, Default)]
+pub struct ServerShared {
+    pub online_users: Arc<RwLock<HashMap<i32, LiteUser>>>,
+    pub online_users_by_username: Arc<RwLock<HashMap<String, i32>>>,
+    pub user_accounts: Arc<RwLock<HashMap<String, UserAccount>>>,
+    pub bridge_channels: Arc<RwLock<HashMap<String, i32>>>,
+    pub server_bans: Arc<RwLock<Vec<BannedUser>>>,
+    pub channels: Arc<RwLock<Vec<LiteChannel>>>,
+    pub server_info: Arc<RwLock<Option<LiteServerInfo>>>,
+}
+
+impl ServerShared {
+    pub fn is_username_online(&self, username: &str) -> bool {
+        self.online_users
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .values()
+            .any(|entry| entry.username == username)
+    }
+
+    pub fn online_user_by_username(&self, username: &str) -> Option<LiteUser> {
+        self.online_users
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .values()
+            .find(|entry| entry.username == username)
+            .cloned()
+    }
+
+    pub fn online_user(&self, user_id: i32) -> Option<LiteUser> {
+        self.online_users
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .get(&user_id)
+            .cloned()
+    }
+
+    pub fn bridge_channel_id(&self, channel_path: &str) -> Option<i32> {
+        self.bridge_channels
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .iter()
+            .find(|(path, _)| same_channel_path(path, channel_path))
+            .map(|(_, id)| *id)
+    }
+
+    pub fn channels(&self) -> Vec<LiteChannel> {
+        self.channels
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .clone()
+    }
+
+    pub fn channel(&self, channel_id: i32) -> Option<LiteChannel> {
+        self.channels
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .iter()
+            .find(|c| c.id == channel_id)
+            .cloned()
+    }
+
+    pub fn server_info(&self) -> Option<LiteServerInfo> {
+        self.server_info
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .clone()
+    }
+
+    pub fn account(&self, username: &str) -> Option<UserAccount> {
+        self.user_accounts
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .get(username)
+            .cloned()
+    }
+
+    pub fn sorted_accounts(&self) -> Vec<UserAccount> {
+        let mut accounts: Vec<UserAccount> = self
+            .user_accounts
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .values()
+            .cloned()
+            .collect();
+        accounts.sort_by_key(|a| a.username.to_lowercase());
+        accounts
+    }
+
+    pub fn sorted_server_bans(&self) -> Vec<BannedUser> {
+        let mut bans = self
+            .server_bans
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .clone();
+        bans.sort_by(|a, b| b.ban_time.cmp(&a.ban_time));
+        bans
+    }
+
+    pub fn sorted_online_users(&self) -> Vec<LiteUser> {
+        let mut users: Vec<LiteUser> = self
+            .online_users
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .values()
+            .cloned()
+            .collect();
+        users.sort_by_key(|u| u.nickname.to_lowercase());
+        users
+    }
+}
+
+pub fn server_ban_key(ban: &BannedUser) -> u64 {
+    use std::hash::{DefaultHasher, Hash, Hasher};
+
+    let mut hasher = DefaultHasher::new();
+    (&ban.ip_address, &ban.username, &ban.channel_path).hash(&mut hasher);
+    hasher.finish()
+}
+
+pub fn merged_user_accounts(servers: &[ServerShared]) -> Vec<UserAccount> {
+    let mut merged: HashMap<String, UserAccount> = HashMap::new();
+    for server in servers {
+        let accounts = server
+            .user_accounts
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        for (username, account) in accounts.iter() {
+            merged
+                .entry(username.clone())
+                .or_insert_with(|| account.clone());
+        }
+    }
+    let mut accounts: Vec<UserAccount> = merged.into_values().collect();
+    accounts.sort_by_key(|a| a.username.to_lowercase());
+    accounts
+}
+
 pub struct WorkerContext {
+    pub server_id: ServerId,
     pub config: Arc<Config>,
     pub online_users: Arc<RwLock<HashMap<i32, LiteUser>>>,
     pub online_users_by_username: Arc<RwLock<HashMap<String, i32>>>,
     pub user_accounts: Arc<RwLock<HashMap<String, UserAccount>>>,
+    pub bridge_channels: Arc<RwLock<HashMap<String, i32>>>,
+    pub server_bans: Arc<RwLock<Vec<BannedUser>>>,
+    pub channels: Arc<RwLock<Vec<LiteChannel>>>,
+    pub server_info: Arc<RwLock<Option<LiteServerInfo>>>,
     pub tx_bridge: tokio::sync::mpsc::Sender<BridgeEvent>,
     pub tx_tt_cmd: Sender<TtCommand>,
     pub db: Database,
@@ -65,6 +228,33 @@ pub struct WorkerContext {
     pub tt_lang_cache: Arc<RwLock<HashMap<String, LanguageCode>>>,
     pub tt_tg_cache: Arc<RwLock<HashMap<String, i64>>>,
     pub tt_cache_stats: Arc<TtCacheStats>,
+    pub notices: tokio::sync::mpsc::UnboundedSender<notices::Notice>,
+    pub sessions: tokio::sync::mpsc::UnboundedSender<sessions::SessionWrite>,
+    pub file_downloads: Arc<RwLock<HashMap<String, PendingDownload>>>,
+    pub file_uploads: Arc<RwLock<HashMap<String, PendingUpload>>>,
+    pub pending_server_edit: Arc<RwLock<Option<server::PendingServerEdit>>>,
+    pub confirmations: Arc<RwLock<HashMap<i32, confirmations::PendingConfirmation>>>,
+    pub health: Arc<RwLock<HealthTracker>>,
+}
+
+pub struct PendingDownload {
+    pub chat_id: i64,
+    pub lang: LanguageCode,
+    pub file_name: String,
+}
+
+pub struct PendingUpload {
+    pub chat_id: i64,
+    pub lang: LanguageCode,
+    pub file_name: String,
+    pub channel: String,
+    pub last_progress: std::time::Instant,
+}
+
+impl WorkerContext {
+    pub fn tt_config(&self) -> &TeamTalkConfig {
+        &self.config.teamtalk[self.server_id.0]
+    }
 }
 
 pub struct TtCacheStats {
@@ -75,10 +265,9 @@ pub struct TtCacheStats {
 }
 
 pub struct RunTeamtalkArgs {
+    pub server_id: ServerId,
     pub config: Arc<Config>,
-    pub online_users: Arc<RwLock<HashMap<i32, LiteUser>>>,
-    pub online_users_by_username: Arc<RwLock<HashMap<String, i32>>>,
-    pub user_accounts: Arc<RwLock<HashMap<String, UserAccount>>>,
+    pub shared: ServerShared,
     pub tx_bridge: tokio::sync::mpsc::Sender<BridgeEvent>,
     pub rx_cmd: Receiver<TtCommand>,
     pub tx_cmd_clone: Sender<TtCommand>,
@@ -88,26 +277,17 @@ pub struct RunTeamtalkArgs {
     pub tx_init: oneshot::Sender<Result<(), String>>,
 }
 
-struct StreamItem {
-    stream_id: u64,
-    channel_id: i32,
-    file_path: String,
-    duration_ms: u32,
-    announce_text: Option<String>,
-}
+const MEDIA_OFFSET_KEEP: u32 = u32::MAX;
 
-type StartNextFn =
-    dyn Fn(&Client, &mut VecDeque<StreamItem>, &mut Option<StreamItem>, &Sender<TtCommand>);
+type StartNextFn = dyn Fn(&Client, &mut StreamQueue, &Sender<TtCommand>);
 
-type SetStreamingStatusFn = dyn Fn(&Client, bool);
+type SetStreamingStatusFn = dyn Fn(&Client, bool, bool);
 
 #[allow(clippy::too_many_arguments)]
 fn handle_cmd(
     cmd: TtCommand,
     async_client: &mut teamtalk::AsyncClient,
-    stream_seq: &mut u64,
-    stream_queue: &mut VecDeque<StreamItem>,
-    current_stream: &mut Option<StreamItem>,
+    queue: &mut StreamQueue,
     tx_cmd_clone: &Sender<TtCommand>,
     is_streaming: &Arc<std::sync::atomic::AtomicBool>,
     ctx: &WorkerContext,
@@ -118,17 +298,27 @@ fn handle_cmd(
         TtCommand::Shutdown => {
             return true;
         }
-        TtCommand::Broadcast { text } => {
+        TtCommand::Broadcast { text, .. } => {
             async_client.with_client_mut(|client_ref| {
                 client_ref.send_to_all(&text);
+                let tt_config = ctx.tt_config();
+                commands::forward_server_broadcast(
+                    client_ref,
+                    ctx,
+                    tt_config.nick_name.clone(),
+                    tt_config.user_name.clone(),
+                    &text,
+                );
             });
         }
-        TtCommand::ReplyToUser { user_id, text } => {
+        TtCommand::ReplyToUser { user_id, text, .. } => {
             async_client.with_client_mut(|client_ref| {
                 client_ref.send_to_user(UserId(user_id), &text);
             });
         }
-        TtCommand::SendToChannel { channel_id, text } => {
+        TtCommand::SendToChannel {
+            channel_id, text, ..
+        } => {
             async_client.with_client_mut(|client_ref| {
                 client_ref.send_to_channel(ChannelId(channel_id), &text);
             });
@@ -138,111 +328,547 @@ fn handle_cmd(
             file_path,
             duration_ms,
             announce_text,
+            title,
+            ..
         } => {
-            *stream_seq = stream_seq.wrapping_add(1);
-            stream_queue.push_back(StreamItem {
-                stream_id: *stream_seq,
-                channel_id,
-                file_path,
-                duration_ms,
-                announce_text,
+            let probed = async_client.with_client(|client_ref| {
+                stream::probe_duration(client_ref, &file_path, duration_ms)
             });
+            let Some(duration_ms) = probed else {
+                tracing::warn!(
+                    component = "tt_worker",
+                    file_path = %file_path,
+                    "Dropping stream with unknown duration"
+                );
+                stream::release_file(ctx, file_path);
+                return false;
+            };
+            queue.push(channel_id, file_path, duration_ms, announce_text, title);
             async_client.with_client_mut(|client_ref| {
-                start_next(client_ref, stream_queue, current_stream, tx_cmd_clone);
+                start_next(client_ref, queue, tx_cmd_clone);
             });
+            stream::publish_queue(ctx, queue);
         }
-        TtCommand::StopStreamingIf { stream_id } => {
-            if current_stream
-                .as_ref()
-                .map(|s| s.stream_id == stream_id)
-                .unwrap_or(false)
-            {
-                async_client.with_client_mut(|client_ref| {
-                    client_ref.stop_streaming();
-                });
-                let is_streaming = is_streaming.clone();
-                let tx_cmd_for_stop = tx_cmd_clone.clone();
-                tokio::task::spawn_local(async move {
-                    tokio::time::sleep(Duration::from_secs(2)).await;
-                    if is_streaming.load(std::sync::atomic::Ordering::Relaxed) {
-                        let _ = tx_cmd_for_stop
-                            .send(TtCommand::SetStreamingStatus { streaming: false })
-                            .await;
-                    }
-                });
-                *current_stream = None;
-                async_client.with_client_mut(|client_ref| {
-                    start_next(client_ref, stream_queue, current_stream, tx_cmd_clone);
-                });
+        TtCommand::StopStreamingIf { stream_id, .. } => {
+            if queue.current_id() == Some(stream_id) && !queue.is_paused() {
+                let remaining = queue.remaining(Instant::now()).unwrap_or_default();
+                if stream::needs_more_time(remaining) {
+                    stream::arm_stop_timer(tx_cmd_clone, ctx.server_id, stream_id, remaining);
+                } else {
+                    stop_current_stream(
+                        async_client,
+                        queue,
+                        tx_cmd_clone,
+                        is_streaming,
+                        ctx,
+                        false,
+                    );
+                    async_client.with_client_mut(|client_ref| {
+                        start_next(client_ref, queue, tx_cmd_clone);
+                    });
+                    stream::publish_queue(ctx, queue);
+                }
             }
         }
-        TtCommand::SkipStream => {
-            if current_stream.is_some() {
-                async_client.with_client_mut(|client_ref| {
-                    client_ref.stop_streaming();
-                });
-                let is_streaming = is_streaming.clone();
-                let tx_cmd_for_stop = tx_cmd_clone.clone();
-                tokio::task::spawn_local(async move {
-                    tokio::time::sleep(Duration::from_secs(2)).await;
-                    if is_streaming.load(std::sync::atomic::Ordering::Relaxed) {
-                        let _ = tx_cmd_for_stop
-                            .send(TtCommand::SetStreamingStatus { streaming: false })
-                            .await;
-                    }
-                });
-                *current_stream = None;
-            }
+        TtCommand::ResumeQueue { .. } => {
             async_client.with_client_mut(|client_ref| {
-                start_next(client_ref, stream_queue, current_stream, tx_cmd_clone);
+                start_next(client_ref, queue, tx_cmd_clone);
             });
+            stream::publish_queue(ctx, queue);
+        }
+        TtCommand::SkipStream { .. } => {
+            skip_stream(
+                async_client,
+                queue,
+                tx_cmd_clone,
+                is_streaming,
+                ctx,
+                start_next,
+            );
         }
-        TtCommand::SetStreamingStatus { streaming } => {
+        TtCommand::SetStreamingStatus { streaming, .. } => {
             if !streaming {
                 is_streaming.store(false, std::sync::atomic::Ordering::Relaxed);
             }
             async_client.with_client_mut(|client_ref| {
-                set_streaming_status(client_ref, streaming);
+                set_streaming_status(client_ref, streaming, false);
             });
         }
-        TtCommand::KickUser { user_id } => {
+        TtCommand::QueueControl {
+            chat_id,
+            message_id,
+            lang,
+            op,
+            ..
+        } => {
+            queue.watch(chat_id, QueueWatcher { message_id, lang });
+            match op {
+                QueueOp::Show => stream::publish_queue(ctx, queue),
+                QueueOp::Remove { stream_id } => {
+                    stream::discard(ctx, queue.remove(stream_id));
+                    stream::publish_queue(ctx, queue);
+                }
+                QueueOp::MoveTop { stream_id } => {
+                    queue.move_to_top(stream_id);
+                    stream::publish_queue(ctx, queue);
+                }
+                QueueOp::Clear => {
+                    stream::discard(ctx, queue.clear());
+                    stream::publish_queue(ctx, queue);
+                }
+                QueueOp::ToggleShuffle => {
+                    queue.toggle_shuffle();
+                    stream::publish_queue(ctx, queue);
+                }
+                QueueOp::CycleRepeat => {
+                    queue.cycle_repeat();
+                    stream::publish_queue(ctx, queue);
+                }
+                QueueOp::Pause | QueueOp::Resume => {
+                    let paused = op == QueueOp::Pause;
+                    let now = Instant::now();
+                    let changed = if paused {
+                        queue.pause(now)
+                    } else {
+                        queue.resume(now)
+                    };
+                    if changed {
+                        async_client.with_client_mut(|client_ref| {
+                            let playback = MediaPlayback {
+                                offset_ms: MEDIA_OFFSET_KEEP,
+                                paused,
+                                preprocessor: AudioPreprocessor::None,
+                            };
+                            if !client_ref.update_streaming(&playback, None) {
+                                tracing::warn!(
+                                    component = "tt_worker",
+                                    paused,
+                                    "Failed to update stream playback"
+                                );
+                            }
+                            set_streaming_status(client_ref, true, paused);
+                        });
+                        if let (false, Some(stream_id), Some(remaining)) =
+                            (paused, queue.current_id(), queue.remaining(now))
+                        {
+                            stream::arm_stop_timer(
+                                tx_cmd_clone,
+                                ctx.server_id,
+                                stream_id,
+                                remaining,
+                            );
+                        }
+                    }
+                    stream::publish_queue(ctx, queue);
+                }
+                QueueOp::Skip => {
+                    skip_stream(
+                        async_client,
+                        queue,
+                        tx_cmd_clone,
+                        is_streaming,
+                        ctx,
+                        start_next,
+                    );
+                }
+            }
+        }
+        TtCommand::KickUser { user_id, .. } => {
             async_client.with_client_mut(|client_ref| {
                 client_ref.kick_user(UserId(user_id), teamtalk::types::ChannelId(0));
             });
         }
-        TtCommand::BanUser { user_id } => {
+        TtCommand::BanUser { user_id, .. } => {
+            async_client.with_client_mut(|client_ref| {
+                client_ref.ban_user(UserId(user_id), ChannelId(0));
+                record_ban_channel(ctx, user_id, ChannelId(0));
+                refresh_server_bans(client_ref, ctx);
+            });
+        }
+        TtCommand::UnbanUser {
+            ban_id,
+            ip_address,
+            channel_id,
+            reply_to,
+            ..
+        } => {
+            if confirmations::join_pending_unban(ctx, ban_id, reply_to) {
+                return false;
+            }
+            tracing::info!(
+                component = "tt_worker",
+                server_id = %ctx.server_id,
+                ip_address = %ip_address,
+                "Lifting ban"
+            );
+            async_client.with_client_mut(|client_ref| {
+                let channel = channel_id.map_or_else(|| client_ref.my_channel_id(), ChannelId);
+                let cmd_id = client_ref.unban_user(&ip_address, channel);
+                if cmd_id > 0 {
+                    confirmations::track(
+                        ctx,
+                        cmd_id,
+                        confirmations::Confirmation::Unban {
+                            ban_id,
+                            ip_address,
+                            reply_to,
+                        },
+                    );
+                } else {
+                    tracing::warn!(
+                        component = "tt_worker",
+                        server_id = %ctx.server_id,
+                        ip_address = %ip_address,
+                        "Failed to send unban"
+                    );
+                    if let Some((chat_id, lang)) = reply_to {
+                        confirmations::report_unban(
+                            ctx,
+                            chat_id,
+                            lang,
+                            ip_address,
+                            Some(BanLiftError::Failed),
+                        );
+                    }
+                }
+            });
+        }
+        TtCommand::ServerBan { target, .. } => {
+            tracing::info!(
+                component = "tt_worker",
+                server_id = %ctx.server_id,
+                target = ?target,
+                "Adding server ban"
+            );
+            async_client.with_client_mut(|client_ref| {
+                match target {
+                    BanTarget::IpAddress(ip_address) => {
+                        client_ref.ban_ip_address(&ip_address, ChannelId(0));
+                    }
+                    BanTarget::Username(username) => {
+                        client_ref.ban(&BannedUser {
+                            username,
+                            ban_types: BanTypes::USERNAME,
+                            ..BannedUser::default()
+                        });
+                    }
+                }
+                refresh_server_bans(client_ref, ctx);
+            });
+        }
+        TtCommand::LiftServerBan { ban_key, .. } => {
+            let ban = ctx
+                .server_bans
+                .read()
+                .ok()
+                .and_then(|bans| bans.iter().find(|b| server_ban_key(b) == ban_key).cloned());
+            let Some(ban) = ban else {
+                tracing::warn!(
+                    component = "tt_worker",
+                    server_id = %ctx.server_id,
+                    "Server ban to lift is no longer listed"
+                );
+                return false;
+            };
+            tracing::info!(
+                component = "tt_worker",
+                server_id = %ctx.server_id,
+                ip_address = %ban.ip_address,
+                username = %ban.username,
+                "Lifting server ban"
+            );
+            async_client.with_client_mut(|client_ref| {
+                client_ref.unban_user_ex(&ban);
+                refresh_server_bans(client_ref, ctx);
+            });
+        }
+        TtCommand::LoadBans { .. } => {
+            async_client.with_client_mut(|client_ref| {
+                refresh_server_bans(client_ref, ctx);
+            });
+        }
+        TtCommand::MoveUser {
+            user_id,
+            channel_id,
+            chat_id,
+            lang,
+            ..
+        } => {
+            async_client.with_client_mut(|client_ref| {
+                channels::move_user(client_ref, ctx, user_id, channel_id, chat_id, lang);
+            });
+        }
+        TtCommand::CreateChannel {
+            parent_id,
+            channel,
+            chat_id,
+            lang,
+            ..
+        } => {
+            async_client.with_client_mut(|client_ref| {
+                channels::create_channel(client_ref, ctx, parent_id, channel, chat_id, lang);
+            });
+        }
+        TtCommand::EditChannel {
+            channel_id,
+            edit,
+            chat_id,
+            lang,
+            ..
+        } => {
             async_client.with_client_mut(|client_ref| {
-                client_ref.ban_user(UserId(user_id), client_ref.my_channel_id());
+                channels::edit_channel(client_ref, ctx, channel_id, edit, chat_id, lang);
+            });
+        }
+        TtCommand::DeleteChannel {
+            channel_id,
+            chat_id,
+            lang,
+            ..
+        } => {
+            async_client.with_client_mut(|client_ref| {
+                channels::delete_channel(client_ref, ctx, channel_id, chat_id, lang);
+            });
+        }
+        TtCommand::EditAccount {
+            username,
+            edit,
+            chat_id,
+            lang,
+            ..
+        } => {
+            async_client.with_client_mut(|client_ref| {
+                accounts::edit_account(client_ref, ctx, username, edit, chat_id, lang);
+            });
+        }
+        TtCommand::DeleteAccount {
+            username,
+            chat_id,
+            lang,
+            ..
+        } => {
+            async_client.with_client_mut(|client_ref| {
+                accounts::delete_account(client_ref, ctx, username, chat_id, lang);
+            });
+        }
+        TtCommand::RegisterAccount {
+            registration,
+            chat_id,
+            lang,
+            ..
+        } => {
+            async_client.with_client_mut(|client_ref| {
+                accounts::register_account(client_ref, ctx, registration, chat_id, lang);
+            });
+        }
+        TtCommand::EditServer {
+            edit,
+            chat_id,
+            lang,
+            ..
+        } => {
+            async_client.with_client_mut(|client_ref| {
+                server::edit_server(client_ref, ctx, edit, chat_id, lang);
             });
         }
         TtCommand::Who {
             chat_id,
             lang,
             reply_to,
+            ..
         } => {
             async_client.with_client(|client_ref| {
                 reports::handle_who_command(client_ref, ctx, chat_id, lang, reply_to);
             });
         }
-        TtCommand::LoadAccounts => {
+        TtCommand::LoadAccounts { .. } => {
             tracing::info!(
                 component = "tt_worker",
+                server_id = %ctx.server_id,
                 "Requesting full user accounts list"
             );
             async_client.with_client_mut(|client_ref| {
                 client_ref.list_user_accounts(0, 1000);
             });
         }
+        TtCommand::DownloadFile {
+            channel_id,
+            file_id,
+            chat_id,
+            lang,
+            ..
+        } => {
+            async_client.with_client(|client_ref| {
+                files::start_download(client_ref, ctx, channel_id, file_id, chat_id, lang);
+            });
+        }
+        TtCommand::UploadFile {
+            channel_id,
+            path,
+            chat_id,
+            lang,
+            ..
+        } => {
+            async_client.with_client(|client_ref| {
+                files::start_upload(client_ref, ctx, channel_id, path, chat_id, lang);
+            });
+        }
     }
     false
 }
 
+fn stop_current_stream(
+    async_client: &mut teamtalk::AsyncClient,
+    queue: &mut StreamQueue,
+    tx_cmd_clone: &Sender<TtCommand>,
+    is_streaming: &Arc<std::sync::atomic::AtomicBool>,
+    ctx: &WorkerContext,
+    skipped: bool,
+) {
+    if !queue.is_active() {
+        return;
+    }
+    let finished = queue.complete_current(skipped);
+    async_client.with_client_mut(|client_ref| {
+        client_ref.stop_streaming();
+    });
+    let is_streaming = is_streaming.clone();
+    let tx_cmd_for_stop = tx_cmd_clone.clone();
+    let server_id = ctx.server_id;
+    tokio::task::spawn_local(async move {
+        tokio::time::sleep(Duration::from_secs(2)).await;
+        if is_streaming.load(std::sync::atomic::Ordering::Relaxed) {
+            let _ = tx_cmd_for_stop
+                .send(TtCommand::SetStreamingStatus {
+                    server_id,
+                    streaming: false,
+                })
+                .await;
+        }
+    });
+    if let Some(item) = finished {
+        stream::schedule_cleanup(ctx, item.file_path);
+    }
+}
+
+fn skip_stream(
+    async_client: &mut teamtalk::AsyncClient,
+    queue: &mut StreamQueue,
+    tx_cmd_clone: &Sender<TtCommand>,
+    is_streaming: &Arc<std::sync::atomic::AtomicBool>,
+    ctx: &WorkerContext,
+    start_next: &StartNextFn,
+) {
+    stop_current_stream(async_client, queue, tx_cmd_clone, is_streaming, ctx, true);
+    async_client.with_client_mut(|client_ref| {
+        start_next(client_ref, queue, tx_cmd_clone);
+    });
+    stream::publish_queue(ctx, queue);
+}
+
+pub async fn remove_temp_file(path: String) {
+    let mut attempts = 0;
+    loop {
+        let delete_path_attempt = path.clone();
+        let res =
+            tokio::task::spawn_blocking(move || std::fs::remove_file(delete_path_attempt)).await;
+
+        match res {
+            Ok(Ok(())) => break,
+            Ok(Err(e)) => {
+                attempts += 1;
+                if attempts >= 10 {
+                    tracing::error!(
+                        file_path = %path,
+                        error = %e,
+                        "Failed to delete temporary file"
+                    );
+                    break;
+                }
+                tokio::time::sleep(Duration::from_secs(30)).await;
+            }
+            Err(e) => {
+                tracing::error!(
+                    file_path = %path,
+                    error = %e,
+                    "Failed to join blocking file delete task"
+                );
+                break;
+            }
+        }
+    }
+}
+
+fn record_ban_channel(ctx: &WorkerContext, user_id: i32, channel: ChannelId) {
+    let Some(ip_address) = ctx
+        .online_users
+        .read()
+        .ok()
+        .and_then(|users| users.get(&user_id).map(|u| u.ip_address.clone()))
+        .filter(|ip| !ip.is_empty())
+    else {
+        return;
+    };
+    let db = ctx.db.clone();
+    let server_key = ctx.tt_config().key();
+    tokio::task::spawn_local(async move {
+        if let Err(e) = db
+            .set_ban_channel(&server_key, &ip_address, channel.0)
+            .await
+        {
+            tracing::error!(ip_address = %ip_address, error = %e, "Failed to record ban channel");
+        }
+    });
+}
+
+pub(super) fn send_to_bridge(ctx: &WorkerContext, event: BridgeEvent) {
+    let tx_bridge = ctx.tx_bridge.clone();
+    tokio::task::spawn_local(async move {
+        if let Err(e) = tx_bridge.send(event).await {
+            tracing::error!(component = "tt_worker", error = %e, "Failed to send event to bridge");
+        }
+    });
+}
+
+pub(super) fn refresh_server_bans(client: &Client, ctx: &WorkerContext) {
+    if let Ok(mut bans) = ctx.server_bans.write() {
+        bans.clear();
+    }
+    client.list_bans(ChannelId(0), 0, 1000);
+}
+
+pub async fn run_command_router(mut rx_cmd: Receiver<TtCommand>, workers: Vec<Sender<TtCommand>>) {
+    while let Some(cmd) = rx_cmd.recv().await {
+        let Some(server_id) = cmd.server_id() else {
+            for worker in &workers {
+                let _ = worker.send(TtCommand::Shutdown).await;
+            }
+            break;
+        };
+        let Some(worker) = workers.get(server_id.0) else {
+            tracing::warn!(
+                component = "tt_router",
+                server_id = %server_id,
+                "Command for unknown TeamTalk server dropped"
+            );
+            continue;
+        };
+        if let Err(e) = worker.send(cmd).await {
+            tracing::error!(
+                component = "tt_router",
+                server_id = %server_id,
+                error = %e,
+                "Failed to forward TeamTalk command"
+            );
+        }
+    }
+}
+
 pub async fn run_teamtalk_worker(args: RunTeamtalkArgs) {
     let RunTeamtalkArgs {
+        server_id,
         config,
-        online_users,
-        online_users_by_username,
-        user_accounts,
+        shared,
         tx_bridge,
         mut rx_cmd,
         tx_cmd_clone,
@@ -251,19 +877,30 @@ pub async fn run_teamtalk_worker(args: RunTeamtalkArgs) {
         client,
         tx_init,
     } = args;
-    let tt_host_name = config.teamtalk.host_name.clone();
-    let tt_port = config.teamtalk.port as i32;
-    let tt_encrypted = config.teamtalk.encrypted;
-    let tt_status_text = config.teamtalk.status_text.clone();
+    let tt_config = config.teamtalk[server_id.0].clone();
+    let tt_host_name = tt_config.host_name.clone();
+    let tt_port = tt_config.port as i32;
+    let tt_encrypted = tt_config.encrypted;
+    let tt_status_text = tt_config.status_text.clone();
     let reconnect_retry_seconds = config.operational_parameters.tt_reconnect_retry;
     let reconnect_check_interval_seconds =
         config.operational_parameters.tt_reconnect_check_interval;
+    let notice_tx = notices::spawn_dispatcher(
+        Duration::from_secs(config.operational_parameters.reconnect_grace),
+        tx_bridge.clone(),
+    );
+    let session_tx = sessions::spawn_writer(db.clone(), tt_config.key());
 
     let ctx = WorkerContext {
+        server_id,
         config: config.clone(),
-        online_users: online_users.clone(),
-        online_users_by_username,
-        user_accounts,
+        online_users: shared.online_users,
+        online_users_by_username: shared.online_users_by_username,
+        user_accounts: shared.user_accounts,
+        bridge_channels: shared.bridge_channels,
+        server_bans: shared.server_bans,
+        channels: shared.channels,
+        server_info: shared.server_info,
         tx_bridge,
         tx_tt_cmd: tx_cmd_clone.clone(),
         db,
@@ -278,6 +915,16 @@ pub async fn run_teamtalk_worker(args: RunTeamtalkArgs) {
             tg_hits: AtomicU64::new(0),
             tg_misses: AtomicU64::new(0),
         }),
+        notices: notice_tx,
+        sessions: session_tx,
+        file_downloads: Arc::new(RwLock::new(HashMap::new())),
+        file_uploads: Arc::new(RwLock::new(HashMap::new())),
+        pending_server_edit: Arc::new(RwLock::new(None)),
+        confirmations: Arc::new(RwLock::new(HashMap::new())),
+        health: Arc::new(RwLock::new(HealthTracker::new(
+            Duration::from_secs(config.operational_parameters.health_alert_cooldown),
+            config.operational_parameters.login_failure_alert_threshold,
+        ))),
     };
     let is_streaming = ctx.is_streaming.clone();
     let tt_lang_cache = ctx.tt_lang_cache.clone();
@@ -305,6 +952,24 @@ pub async fn run_teamtalk_worker(args: RunTeamtalkArgs) {
         );
     }
 
+    match db_for_cache.close_open_sessions(&tt_config.key()).await {
+        Ok(closed) if closed > 0 => {
+            tracing::info!(
+                component = "tt_worker",
+                closed,
+                "Closed stale sessions from previous run"
+            );
+        }
+        Ok(_) => {}
+        Err(e) => {
+            tracing::warn!(
+                component = "tt_worker",
+                error = %e,
+                "Failed to close stale sessions"
+            );
+        }
+    }
+
     let db_for_refresh = ctx.db.clone();
     let tt_lang_cache_refresh = ctx.tt_lang_cache.clone();
     let tt_tg_cache_refresh = ctx.tt_tg_cache.clone();
@@ -334,17 +999,60 @@ pub async fn run_teamtalk_worker(args: RunTeamtalkArgs) {
         }
     });
 
+    let db_for_samples = ctx.db.clone();
+    let online_users_for_samples = ctx.online_users.clone();
+    let sample_server_key = tt_config.key();
+    let sample_interval = config.operational_parameters.online_sample_interval.max(1);
+    let samples_ready = Arc::new(std::sync::atomic::AtomicBool::new(false));
+    let samples_ready_for_task = samples_ready.clone();
+    tokio::task::spawn_local(async move {
+        let mut tick = interval(Duration::from_secs(sample_interval));
+        loop {
+            tick.tick().await;
+            if !samples_ready_for_task.load(Ordering::Relaxed) {
+                continue;
+            }
+            let count = online_users_for_samples
+                .read()
+                .map(|users| users.len())
+                .unwrap_or(0);
+            let now = chrono::Utc::now().naive_utc();
+            if let Err(e) = db_for_samples
+                .add_online_sample(&sample_server_key, now, count as i64)
+                .await
+            {
+                tracing::warn!(
+                    component = "tt_worker",
+                    error = %e,
+                    "Failed to record online sample"
+                );
+            }
+            if let Err(e) = db_for_samples
+                .cleanup_online_samples(now - chrono::Duration::days(ONLINE_SAMPLE_RETENTION_DAYS))
+                .await
+            {
+                tracing::warn!(
+                    component = "tt_worker",
+                    error = %e,
+                    "Failed to clean up online samples"
+                );
+            }
+        }
+    });
+
     let _ = tx_init.send(Ok(()));
     let mut ready_time: Option<std::time::Instant> = None;
     let mut is_connected = false;
-    let mut stream_queue: VecDeque<StreamItem> = VecDeque::new();
-    let mut current_stream: Option<StreamItem> = None;
-    let mut stream_seq: u64 = 0;
+    let mut queue = StreamQueue::default();
+    let queue_server_key = tt_config.key();
+    let saved_queue = stream::restore_queue(&ctx.db, &queue_server_key, &mut queue).await;
+    let mut queue_store = stream::QueueStore::new(ctx.db.clone(), queue_server_key, saved_queue);
     let status_gender = config.general.gender.to_user_gender();
-    let set_streaming_status = move |client: &Client, streaming: bool| {
+    let set_streaming_status = move |client: &Client, streaming: bool, media_paused: bool| {
         let status = UserStatus {
             gender: status_gender,
             streaming,
+            media_paused,
             ..UserStatus::default()
         };
         client.set_status(status, &tt_status_text);
@@ -359,6 +1067,7 @@ pub async fn run_teamtalk_worker(args: RunTeamtalkArgs) {
 
     tracing::info!(
         component = "tt_worker",
+        server_id = %server_id,
         host = %tt_host_name,
         port = tt_port,
         encrypted = tt_encrypted,
@@ -369,6 +1078,7 @@ pub async fn run_teamtalk_worker(args: RunTeamtalkArgs) {
 
     if let Err(e) = client.connect(&tt_host_name, tt_port, tt_port, tt_encrypted) {
         tracing::error!(
+            server_id = %server_id,
             host = %tt_host_name,
             port = tt_port,
             encrypted = tt_encrypted,
@@ -381,14 +1091,12 @@ pub async fn run_teamtalk_worker(args: RunTeamtalkArgs) {
     let tt_host_name_for_driver = tt_host_name.clone();
     let tt_port_for_driver = tt_port;
     let tt_encrypted_for_driver = tt_encrypted;
-    let start_next = move |client: &Client,
-                           queue: &mut VecDeque<StreamItem>,
-                           current: &mut Option<StreamItem>,
-                           tx_cmd: &Sender<TtCommand>| {
-        if current.is_some() {
+    let spool_dir = config.database.spool_dir();
+    let start_next = move |client: &Client, queue: &mut StreamQueue, tx_cmd: &Sender<TtCommand>| {
+        if queue.is_active() || client.my_channel_id().0 <= 0 {
             return;
         }
-        while let Some(mut item) = queue.pop_front() {
+        while let Some(mut item) = queue.pop_next() {
             let channel_id = if item.channel_id == 0 {
                 client.my_channel_id().0
             } else {
@@ -408,58 +1116,22 @@ pub async fn run_teamtalk_worker(args: RunTeamtalkArgs) {
                     file_path = %item.file_path,
                     "Failed to start streaming"
                 );
-                let delete_path = item.file_path.clone();
-                tokio::task::spawn_blocking(move || {
-                    let _ = std::fs::remove_file(&delete_path);
-                });
+                if stream::is_spooled(&spool_dir, &item.file_path) {
+                    let delete_path = item.file_path.clone();
+                    tokio::task::spawn_blocking(move || {
+                        let _ = std::fs::remove_file(&delete_path);
+                    });
+                }
                 continue;
             }
             is_streaming_for_start.store(true, std::sync::atomic::Ordering::Relaxed);
-            let stream_id = item.stream_id;
-            let delete_path = item.file_path.clone();
-            let duration_ms = item.duration_ms;
-            let tx_cmd_for_stop = tx_cmd.clone();
-            tokio::task::spawn_local(async move {
-                tokio::time::sleep(Duration::from_millis(duration_ms as u64)).await;
-                let _ = tx_cmd_for_stop
-                    .send(TtCommand::StopStreamingIf { stream_id })
-                    .await;
-
-                tokio::time::sleep(Duration::from_millis(10_000)).await;
-                let mut attempts = 0;
-                loop {
-                    let delete_path_attempt = delete_path.clone();
-                    let res = tokio::task::spawn_blocking(move || {
-                        std::fs::remove_file(delete_path_attempt)
-                    })
-                    .await;
-
-                    match res {
-                        Ok(Ok(())) => break,
-                        Ok(Err(e)) => {
-                            attempts += 1;
-                            if attempts >= 10 {
-                                tracing::error!(
-                                    file_path = %delete_path,
-                                    error = %e,
-                                    "Failed to delete streamed file"
-                                );
-                                break;
-                            }
-                            tokio::time::sleep(Duration::from_secs(30)).await;
-                        }
-                        Err(e) => {
-                            tracing::error!(
-                                file_path = %delete_path,
-                                error = %e,
-                                "Failed to join blocking file delete task"
-                            );
-                            break;
-                        }
-                    }
-                }
-            });
-            *current = Some(item);
+            stream::arm_stop_timer(
+                tx_cmd,
+                server_id,
+                item.stream_id,
+                Duration::from_millis(u64::from(item.duration_ms)),
+            );
+            queue.start(item, Instant::now());
             break;
         }
     };
@@ -481,8 +1153,18 @@ pub async fn run_teamtalk_worker(args: RunTeamtalkArgs) {
             udp: tt_port_for_driver,
             encrypted: tt_encrypted_for_driver,
         };
+        let mut now_playing_tick = interval(Duration::from_secs(stream::NOW_PLAYING_REFRESH_SECS));
+        let mut confirmation_tick = interval(confirmations::CONFIRMATION_SWEEP_INTERVAL);
+        let mut health_tick = interval(health::DEFERRED_ALERT_CHECK_INTERVAL);
         let shutdown = loop {
             tokio::select! {
+                _ = confirmation_tick.tick() => confirmations::expire_stale(&ctx),
+                _ = health_tick.tick() => health::check_deferred(&ctx),
+                _ = now_playing_tick.tick() => {
+                    if queue.is_active() && !queue.is_paused() {
+                        stream::publish_queue(&ctx, &mut queue);
+                    }
+                }
                 maybe_cmd = cmd_rx.recv() => {
                     let Some(cmd) = maybe_cmd else {
                         break true;
@@ -490,9 +1172,7 @@ pub async fn run_teamtalk_worker(args: RunTeamtalkArgs) {
                     if handle_cmd(
                         cmd,
                         &mut async_client,
-                        &mut stream_seq,
-                        &mut stream_queue,
-                        &mut current_stream,
+                        &mut queue,
                         &tx_cmd_clone,
                         &is_streaming,
                         &ctx,
@@ -507,10 +1187,11 @@ pub async fn run_teamtalk_worker(args: RunTeamtalkArgs) {
                         break true;
                     };
 
-                    if current_stream.is_some() && matches!(event, teamtalk::events::Event::CmdProcessing) {
+                    if queue.is_active() && matches!(event, teamtalk::events::Event::CmdProcessing) {
                         continue;
                     }
 
+                    let was_ready = ready_time.is_some();
                     async_client.with_client(|client_ref| {
                         events::handle_sdk_event(
                             client_ref,
@@ -522,6 +1203,23 @@ pub async fn run_teamtalk_worker(args: RunTeamtalkArgs) {
                             &mut ready_time,
                         );
                     });
+                    samples_ready.store(ready_time.is_some(), Ordering::Relaxed);
+
+                    match (was_ready, ready_time.is_some()) {
+                        (true, false) => {
+                            if queue.interrupt() {
+                                tracing::info!(
+                                    component = "tt_worker",
+                                    "Stream interrupted by disconnect; re-queued"
+                                );
+                            }
+                            is_streaming.store(false, std::sync::atomic::Ordering::Relaxed);
+                        }
+                        (false, true) if !queue.is_idle() => {
+                            stream::arm_queue_resume(&tx_cmd_clone, server_id);
+                        }
+                        _ => {}
+                    }
 
                     if !is_connected {
                         async_client.with_client_mut(|client_ref| {
@@ -533,9 +1231,7 @@ pub async fn run_teamtalk_worker(args: RunTeamtalkArgs) {
                         if handle_cmd(
                             cmd,
                             &mut async_client,
-                            &mut stream_seq,
-                            &mut stream_queue,
-                            &mut current_stream,
+                            &mut queue,
                             &tx_cmd_clone,
                             &is_streaming,
                             &ctx,
@@ -551,24 +1247,26 @@ pub async fn run_teamtalk_worker(args: RunTeamtalkArgs) {
                     }
                 }
             }
+            queue_store.sync(&queue);
         };
+        queue_store.flush(&queue).await;
 
         if shutdown {
-            tracing::info!(component = "tt_worker", "Shutdown requested");
-            if current_stream.is_some() {
+            tracing::info!(component = "tt_worker", server_id = %server_id, "Shutdown requested");
+            if queue.is_active() {
                 tracing::info!(component = "tt_worker", "Stopping active stream");
                 async_client.with_client_mut(|client_ref| {
                     client_ref.stop_streaming();
                 });
             }
-            tracing::info!(component = "tt_worker", "Logging out");
+            tracing::info!(component = "tt_worker", server_id = %server_id, "Logging out");
             async_client.with_client_mut(|client_ref| {
                 client_ref.logout();
             });
         }
 
         if let Some(client) = async_client.into_client() {
-            tracing::info!(component = "tt_worker", "Disconnecting");
+            tracing::info!(component = "tt_worker", server_id = %server_id, "Disconnecting");
             let _ = client.disconnect();
         }
     });
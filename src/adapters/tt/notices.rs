@@ -0,0 +1,93 @@
+#![allow(clippy::pedantic, clippy::nursery)]
+
+use crate::adapters::tt::WorkerContext;
+use crate::app::services::reconnect_grace::ReconnectGrace;
+use crate::core::types::BridgeEvent;
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc::{self, UnboundedSender};
+
+pub enum Notice {
+    Event(BridgeEvent),
+    Leave {
+        username: String,
+        event: BridgeEvent,
+    },
+    Login {
+        username: String,
+        event: Option<BridgeEvent>,
+    },
+    ChannelLeave {
+        username: String,
+        event: BridgeEvent,
+    },
+    ChannelJoin {
+        username: String,
+        event: BridgeEvent,
+    },
+    Reset,
+}
+
+pub(super) fn spawn_dispatcher(
+    window: Duration,
+    tx_bridge: mpsc::Sender<BridgeEvent>,
+) -> UnboundedSender<Notice> {
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    tokio::task::spawn_local(async move {
+        let mut grace = ReconnectGrace::new(window);
+        loop {
+            let deadline = grace.next_deadline();
+            let notice = tokio::select! {
+                notice = rx.recv() => match notice {
+                    Some(notice) => Some(notice),
+                    None => break,
+                },
+                () = sleep_until(deadline), if deadline.is_some() => None,
+            };
+            let mut ready = Vec::new();
+            match notice {
+                Some(Notice::Event(event)) => ready.push(event),
+                Some(Notice::Leave { username, event }) => {
+                    ready.extend(grace.hold_leave(&username, Instant::now(), event));
+                }
+                Some(Notice::Login { username, event }) => {
+                    if grace.logged_in(&username, Instant::now()) {
+                        tracing::debug!(
+                            component = "tt_worker",
+                            username = %username,
+                            "Reconnect within grace window; notifications suppressed"
+                        );
+                    } else {
+                        ready.extend(event);
+                    }
+                }
+                Some(Notice::ChannelLeave { username, event }) => {
+                    ready.extend(grace.hold_channel_leave(&username, Instant::now(), event));
+                }
+                Some(Notice::ChannelJoin { username, event }) => {
+                    ready.extend(grace.channel_joined(&username, Instant::now(), event));
+                }
+                Some(Notice::Reset) => grace.clear(),
+                None => {}
+            }
+            ready.extend(grace.take_expired(Instant::now()));
+            for event in ready {
+                if let Err(e) = tx_bridge.send(event).await {
+                    tracing::error!(error = %e, "Failed to send broadcast");
+                }
+            }
+        }
+    });
+    tx
+}
+
+async fn sleep_until(deadline: Option<Instant>) {
+    if let Some(deadline) = deadline {
+        tokio::time::sleep_until(tokio::time::Instant::from_std(deadline)).await;
+    }
+}
+
+pub(super) fn send(ctx: &WorkerContext, notice: Notice) {
+    if ctx.notices.send(notice).is_err() {
+        tracing::error!(component = "tt_worker", "Notice dispatcher has stopped");
+    }
+}
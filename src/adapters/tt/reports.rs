@@ -16,7 +16,7 @@ pub(super) fn handle_who_command(
     lang: LanguageCode,
     reply_to: Option<i32>,
 ) {
-    let tt_config = &ctx.config.teamtalk;
+    let tt_config = ctx.tt_config();
 
     let real_name = client.get_server_properties().map(|p| p.name);
     let server_name = resolve_server_name(tt_config, real_name.as_deref());
@@ -0,0 +1,140 @@
+#![allow(clippy::pedantic, clippy::nursery)]
+
+use crate::adapters::tt::{WorkerContext, send_to_bridge};
+use crate::core::types::{BridgeEvent, LanguageCode, LiteServerInfo, ServerEdit, ServerField};
+use std::time::{Duration, Instant};
+use teamtalk::Client;
+use teamtalk::types::ServerProperties;
+
+const EDIT_CONFIRM_WINDOW: Duration = Duration::from_secs(30);
+
+pub struct PendingServerEdit {
+    pub chat_id: i64,
+    pub lang: LanguageCode,
+    pub edit: ServerEdit,
+    pub sent_at: Instant,
+}
+
+fn lite_info(props: &ServerProperties) -> LiteServerInfo {
+    LiteServerInfo {
+        name: props.name.clone(),
+        motd: props.motd.clone(),
+        max_users: props.max_users,
+        login_delay_ms: props.login_delay_msec,
+        version: props.version.clone(),
+    }
+}
+
+pub(super) fn refresh_server_info(client: &Client, ctx: &WorkerContext) -> Option<LiteServerInfo> {
+    let current = client
+        .get_server_properties()
+        .map(|props| lite_info(&props));
+    let mut cache = ctx.server_info.write().ok()?;
+    std::mem::replace(&mut *cache, current)
+}
+
+fn edit_applied(edit: &ServerEdit, props: &ServerProperties) -> bool {
+    match edit {
+        ServerEdit::Name(name) => props.name == *name,
+        ServerEdit::Motd(motd) => props.motd_raw == *motd,
+    }
+}
+
+pub(super) fn server_updated(client: &Client, ctx: &WorkerContext) {
+    let previous = refresh_server_info(client, ctx);
+    let props = client.get_server_properties();
+    let pending = ctx
+        .pending_server_edit
+        .write()
+        .ok()
+        .and_then(|mut pending| {
+            pending.take_if(|p| p.sent_at.elapsed() >= EDIT_CONFIRM_WINDOW);
+            pending.take_if(|p| {
+                props
+                    .as_ref()
+                    .is_some_and(|props| edit_applied(&p.edit, props))
+            })
+        });
+    if let Some(PendingServerEdit {
+        chat_id,
+        lang,
+        edit,
+        ..
+    }) = pending
+    {
+        send_to_bridge(
+            ctx,
+            BridgeEvent::ServerEdited {
+                chat_id,
+                lang,
+                field: edit.field(),
+            },
+        );
+        return;
+    }
+    let current = ctx.server_info.read().ok().and_then(|info| info.clone());
+    if let (Some(previous), Some(current)) = (previous, current)
+        && previous != current
+    {
+        tracing::info!(
+            component = "tt_worker",
+            server = %current.name,
+            "Server properties changed outside the bot"
+        );
+        send_to_bridge(
+            ctx,
+            BridgeEvent::ServerInfoChanged {
+                server_id: ctx.server_id,
+                previous,
+                current,
+            },
+        );
+    }
+}
+
+pub(super) fn edit_server(
+    client: &Client,
+    ctx: &WorkerContext,
+    edit: ServerEdit,
+    chat_id: i64,
+    lang: LanguageCode,
+) {
+    let field = edit.field();
+    let Some(mut props) = client.get_server_properties() else {
+        fail_edit(ctx, chat_id, lang, field);
+        return;
+    };
+    match &edit {
+        ServerEdit::Name(name) => props.name = name.clone(),
+        ServerEdit::Motd(motd) => props.motd_raw = motd.clone(),
+    }
+    if client.update_server(&props) <= 0 {
+        tracing::error!(
+            component = "tt_worker",
+            field = ?field,
+            "Failed to send server update"
+        );
+        fail_edit(ctx, chat_id, lang, field);
+        return;
+    }
+    tracing::info!(component = "tt_worker", field = ?field, "Server update sent");
+    if let Ok(mut pending) = ctx.pending_server_edit.write() {
+        *pending = Some(PendingServerEdit {
+            chat_id,
+            lang,
+            edit,
+            sent_at: Instant::now(),
+        });
+    }
+}
+
+fn fail_edit(ctx: &WorkerContext, chat_id: i64, lang: LanguageCode, field: ServerField) {
+    send_to_bridge(
+        ctx,
+        BridgeEvent::ServerEditFailed {
+            chat_id,
+            lang,
+            field,
+        },
+    );
+}
@@ -0,0 +1,68 @@
+#![allow(clippy::pedantic, clippy::nursery)]
+
+use crate::adapters::tt::WorkerContext;
+use crate::infra::db::Database;
+use tokio::sync::mpsc::{self, UnboundedSender};
+
+pub enum SessionWrite {
+    Open { username: String, nickname: String },
+    Channel { username: String, path: String },
+    Close { username: String },
+    CloseAll,
+}
+
+pub(super) fn spawn_writer(db: Database, server_key: String) -> UnboundedSender<SessionWrite> {
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    tokio::task::spawn_local(async move {
+        while let Some(write) = rx.recv().await {
+            let (username, res) = match write {
+                SessionWrite::Open { username, nickname } => {
+                    let res = db.open_session(&server_key, &username, &nickname).await;
+                    (username, res)
+                }
+                SessionWrite::Channel { username, path } => {
+                    let res = db
+                        .update_session_channel(&server_key, &username, &path)
+                        .await;
+                    (username, res)
+                }
+                SessionWrite::Close { username } => {
+                    let res = db.close_session(&server_key, &username).await;
+                    (username, res)
+                }
+                SessionWrite::CloseAll => {
+                    if let Err(e) = db.close_open_sessions(&server_key).await {
+                        tracing::error!(
+                            component = "tt_worker",
+                            error = %e,
+                            "Failed to close open sessions"
+                        );
+                    }
+                    continue;
+                }
+            };
+            if let Err(e) = res {
+                tracing::error!(
+                    component = "tt_worker",
+                    tt_username = %username,
+                    error = %e,
+                    "Failed to record user session"
+                );
+            }
+        }
+    });
+    tx
+}
+
+pub(super) fn record(ctx: &WorkerContext, write: SessionWrite) {
+    if let SessionWrite::Open { username, .. }
+    | SessionWrite::Channel { username, .. }
+    | SessionWrite::Close { username } = &write
+        && username.is_empty()
+    {
+        return;
+    }
+    if ctx.sessions.send(write).is_err() {
+        tracing::error!(component = "tt_worker", "Session writer has stopped");
+    }
+}
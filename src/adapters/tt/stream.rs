@@ -0,0 +1,500 @@
+#![allow(clippy::pedantic, clippy::nursery)]
+
+use crate::adapters::tt::{WorkerContext, remove_temp_file};
+use crate::core::types::{
+    BridgeEvent, LanguageCode, NowPlaying, QueueEntry, QueueSnapshot, RepeatMode, ServerId,
+    TtCommand,
+};
+use crate::infra::db::{Database, types::QueuedStream};
+use anyhow::Result;
+use std::collections::hash_map::RandomState;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::{BuildHasher, Hasher};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use teamtalk::Client;
+use tokio::sync::mpsc::Sender;
+use tokio::sync::watch;
+
+pub(super) const NOW_PLAYING_REFRESH_SECS: u64 = 10;
+const STOP_TOLERANCE: Duration = Duration::from_millis(500);
+const CLEANUP_DELAY: Duration = Duration::from_secs(10);
+const RESUME_DELAY: Duration = Duration::from_secs(2);
+
+pub(super) struct StreamItem {
+    pub stream_id: u64,
+    pub channel_id: i32,
+    pub file_path: String,
+    pub duration_ms: u32,
+    pub announce_text: Option<String>,
+    pub title: Option<String>,
+}
+
+impl StreamItem {
+    fn entry(&self) -> QueueEntry {
+        QueueEntry {
+            stream_id: self.stream_id,
+            title: self.title.clone(),
+            duration_ms: self.duration_ms,
+        }
+    }
+
+    fn record(&self) -> QueuedStream {
+        QueuedStream {
+            channel_id: i64::from(self.channel_id),
+            file_path: self.file_path.clone(),
+            duration_ms: i64::from(self.duration_ms),
+            announce_text: self.announce_text.clone(),
+            title: self.title.clone(),
+        }
+    }
+}
+
+pub(super) struct PlayingItem {
+    pub item: StreamItem,
+    started_at: Instant,
+    paused_at: Option<Instant>,
+    paused_total: Duration,
+}
+
+impl PlayingItem {
+    fn elapsed(&self, now: Instant) -> Duration {
+        let until = self.paused_at.unwrap_or(now);
+        until
+            .saturating_duration_since(self.started_at)
+            .saturating_sub(self.paused_total)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct QueueWatcher {
+    pub message_id: i32,
+    pub lang: LanguageCode,
+}
+
+#[derive(Default)]
+pub(super) struct StreamQueue {
+    seq: u64,
+    pending: VecDeque<StreamItem>,
+    current: Option<PlayingItem>,
+    watchers: HashMap<i64, QueueWatcher>,
+    shuffle: bool,
+    repeat: RepeatMode,
+}
+
+impl StreamQueue {
+    pub fn push(
+        &mut self,
+        channel_id: i32,
+        file_path: String,
+        duration_ms: u32,
+        announce_text: Option<String>,
+        title: Option<String>,
+    ) -> u64 {
+        self.seq = self.seq.wrapping_add(1);
+        self.pending.push_back(StreamItem {
+            stream_id: self.seq,
+            channel_id,
+            file_path,
+            duration_ms,
+            announce_text,
+            title,
+        });
+        self.seq
+    }
+
+    pub fn pop_next(&mut self) -> Option<StreamItem> {
+        if self.shuffle && self.pending.len() > 1 {
+            let pick = random_index(self.pending.len());
+            return self.pending.remove(pick);
+        }
+        self.pending.pop_front()
+    }
+
+    pub fn start(&mut self, item: StreamItem, now: Instant) {
+        self.current = Some(PlayingItem {
+            item,
+            started_at: now,
+            paused_at: None,
+            paused_total: Duration::ZERO,
+        });
+    }
+
+    pub const fn is_active(&self) -> bool {
+        self.current.is_some()
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.current
+            .as_ref()
+            .is_some_and(|playing| playing.paused_at.is_some())
+    }
+
+    pub fn current_id(&self) -> Option<u64> {
+        self.current.as_ref().map(|playing| playing.item.stream_id)
+    }
+
+    pub fn finish_current(&mut self) -> Option<StreamItem> {
+        self.current.take().map(|playing| playing.item)
+    }
+
+    pub fn complete_current(&mut self, skipped: bool) -> Option<StreamItem> {
+        let item = self.finish_current()?;
+        match self.repeat {
+            RepeatMode::One if !skipped => self.pending.push_front(item),
+            RepeatMode::All => self.pending.push_back(item),
+            _ => return Some(item),
+        }
+        None
+    }
+
+    pub fn toggle_shuffle(&mut self) -> bool {
+        self.shuffle = !self.shuffle;
+        self.shuffle
+    }
+
+    pub fn cycle_repeat(&mut self) -> RepeatMode {
+        self.repeat = self.repeat.next();
+        self.repeat
+    }
+
+    pub fn interrupt(&mut self) -> bool {
+        match self.current.take() {
+            Some(playing) => {
+                self.pending.push_front(playing.item);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn remaining(&self, now: Instant) -> Option<Duration> {
+        self.current.as_ref().map(|playing| {
+            Duration::from_millis(u64::from(playing.item.duration_ms))
+                .saturating_sub(playing.elapsed(now))
+        })
+    }
+
+    pub fn pause(&mut self, now: Instant) -> bool {
+        match self.current.as_mut() {
+            Some(playing) if playing.paused_at.is_none() => {
+                playing.paused_at = Some(now);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn resume(&mut self, now: Instant) -> bool {
+        match self.current.as_mut() {
+            Some(playing) => match playing.paused_at.take() {
+                Some(paused_at) => {
+                    playing.paused_total += now.saturating_duration_since(paused_at);
+                    true
+                }
+                None => false,
+            },
+            None => false,
+        }
+    }
+
+    pub fn remove(&mut self, stream_id: u64) -> Option<StreamItem> {
+        let pos = self
+            .pending
+            .iter()
+            .position(|item| item.stream_id == stream_id)?;
+        self.pending.remove(pos)
+    }
+
+    pub fn move_to_top(&mut self, stream_id: u64) -> bool {
+        match self.remove(stream_id) {
+            Some(item) => {
+                self.pending.push_front(item);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn clear(&mut self) -> Vec<StreamItem> {
+        self.pending.drain(..).collect()
+    }
+
+    pub fn snapshot(&self, now: Instant) -> QueueSnapshot {
+        QueueSnapshot {
+            now_playing: self.current.as_ref().map(|playing| NowPlaying {
+                entry: playing.item.entry(),
+                elapsed_ms: u64::try_from(playing.elapsed(now).as_millis()).unwrap_or(u64::MAX),
+                paused: playing.paused_at.is_some(),
+            }),
+            pending: self.pending.iter().map(StreamItem::entry).collect(),
+            shuffle: self.shuffle,
+            repeat: self.repeat,
+        }
+    }
+
+    pub fn records(&self) -> Vec<QueuedStream> {
+        self.current
+            .iter()
+            .map(|playing| &playing.item)
+            .chain(&self.pending)
+            .map(StreamItem::record)
+            .collect()
+    }
+
+    pub fn watch(&mut self, chat_id: i64, watcher: QueueWatcher) {
+        self.watchers.insert(chat_id, watcher);
+    }
+
+    pub fn watchers(&self) -> impl Iterator<Item = (i64, QueueWatcher)> + '_ {
+        self.watchers
+            .iter()
+            .map(|(chat_id, watcher)| (*chat_id, *watcher))
+    }
+
+    pub fn has_watchers(&self) -> bool {
+        !self.watchers.is_empty()
+    }
+
+    pub fn is_idle(&self) -> bool {
+        self.current.is_none() && self.pending.is_empty()
+    }
+
+    pub fn forget_watchers(&mut self) {
+        self.watchers.clear();
+    }
+}
+
+pub(super) struct QueueStore {
+    saved: Vec<QueuedStream>,
+    tx: watch::Sender<Vec<QueuedStream>>,
+    db: Database,
+    server_key: String,
+}
+
+impl QueueStore {
+    pub fn new(db: Database, server_key: String, saved: Vec<QueuedStream>) -> Self {
+        let (tx, mut rx) = watch::channel(saved.clone());
+        let writer_db = db.clone();
+        let writer_key = server_key.clone();
+        tokio::task::spawn_local(async move {
+            while rx.changed().await.is_ok() {
+                let items = rx.borrow_and_update().clone();
+                if let Err(e) = writer_db.replace_stream_queue(&writer_key, &items).await {
+                    tracing::warn!(
+                        component = "tt_worker",
+                        error = %e,
+                        "Failed to persist stream queue"
+                    );
+                }
+            }
+        });
+        Self {
+            saved,
+            tx,
+            db,
+            server_key,
+        }
+    }
+
+    pub fn sync(&mut self, queue: &StreamQueue) {
+        let records = queue.records();
+        if records != self.saved {
+            self.saved.clone_from(&records);
+            let _ = self.tx.send(records);
+        }
+    }
+
+    pub async fn flush(&mut self, queue: &StreamQueue) {
+        self.saved = queue.records();
+        if let Err(e) = self
+            .db
+            .replace_stream_queue(&self.server_key, &self.saved)
+            .await
+        {
+            tracing::warn!(
+                component = "tt_worker",
+                error = %e,
+                "Failed to persist stream queue on shutdown"
+            );
+        }
+    }
+}
+
+pub(super) async fn restore_queue(
+    db: &Database,
+    server_key: &str,
+    queue: &mut StreamQueue,
+) -> Vec<QueuedStream> {
+    let saved = match db.get_stream_queue(server_key).await {
+        Ok(saved) => saved,
+        Err(e) => {
+            tracing::warn!(
+                component = "tt_worker",
+                error = %e,
+                "Failed to load saved stream queue"
+            );
+            return Vec::new();
+        }
+    };
+    for record in &saved {
+        if !tokio::fs::try_exists(&record.file_path)
+            .await
+            .unwrap_or(false)
+        {
+            tracing::warn!(
+                component = "tt_worker",
+                file_path = %record.file_path,
+                "Dropping saved stream with missing file"
+            );
+            continue;
+        }
+        queue.push(
+            i32::try_from(record.channel_id).unwrap_or(0),
+            record.file_path.clone(),
+            u32::try_from(record.duration_ms).unwrap_or(0),
+            record.announce_text.clone(),
+            record.title.clone(),
+        );
+    }
+    if !saved.is_empty() {
+        tracing::info!(
+            component = "tt_worker",
+            restored = queue.records().len(),
+            "Restored stream queue from previous run"
+        );
+    }
+    saved
+}
+
+pub async fn sweep_spool(db: &Database, spool_dir: &Path) -> Result<usize> {
+    tokio::fs::create_dir_all(spool_dir).await?;
+    let referenced: HashSet<PathBuf> = db
+        .get_stream_queue_paths()
+        .await?
+        .into_iter()
+        .map(PathBuf::from)
+        .collect();
+    let mut removed = 0;
+    let mut entries = tokio::fs::read_dir(spool_dir).await?;
+    while let Some(entry) = entries.next_entry().await? {
+        let path = entry.path();
+        if referenced.contains(&path) || !entry.file_type().await?.is_file() {
+            continue;
+        }
+        match tokio::fs::remove_file(&path).await {
+            Ok(()) => removed += 1,
+            Err(e) => {
+                tracing::warn!(
+                    file_path = %path.display(),
+                    error = %e,
+                    "Failed to remove orphaned spool file"
+                );
+            }
+        }
+    }
+    Ok(removed)
+}
+
+pub(super) fn resolve_duration(probed_ms: Option<u32>, reported_ms: u32) -> Option<u32> {
+    probed_ms
+        .filter(|ms| *ms > 0)
+        .or(Some(reported_ms).filter(|ms| *ms > 0))
+}
+
+pub(super) fn probe_duration(client: &Client, file_path: &str, reported_ms: u32) -> Option<u32> {
+    let probed_ms = client
+        .get_media_file_info(file_path)
+        .map(|info| info.duration_ms);
+    resolve_duration(probed_ms, reported_ms)
+}
+
+fn random_index(len: usize) -> usize {
+    let seed = RandomState::new().build_hasher().finish();
+    usize::try_from(seed % len as u64).unwrap_or(0)
+}
+
+pub(super) fn needs_more_time(remaining: Duration) -> bool {
+    remaining > STOP_TOLERANCE
+}
+
+pub(super) fn arm_stop_timer(
+    tx_cmd: &Sender<TtCommand>,
+    server_id: ServerId,
+    stream_id: u64,
+    delay: Duration,
+) {
+    let tx_cmd = tx_cmd.clone();
+    tokio::task::spawn_local(async move {
+        tokio::time::sleep(delay).await;
+        let _ = tx_cmd
+            .send(TtCommand::StopStreamingIf {
+                server_id,
+                stream_id,
+            })
+            .await;
+    });
+}
+
+pub(super) fn arm_queue_resume(tx_cmd: &Sender<TtCommand>, server_id: ServerId) {
+    let tx_cmd = tx_cmd.clone();
+    tokio::task::spawn_local(async move {
+        tokio::time::sleep(RESUME_DELAY).await;
+        let _ = tx_cmd.send(TtCommand::ResumeQueue { server_id }).await;
+    });
+}
+
+pub(super) fn is_spooled(spool_dir: &Path, file_path: &str) -> bool {
+    Path::new(file_path).starts_with(spool_dir)
+}
+
+pub(super) fn release_file(ctx: &WorkerContext, file_path: String) {
+    if is_spooled(&ctx.config.database.spool_dir(), &file_path) {
+        tokio::task::spawn_local(remove_temp_file(file_path));
+    }
+}
+
+pub(super) fn schedule_cleanup(ctx: &WorkerContext, file_path: String) {
+    if !is_spooled(&ctx.config.database.spool_dir(), &file_path) {
+        return;
+    }
+    tokio::task::spawn_local(async move {
+        tokio::time::sleep(CLEANUP_DELAY).await;
+        remove_temp_file(file_path).await;
+    });
+}
+
+pub(super) fn discard(ctx: &WorkerContext, items: impl IntoIterator<Item = StreamItem>) {
+    for item in items {
+        release_file(ctx, item.file_path);
+    }
+}
+
+pub(super) fn publish_queue(ctx: &WorkerContext, queue: &mut StreamQueue) {
+    if !queue.has_watchers() {
+        return;
+    }
+    let snapshot = queue.snapshot(Instant::now());
+    for (chat_id, watcher) in queue.watchers() {
+        let tx_bridge = ctx.tx_bridge.clone();
+        let event = BridgeEvent::QueueView {
+            server_id: ctx.server_id,
+            chat_id,
+            message_id: watcher.message_id,
+            lang: watcher.lang,
+            snapshot: snapshot.clone(),
+        };
+        tokio::task::spawn_local(async move {
+            if let Err(e) = tx_bridge.send(event).await {
+                tracing::error!(chat_id, error = %e, "Failed to send queue view to bridge");
+            }
+        });
+    }
+    if queue.is_idle() {
+        queue.forget_watchers();
+    }
+}
+
+#[cfg(test)]
+#[path = "../../../tests/unit/tt_stream.rs"]
+mod tests;
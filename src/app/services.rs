@@ -1,6 +1,19 @@
+pub mod accounts;
 pub mod admin_cleanup;
+pub mod bans;
+pub mod channels;
 pub mod deeplink;
+pub mod files;
+pub mod graph;
+pub mod health;
+pub mod music;
 pub mod pending;
+pub mod queue;
+pub mod reconnect_grace;
+pub mod registration;
+pub mod reports;
+pub mod seen;
+pub mod server_info;
 pub mod subscriber_actions;
 pub mod subscription;
 pub mod user_settings;
@@ -0,0 +1,39 @@
+use crate::core::types::{AccountEdit, AccountField, NewAccount};
+
+const CLEAR_VALUE: &str = "-";
+
+pub fn parse_new_account(input: &str) -> Option<NewAccount> {
+    let mut parts = input.split('|').map(str::trim);
+    let username = parts.next().filter(|name| !name.is_empty())?.to_string();
+    let password = parts.next().filter(|pass| !pass.is_empty())?.to_string();
+    let note = parts.next().unwrap_or_default().to_string();
+    if parts.next().is_some() {
+        return None;
+    }
+    Some(NewAccount {
+        username,
+        password,
+        note,
+    })
+}
+
+pub fn parse_account_edit(field: AccountField, input: &str) -> Option<AccountEdit> {
+    let value = input.trim();
+    match field {
+        AccountField::Password if value.is_empty() || value == CLEAR_VALUE => None,
+        AccountField::Password => Some(AccountEdit::Password(value.to_string())),
+        AccountField::Note if value == CLEAR_VALUE => Some(AccountEdit::Note(String::new())),
+        AccountField::Note => Some(AccountEdit::Note(value.to_string())),
+    }
+}
+
+pub fn matches_query(username: &str, note: &str, query: &str) -> bool {
+    let query = query.trim().to_lowercase();
+    query.is_empty()
+        || username.to_lowercase().contains(&query)
+        || note.to_lowercase().contains(&query)
+}
+
+#[cfg(test)]
+#[path = "../../../tests/unit/app_accounts.rs"]
+mod tests;
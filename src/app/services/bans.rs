@@ -0,0 +1,97 @@
+use crate::app::services::seen::format_duration;
+use crate::args;
+use crate::bootstrap::config::Config;
+use crate::core::types::{BanTarget, BanTerm, LanguageCode, TtCommand};
+use crate::infra::db::Database;
+use crate::infra::db::types::{BanEntry, LiftedBan};
+use crate::infra::locales;
+use anyhow::Result;
+use chrono::{Duration, NaiveDateTime};
+use std::net::IpAddr;
+
+pub fn expires_at(term: BanTerm, now: NaiveDateTime) -> Option<NaiveDateTime> {
+    term.seconds().map(|secs| now + Duration::seconds(secs))
+}
+
+pub fn parse_ban_target(input: &str) -> Option<BanTarget> {
+    let input = input.trim();
+    if input.is_empty() || input.contains(char::is_whitespace) {
+        return None;
+    }
+    if input.parse::<IpAddr>().is_ok() {
+        return Some(BanTarget::IpAddress(input.to_string()));
+    }
+    Some(BanTarget::Username(input.to_string()))
+}
+
+pub fn term_label(lang: LanguageCode, term: BanTerm) -> String {
+    let key = match term {
+        BanTerm::Hour => "ban-term-hour",
+        BanTerm::Day => "ban-term-day",
+        BanTerm::Week => "ban-term-week",
+        BanTerm::Permanent => "ban-term-permanent",
+    };
+    locales::get_text(lang.as_str(), key, None)
+}
+
+pub fn ban_label(lang: LanguageCode, entry: &BanEntry, now: NaiveDateTime) -> String {
+    let name = entry.teamtalk_username.clone().unwrap_or_else(|| {
+        entry
+            .telegram_id
+            .map_or_else(|| "Unknown".to_string(), |tg| format!("{tg}"))
+    });
+    let remaining = entry.expires_at.map_or_else(
+        || term_label(lang, BanTerm::Permanent),
+        |expires| {
+            locales::get_text(
+                lang.as_str(),
+                "ban-time-left",
+                args!(time = format_duration(lang, (expires - now).num_seconds())).as_ref(),
+            )
+        },
+    );
+    match entry.ban_reason.as_deref().filter(|r| !r.is_empty()) {
+        Some(reason) => format!("{name} · {remaining} · {reason}"),
+        None => format!("{name} · {remaining}"),
+    }
+}
+
+pub fn unban_command(
+    config: &Config,
+    ban: &LiftedBan,
+    reply_to: Option<(i64, LanguageCode)>,
+) -> Option<TtCommand> {
+    let ip_address = ban.ip_address.clone().filter(|ip| !ip.is_empty())?;
+    let server_id = config.server_id_by_key(ban.server_key.as_deref()?)?;
+    Some(TtCommand::UnbanUser {
+        server_id,
+        ban_id: ban.id,
+        ip_address,
+        channel_id: ban.channel_id.and_then(|id| i32::try_from(id).ok()),
+        reply_to,
+    })
+}
+
+pub async fn lift_expired(
+    db: &Database,
+    config: &Config,
+    now: NaiveDateTime,
+) -> Result<Vec<TtCommand>> {
+    let mut commands = Vec::new();
+    for ban in db.get_expired_bans(now).await? {
+        if let Some(command) = unban_command(config, &ban, None) {
+            commands.push(command);
+            continue;
+        }
+        db.remove_ban_by_id(ban.id).await?;
+        tracing::info!(
+            tt_username = ban.teamtalk_username.as_deref().unwrap_or_default(),
+            "Ban expired"
+        );
+    }
+    Ok(commands)
+}
+
+#[cfg(test)]
+#[path = "../../../tests/unit/app_bans.rs"]
+mod tests;
@@ -0,0 +1,99 @@
+use crate::args;
+use crate::core::types::{ChannelEdit, ChannelField, LanguageCode, LiteChannel, NewChannel};
+use crate::infra::locales;
+use std::collections::HashSet;
+
+const CLEAR_VALUE: &str = "-";
+
+pub fn parse_new_channel(input: &str) -> Option<NewChannel> {
+    let mut parts = input.split('|').map(str::trim);
+    let name = parts
+        .next()
+        .filter(|name| !name.is_empty() && !name.contains('/'))?
+        .to_string();
+    let password = parts.next().unwrap_or_default().to_string();
+    let topic = parts.next().unwrap_or_default().to_string();
+    let max_users = match parts.next().filter(|value| !value.is_empty()) {
+        Some(value) => Some(value.parse::<i32>().ok().filter(|max| *max > 0)?),
+        None => None,
+    };
+    if parts.next().is_some() {
+        return None;
+    }
+    Some(NewChannel {
+        name,
+        password,
+        topic,
+        max_users,
+    })
+}
+
+pub fn parse_channel_edit(field: ChannelField, input: &str) -> Option<ChannelEdit> {
+    let value = match input.trim() {
+        CLEAR_VALUE => String::new(),
+        value => value.to_string(),
+    };
+    match field {
+        ChannelField::Topic => Some(ChannelEdit::Topic(value)),
+        ChannelField::Password => Some(ChannelEdit::Password(value)),
+        ChannelField::SubChannel => None,
+    }
+}
+
+pub fn subtree(channel_id: i32, channels: &[LiteChannel]) -> HashSet<i32> {
+    let mut ids = HashSet::from([channel_id]);
+    loop {
+        let before = ids.len();
+        ids.extend(
+            channels
+                .iter()
+                .filter(|c| ids.contains(&c.parent_id))
+                .map(|c| c.id)
+                .collect::<Vec<_>>(),
+        );
+        if ids.len() == before {
+            return ids;
+        }
+    }
+}
+
+pub fn occupants(
+    channel_id: i32,
+    channels: &[LiteChannel],
+    user_channels: impl IntoIterator<Item = i32>,
+) -> usize {
+    let ids = subtree(channel_id, channels);
+    user_channels
+        .into_iter()
+        .filter(|id| ids.contains(id))
+        .count()
+}
+
+pub fn channel_details(lang: LanguageCode, channel: &LiteChannel, occupants: usize) -> String {
+    let topic = if channel.topic.is_empty() {
+        locales::get_text(lang.as_str(), "channel-no-topic", None)
+    } else {
+        channel.topic.clone()
+    };
+    let password_key = if channel.password_protected {
+        "channel-password-set"
+    } else {
+        "channel-password-none"
+    };
+    locales::get_text(
+        lang.as_str(),
+        "channel-details",
+        args!(
+            channel = channel.path.clone(),
+            topic = topic,
+            password = locales::get_text(lang.as_str(), password_key, None),
+            max = channel.max_users,
+            users = occupants
+        )
+        .as_ref(),
+    )
+}
+
+#[cfg(test)]
+#[path = "../../../tests/unit/app_channels.rs"]
+mod tests;
@@ -33,6 +33,20 @@ pub async fn resolve_for_user(
     }))
 }
 
+pub async fn resolve_for_chat(db: &Database, token: &str) -> Result<Option<ResolvedDeeplink>> {
+    let Some(deeplink) = db.resolve_deeplink(token).await? else {
+        return Ok(None);
+    };
+    let Ok(action) = DeeplinkAction::try_from(deeplink.action.as_str()) else {
+        return Ok(None);
+    };
+
+    Ok(Some(ResolvedDeeplink {
+        action,
+        payload: None,
+    }))
+}
+
 #[cfg(test)]
 #[path = "../../../tests/unit/app_deeplink.rs"]
 mod tests;
@@ -0,0 +1,43 @@
+const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+
+pub fn format_file_size(bytes: i64) -> String {
+    let bytes = bytes.max(0);
+    let mut unit = 0;
+    let mut scale = 1_i64;
+    while unit + 1 < UNITS.len() && bytes >= scale * 1024 {
+        scale *= 1024;
+        unit += 1;
+    }
+    if unit == 0 {
+        return format!("{bytes} {}", UNITS[0]);
+    }
+    let tenths = bytes * 10 / scale;
+    format!("{}.{} {}", tenths / 10, tenths % 10, UNITS[unit])
+}
+
+pub fn fits_download_limit(size: i64, max_mb: u64) -> bool {
+    u64::try_from(size).is_ok_and(|size| size <= max_mb.saturating_mul(1024 * 1024))
+}
+
+pub fn fits_channel_quota(used: i64, size: i64, quota: i64) -> bool {
+    used.max(0).saturating_add(size.max(0)) <= quota
+}
+
+pub fn transfer_percent(transferred: i64, total: i64) -> u8 {
+    if total <= 0 {
+        return 0;
+    }
+    u8::try_from(transferred.clamp(0, total) * 100 / total).unwrap_or(100)
+}
+
+pub fn upload_file_name(name: Option<&str>, fallback: &str) -> String {
+    name.and_then(|name| std::path::Path::new(name).file_name())
+        .and_then(|name| name.to_str())
+        .map(str::trim)
+        .filter(|name| !name.is_empty() && *name != "." && *name != "..")
+        .map_or_else(|| fallback.to_string(), str::to_string)
+}
+
+#[cfg(test)]
+#[path = "../../../tests/unit/app_files.rs"]
+mod tests;
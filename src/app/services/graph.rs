@@ -0,0 +1,113 @@
+use crate::args;
+use crate::core::types::LanguageCode;
+use crate::infra::db::{Database, types::OnlineSample};
+use crate::infra::locales;
+use anyhow::Result;
+use chrono::{Duration, NaiveDateTime};
+use std::collections::{BTreeMap, HashMap};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphRange {
+    Day,
+    Week,
+    Month,
+}
+
+impl GraphRange {
+    pub fn parse(arg: &str) -> Option<Self> {
+        match arg.trim().to_lowercase().as_str() {
+            "" | "day" => Some(Self::Day),
+            "week" => Some(Self::Week),
+            "month" => Some(Self::Month),
+            _ => None,
+        }
+    }
+
+    pub const fn span(self) -> Duration {
+        match self {
+            Self::Day => Duration::days(1),
+            Self::Week => Duration::days(7),
+            Self::Month => Duration::days(30),
+        }
+    }
+
+    pub const fn bucket_seconds(self) -> i64 {
+        match self {
+            Self::Day => 300,
+            Self::Week => 3600,
+            Self::Month => 6 * 3600,
+        }
+    }
+
+    const fn label_key(self) -> &'static str {
+        match self {
+            Self::Day => "graph-period-day",
+            Self::Week => "graph-period-week",
+            Self::Month => "graph-period-month",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct OnlineGraph {
+    pub points: Vec<(i64, u32)>,
+    pub peak: u32,
+    pub average: u32,
+}
+
+pub async fn load_graph(
+    db: &Database,
+    range: GraphRange,
+    now: NaiveDateTime,
+) -> Result<Option<OnlineGraph>> {
+    let samples = db.get_online_samples_since(now - range.span()).await?;
+    Ok(summarize_samples(&samples, range.bucket_seconds()))
+}
+
+pub fn summarize_samples(samples: &[OnlineSample], bucket_seconds: i64) -> Option<OnlineGraph> {
+    let mut buckets: BTreeMap<i64, HashMap<&str, i64>> = BTreeMap::new();
+    for sample in samples {
+        let ts = sample.sampled_at.and_utc().timestamp();
+        let bucket = ts - ts.rem_euclid(bucket_seconds);
+        let slot = buckets
+            .entry(bucket)
+            .or_default()
+            .entry(sample.server_key.as_str())
+            .or_default();
+        *slot = (*slot).max(sample.user_count);
+    }
+
+    let points: Vec<(i64, u32)> = buckets
+        .into_iter()
+        .map(|(bucket, servers)| {
+            let total: i64 = servers.values().sum();
+            (bucket, u32::try_from(total).unwrap_or(0))
+        })
+        .collect();
+    if points.is_empty() {
+        return None;
+    }
+
+    let peak = points.iter().map(|(_, count)| *count).max().unwrap_or(0);
+    let sum: u64 = points.iter().map(|(_, count)| u64::from(*count)).sum();
+    let len = points.len() as u64;
+    let average = u32::try_from((sum + len / 2) / len).unwrap_or(peak);
+    Some(OnlineGraph {
+        points,
+        peak,
+        average,
+    })
+}
+
+pub fn render_caption(lang: LanguageCode, range: GraphRange, graph: &OnlineGraph) -> String {
+    let period = locales::get_text(lang.as_str(), range.label_key(), None);
+    locales::get_text(
+        lang.as_str(),
+        "graph-caption",
+        args!(period = period, peak = graph.peak, average = graph.average).as_ref(),
+    )
+}
+
+#[cfg(test)]
+#[path = "../../../tests/unit/app_graph.rs"]
+mod tests;
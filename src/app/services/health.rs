@@ -0,0 +1,134 @@
+use crate::app::services::seen::format_duration;
+use crate::args;
+use crate::core::types::{HealthAlert, LanguageCode};
+use crate::infra::locales;
+use std::time::{Duration, Instant};
+
+pub struct HealthTracker {
+    cooldown: Duration,
+    login_threshold: u32,
+    down_since: Option<Instant>,
+    login_failures: u32,
+    down_alerted: bool,
+    login_alerted: bool,
+    last_alert: Option<Instant>,
+}
+
+impl HealthTracker {
+    pub const fn new(cooldown: Duration, login_threshold: u32) -> Self {
+        Self {
+            cooldown,
+            login_threshold,
+            down_since: None,
+            login_failures: 0,
+            down_alerted: false,
+            login_alerted: false,
+            last_alert: None,
+        }
+    }
+
+    pub fn disconnected(&mut self, now: Instant) -> Option<HealthAlert> {
+        self.down_since.get_or_insert(now);
+        self.deferred_alert(now)
+    }
+
+    pub fn deferred_alert(&mut self, now: Instant) -> Option<HealthAlert> {
+        if self.down_since.is_none()
+            || self.down_alerted
+            || self.login_alerted
+            || !self.claim_alert(now)
+        {
+            return None;
+        }
+        self.down_alerted = true;
+        Some(HealthAlert::Down)
+    }
+
+    pub fn login_failed(&mut self, now: Instant) -> Option<HealthAlert> {
+        self.down_since.get_or_insert(now);
+        self.login_failures = self.login_failures.saturating_add(1);
+        if self.login_alerted
+            || self.login_failures < self.login_threshold.max(1)
+            || !self.claim_alert(now)
+        {
+            return None;
+        }
+        self.login_alerted = true;
+        Some(HealthAlert::LoginFailing {
+            attempts: self.login_failures,
+        })
+    }
+
+    pub fn logged_in(&mut self, now: Instant) -> Option<HealthAlert> {
+        let down_since = self.down_since.take()?;
+        let alerted = self.down_alerted || self.login_alerted;
+        self.login_failures = 0;
+        self.down_alerted = false;
+        self.login_alerted = false;
+        if !alerted {
+            return None;
+        }
+        self.last_alert = Some(now);
+        Some(HealthAlert::Restored {
+            downtime: now.saturating_duration_since(down_since),
+        })
+    }
+
+    pub const fn notifies_subscribers(&self, alert: HealthAlert) -> bool {
+        !matches!(alert, HealthAlert::LoginFailing { .. } if self.down_alerted)
+    }
+
+    fn claim_alert(&mut self, now: Instant) -> bool {
+        if self
+            .last_alert
+            .is_some_and(|last| now.saturating_duration_since(last) < self.cooldown)
+        {
+            return false;
+        }
+        self.last_alert = Some(now);
+        true
+    }
+}
+
+fn downtime_text(lang: LanguageCode, downtime: Duration) -> String {
+    format_duration(lang, i64::try_from(downtime.as_secs()).unwrap_or(i64::MAX))
+}
+
+pub fn admin_alert_text(lang: LanguageCode, server: &str, alert: HealthAlert) -> String {
+    match alert {
+        HealthAlert::Down => locales::get_text(
+            lang.as_str(),
+            "health-down-admin",
+            args!(server = server).as_ref(),
+        ),
+        HealthAlert::LoginFailing { attempts } => locales::get_text(
+            lang.as_str(),
+            "health-login-failing-admin",
+            args!(server = server, attempts = attempts).as_ref(),
+        ),
+        HealthAlert::Restored { downtime } => locales::get_text(
+            lang.as_str(),
+            "health-restored-admin",
+            args!(server = server, downtime = downtime_text(lang, downtime)).as_ref(),
+        ),
+    }
+}
+
+pub fn subscriber_notice_text(lang: LanguageCode, server: &str, alert: HealthAlert) -> String {
+    match alert {
+        HealthAlert::Down | HealthAlert::LoginFailing { .. } => locales::get_text(
+            lang.as_str(),
+            "health-down",
+            args!(server = server).as_ref(),
+        ),
+        HealthAlert::Restored { downtime } => locales::get_text(
+            lang.as_str(),
+            "health-restored",
+            args!(server = server, downtime = downtime_text(lang, downtime)).as_ref(),
+        ),
+    }
+}
+
+#[cfg(test)]
+#[path = "../../../tests/unit/app_health.rs"]
+mod tests;
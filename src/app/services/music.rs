@@ -0,0 +1,357 @@
+use crate::app::services::queue as queue_service;
+use crate::args;
+use crate::core::types::{LanguageCode, ServerId, TtCommand};
+use crate::infra::db::Database;
+use crate::infra::locales;
+use anyhow::Result;
+use std::path::{Component, Path, PathBuf};
+
+pub const SEARCH_LIMIT: usize = 5;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Track {
+    pub path: PathBuf,
+    pub name: String,
+}
+
+impl Track {
+    pub fn title(&self) -> String {
+        queue_service::track_title(None, None, Some(&self.name))
+            .unwrap_or_else(|| self.name.clone())
+    }
+
+    pub fn enqueue_command(&self, server_id: ServerId) -> TtCommand {
+        TtCommand::EnqueueStream {
+            server_id,
+            channel_id: 0,
+            file_path: self.path.to_string_lossy().to_string(),
+            duration_ms: 0,
+            announce_text: None,
+            title: Some(self.title()),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct MusicReply {
+    pub text: String,
+    pub enqueue: Vec<Track>,
+}
+
+impl MusicReply {
+    const fn new(text: String) -> Self {
+        Self {
+            text,
+            enqueue: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlaylistCommand<'a> {
+    List,
+    Show { name: &'a str },
+    Add { name: &'a str, query: &'a str },
+    Play { name: &'a str },
+    Delete { name: &'a str },
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum MusicCommand<'a> {
+    Play { query: &'a str },
+    Playlist { args: &'a str },
+}
+
+pub fn parse_music_command(text: &str) -> Option<MusicCommand<'_>> {
+    let text = text.trim();
+    let (command, args) = text
+        .split_once(char::is_whitespace)
+        .map_or((text, ""), |(command, args)| (command, args.trim()));
+    let command = command.split('@').next().unwrap_or_default().to_lowercase();
+    match command.as_str() {
+        "/play" => Some(MusicCommand::Play { query: args }),
+        "/playlist" => Some(MusicCommand::Playlist { args }),
+        _ => None,
+    }
+}
+
+pub fn parse_playlist_command(args: &str) -> Option<PlaylistCommand<'_>> {
+    let split = |s: &'_ str| {
+        s.trim()
+            .split_once(char::is_whitespace)
+            .map_or((s.trim(), ""), |(head, tail)| (head, tail.trim()))
+    };
+    let (verb, rest) = split(args);
+    let (name, query) = split(rest);
+    match (verb.to_lowercase().as_str(), name.is_empty()) {
+        ("" | "list", _) => Some(PlaylistCommand::List),
+        ("show", false) => Some(PlaylistCommand::Show { name }),
+        ("add", false) if !query.is_empty() => Some(PlaylistCommand::Add { name, query }),
+        ("play", false) => Some(PlaylistCommand::Play { name }),
+        ("delete", false) => Some(PlaylistCommand::Delete { name }),
+        _ => None,
+    }
+}
+
+pub fn scan_library(root: &Path) -> Vec<Track> {
+    let mut tracks = Vec::new();
+    let mut dirs = vec![root.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            tracing::warn!(dir = %dir.display(), "Failed to read music directory");
+            continue;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let Ok(file_type) = entry.file_type() else {
+                continue;
+            };
+            if file_type.is_dir() {
+                dirs.push(path);
+                continue;
+            }
+            let Ok(relative) = path.strip_prefix(root) else {
+                continue;
+            };
+            let name = relative.to_string_lossy().replace('\\', "/");
+            if file_type.is_file() && queue_service::is_audio_file(None, Some(&name)) {
+                tracks.push(Track { path, name });
+            }
+        }
+    }
+    tracks.sort_by(|a, b| a.name.cmp(&b.name));
+    tracks
+}
+
+pub fn resolve_track(root: &Path, name: &str) -> Option<Track> {
+    let relative = Path::new(name);
+    if !relative
+        .components()
+        .all(|part| matches!(part, Component::Normal(_)))
+    {
+        return None;
+    }
+    let path = root.join(relative);
+    path.is_file().then(|| Track {
+        path,
+        name: name.to_string(),
+    })
+}
+
+fn normalize(text: &str) -> String {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+pub fn match_score(query: &str, name: &str) -> Option<usize> {
+    let query = normalize(query);
+    let name = normalize(name);
+    if query.is_empty() {
+        return None;
+    }
+    if let Some(pos) = name.find(&query) {
+        let word_start = pos == 0 || name.as_bytes()[pos - 1] == b' ';
+        let penalty = if word_start { 0 } else { 500 };
+        return Some(penalty + pos + name.len());
+    }
+    if query.split(' ').all(|word| name.contains(word)) {
+        return Some(1_000 + name.len());
+    }
+    let mut gaps = 0;
+    let mut chars = name.chars();
+    for wanted in query.chars().filter(|c| *c != ' ') {
+        loop {
+            match chars.next() {
+                Some(c) if c == wanted => break,
+                Some(_) => gaps += 1,
+                None => return None,
+            }
+        }
+    }
+    Some(10_000 + gaps)
+}
+
+pub fn search(tracks: Vec<Track>, query: &str, limit: usize) -> Vec<Track> {
+    let mut scored = tracks
+        .into_iter()
+        .filter_map(|track| match_score(query, &track.name).map(|score| (score, track)))
+        .collect::<Vec<_>>();
+    scored.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.name.cmp(&b.1.name)));
+    scored
+        .into_iter()
+        .take(limit)
+        .map(|(_, track)| track)
+        .collect()
+}
+
+pub async fn find_tracks(root: &Path, query: &str) -> Vec<Track> {
+    let root = root.to_path_buf();
+    let query = query.to_string();
+    tokio::task::spawn_blocking(move || search(scan_library(&root), &query, SEARCH_LIMIT))
+        .await
+        .unwrap_or_default()
+}
+
+pub async fn play(library: Option<&Path>, lang: LanguageCode, query: &str) -> MusicReply {
+    let Some(root) = library else {
+        return MusicReply::new(locales::get_text(lang.as_str(), "music-no-library", None));
+    };
+    let query = query.trim();
+    if query.is_empty() {
+        return MusicReply::new(locales::get_text(lang.as_str(), "music-play-usage", None));
+    }
+    let Some(track) = find_tracks(root, query).await.into_iter().next() else {
+        return MusicReply::new(locales::get_text(
+            lang.as_str(),
+            "music-not-found",
+            args!(query = query.to_string()).as_ref(),
+        ));
+    };
+    MusicReply {
+        text: locales::get_text(
+            lang.as_str(),
+            "music-queued",
+            args!(title = track.title()).as_ref(),
+        ),
+        enqueue: vec![track],
+    }
+}
+
+pub async fn playlist(
+    db: &Database,
+    library: Option<&Path>,
+    lang: LanguageCode,
+    args: &str,
+) -> Result<MusicReply> {
+    let Some(command) = parse_playlist_command(args) else {
+        return Ok(MusicReply::new(locales::get_text(
+            lang.as_str(),
+            "music-playlist-usage",
+            None,
+        )));
+    };
+    let not_found = |name: &str| {
+        MusicReply::new(locales::get_text(
+            lang.as_str(),
+            "music-playlist-not-found",
+            args!(name = name.to_string()).as_ref(),
+        ))
+    };
+    match command {
+        PlaylistCommand::List => {
+            let playlists = db.list_playlists().await?;
+            if playlists.is_empty() {
+                return Ok(MusicReply::new(locales::get_text(
+                    lang.as_str(),
+                    "music-playlists-empty",
+                    None,
+                )));
+            }
+            let mut lines = vec![locales::get_text(
+                lang.as_str(),
+                "music-playlists-title",
+                None,
+            )];
+            lines.extend(playlists.into_iter().map(|p| {
+                locales::get_text(
+                    lang.as_str(),
+                    "music-playlist-line",
+                    args!(name = p.name, count = p.track_count).as_ref(),
+                )
+            }));
+            Ok(MusicReply::new(lines.join("\n")))
+        }
+        PlaylistCommand::Show { name } => {
+            let tracks = db.get_playlist_tracks(name).await?;
+            if tracks.is_empty() {
+                return Ok(not_found(name));
+            }
+            let mut lines = vec![locales::get_text(
+                lang.as_str(),
+                "music-playlist-tracks-title",
+                args!(name = name.to_string()).as_ref(),
+            )];
+            lines.extend(
+                tracks
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, track)| format!("{}. {track}", idx + 1)),
+            );
+            Ok(MusicReply::new(lines.join("\n")))
+        }
+        PlaylistCommand::Add { name, query } => {
+            let Some(root) = library else {
+                return Ok(MusicReply::new(locales::get_text(
+                    lang.as_str(),
+                    "music-no-library",
+                    None,
+                )));
+            };
+            let Some(track) = find_tracks(root, query).await.into_iter().next() else {
+                return Ok(MusicReply::new(locales::get_text(
+                    lang.as_str(),
+                    "music-not-found",
+                    args!(query = query.to_string()).as_ref(),
+                )));
+            };
+            let count = db.add_playlist_track(name, &track.name).await?;
+            Ok(MusicReply::new(locales::get_text(
+                lang.as_str(),
+                "music-playlist-added",
+                args!(
+                    title = track.title(),
+                    name = name.to_string(),
+                    count = count
+                )
+                .as_ref(),
+            )))
+        }
+        PlaylistCommand::Play { name } => {
+            let Some(root) = library else {
+                return Ok(MusicReply::new(locales::get_text(
+                    lang.as_str(),
+                    "music-no-library",
+                    None,
+                )));
+            };
+            let names = db.get_playlist_tracks(name).await?;
+            if names.is_empty() {
+                return Ok(not_found(name));
+            }
+            let tracks = names
+                .iter()
+                .filter_map(|track| resolve_track(root, track))
+                .collect::<Vec<_>>();
+            let key = if tracks.is_empty() {
+                "music-playlist-unavailable"
+            } else {
+                "music-playlist-queued"
+            };
+            Ok(MusicReply {
+                text: locales::get_text(
+                    lang.as_str(),
+                    key,
+                    args!(name = name.to_string(), count = tracks.len()).as_ref(),
+                ),
+                enqueue: tracks,
+            })
+        }
+        PlaylistCommand::Delete { name } => {
+            if !db.delete_playlist(name).await? {
+                return Ok(not_found(name));
+            }
+            Ok(MusicReply::new(locales::get_text(
+                lang.as_str(),
+                "music-playlist-deleted",
+                args!(name = name.to_string()).as_ref(),
+            )))
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "../../../tests/unit/app_music.rs"]
+mod tests;
@@ -1,8 +1,33 @@
-use crate::infra::db::Database;
+use crate::bootstrap::config::Config;
+use crate::core::types::ServerId;
+use crate::infra::db::{Database, types::PendingChannelReply};
 use anyhow::Result;
 
-pub async fn get_pending_reply_user_id(db: &Database, reply_id: i64) -> Result<Option<i32>> {
-    db.get_pending_reply_user_id(reply_id).await
+fn resolve_server(config: &Config, server_key: &str) -> Option<ServerId> {
+    if server_key.is_empty() {
+        return Some(ServerId(0));
+    }
+    let server_id = config.server_id_by_key(server_key);
+    if server_id.is_none() {
+        tracing::warn!(
+            server_key,
+            "Pending reply belongs to a server that is no longer configured"
+        );
+    }
+    server_id
+}
+
+pub async fn get_pending_reply(
+    db: &Database,
+    config: &Config,
+    reply_id: i64,
+) -> Result<Option<(ServerId, i32)>> {
+    Ok(db
+        .get_pending_reply(reply_id)
+        .await?
+        .and_then(|(server_key, tt_user_id)| {
+            resolve_server(config, &server_key).map(|server_id| (server_id, tt_user_id))
+        }))
 }
 
 pub async fn touch_pending_reply(db: &Database, reply_id: i64) -> Result<()> {
@@ -11,9 +36,13 @@ pub async fn touch_pending_reply(db: &Database, reply_id: i64) -> Result<()> {
 
 pub async fn get_pending_channel_reply(
     db: &Database,
+    config: &Config,
     reply_id: i64,
-) -> Result<Option<(i32, String, String, String)>> {
-    db.get_pending_channel_reply(reply_id).await
+) -> Result<Option<(ServerId, PendingChannelReply)>> {
+    Ok(db
+        .get_pending_channel_reply(reply_id)
+        .await?
+        .and_then(|reply| resolve_server(config, &reply.server_key).map(|id| (id, reply))))
 }
 
 pub async fn touch_pending_channel_reply(db: &Database, reply_id: i64) -> Result<()> {
@@ -0,0 +1,116 @@
+use crate::args;
+use crate::core::types::{LanguageCode, QueueEntry, QueueSnapshot};
+use crate::infra::locales;
+
+pub const QUEUE_ITEMS_SHOWN: usize = 10;
+
+const AUDIO_EXTENSIONS: [&str; 9] = [
+    "mp3", "m4a", "aac", "flac", "ogg", "oga", "opus", "wav", "wma",
+];
+
+pub fn file_extension(file_name: Option<&str>) -> Option<String> {
+    std::path::Path::new(file_name?)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(str::to_ascii_lowercase)
+}
+
+pub fn is_audio_file(mime_type: Option<&str>, file_name: Option<&str>) -> bool {
+    mime_type.is_some_and(|mime| mime.starts_with("audio/"))
+        || file_extension(file_name).is_some_and(|ext| AUDIO_EXTENSIONS.contains(&ext.as_str()))
+}
+
+pub fn track_title(
+    performer: Option<&str>,
+    title: Option<&str>,
+    file_name: Option<&str>,
+) -> Option<String> {
+    let clean = |value: Option<&str>| value.map(str::trim).filter(|value| !value.is_empty());
+    match (clean(performer), clean(title)) {
+        (Some(performer), Some(title)) => Some(format!("{performer} — {title}")),
+        (None, Some(title)) => Some(title.to_string()),
+        (performer, None) => clean(file_name)
+            .and_then(|name| std::path::Path::new(name).file_stem())
+            .and_then(|stem| stem.to_str())
+            .map(str::to_string)
+            .or_else(|| performer.map(str::to_string)),
+    }
+}
+
+pub fn format_clock(ms: u64) -> String {
+    let total = ms / 1000;
+    let (hours, minutes, seconds) = (total / 3600, total / 60 % 60, total % 60);
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{minutes:02}:{seconds:02}")
+    }
+}
+
+pub fn entry_title(lang: LanguageCode, entry: &QueueEntry) -> String {
+    entry
+        .title
+        .clone()
+        .filter(|title| !title.is_empty())
+        .unwrap_or_else(|| locales::get_text(lang.as_str(), "queue-item-voice", None))
+}
+
+pub fn render_queue(lang: LanguageCode, server: &str, snapshot: &QueueSnapshot) -> String {
+    let mut lines = vec![locales::get_text(
+        lang.as_str(),
+        "queue-title",
+        args!(server = server).as_ref(),
+    )];
+
+    lines.push(match &snapshot.now_playing {
+        Some(playing) => {
+            let key = if playing.paused {
+                "queue-now-paused"
+            } else {
+                "queue-now-playing"
+            };
+            let total = u64::from(playing.entry.duration_ms);
+            locales::get_text(
+                lang.as_str(),
+                key,
+                args!(
+                    title = entry_title(lang, &playing.entry),
+                    elapsed = format_clock(playing.elapsed_ms.min(total)),
+                    total = format_clock(total)
+                )
+                .as_ref(),
+            )
+        }
+        None => locales::get_text(lang.as_str(), "queue-idle", None),
+    });
+    lines.push(String::new());
+
+    if snapshot.pending.is_empty() {
+        lines.push(locales::get_text(lang.as_str(), "queue-empty", None));
+    }
+    for (pos, entry) in snapshot.pending.iter().take(QUEUE_ITEMS_SHOWN).enumerate() {
+        lines.push(locales::get_text(
+            lang.as_str(),
+            "queue-item",
+            args!(
+                pos = pos + 1,
+                title = entry_title(lang, entry),
+                total = format_clock(u64::from(entry.duration_ms))
+            )
+            .as_ref(),
+        ));
+    }
+    let hidden = snapshot.pending.len().saturating_sub(QUEUE_ITEMS_SHOWN);
+    if hidden > 0 {
+        lines.push(locales::get_text(
+            lang.as_str(),
+            "queue-more",
+            args!(count = hidden).as_ref(),
+        ));
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+#[path = "../../../tests/unit/app_queue.rs"]
+mod tests;
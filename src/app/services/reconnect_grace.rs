@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+const CHANNEL_SETTLE: Duration = Duration::from_secs(2);
+
+struct Held<T> {
+    deadline: Instant,
+    logged_out: bool,
+    notices: Vec<T>,
+}
+
+pub struct ReconnectGrace<T> {
+    window: Duration,
+    pending: HashMap<String, Held<T>>,
+    rejoining: HashMap<String, Instant>,
+}
+
+impl<T> ReconnectGrace<T> {
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            pending: HashMap::new(),
+            rejoining: HashMap::new(),
+        }
+    }
+
+    fn settle(&self) -> Duration {
+        self.window.min(CHANNEL_SETTLE)
+    }
+
+    pub fn hold_leave(&mut self, username: &str, now: Instant, notice: T) -> Option<T> {
+        if self.window.is_zero() || username.is_empty() {
+            return Some(notice);
+        }
+        let held = self
+            .pending
+            .entry(username.to_string())
+            .or_insert_with(|| Held {
+                deadline: now,
+                logged_out: false,
+                notices: Vec::new(),
+            });
+        held.deadline = now + self.window;
+        held.logged_out = true;
+        held.notices.push(notice);
+        None
+    }
+
+    pub fn hold_channel_leave(&mut self, username: &str, now: Instant, notice: T) -> Option<T> {
+        if self.window.is_zero() || username.is_empty() {
+            return Some(notice);
+        }
+        let deadline = now + self.settle();
+        self.pending
+            .entry(username.to_string())
+            .or_insert_with(|| Held {
+                deadline,
+                logged_out: false,
+                notices: Vec::new(),
+            })
+            .notices
+            .push(notice);
+        None
+    }
+
+    pub fn logged_in(&mut self, username: &str, now: Instant) -> bool {
+        if !self
+            .pending
+            .get(username)
+            .is_some_and(|held| held.logged_out)
+        {
+            return false;
+        }
+        self.pending.remove(username);
+        self.rejoining
+            .insert(username.to_string(), now + self.settle());
+        true
+    }
+
+    pub fn channel_joined(&mut self, username: &str, now: Instant, notice: T) -> Vec<T> {
+        if self
+            .rejoining
+            .remove(username)
+            .is_some_and(|until| now <= until)
+        {
+            return Vec::new();
+        }
+        let mut ready = match self.pending.get(username) {
+            Some(held) if !held.logged_out => self
+                .pending
+                .remove(username)
+                .map(|held| held.notices)
+                .unwrap_or_default(),
+            _ => Vec::new(),
+        };
+        ready.push(notice);
+        ready
+    }
+
+    pub fn take_expired(&mut self, now: Instant) -> Vec<T> {
+        self.rejoining.retain(|_, until| *until >= now);
+        let mut expired = self
+            .pending
+            .iter()
+            .filter(|(_, held)| held.deadline <= now)
+            .map(|(username, held)| (held.deadline, username.clone()))
+            .collect::<Vec<_>>();
+        expired.sort();
+        expired
+            .into_iter()
+            .filter_map(|(_, username)| self.pending.remove(&username))
+            .flat_map(|held| held.notices)
+            .collect()
+    }
+
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.pending.values().map(|held| held.deadline).min()
+    }
+
+    pub fn clear(&mut self) {
+        self.pending.clear();
+        self.rejoining.clear();
+    }
+}
+
+#[cfg(test)]
+#[path = "../../../tests/unit/app_reconnect_grace.rs"]
+mod tests;
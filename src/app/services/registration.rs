@@ -0,0 +1,53 @@
+use crate::infra::db::Database;
+use anyhow::Result;
+use rand::distr::{Alphanumeric, SampleString};
+
+pub const PASSWORD_LENGTH: usize = 12;
+const USERNAME_MIN_LEN: usize = 2;
+const USERNAME_MAX_LEN: usize = 32;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubmitOutcome {
+    Filed(i64),
+    AlreadyPending,
+    UsernameTaken,
+}
+
+pub fn normalize_username(input: &str) -> Option<String> {
+    let name = input.trim();
+    let valid = (USERNAME_MIN_LEN..=USERNAME_MAX_LEN).contains(&name.chars().count())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-'));
+    valid.then(|| name.to_string())
+}
+
+pub fn generate_password() -> String {
+    Alphanumeric.sample_string(&mut rand::rng(), PASSWORD_LENGTH)
+}
+
+pub async fn submit(
+    db: &Database,
+    telegram_id: i64,
+    server_key: &str,
+    username: &str,
+) -> Result<SubmitOutcome> {
+    if db
+        .get_registration_by_telegram_id(telegram_id)
+        .await?
+        .is_some()
+    {
+        return Ok(SubmitOutcome::AlreadyPending);
+    }
+    if db.is_username_requested(server_key, username).await? {
+        return Ok(SubmitOutcome::UsernameTaken);
+    }
+    let id = db
+        .add_registration_request(telegram_id, server_key, username)
+        .await?;
+    Ok(SubmitOutcome::Filed(id))
+}
+
+#[cfg(test)]
+#[path = "../../../tests/unit/app_registration.rs"]
+mod tests;
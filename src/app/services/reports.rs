@@ -0,0 +1,190 @@
+use crate::app::services::seen::format_duration;
+use crate::args;
+use crate::bootstrap::config::{ReportSchedule, ReportsConfig};
+use crate::core::types::LanguageCode;
+use crate::infra::db::{Database, types::ReportSession};
+use crate::infra::locales;
+use anyhow::Result;
+use chrono::{DateTime, Datelike, Duration, NaiveDateTime, TimeZone, Utc};
+use std::collections::{HashMap, HashSet};
+
+const TOP_ENTRIES: usize = 3;
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ActivityReport {
+    pub peak_users: usize,
+    pub unique_visitors: usize,
+    pub longest_sessions: Vec<(String, i64)>,
+    pub final_channels: Vec<(String, usize)>,
+    pub new_subscribers: i64,
+    pub bans_issued: i64,
+}
+
+pub async fn build_report(
+    db: &Database,
+    start: NaiveDateTime,
+    end: NaiveDateTime,
+) -> Result<ActivityReport> {
+    let sessions = db.get_sessions_between(start, end).await?;
+    let mut report = summarize_sessions(&sessions, start, end);
+    report.new_subscribers = db.count_new_subscribers(start, end).await?;
+    report.bans_issued = db.count_bans_issued(start, end).await?;
+    Ok(report)
+}
+
+pub fn summarize_sessions(
+    sessions: &[ReportSession],
+    start: NaiveDateTime,
+    end: NaiveDateTime,
+) -> ActivityReport {
+    let mut edges: Vec<(NaiveDateTime, i32)> = Vec::with_capacity(sessions.len() * 2);
+    for session in sessions {
+        let from = session.login_time.max(start);
+        let to = session.logout_time.unwrap_or(end).min(end);
+        if from < to {
+            edges.push((from, 1));
+            edges.push((to, -1));
+        }
+    }
+    edges.sort_unstable();
+    let mut current = 0_i32;
+    let mut peak = 0_i32;
+    for (_, delta) in edges {
+        current += delta;
+        peak = peak.max(current);
+    }
+
+    let unique_visitors = sessions
+        .iter()
+        .map(|s| s.username.as_str())
+        .collect::<HashSet<_>>()
+        .len();
+
+    let mut longest: Vec<(String, i64)> = sessions
+        .iter()
+        .map(|s| {
+            let stayed = s.logout_time.unwrap_or(end) - s.login_time;
+            (s.nickname.clone(), stayed.num_seconds())
+        })
+        .collect();
+    longest.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    longest.truncate(TOP_ENTRIES);
+
+    let mut channel_counts: HashMap<&str, usize> = HashMap::new();
+    for channel in sessions.iter().filter_map(|s| s.channel.as_deref()) {
+        *channel_counts.entry(channel).or_default() += 1;
+    }
+    let mut final_channels: Vec<(String, usize)> = channel_counts
+        .into_iter()
+        .map(|(channel, count)| (channel.to_string(), count))
+        .collect();
+    final_channels.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    final_channels.truncate(TOP_ENTRIES);
+
+    ActivityReport {
+        peak_users: usize::try_from(peak).unwrap_or_default(),
+        unique_visitors,
+        longest_sessions: longest,
+        final_channels,
+        new_subscribers: 0,
+        bans_issued: 0,
+    }
+}
+
+pub const fn report_period(schedule: ReportSchedule) -> Duration {
+    match schedule {
+        ReportSchedule::Weekly => Duration::weeks(1),
+        ReportSchedule::Daily | ReportSchedule::Off => Duration::days(1),
+    }
+}
+
+pub fn next_run(now: DateTime<Utc>, config: &ReportsConfig) -> Option<DateTime<Utc>> {
+    let local_now = now.with_timezone(&config.utc_offset);
+    let today = local_now.date_naive();
+    let (first, step) = match config.schedule {
+        ReportSchedule::Off => return None,
+        ReportSchedule::Daily => (today, Duration::days(1)),
+        ReportSchedule::Weekly => {
+            let ahead = (7 + config.weekday.num_days_from_monday()
+                - today.weekday().num_days_from_monday())
+                % 7;
+            (today + Duration::days(i64::from(ahead)), Duration::weeks(1))
+        }
+    };
+    let mut candidate = config
+        .utc_offset
+        .from_local_datetime(&first.and_time(config.time))
+        .single()?;
+    if candidate <= local_now {
+        candidate += step;
+    }
+    Some(candidate.with_timezone(&Utc))
+}
+
+pub fn render_report(
+    lang: LanguageCode,
+    schedule: ReportSchedule,
+    report: &ActivityReport,
+) -> String {
+    let lang_code = lang.as_str();
+    let title_key = match schedule {
+        ReportSchedule::Weekly => "report-title-weekly",
+        ReportSchedule::Daily | ReportSchedule::Off => "report-title-daily",
+    };
+    let none = locales::get_text(lang_code, "report-none", None);
+
+    let mut lines = vec![
+        locales::get_text(lang_code, title_key, None),
+        locales::get_text(
+            lang_code,
+            "report-peak",
+            args!(count = report.peak_users).as_ref(),
+        ),
+        locales::get_text(
+            lang_code,
+            "report-unique",
+            args!(count = report.unique_visitors).as_ref(),
+        ),
+        locales::get_text(lang_code, "report-longest-header", None),
+    ];
+    if report.longest_sessions.is_empty() {
+        lines.push(none.clone());
+    }
+    for (nickname, seconds) in &report.longest_sessions {
+        lines.push(locales::get_text(
+            lang_code,
+            "report-longest-row",
+            args!(
+                nickname = nickname.as_str(),
+                duration = format_duration(lang, *seconds)
+            )
+            .as_ref(),
+        ));
+    }
+    lines.push(locales::get_text(lang_code, "report-channels-header", None));
+    if report.final_channels.is_empty() {
+        lines.push(none);
+    }
+    for (channel, count) in &report.final_channels {
+        lines.push(locales::get_text(
+            lang_code,
+            "report-channel-row",
+            args!(channel = channel.as_str(), count = *count).as_ref(),
+        ));
+    }
+    lines.push(locales::get_text(
+        lang_code,
+        "report-new-subs",
+        args!(count = report.new_subscribers).as_ref(),
+    ));
+    lines.push(locales::get_text(
+        lang_code,
+        "report-bans",
+        args!(count = report.bans_issued).as_ref(),
+    ));
+    lines.join("\n")
+}
+
+#[cfg(test)]
+#[path = "../../../tests/unit/app_reports.rs"]
+mod tests;
@@ -0,0 +1,106 @@
+use crate::args;
+use crate::bootstrap::config::Config;
+use crate::core::types::LanguageCode;
+use crate::infra::db::{Database, types::UserSession};
+use crate::infra::locales;
+use anyhow::Result;
+use chrono::NaiveDateTime;
+
+pub struct OnlineSighting {
+    pub nickname: String,
+    pub channel: String,
+    pub server: String,
+}
+
+pub async fn last_session(db: &Database, username: &str) -> Result<Option<UserSession>> {
+    db.get_last_session(username).await
+}
+
+pub fn render_seen(
+    lang: LanguageCode,
+    config: &Config,
+    username: &str,
+    online: Option<OnlineSighting>,
+    last: Option<&UserSession>,
+    now: NaiveDateTime,
+) -> String {
+    if let Some(online) = online {
+        return locales::get_text(
+            lang.as_str(),
+            "seen-online",
+            args!(
+                nickname = online.nickname,
+                username = username,
+                channel = online.channel,
+                server = online.server
+            )
+            .as_ref(),
+        );
+    }
+
+    let Some(session) = last else {
+        return locales::get_text(
+            lang.as_str(),
+            "seen-unknown",
+            args!(username = username).as_ref(),
+        );
+    };
+    let server = config
+        .server_display_name_by_key(&session.server_key)
+        .to_string();
+
+    match session.logout_time {
+        None => locales::get_text(
+            lang.as_str(),
+            "seen-online",
+            args!(
+                nickname = session.nickname.clone(),
+                username = username,
+                channel = session.channel.clone().unwrap_or_else(|| "/".to_string()),
+                server = server
+            )
+            .as_ref(),
+        ),
+        Some(logout) => locales::get_text(
+            lang.as_str(),
+            "seen-last",
+            args!(
+                nickname = session.nickname.clone(),
+                username = username,
+                ago = format_duration(lang, (now - logout).num_seconds()),
+                stayed = format_duration(lang, (logout - session.login_time).num_seconds()),
+                server = server
+            )
+            .as_ref(),
+        ),
+    }
+}
+
+pub fn format_duration(lang: LanguageCode, seconds: i64) -> String {
+    let seconds = seconds.max(0);
+    if seconds < 60 {
+        return locales::get_text(
+            lang.as_str(),
+            "duration-seconds",
+            args!(count = seconds).as_ref(),
+        );
+    }
+
+    let units = [
+        ("duration-days", seconds / 86_400),
+        ("duration-hours", seconds % 86_400 / 3600),
+        ("duration-minutes", seconds % 3600 / 60),
+    ];
+    units
+        .iter()
+        .skip_while(|(_, value)| *value == 0)
+        .take(2)
+        .filter(|(_, value)| *value > 0)
+        .map(|(key, value)| locales::get_text(lang.as_str(), key, args!(count = *value).as_ref()))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+#[path = "../../../tests/unit/app_seen.rs"]
+mod tests;
@@ -0,0 +1,89 @@
+use crate::args;
+use crate::core::types::{LanguageCode, LiteServerInfo, ServerEdit, ServerField};
+use crate::infra::locales;
+
+const CLEAR_VALUE: &str = "-";
+
+pub fn parse_server_edit(field: ServerField, input: &str) -> Option<ServerEdit> {
+    let value = input.trim();
+    match field {
+        ServerField::Name if value.is_empty() || value == CLEAR_VALUE => None,
+        ServerField::Name => Some(ServerEdit::Name(value.to_string())),
+        ServerField::Motd if value == CLEAR_VALUE => Some(ServerEdit::Motd(String::new())),
+        ServerField::Motd => Some(ServerEdit::Motd(value.to_string())),
+    }
+}
+
+fn motd_text(lang: LanguageCode, motd: &str) -> String {
+    if motd.is_empty() {
+        locales::get_text(lang.as_str(), "server-no-motd", None)
+    } else {
+        motd.to_string()
+    }
+}
+
+pub fn server_details(lang: LanguageCode, info: &LiteServerInfo) -> String {
+    locales::get_text(
+        lang.as_str(),
+        "server-details",
+        args!(
+            name = info.name.clone(),
+            motd = motd_text(lang, &info.motd),
+            max = info.max_users,
+            delay = info.login_delay_ms,
+            version = info.version.clone()
+        )
+        .as_ref(),
+    )
+}
+
+pub fn describe_changes(
+    lang: LanguageCode,
+    previous: &LiteServerInfo,
+    current: &LiteServerInfo,
+) -> Vec<String> {
+    let line = |key: &str, old: String, new: String| {
+        locales::get_text(lang.as_str(), key, args!(old = old, new = new).as_ref())
+    };
+    let mut changes = Vec::new();
+    if previous.name != current.name {
+        changes.push(line(
+            "server-change-name",
+            previous.name.clone(),
+            current.name.clone(),
+        ));
+    }
+    if previous.motd != current.motd {
+        changes.push(line(
+            "server-change-motd",
+            motd_text(lang, &previous.motd),
+            motd_text(lang, &current.motd),
+        ));
+    }
+    if previous.max_users != current.max_users {
+        changes.push(line(
+            "server-change-max-users",
+            previous.max_users.to_string(),
+            current.max_users.to_string(),
+        ));
+    }
+    if previous.login_delay_ms != current.login_delay_ms {
+        changes.push(line(
+            "server-change-login-delay",
+            previous.login_delay_ms.to_string(),
+            current.login_delay_ms.to_string(),
+        ));
+    }
+    if previous.version != current.version {
+        changes.push(line(
+            "server-change-version",
+            previous.version.clone(),
+            current.version.clone(),
+        ));
+    }
+    changes
+}
+
+#[cfg(test)]
+#[path = "../../../tests/unit/app_server_info.rs"]
+mod tests;
@@ -7,6 +7,7 @@ pub enum SubscribeOutcome {
     BannedTeamTalk { username: String },
     SubscribedLinked,
     SubscribedGuest,
+    SubscribedChat,
 }
 
 pub async fn subscribe_via_deeplink(
@@ -36,6 +37,14 @@ pub async fn subscribe_via_deeplink(
     }
 }
 
+pub async fn subscribe_chat(db: &Database, chat_id: i64) -> Result<SubscribeOutcome> {
+    if db.is_telegram_id_banned(chat_id).await? {
+        return Ok(SubscribeOutcome::BannedUser);
+    }
+    db.add_subscriber(chat_id).await?;
+    Ok(SubscribeOutcome::SubscribedChat)
+}
+
 pub async fn unsubscribe(db: &Database, telegram_id: i64) -> Result<()> {
     db.delete_user_profile(telegram_id).await
 }
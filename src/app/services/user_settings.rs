@@ -1,4 +1,4 @@
-use crate::core::types::{LanguageCode, MuteListMode, NotificationSetting};
+use crate::core::types::{DeliveryMode, LanguageCode, MuteListMode, NotificationSetting};
 use crate::infra::db::Database;
 use anyhow::Result;
 
@@ -18,6 +18,10 @@ pub fn parse_mute_list_mode(raw: &str) -> MuteListMode {
     MuteListMode::try_from(raw).unwrap_or(MuteListMode::Blacklist)
 }
 
+pub fn parse_delivery_mode(raw: &str) -> DeliveryMode {
+    DeliveryMode::try_from(raw).unwrap_or(DeliveryMode::Instant)
+}
+
 #[cfg(test)]
 #[path = "../../../tests/unit/app_user_settings.rs"]
 mod tests;
@@ -1,12 +1,14 @@
 use crate::adapters;
-use crate::bootstrap::config::Config;
+use crate::adapters::tt::ServerShared;
+use crate::app::services::bans as bans_service;
+use crate::app::services::reports as reports_service;
+use crate::bootstrap::config::{Config, ReportSchedule};
+use crate::core::types::ServerId;
 use crate::infra::db::Database;
 use anyhow::{Result, anyhow};
-use std::collections::HashMap;
 use std::path::Path;
-use std::sync::{Arc, RwLock};
+use std::sync::Arc;
 use teamtalk::Client;
-use teamtalk::types::UserAccount;
 use teloxide::{Bot, prelude::Requester};
 use tokio::sync::mpsc as tokio_mpsc;
 use tokio::sync::oneshot;
@@ -14,6 +16,8 @@ use tokio::task::{LocalSet, spawn_local};
 use tokio::time::Duration;
 use tokio_util::sync::CancellationToken;
 
+const BAN_EXPIRY_CHECK_SECS: u64 = 60;
+
 pub struct Application {
     config: Arc<Config>,
     db: Database,
@@ -28,16 +32,13 @@ struct BotInit {
 }
 
 struct SharedState {
-    online_users: Arc<RwLock<HashMap<i32, crate::core::types::LiteUser>>>,
-    online_users_by_username: Arc<RwLock<HashMap<String, i32>>>,
-    all_user_accounts: Arc<RwLock<HashMap<String, UserAccount>>>,
+    servers: Vec<ServerShared>,
 }
 
 struct TeamtalkWorkerConfig {
+    server_id: ServerId,
     config: Arc<Config>,
-    online_users: Arc<RwLock<HashMap<i32, crate::core::types::LiteUser>>>,
-    online_users_by_username: Arc<RwLock<HashMap<String, i32>>>,
-    user_accounts: Arc<RwLock<HashMap<String, UserAccount>>>,
+    shared: ServerShared,
     tx_bridge: tokio_mpsc::Sender<crate::core::types::BridgeEvent>,
     rx_tt_cmd: tokio_mpsc::Receiver<crate::core::types::TtCommand>,
     tx_tt_cmd: tokio_mpsc::Sender<crate::core::types::TtCommand>,
@@ -55,7 +56,7 @@ struct TelegramRunContext {
     config: Arc<Config>,
     cancel_token: CancellationToken,
     bridge_handle: tokio::task::JoinHandle<()>,
-    tt_handle: tokio::task::JoinHandle<()>,
+    tt_handles: Vec<tokio::task::JoinHandle<()>>,
 }
 
 impl Application {
@@ -64,6 +65,7 @@ impl Application {
 
         let config_content = std::fs::read_to_string(&config_path)?;
         let mut config: Config = toml::from_str(&config_content)?;
+        config.check_chat_bridges().map_err(|e| anyhow!(e))?;
 
         let config_path_obj = Path::new(&config_path);
         let config_dir = config_path_obj.parent().unwrap_or_else(|| Path::new("."));
@@ -82,6 +84,14 @@ impl Application {
 
         config.database.db_file.clone_from(&db_path_str);
 
+        if let Some(library_dir) = config.music.library_dir()
+            && library_dir.is_relative()
+        {
+            let library_dir = config_dir.join(library_dir);
+            tracing::info!(library_dir = %library_dir.display(), "Music library path");
+            config.music.library_dir = Some(library_dir.to_string_lossy().to_string());
+        }
+
         let config = Arc::new(config);
         let db = Database::new(&db_path_str).await?;
         let cancel_token = CancellationToken::new();
@@ -107,38 +117,53 @@ impl Application {
             cancel_token.clone(),
         );
         spawn_pending_cleanup_task(db.clone(), 3600, 3600, cancel_token.clone());
+        sweep_spool_dir(&db, &config).await;
 
         let local = LocalSet::new();
-        let client = tokio::task::block_in_place(Client::new)
-            .map_err(|e| anyhow!("Failed to initialize TeamTalk SDK: {e}"))?;
+        let mut clients = Vec::with_capacity(config.teamtalk.len());
+        for _ in &config.teamtalk {
+            let client = tokio::task::block_in_place(Client::new)
+                .map_err(|e| anyhow!("Failed to initialize TeamTalk SDK: {e}"))?;
+            clients.push(client);
+        }
 
         local
             .run_until(async move {
-                let shared = init_shared_state();
+                let shared = init_shared_state(&config);
                 let (tx_bridge, rx_bridge) =
                     tokio_mpsc::channel::<crate::core::types::BridgeEvent>(100);
                 let (tx_tt_cmd, rx_tt_cmd) =
                     tokio_mpsc::channel::<crate::core::types::TtCommand>(256);
 
                 let bots = init_bots(&config).await?;
-                let tt_handle = start_teamtalk_worker(TeamtalkWorkerConfig {
-                    config: config.clone(),
-                    online_users: shared.online_users.clone(),
-                    online_users_by_username: shared.online_users_by_username.clone(),
-                    user_accounts: shared.all_user_accounts.clone(),
-                    tx_bridge: tx_bridge.clone(),
-                    rx_tt_cmd,
-                    tx_tt_cmd: tx_tt_cmd.clone(),
-                    db: db.clone(),
-                    bot_username: bots.bot_username.clone(),
-                    client,
-                })
-                .await?;
+                let mut worker_senders = Vec::with_capacity(clients.len());
+                let mut tt_handles = Vec::with_capacity(clients.len());
+                for ((server_id, client), server) in
+                    config.server_ids().zip(clients).zip(&shared.servers)
+                {
+                    let (tx_worker, rx_worker) =
+                        tokio_mpsc::channel::<crate::core::types::TtCommand>(256);
+                    let tt_handle = start_teamtalk_worker(TeamtalkWorkerConfig {
+                        server_id,
+                        config: config.clone(),
+                        shared: server.clone(),
+                        tx_bridge: tx_bridge.clone(),
+                        rx_tt_cmd: rx_worker,
+                        tx_tt_cmd: tx_worker.clone(),
+                        db: db.clone(),
+                        bot_username: bots.bot_username.clone(),
+                        client,
+                    })
+                    .await?;
+                    worker_senders.push(tx_worker);
+                    tt_handles.push(tt_handle);
+                }
+                tokio::spawn(adapters::tt::run_command_router(rx_tt_cmd, worker_senders));
 
                 let bridge_handle = tokio::spawn(adapters::bridge::run_bridge(
                     adapters::bridge::BridgeContext {
                         db: db.clone(),
-                        online_users: shared.online_users.clone(),
+                        servers: shared.servers.clone(),
                         config: config.clone(),
                         event_bot: bots.event_bot.clone(),
                         msg_bot: bots.message_bot.clone(),
@@ -149,6 +174,27 @@ impl Application {
                     rx_bridge,
                 ));
 
+                let report_bot = bots.message_bot.clone().or_else(|| {
+                    if bots.message_token_present {
+                        bots.event_bot.clone()
+                    } else {
+                        None
+                    }
+                });
+                spawn_activity_report_task(
+                    db.clone(),
+                    config.clone(),
+                    report_bot,
+                    cancel_token.clone(),
+                );
+
+                spawn_ban_expiry_task(
+                    db.clone(),
+                    config.clone(),
+                    tx_tt_cmd.clone(),
+                    cancel_token.clone(),
+                );
+
                 tokio::spawn(wait_for_shutdown_signal(
                     cancel_token.clone(),
                     tx_tt_cmd.clone(),
@@ -163,7 +209,7 @@ impl Application {
                     config,
                     cancel_token,
                     bridge_handle,
-                    tt_handle,
+                    tt_handles,
                 })
                 .await?;
 
@@ -200,6 +246,111 @@ fn spawn_deeplink_cleanup_task(
     });
 }
 
+fn spawn_activity_report_task(
+    db: Database,
+    config: Arc<Config>,
+    bot: Option<Bot>,
+    cancel_token: CancellationToken,
+) {
+    let schedule = config.reports.schedule;
+    if schedule == ReportSchedule::Off {
+        return;
+    }
+    let Some(bot) = bot else {
+        tracing::warn!(
+            config_key = "message_token",
+            "Activity reports disabled: no bot to deliver them"
+        );
+        return;
+    };
+    tokio::spawn(async move {
+        loop {
+            let now = chrono::Utc::now();
+            let Some(next) = reports_service::next_run(now, &config.reports) else {
+                break;
+            };
+            let wait = (next - now).to_std().unwrap_or_default();
+            tracing::debug!(next_run = %next, "Scheduled activity report");
+            tokio::select! {
+                () = cancel_token.cancelled() => break,
+                () = tokio::time::sleep(wait) => {}
+            }
+
+            let end = chrono::Utc::now().naive_utc();
+            let start = end - reports_service::report_period(schedule);
+            match reports_service::build_report(&db, start, end).await {
+                Ok(report) => send_activity_report(&bot, &db, &config, &report).await,
+                Err(e) => {
+                    tracing::error!(error = %e, "Failed to build activity report");
+                }
+            }
+        }
+    });
+}
+
+async fn send_activity_report(
+    bot: &Bot,
+    db: &Database,
+    config: &Config,
+    report: &reports_service::ActivityReport,
+) {
+    let mut recipients = vec![config.telegram.admin_chat_id];
+    match db.get_all_admins().await {
+        Ok(admins) => {
+            for admin in admins {
+                if !recipients.contains(&admin) {
+                    recipients.push(admin);
+                }
+            }
+        }
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to load admin list for report");
+        }
+    }
+
+    let default_lang = config.general.default_lang;
+    for chat_id in recipients {
+        let lang = db.get_user_lang(chat_id).await.unwrap_or(default_lang);
+        let text = reports_service::render_report(lang, config.reports.schedule, report);
+        if let Err(e) = bot
+            .send_message(teloxide::types::ChatId(chat_id), text)
+            .await
+        {
+            tracing::warn!(telegram_id = chat_id, error = %e, "Failed to send activity report");
+        }
+    }
+}
+
+fn spawn_ban_expiry_task(
+    db: Database,
+    config: Arc<Config>,
+    tx_tt_cmd: tokio_mpsc::Sender<crate::core::types::TtCommand>,
+    cancel_token: CancellationToken,
+) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(BAN_EXPIRY_CHECK_SECS));
+        loop {
+            tokio::select! {
+                () = cancel_token.cancelled() => break,
+                _ = interval.tick() => {}
+            }
+            let now = chrono::Utc::now().naive_utc();
+            match bans_service::lift_expired(&db, &config, now).await {
+                Ok(commands) => {
+                    for cmd in commands {
+                        if let Err(e) = tx_tt_cmd.send(cmd).await {
+                            tracing::error!(error = %e, "Failed to send unban command");
+                        }
+                    }
+                }
+                Err(e) => {
+                    tracing::error!(error = %e, "Failed to lift expired bans");
+                }
+            }
+        }
+    });
+}
+
 fn spawn_pending_cleanup_task(
     db: Database,
     cleanup_interval: u64,
@@ -238,18 +389,34 @@ fn spawn_pending_cleanup_task(
     });
 }
 
-fn init_shared_state() -> SharedState {
-    let online_users: Arc<RwLock<HashMap<i32, crate::core::types::LiteUser>>> =
-        Arc::new(RwLock::new(HashMap::new()));
-    let online_users_by_username: Arc<RwLock<HashMap<String, i32>>> =
-        Arc::new(RwLock::new(HashMap::new()));
-    let all_user_accounts: Arc<RwLock<HashMap<String, UserAccount>>> =
-        Arc::new(RwLock::new(HashMap::new()));
+async fn sweep_spool_dir(db: &Database, config: &Config) {
+    let spool_dir = config.database.spool_dir();
+    match adapters::tt::stream::sweep_spool(db, &spool_dir).await {
+        Ok(0) => {}
+        Ok(removed) => {
+            tracing::info!(
+                spool_dir = %spool_dir.display(),
+                removed,
+                "Removed orphaned spool files"
+            );
+        }
+        Err(e) => {
+            tracing::warn!(
+                spool_dir = %spool_dir.display(),
+                error = %e,
+                "Failed to sweep spool directory"
+            );
+        }
+    }
+}
 
+fn init_shared_state(config: &Config) -> SharedState {
     SharedState {
-        online_users,
-        online_users_by_username,
-        all_user_accounts,
+        servers: config
+            .teamtalk
+            .iter()
+            .map(|_| ServerShared::default())
+            .collect(),
     }
 }
 
@@ -302,12 +469,12 @@ async fn init_bots(config: &Arc<Config>) -> Result<BotInit> {
 #[allow(clippy::future_not_send)]
 async fn start_teamtalk_worker(cfg: TeamtalkWorkerConfig) -> Result<tokio::task::JoinHandle<()>> {
     let (tx_init, rx_init) = oneshot::channel();
+    let server_id = cfg.server_id;
     let tt_handle = {
         let TeamtalkWorkerConfig {
+            server_id,
             config,
-            online_users,
-            online_users_by_username,
-            user_accounts,
+            shared,
             tx_bridge,
             rx_tt_cmd,
             tx_tt_cmd,
@@ -317,10 +484,9 @@ async fn start_teamtalk_worker(cfg: TeamtalkWorkerConfig) -> Result<tokio::task:
         } = cfg;
         spawn_local(adapters::tt::run_teamtalk_worker(
             adapters::tt::RunTeamtalkArgs {
+                server_id,
                 config,
-                online_users,
-                online_users_by_username,
-                user_accounts,
+                shared,
                 tx_bridge,
                 rx_cmd: rx_tt_cmd,
                 tx_cmd_clone: tx_tt_cmd,
@@ -333,7 +499,7 @@ async fn start_teamtalk_worker(cfg: TeamtalkWorkerConfig) -> Result<tokio::task:
     };
 
     match rx_init.await {
-        Ok(Ok(())) => tracing::info!("TeamTalk worker started successfully"),
+        Ok(Ok(())) => tracing::info!(%server_id, "TeamTalk worker started successfully"),
         Ok(Err(e)) => return Err(anyhow!("TeamTalk worker failed to start: {e}")),
         Err(_) => return Err(anyhow!("TeamTalk worker disconnected during startup")),
     }
@@ -347,18 +513,21 @@ async fn run_telegram_or_wait(ctx: TelegramRunContext) -> Result<()> {
             event_bot: bot,
             message_bot: ctx.message_bot,
             db: ctx.db.clone(),
-            online_users: ctx.shared.online_users,
-            user_accounts: ctx.shared.all_user_accounts,
+            servers: ctx.shared.servers,
             tx_tt_cmd: ctx.tx_tt_cmd,
             config: ctx.config,
             cancel_token: ctx.cancel_token,
         })
         .await;
         let _ = ctx.bridge_handle.await;
-        let _ = ctx.tt_handle.await;
+        for handle in ctx.tt_handles {
+            let _ = handle.await;
+        }
     } else if let Err(e) = ctx.bridge_handle.await {
         tracing::error!(error = %e, "Bridge task failed");
-        let _ = ctx.tt_handle.await;
+        for handle in ctx.tt_handles {
+            let _ = handle.await;
+        }
     }
 
     tracing::info!(component = "shutdown", "Closing database pool");
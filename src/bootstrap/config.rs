@@ -1,5 +1,8 @@
-use crate::core::types::LanguageCode;
-use serde::Deserialize;
+use crate::core::types::{LanguageCode, ServerId};
+use chrono::{FixedOffset, NaiveTime, Offset, Utc, Weekday};
+use serde::{Deserialize, Deserializer, de::Error as _};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
 use teamtalk::types::UserGender;
 
 #[derive(Deserialize, Clone)]
@@ -7,10 +10,146 @@ pub struct Config {
     pub general: GeneralConfig,
     pub database: DatabaseConfig,
     pub telegram: TelegramConfig,
-    pub teamtalk: TeamTalkConfig,
+    #[serde(deserialize_with = "deserialize_servers")]
+    pub teamtalk: Vec<TeamTalkConfig>,
 
     #[serde(default)]
     pub operational_parameters: OperationalParameters,
+
+    #[serde(default)]
+    pub reports: ReportsConfig,
+
+    #[serde(default)]
+    pub music: MusicConfig,
+
+    #[serde(default)]
+    #[serde(rename = "chat_bridge")]
+    pub chat_bridges: Vec<ChatBridgeConfig>,
+}
+
+impl Config {
+    pub fn server(&self, server_id: ServerId) -> Option<&TeamTalkConfig> {
+        self.teamtalk.get(server_id.0)
+    }
+
+    pub fn server_ids(&self) -> impl Iterator<Item = ServerId> + '_ {
+        (0..self.teamtalk.len()).map(ServerId)
+    }
+
+    pub fn server_key(&self, server_id: ServerId) -> String {
+        self.server(server_id)
+            .map_or_else(String::new, TeamTalkConfig::key)
+    }
+
+    pub fn server_display_name(&self, server_id: ServerId) -> &str {
+        self.server(server_id)
+            .map_or("Unknown", TeamTalkConfig::display_name)
+    }
+
+    pub fn server_display_name_by_key<'a>(&'a self, key: &'a str) -> &'a str {
+        self.teamtalk
+            .iter()
+            .find(|s| s.key() == key)
+            .map_or(key, TeamTalkConfig::display_name)
+    }
+
+    pub fn server_id_by_key(&self, key: &str) -> Option<ServerId> {
+        self.teamtalk
+            .iter()
+            .position(|s| s.key() == key)
+            .map(ServerId)
+    }
+
+    pub const fn is_multi_server(&self) -> bool {
+        self.teamtalk.len() > 1
+    }
+
+    pub fn chat_bridge_server(&self, bridge: &ChatBridgeConfig) -> Option<ServerId> {
+        bridge.server.as_deref().map_or(Some(ServerId(0)), |key| {
+            self.teamtalk
+                .iter()
+                .position(|s| s.key() == key)
+                .map(ServerId)
+        })
+    }
+
+    pub fn chat_bridge_for_channel(
+        &self,
+        server_id: ServerId,
+        channel_path: &str,
+    ) -> Option<&ChatBridgeConfig> {
+        self.chat_bridges.iter().find(|b| {
+            b.enabled
+                && self.chat_bridge_server(b) == Some(server_id)
+                && same_channel_path(&b.channel, channel_path)
+        })
+    }
+
+    pub fn chat_bridge_for_chat(&self, chat_id: i64) -> Option<(ServerId, &ChatBridgeConfig)> {
+        self.chat_bridges
+            .iter()
+            .filter(|b| b.enabled && b.chat_id == chat_id)
+            .find_map(|b| self.chat_bridge_server(b).map(|server_id| (server_id, b)))
+    }
+
+    pub fn check_chat_bridges(&self) -> Result<(), String> {
+        let mut chats = HashSet::new();
+        for bridge in &self.chat_bridges {
+            if self.chat_bridge_server(bridge).is_none() {
+                return Err(format!(
+                    "chat_bridge for chat {} refers to unknown teamtalk server '{}'",
+                    bridge.chat_id,
+                    bridge.server.as_deref().unwrap_or_default()
+                ));
+            }
+            if !chats.insert(bridge.chat_id) {
+                return Err(format!(
+                    "chat {} is bound by more than one chat_bridge",
+                    bridge.chat_id
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn guest_usernames(&self) -> Vec<String> {
+        self.teamtalk
+            .iter()
+            .filter_map(|s| s.guest_username.clone())
+            .collect()
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum OneOrManyServers {
+    Many(Vec<TeamTalkConfig>),
+    One(Box<TeamTalkConfig>),
+}
+
+fn deserialize_servers<'de, D>(deserializer: D) -> Result<Vec<TeamTalkConfig>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let servers = match OneOrManyServers::deserialize(deserializer)? {
+        OneOrManyServers::Many(servers) => servers,
+        OneOrManyServers::One(server) => vec![*server],
+    };
+    if servers.is_empty() {
+        return Err(D::Error::custom(
+            "at least one [[teamtalk]] server is required",
+        ));
+    }
+    let mut keys = HashSet::new();
+    for server in &servers {
+        let key = server.key();
+        if !keys.insert(key.clone()) {
+            return Err(D::Error::custom(format!(
+                "duplicate teamtalk server id '{key}'"
+            )));
+        }
+    }
+    Ok(servers)
 }
 
 #[derive(Deserialize, Clone)]
@@ -80,11 +219,139 @@ const fn default_deeplink_cleanup_interval_seconds() -> u64 {
     3600
 }
 
+const fn default_digest_batch_minutes() -> u64 {
+    15
+}
+
+const fn default_online_sample_interval_seconds() -> u64 {
+    300
+}
+
+const fn default_file_download_max_mb() -> u64 {
+    50
+}
+
+const fn default_health_alert_cooldown_seconds() -> u64 {
+    300
+}
+
+const fn default_login_failure_alert_threshold() -> u32 {
+    3
+}
+
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ReportSchedule {
+    #[default]
+    Off,
+    Daily,
+    Weekly,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct ReportsConfig {
+    #[serde(default)]
+    pub schedule: ReportSchedule,
+    #[serde(default = "default_report_time")]
+    #[serde(deserialize_with = "deserialize_report_time")]
+    pub time: NaiveTime,
+    #[serde(default = "default_report_weekday")]
+    pub weekday: Weekday,
+    #[serde(default = "default_utc_offset")]
+    #[serde(deserialize_with = "deserialize_utc_offset")]
+    pub utc_offset: FixedOffset,
+}
+
+impl Default for ReportsConfig {
+    fn default() -> Self {
+        Self {
+            schedule: ReportSchedule::Off,
+            time: default_report_time(),
+            weekday: default_report_weekday(),
+            utc_offset: default_utc_offset(),
+        }
+    }
+}
+
+fn default_report_time() -> NaiveTime {
+    NaiveTime::from_hms_opt(9, 0, 0).unwrap_or_default()
+}
+
+const fn default_report_weekday() -> Weekday {
+    Weekday::Mon
+}
+
+fn default_utc_offset() -> FixedOffset {
+    Utc.fix()
+}
+
+fn deserialize_report_time<'de, D>(deserializer: D) -> Result<NaiveTime, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let raw = String::deserialize(deserializer)?;
+    NaiveTime::parse_from_str(&raw, "%H:%M")
+        .map_err(|e| D::Error::custom(format!("invalid report time '{raw}': {e}")))
+}
+
+fn deserialize_utc_offset<'de, D>(deserializer: D) -> Result<FixedOffset, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let raw = String::deserialize(deserializer)?;
+    raw.parse::<FixedOffset>().map_err(|e| {
+        D::Error::custom(format!(
+            "invalid utc_offset '{raw}': {e}; expected a fixed offset such as \"+03:00\" \
+             (time zone names are not supported and daylight saving time is not applied)"
+        ))
+    })
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct ChatBridgeConfig {
+    pub server: Option<String>,
+    pub channel: String,
+    pub chat_id: i64,
+    #[serde(default = "default_bridge_enabled")]
+    pub enabled: bool,
+}
+
+const fn default_bridge_enabled() -> bool {
+    true
+}
+
+pub fn same_channel_path(a: &str, b: &str) -> bool {
+    a.trim_end_matches('/') == b.trim_end_matches('/')
+}
+
+#[derive(Deserialize, Clone, Default)]
+pub struct MusicConfig {
+    pub library_dir: Option<String>,
+}
+
+impl MusicConfig {
+    pub fn library_dir(&self) -> Option<&Path> {
+        self.library_dir
+            .as_deref()
+            .filter(|dir| !dir.trim().is_empty())
+            .map(Path::new)
+    }
+}
+
 #[derive(Deserialize, Clone)]
 pub struct DatabaseConfig {
     pub db_file: String,
 }
 
+impl DatabaseConfig {
+    pub fn spool_dir(&self) -> PathBuf {
+        Path::new(&self.db_file)
+            .parent()
+            .unwrap_or_else(|| Path::new("."))
+            .join("spool")
+    }
+}
+
 #[derive(Deserialize, Clone)]
 pub struct TelegramConfig {
     pub event_token: Option<String>,
@@ -94,6 +361,7 @@ pub struct TelegramConfig {
 
 #[derive(Deserialize, Clone)]
 pub struct TeamTalkConfig {
+    pub id: Option<String>,
     pub host_name: String,
     pub port: u32,
     pub encrypted: bool,
@@ -118,6 +386,13 @@ impl TeamTalkConfig {
             .filter(|s| !s.is_empty())
             .unwrap_or(&self.host_name)
     }
+
+    pub fn key(&self) -> String {
+        self.id
+            .clone()
+            .filter(|id| !id.is_empty())
+            .unwrap_or_else(|| format!("{}:{}", self.host_name, self.port))
+    }
 }
 
 #[cfg(test)]
@@ -135,6 +410,23 @@ pub struct OperationalParameters {
     pub deeplink_cleanup_interval: u64,
     #[serde(rename = "tt_reconnect_check_interval_seconds")]
     pub tt_reconnect_check_interval: u64,
+    #[serde(default)]
+    #[serde(rename = "reconnect_grace_seconds")]
+    pub reconnect_grace: u64,
+    #[serde(default = "default_digest_batch_minutes")]
+    #[serde(rename = "digest_batch_minutes")]
+    pub digest_batch: u64,
+    #[serde(default = "default_online_sample_interval_seconds")]
+    #[serde(rename = "online_sample_interval_seconds")]
+    pub online_sample_interval: u64,
+    #[serde(default = "default_file_download_max_mb")]
+    #[serde(rename = "file_download_max_mb")]
+    pub file_download_max: u64,
+    #[serde(default = "default_health_alert_cooldown_seconds")]
+    #[serde(rename = "health_alert_cooldown_seconds")]
+    pub health_alert_cooldown: u64,
+    #[serde(default = "default_login_failure_alert_threshold")]
+    pub login_failure_alert_threshold: u32,
 }
 
 impl Default for OperationalParameters {
@@ -144,6 +436,12 @@ impl Default for OperationalParameters {
             tt_reconnect_retry: 10,
             deeplink_cleanup_interval: 3600,
             tt_reconnect_check_interval: 30,
+            reconnect_grace: 0,
+            digest_batch: 15,
+            online_sample_interval: 300,
+            file_download_max: 50,
+            health_alert_cooldown: 300,
+            login_failure_alert_threshold: 3,
         }
     }
 }
@@ -1,4 +1,7 @@
-use crate::core::types::{LanguageCode, MuteListMode, NotificationSetting, TtUsername};
+use crate::core::types::{
+    AccountField, AccountType, BanTerm, ChannelField, DeliveryMode, EventOptIn, LanguageCode,
+    MuteListMode, NotificationSetting, QueueOp, ServerField, ServerId, TtUsername,
+};
 use anyhow::{Result, anyhow};
 use derive_more::From;
 use serde::{Deserialize, Serialize};
@@ -13,6 +16,19 @@ pub enum CallbackAction {
     Mute(MuteAction),
     Unsub(UnsubAction),
     NoOp,
+    File(FileAction),
+    Queue(QueueAction),
+}
+
+impl CallbackAction {
+    pub const fn changes_chat_settings(&self) -> bool {
+        matches!(self, Self::Settings(_) | Self::Mute(_) | Self::Unsub(_))
+    }
+
+    pub const fn acts_for_chat(&self, private_chat: bool) -> bool {
+        self.changes_chat_settings()
+            || (!private_chat && matches!(self, Self::Menu(_) | Self::File(_)))
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
@@ -21,17 +37,154 @@ pub enum MenuAction {
     Settings,
     Help,
     Unsub,
+    WhoServer {
+        server_id: ServerId,
+    },
+    RegisterServer {
+        server_id: ServerId,
+        username: TtUsername,
+    },
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
 pub enum AdminAction {
-    KickList { page: usize },
-    KickPerform { user_id: i32 },
-    BanList { page: usize },
-    BanPerform { user_id: i32 },
-    UnbanList { page: usize },
-    UnbanPerform { ban_db_id: i64, page: usize },
-    SubsList { page: usize },
+    KickList {
+        server_id: ServerId,
+        page: usize,
+    },
+    KickPerform {
+        server_id: ServerId,
+        user_id: i32,
+    },
+    BanList {
+        server_id: ServerId,
+        page: usize,
+    },
+    BanPerform {
+        server_id: ServerId,
+        user_id: i32,
+    },
+    UnbanList {
+        page: usize,
+    },
+    UnbanPerform {
+        ban_db_id: i64,
+        page: usize,
+    },
+    SubsList {
+        page: usize,
+    },
+    KickServers,
+    BanServers,
+    BanTerm {
+        server_id: ServerId,
+        user_id: i32,
+        term: BanTerm,
+    },
+    BanConfirm {
+        server_id: ServerId,
+        user_id: i32,
+        term: BanTerm,
+    },
+    ServerBanList {
+        server_id: ServerId,
+        page: usize,
+    },
+    ServerUnban {
+        server_id: ServerId,
+        page: usize,
+        ban_key: u64,
+    },
+    MoveServers,
+    MoveList {
+        server_id: ServerId,
+        page: usize,
+    },
+    MoveChannels {
+        server_id: ServerId,
+        user_id: i32,
+        page: usize,
+    },
+    MovePerform {
+        server_id: ServerId,
+        user_id: i32,
+        channel_id: i32,
+    },
+    ChannelServers,
+    ChannelList {
+        server_id: ServerId,
+        page: usize,
+    },
+    ChannelView {
+        server_id: ServerId,
+        channel_id: i32,
+    },
+    ChannelPrompt {
+        server_id: ServerId,
+        channel_id: i32,
+        field: ChannelField,
+    },
+    ChannelDelete {
+        server_id: ServerId,
+        channel_id: i32,
+    },
+    ChannelDeleteConfirm {
+        server_id: ServerId,
+        channel_id: i32,
+    },
+    AccountServers,
+    AccountList {
+        server_id: ServerId,
+        page: usize,
+    },
+    AccountView {
+        server_id: ServerId,
+        username: TtUsername,
+    },
+    AccountSearch {
+        server_id: ServerId,
+    },
+    AccountCreate {
+        server_id: ServerId,
+    },
+    AccountPrompt {
+        server_id: ServerId,
+        username: TtUsername,
+        field: AccountField,
+    },
+    AccountSetType {
+        server_id: ServerId,
+        username: TtUsername,
+        account_type: AccountType,
+    },
+    AccountDelete {
+        server_id: ServerId,
+        username: TtUsername,
+    },
+    AccountDeleteConfirm {
+        server_id: ServerId,
+        username: TtUsername,
+    },
+    RegistrationApprove {
+        request_id: i64,
+    },
+    RegistrationReject {
+        request_id: i64,
+    },
+    ServerInfoServers,
+    ServerInfo {
+        server_id: ServerId,
+    },
+    ServerPrompt {
+        server_id: ServerId,
+        field: ServerField,
+    },
+    MediaServer {
+        server_id: ServerId,
+    },
+    MusicServer {
+        server_id: ServerId,
+    },
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
@@ -44,6 +197,11 @@ pub enum SettingsAction {
     NotifSelect,
     NoonToggle,
     MuteManage,
+    ServersSelect,
+    ServerToggle { server_id: ServerId },
+    EventOptInToggle { kind: EventOptIn },
+    DeliverySelect,
+    DeliverySet { mode: DeliveryMode },
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
@@ -150,6 +308,20 @@ pub enum UnsubAction {
     Cancel,
 }
 
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub enum FileAction {
+    Download {
+        server_id: ServerId,
+        channel_id: i32,
+        file_id: i32,
+    },
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub enum QueueAction {
+    Control { server_id: ServerId, op: QueueOp },
+}
+
 pub trait AsCallbackData {
     fn into_data(self) -> String;
 }
@@ -128,6 +128,36 @@ impl TryFrom<&str> for MuteListMode {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DeliveryMode {
+    Instant,
+    Batched,
+    Hourly,
+}
+
+impl fmt::Display for DeliveryMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Instant => write!(f, "instant"),
+            Self::Batched => write!(f, "batched"),
+            Self::Hourly => write!(f, "hourly"),
+        }
+    }
+}
+
+impl TryFrom<&str> for DeliveryMode {
+    type Error = &'static str;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "instant" => Ok(Self::Instant),
+            "batched" => Ok(Self::Batched),
+            "hourly" => Ok(Self::Hourly),
+            _ => Err("unsupported delivery mode"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct TtUsername(String);
@@ -166,6 +196,16 @@ impl From<&str> for TtUsername {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ServerId(pub usize);
+
+impl fmt::Display for ServerId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AdminErrorContext {
     Command,
@@ -190,12 +230,15 @@ impl AdminErrorContext {
 #[derive(Debug)]
 pub enum BridgeEvent {
     Broadcast {
+        server_id: ServerId,
         event_type: NotificationType,
         nickname: String,
         server_name: String,
         related_tt_username: String,
+        detail: Option<BroadcastDetail>,
     },
     ToAdmin {
+        server_id: ServerId,
         user_id: i32,
         nick: String,
         tt_username: String,
@@ -203,6 +246,7 @@ pub enum BridgeEvent {
         server_name: String,
     },
     ToAdminChannel {
+        server_id: ServerId,
         channel_id: i32,
         channel_name: String,
         server_name: String,
@@ -213,53 +257,588 @@ pub enum BridgeEvent {
         text: String,
         reply_to: Option<i32>,
     },
+    ServerBroadcast {
+        server_id: ServerId,
+        server_name: String,
+        nickname: String,
+        tt_username: String,
+        text: String,
+    },
+    ChatMirror {
+        chat_id: i64,
+        nickname: String,
+        text: String,
+    },
+    FileDownloaded {
+        chat_id: i64,
+        file_path: String,
+        file_name: String,
+    },
+    FileDownloadFailed {
+        chat_id: i64,
+        lang: LanguageCode,
+        file_name: String,
+        error: FileDownloadError,
+    },
+    FileUploadProgress {
+        chat_id: i64,
+        lang: LanguageCode,
+        upload_id: String,
+        file_name: String,
+        transferred: i64,
+        total: i64,
+    },
+    FileUploaded {
+        chat_id: i64,
+        lang: LanguageCode,
+        upload_id: String,
+        file_name: String,
+        channel: String,
+    },
+    FileUploadFailed {
+        chat_id: i64,
+        lang: LanguageCode,
+        upload_id: String,
+        file_name: String,
+        error: FileUploadError,
+    },
+    UserMoved {
+        chat_id: i64,
+        lang: LanguageCode,
+        nickname: String,
+        channel: String,
+    },
+    UserMoveFailed {
+        chat_id: i64,
+        lang: LanguageCode,
+        nickname: String,
+        error: MoveUserError,
+    },
+    BanLifted {
+        chat_id: i64,
+        lang: LanguageCode,
+        ip_address: String,
+    },
+    BanLiftFailed {
+        chat_id: i64,
+        lang: LanguageCode,
+        ip_address: String,
+        error: BanLiftError,
+    },
+    ChannelChanged {
+        chat_id: i64,
+        lang: LanguageCode,
+        op: ChannelOp,
+        path: String,
+    },
+    ChannelChangeFailed {
+        chat_id: i64,
+        lang: LanguageCode,
+        op: ChannelOp,
+        path: String,
+        error: ChannelChangeError,
+    },
+    AccountChanged {
+        chat_id: i64,
+        lang: LanguageCode,
+        op: AccountOp,
+        username: String,
+    },
+    AccountChangeFailed {
+        chat_id: i64,
+        lang: LanguageCode,
+        op: AccountOp,
+        username: String,
+        error: AccountChangeError,
+    },
+    AccountRegistered {
+        server_id: ServerId,
+        chat_id: i64,
+        lang: LanguageCode,
+        registration: AccountRegistration,
+    },
+    ServerEdited {
+        chat_id: i64,
+        lang: LanguageCode,
+        field: ServerField,
+    },
+    ServerEditFailed {
+        chat_id: i64,
+        lang: LanguageCode,
+        field: ServerField,
+    },
+    ServerInfoChanged {
+        server_id: ServerId,
+        previous: LiteServerInfo,
+        current: LiteServerInfo,
+    },
+    ServerHealth {
+        server_id: ServerId,
+        alert: HealthAlert,
+        notify_subscribers: bool,
+    },
+    QueueView {
+        server_id: ServerId,
+        chat_id: i64,
+        message_id: i32,
+        lang: LanguageCode,
+        snapshot: QueueSnapshot,
+    },
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum NotificationType {
     Join,
     Leave,
+    ChannelJoin,
+    ChannelLeave,
+    NickChange,
+    StatusChange,
+    ServerBroadcast,
+    FileUpload,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EventOptIn {
+    ChannelMoves,
+    NickChanges,
+    StatusChanges,
+    ServerBroadcasts,
+    FileUploads,
+    ServerHealth,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Presence {
+    Available,
+    Away,
+    Question,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BroadcastDetail {
+    Channel {
+        path: String,
+    },
+    Nickname {
+        previous: String,
+    },
+    Status {
+        presence: Presence,
+        text: String,
+    },
+    Message {
+        text: String,
+    },
+    File {
+        path: String,
+        channel_id: i32,
+        file_id: i32,
+        name: String,
+        size: i64,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileDownloadError {
+    NotFound,
+    TooLarge { limit_mb: u64 },
+    Failed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileUploadError {
+    ChannelNotFound,
+    NotAllowed,
+    QuotaExceeded,
+    Failed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MoveUserError {
+    UserOffline,
+    ChannelNotFound,
+    Failed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BanLiftError {
+    Failed,
+    Unconfirmed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChannelField {
+    SubChannel,
+    Topic,
+    Password,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct NewChannel {
+    pub name: String,
+    pub password: String,
+    pub topic: String,
+    pub max_users: Option<i32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChannelEdit {
+    Topic(String),
+    Password(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChannelOp {
+    Create,
+    EditTopic,
+    EditPassword,
+    Delete,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChannelChangeError {
+    NotFound,
+    NotEmpty,
+    Failed,
+    Unconfirmed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AccountType {
+    Default,
+    Admin,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AccountField {
+    Password,
+    Note,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct NewAccount {
+    pub username: String,
+    pub password: String,
+    pub note: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountRegistration {
+    pub request_id: i64,
+    pub username: String,
+    pub password: String,
+    pub note: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AccountEdit {
+    Create { password: String, note: String },
+    Password(String),
+    Note(String),
+    Type(AccountType),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccountOp {
+    Create,
+    Password,
+    Note,
+    Type,
+    Delete,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccountChangeError {
+    NotFound,
+    AlreadyExists,
+    Failed,
+    Unconfirmed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ServerField {
+    Name,
+    Motd,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ServerEdit {
+    Name(String),
+    Motd(String),
+}
+
+impl ServerEdit {
+    pub const fn field(&self) -> ServerField {
+        match self {
+            Self::Name(_) => ServerField::Name,
+            Self::Motd(_) => ServerField::Motd,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum QueueOp {
+    Show,
+    Remove { stream_id: u64 },
+    MoveTop { stream_id: u64 },
+    Clear,
+    Pause,
+    Resume,
+    Skip,
+    ToggleShuffle,
+    CycleRepeat,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum RepeatMode {
+    #[default]
+    Off,
+    All,
+    One,
+}
+
+impl RepeatMode {
+    pub const fn next(self) -> Self {
+        match self {
+            Self::Off => Self::All,
+            Self::All => Self::One,
+            Self::One => Self::Off,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BanTerm {
+    Hour,
+    Day,
+    Week,
+    Permanent,
+}
+
+impl BanTerm {
+    pub const ALL: [Self; 4] = [Self::Hour, Self::Day, Self::Week, Self::Permanent];
+
+    pub const fn seconds(self) -> Option<i64> {
+        match self {
+            Self::Hour => Some(3600),
+            Self::Day => Some(86_400),
+            Self::Week => Some(7 * 86_400),
+            Self::Permanent => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BanTarget {
+    IpAddress(String),
+    Username(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueueEntry {
+    pub stream_id: u64,
+    pub title: Option<String>,
+    pub duration_ms: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NowPlaying {
+    pub entry: QueueEntry,
+    pub elapsed_ms: u64,
+    pub paused: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct QueueSnapshot {
+    pub now_playing: Option<NowPlaying>,
+    pub pending: Vec<QueueEntry>,
+    pub shuffle: bool,
+    pub repeat: RepeatMode,
 }
 
 #[derive(Debug)]
 pub enum TtCommand {
     Shutdown,
     Broadcast {
+        server_id: ServerId,
         text: String,
     },
     ReplyToUser {
+        server_id: ServerId,
         user_id: i32,
         text: String,
     },
     SendToChannel {
+        server_id: ServerId,
         channel_id: i32,
         text: String,
     },
     EnqueueStream {
+        server_id: ServerId,
         channel_id: i32,
         file_path: String,
         duration_ms: u32,
         announce_text: Option<String>,
+        title: Option<String>,
     },
     StopStreamingIf {
+        server_id: ServerId,
         stream_id: u64,
     },
-    SkipStream,
+    SkipStream {
+        server_id: ServerId,
+    },
+    ResumeQueue {
+        server_id: ServerId,
+    },
     SetStreamingStatus {
+        server_id: ServerId,
         streaming: bool,
     },
     KickUser {
+        server_id: ServerId,
         user_id: i32,
     },
     BanUser {
+        server_id: ServerId,
+        user_id: i32,
+    },
+    UnbanUser {
+        server_id: ServerId,
+        ban_id: i64,
+        ip_address: String,
+        channel_id: Option<i32>,
+        reply_to: Option<(i64, LanguageCode)>,
+    },
+    ServerBan {
+        server_id: ServerId,
+        target: BanTarget,
+    },
+    LiftServerBan {
+        server_id: ServerId,
+        ban_key: u64,
+    },
+    LoadBans {
+        server_id: ServerId,
+    },
+    MoveUser {
+        server_id: ServerId,
         user_id: i32,
+        channel_id: i32,
+        chat_id: i64,
+        lang: LanguageCode,
+    },
+    CreateChannel {
+        server_id: ServerId,
+        parent_id: i32,
+        channel: NewChannel,
+        chat_id: i64,
+        lang: LanguageCode,
+    },
+    EditChannel {
+        server_id: ServerId,
+        channel_id: i32,
+        edit: ChannelEdit,
+        chat_id: i64,
+        lang: LanguageCode,
+    },
+    DeleteChannel {
+        server_id: ServerId,
+        channel_id: i32,
+        chat_id: i64,
+        lang: LanguageCode,
+    },
+    EditAccount {
+        server_id: ServerId,
+        username: String,
+        edit: AccountEdit,
+        chat_id: i64,
+        lang: LanguageCode,
+    },
+    DeleteAccount {
+        server_id: ServerId,
+        username: String,
+        chat_id: i64,
+        lang: LanguageCode,
+    },
+    RegisterAccount {
+        server_id: ServerId,
+        registration: AccountRegistration,
+        chat_id: i64,
+        lang: LanguageCode,
+    },
+    EditServer {
+        server_id: ServerId,
+        edit: ServerEdit,
+        chat_id: i64,
+        lang: LanguageCode,
     },
     Who {
+        server_id: ServerId,
         chat_id: i64,
         lang: LanguageCode,
         reply_to: Option<i32>,
     },
-    LoadAccounts,
+    LoadAccounts {
+        server_id: ServerId,
+    },
+    DownloadFile {
+        server_id: ServerId,
+        channel_id: i32,
+        file_id: i32,
+        chat_id: i64,
+        lang: LanguageCode,
+    },
+    UploadFile {
+        server_id: ServerId,
+        channel_id: i32,
+        path: String,
+        chat_id: i64,
+        lang: LanguageCode,
+    },
+    QueueControl {
+        server_id: ServerId,
+        chat_id: i64,
+        message_id: i32,
+        lang: LanguageCode,
+        op: QueueOp,
+    },
+}
+
+impl TtCommand {
+    pub const fn server_id(&self) -> Option<ServerId> {
+        match self {
+            Self::Shutdown => None,
+            Self::Broadcast { server_id, .. }
+            | Self::ReplyToUser { server_id, .. }
+            | Self::SendToChannel { server_id, .. }
+            | Self::EnqueueStream { server_id, .. }
+            | Self::StopStreamingIf { server_id, .. }
+            | Self::SkipStream { server_id }
+            | Self::ResumeQueue { server_id }
+            | Self::SetStreamingStatus { server_id, .. }
+            | Self::KickUser { server_id, .. }
+            | Self::BanUser { server_id, .. }
+            | Self::UnbanUser { server_id, .. }
+            | Self::ServerBan { server_id, .. }
+            | Self::LiftServerBan { server_id, .. }
+            | Self::LoadBans { server_id }
+            | Self::MoveUser { server_id, .. }
+            | Self::CreateChannel { server_id, .. }
+            | Self::EditChannel { server_id, .. }
+            | Self::DeleteChannel { server_id, .. }
+            | Self::EditAccount { server_id, .. }
+            | Self::DeleteAccount { server_id, .. }
+            | Self::RegisterAccount { server_id, .. }
+            | Self::EditServer { server_id, .. }
+            | Self::Who { server_id, .. }
+            | Self::LoadAccounts { server_id }
+            | Self::DownloadFile { server_id, .. }
+            | Self::UploadFile { server_id, .. }
+            | Self::QueueControl { server_id, .. } => Some(*server_id),
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -267,7 +846,37 @@ pub struct LiteUser {
     pub id: i32,
     pub nickname: String,
     pub username: String,
+    pub ip_address: String,
+    pub channel_id: i32,
     pub channel_name: String,
+    pub presence: Presence,
+    pub status_text: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LiteChannel {
+    pub id: i32,
+    pub parent_id: i32,
+    pub path: String,
+    pub topic: String,
+    pub password_protected: bool,
+    pub max_users: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct LiteServerInfo {
+    pub name: String,
+    pub motd: String,
+    pub max_users: i32,
+    pub login_delay_ms: i32,
+    pub version: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HealthAlert {
+    Down,
+    LoginFailing { attempts: u32 },
+    Restored { downtime: std::time::Duration },
 }
 
 #[cfg(test)]
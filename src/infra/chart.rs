@@ -0,0 +1,104 @@
+use anyhow::{Result, anyhow};
+use chrono::DateTime;
+use plotters::prelude::*;
+use plotters::style::register_font;
+use std::sync::OnceLock;
+
+const BACKGROUND: RGBColor = RGBColor(255, 255, 255);
+const GRID: RGBColor = RGBColor(220, 220, 220);
+const LINE: RGBColor = RGBColor(33, 150, 243);
+const FONT_FAMILY: &str = "sans-serif";
+const FONT: &[u8] = include_bytes!("../../assets/fonts/DejaVuSans.ttf");
+const HOURLY_TICKS_MAX_SPAN: i64 = 2 * 24 * 60 * 60;
+
+pub struct AxisLabels<'a> {
+    pub x: &'a str,
+    pub y: &'a str,
+}
+
+fn ensure_font() -> Result<()> {
+    static REGISTERED: OnceLock<bool> = OnceLock::new();
+    let registered =
+        *REGISTERED.get_or_init(|| register_font(FONT_FAMILY, FontStyle::Normal, FONT).is_ok());
+    if registered {
+        Ok(())
+    } else {
+        Err(anyhow!("chart font could not be loaded"))
+    }
+}
+
+fn format_tick(timestamp: i64, span: i64) -> String {
+    let format = if span <= HOURLY_TICKS_MAX_SPAN {
+        "%H:%M"
+    } else {
+        "%d.%m"
+    };
+    DateTime::from_timestamp(timestamp, 0)
+        .map(|dt| dt.format(format).to_string())
+        .unwrap_or_default()
+}
+
+pub fn render_line_chart(
+    points: &[(i64, u32)],
+    labels: &AxisLabels<'_>,
+    width: u32,
+    height: u32,
+) -> Result<Vec<u8>> {
+    let (Some(first), Some(last)) = (points.first(), points.last()) else {
+        return Err(anyhow!("no points to plot"));
+    };
+    ensure_font()?;
+    let x_range = first.0..last.0.max(first.0 + 1);
+    let span = x_range.end - x_range.start;
+    let y_max = points.iter().map(|(_, y)| *y).max().unwrap_or(0).max(1) + 1;
+
+    let pixels = usize::try_from(width * height * 3)?;
+    let mut buffer = vec![0_u8; pixels];
+    {
+        let root = BitMapBackend::with_buffer(&mut buffer, (width, height)).into_drawing_area();
+        root.fill(&BACKGROUND)
+            .map_err(|e| anyhow!("chart fill failed: {e}"))?;
+        let mut chart = ChartBuilder::on(&root)
+            .margin(16)
+            .x_label_area_size(48)
+            .y_label_area_size(56)
+            .build_cartesian_2d(x_range, 0..y_max)
+            .map_err(|e| anyhow!("chart setup failed: {e}"))?;
+
+        chart
+            .configure_mesh()
+            .bold_line_style(GRID)
+            .light_line_style(BACKGROUND)
+            .x_labels(6)
+            .y_labels(6)
+            .x_label_formatter(&|ts| format_tick(*ts, span))
+            .x_desc(labels.x)
+            .y_desc(labels.y)
+            .label_style((FONT_FAMILY, 14))
+            .axis_desc_style((FONT_FAMILY, 15))
+            .draw()
+            .map_err(|e| anyhow!("chart mesh failed: {e}"))?;
+        chart
+            .draw_series(
+                AreaSeries::new(points.iter().copied(), 0, LINE.mix(0.2))
+                    .border_style(LINE.stroke_width(2)),
+            )
+            .map_err(|e| anyhow!("chart series failed: {e}"))?;
+        root.present()
+            .map_err(|e| anyhow!("chart present failed: {e}"))?;
+    }
+
+    let mut png_bytes = Vec::new();
+    {
+        let mut encoder = png::Encoder::new(&mut png_bytes, width, height);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&buffer)?;
+    }
+    Ok(png_bytes)
+}
+
+#[cfg(test)]
+#[path = "../../tests/unit/infra_chart.rs"]
+mod tests;
@@ -1,7 +1,10 @@
 use anyhow::Result;
-use chrono::Utc;
+use chrono::{NaiveDateTime, Utc};
 
-use super::{Database, types::BanEntry};
+use super::{
+    Database,
+    types::{BanEntry, LiftedBan, NewBan},
+};
 
 impl Database {
     pub async fn add_ban(
@@ -10,13 +13,26 @@ impl Database {
         teamtalk_username: Option<String>,
         reason: Option<String>,
     ) -> Result<()> {
-        let now = Utc::now().naive_utc();
-        sqlx::query!(
-            "INSERT INTO ban_list (telegram_id, teamtalk_username, ban_reason, banned_at) VALUES (?, ?, ?, ?)",
+        self.add_ban_entry(&NewBan {
             telegram_id,
             teamtalk_username,
             reason,
-            now
+            ..NewBan::default()
+        })
+        .await
+    }
+
+    pub async fn add_ban_entry(&self, ban: &NewBan) -> Result<()> {
+        let now = Utc::now().naive_utc();
+        sqlx::query!(
+            "INSERT INTO ban_list (telegram_id, teamtalk_username, ban_reason, banned_at, expires_at, server_key, ip_address) VALUES (?, ?, ?, ?, ?, ?, ?)",
+            ban.telegram_id,
+            ban.teamtalk_username,
+            ban.reason,
+            now,
+            ban.expires_at,
+            ban.server_key,
+            ban.ip_address
         )
         .execute(&self.pool)
         .await?;
@@ -24,33 +40,83 @@ impl Database {
     }
 
     pub async fn get_banned_users(&self) -> Result<Vec<BanEntry>> {
+        let now = Utc::now().naive_utc();
         let rows = sqlx::query_as!(
             BanEntry,
             r#"
             SELECT
                 id as "id!",
                 telegram_id as "telegram_id?",
-                teamtalk_username as "teamtalk_username?"
+                teamtalk_username as "teamtalk_username?",
+                ban_reason as "ban_reason?",
+                expires_at as "expires_at?"
             FROM ban_list
+            WHERE expires_at IS NULL OR expires_at > ?
             ORDER BY banned_at DESC
-            "#
+            "#,
+            now
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows)
+    }
+
+    pub async fn remove_ban_by_id(&self, id: i64) -> Result<Option<LiftedBan>> {
+        let row = sqlx::query_as!(
+            LiftedBan,
+            "DELETE FROM ban_list WHERE id = ? RETURNING id, teamtalk_username, server_key, ip_address, channel_id",
+            id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(row)
+    }
+
+    pub async fn get_ban_by_id(&self, id: i64) -> Result<Option<LiftedBan>> {
+        let row = sqlx::query_as!(
+            LiftedBan,
+            "SELECT id, teamtalk_username, server_key, ip_address, channel_id FROM ban_list WHERE id = ?",
+            id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(row)
+    }
+
+    pub async fn get_expired_bans(&self, now: NaiveDateTime) -> Result<Vec<LiftedBan>> {
+        let rows = sqlx::query_as!(
+            LiftedBan,
+            "SELECT id, teamtalk_username, server_key, ip_address, channel_id FROM ban_list WHERE expires_at IS NOT NULL AND expires_at <= ?",
+            now
         )
         .fetch_all(&self.pool)
         .await?;
         Ok(rows)
     }
 
-    pub async fn remove_ban_by_id(&self, id: i64) -> Result<()> {
-        sqlx::query!("DELETE FROM ban_list WHERE id = ?", id)
-            .execute(&self.pool)
-            .await?;
+    pub async fn set_ban_channel(
+        &self,
+        server_key: &str,
+        ip_address: &str,
+        channel_id: i32,
+    ) -> Result<()> {
+        sqlx::query!(
+            "UPDATE ban_list SET channel_id = ? WHERE server_key = ? AND ip_address = ? AND channel_id IS NULL",
+            channel_id,
+            server_key,
+            ip_address
+        )
+        .execute(&self.pool)
+        .await?;
         Ok(())
     }
 
     pub async fn is_telegram_id_banned(&self, telegram_id: i64) -> Result<bool> {
+        let now = Utc::now().naive_utc();
         let record = sqlx::query!(
-            "SELECT count(*) as count FROM ban_list WHERE telegram_id = ?",
-            telegram_id
+            "SELECT count(*) as count FROM ban_list WHERE telegram_id = ? AND (expires_at IS NULL OR expires_at > ?)",
+            telegram_id,
+            now
         )
         .fetch_one(&self.pool)
         .await?;
@@ -58,9 +124,11 @@ impl Database {
     }
 
     pub async fn is_teamtalk_username_banned(&self, tt_username: &str) -> Result<bool> {
+        let now = Utc::now().naive_utc();
         let record = sqlx::query!(
-            "SELECT count(*) as count FROM ban_list WHERE teamtalk_username = ? COLLATE NOCASE",
-            tt_username
+            "SELECT count(*) as count FROM ban_list WHERE teamtalk_username = ? COLLATE NOCASE AND (expires_at IS NULL OR expires_at > ?)",
+            tt_username,
+            now
         )
         .fetch_one(&self.pool)
         .await?;
@@ -4,6 +4,13 @@ pub mod deeplinks;
 pub mod mutes;
 pub mod pending_channel_replies;
 pub mod pending_replies;
+pub mod playlists;
+pub mod registrations;
+pub mod reports;
+pub mod samples;
+pub mod servers;
+pub mod sessions;
+pub mod stream_queue;
 pub mod subscriptions;
 pub mod types;
 pub mod user_settings;
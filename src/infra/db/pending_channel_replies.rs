@@ -1,21 +1,23 @@
 use anyhow::Result;
 
-use super::Database;
+use super::{Database, types::PendingChannelReply};
 use sqlx::Row;
 
 impl Database {
     pub async fn add_pending_channel_reply(
         &self,
         tg_message_id: i64,
+        server_key: &str,
         channel_id: i32,
         channel_name: &str,
         server_name: &str,
         original_text: &str,
     ) -> Result<()> {
         sqlx::query(
-            "INSERT OR IGNORE INTO pending_channel_replies (tg_message_id, channel_id, channel_name, server_name, original_text) VALUES (?, ?, ?, ?, ?)",
+            "INSERT OR IGNORE INTO pending_channel_replies (tg_message_id, server_key, channel_id, channel_name, server_name, original_text) VALUES (?, ?, ?, ?, ?, ?)",
         )
         .bind(tg_message_id)
+        .bind(server_key)
         .bind(channel_id)
         .bind(channel_name)
         .bind(server_name)
@@ -28,10 +30,11 @@ impl Database {
     pub async fn get_pending_channel_reply(
         &self,
         tg_message_id: i64,
-    ) -> Result<Option<(i32, String, String, String)>> {
+    ) -> Result<Option<PendingChannelReply>> {
         let row = sqlx::query(
             r"
             SELECT
+                server_key,
                 channel_id,
                 channel_name,
                 server_name,
@@ -44,13 +47,12 @@ impl Database {
         .fetch_optional(&self.pool)
         .await?;
 
-        Ok(row.map(|r| {
-            (
-                r.get::<i32, _>("channel_id"),
-                r.get::<String, _>("channel_name"),
-                r.get::<String, _>("server_name"),
-                r.get::<String, _>("original_text"),
-            )
+        Ok(row.map(|r| PendingChannelReply {
+            server_key: r.get::<String, _>("server_key"),
+            channel_id: r.get::<i32, _>("channel_id"),
+            channel_name: r.get::<String, _>("channel_name"),
+            server_name: r.get::<String, _>("server_name"),
+            original_text: r.get::<String, _>("original_text"),
         }))
     }
 
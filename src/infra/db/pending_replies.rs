@@ -3,20 +3,26 @@ use anyhow::Result;
 use super::Database;
 
 impl Database {
-    pub async fn add_pending_reply(&self, tg_message_id: i64, tt_user_id: i32) -> Result<()> {
+    pub async fn add_pending_reply(
+        &self,
+        tg_message_id: i64,
+        server_key: &str,
+        tt_user_id: i32,
+    ) -> Result<()> {
         sqlx::query(
-            "INSERT OR IGNORE INTO pending_replies (tg_message_id, tt_user_id) VALUES (?, ?)",
+            "INSERT OR IGNORE INTO pending_replies (tg_message_id, server_key, tt_user_id) VALUES (?, ?, ?)",
         )
         .bind(tg_message_id)
+        .bind(server_key)
         .bind(tt_user_id)
         .execute(&self.pool)
         .await?;
         Ok(())
     }
 
-    pub async fn get_pending_reply_user_id(&self, tg_message_id: i64) -> Result<Option<i32>> {
-        let res = sqlx::query_scalar::<_, i32>(
-            "SELECT tt_user_id FROM pending_replies WHERE tg_message_id = ?",
+    pub async fn get_pending_reply(&self, tg_message_id: i64) -> Result<Option<(String, i32)>> {
+        let res = sqlx::query_as::<_, (String, i32)>(
+            "SELECT server_key, tt_user_id FROM pending_replies WHERE tg_message_id = ?",
         )
         .bind(tg_message_id)
         .fetch_optional(&self.pool)
@@ -0,0 +1,77 @@
+use anyhow::Result;
+
+use super::{Database, types::PlaylistSummary};
+
+impl Database {
+    pub async fn add_playlist_track(&self, name: &str, track_path: &str) -> Result<i64> {
+        let mut tx = self.pool.begin().await?;
+        sqlx::query!("INSERT OR IGNORE INTO playlists (name) VALUES (?)", name)
+            .execute(&mut *tx)
+            .await?;
+        let playlist_id = sqlx::query_scalar!("SELECT id FROM playlists WHERE name = ?", name)
+            .fetch_one(&mut *tx)
+            .await?;
+        sqlx::query!(
+            "INSERT INTO playlist_tracks (playlist_id, track_path) VALUES (?, ?)",
+            playlist_id,
+            track_path
+        )
+        .execute(&mut *tx)
+        .await?;
+        let count = sqlx::query_scalar!(
+            "SELECT COUNT(*) FROM playlist_tracks WHERE playlist_id = ?",
+            playlist_id
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(count)
+    }
+
+    pub async fn get_playlist_tracks(&self, name: &str) -> Result<Vec<String>> {
+        let rows = sqlx::query_scalar!(
+            "SELECT t.track_path FROM playlist_tracks t JOIN playlists p ON p.id = t.playlist_id WHERE p.name = ? ORDER BY t.id",
+            name
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows)
+    }
+
+    pub async fn list_playlists(&self) -> Result<Vec<PlaylistSummary>> {
+        let rows = sqlx::query_as!(
+            PlaylistSummary,
+            r#"
+            SELECT
+                p.name as "name!",
+                COUNT(t.id) as "track_count!: i64"
+            FROM playlists p
+            LEFT JOIN playlist_tracks t ON t.playlist_id = p.id
+            GROUP BY p.id
+            ORDER BY p.name
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows)
+    }
+
+    pub async fn delete_playlist(&self, name: &str) -> Result<bool> {
+        let mut tx = self.pool.begin().await?;
+        sqlx::query!(
+            "DELETE FROM playlist_tracks WHERE playlist_id IN (SELECT id FROM playlists WHERE name = ?)",
+            name
+        )
+        .execute(&mut *tx)
+        .await?;
+        let res = sqlx::query!("DELETE FROM playlists WHERE name = ?", name)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        Ok(res.rows_affected() > 0)
+    }
+}
+
+#[cfg(test)]
+#[path = "../../../tests/unit/infra_db_playlists.rs"]
+mod tests;
@@ -0,0 +1,108 @@
+use anyhow::Result;
+
+use super::{Database, types::RegistrationRequest};
+
+impl Database {
+    pub async fn add_registration_request(
+        &self,
+        telegram_id: i64,
+        server_key: &str,
+        username: &str,
+    ) -> Result<i64> {
+        let id = sqlx::query_scalar!(
+            "INSERT INTO registration_requests (telegram_id, server_key, username) VALUES (?, ?, ?) RETURNING id",
+            telegram_id,
+            server_key,
+            username
+        )
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(id)
+    }
+
+    pub async fn get_registration_request(&self, id: i64) -> Result<Option<RegistrationRequest>> {
+        let request = sqlx::query_as!(
+            RegistrationRequest,
+            r#"SELECT id as "id!", telegram_id, server_key, username FROM registration_requests WHERE id = ?"#,
+            id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(request)
+    }
+
+    pub async fn get_registration_by_telegram_id(
+        &self,
+        telegram_id: i64,
+    ) -> Result<Option<RegistrationRequest>> {
+        let request = sqlx::query_as!(
+            RegistrationRequest,
+            r#"SELECT id as "id!", telegram_id, server_key, username FROM registration_requests WHERE telegram_id = ?"#,
+            telegram_id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(request)
+    }
+
+    pub async fn is_username_requested(&self, server_key: &str, username: &str) -> Result<bool> {
+        let count = sqlx::query_scalar!(
+            "SELECT COUNT(*) FROM registration_requests WHERE server_key = ? AND username = ?",
+            server_key,
+            username
+        )
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(count > 0)
+    }
+
+    pub async fn claim_registration_request(&self, id: i64) -> Result<Option<RegistrationRequest>> {
+        let request = sqlx::query_as!(
+            RegistrationRequest,
+            r#"UPDATE registration_requests SET approved_at = CURRENT_TIMESTAMP WHERE id = ? AND approved_at IS NULL RETURNING id as "id!", telegram_id as "telegram_id!", server_key as "server_key!", username as "username!""#,
+            id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(request)
+    }
+
+    pub async fn release_registration_request(&self, id: i64) -> Result<()> {
+        sqlx::query!(
+            "UPDATE registration_requests SET approved_at = NULL WHERE id = ?",
+            id
+        )
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn withdraw_registration_request(
+        &self,
+        id: i64,
+    ) -> Result<Option<RegistrationRequest>> {
+        let request = sqlx::query_as!(
+            RegistrationRequest,
+            r#"DELETE FROM registration_requests WHERE id = ? AND approved_at IS NULL RETURNING id as "id!", telegram_id as "telegram_id!", server_key as "server_key!", username as "username!""#,
+            id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(request)
+    }
+
+    pub async fn take_registration_request(&self, id: i64) -> Result<Option<RegistrationRequest>> {
+        let request = sqlx::query_as!(
+            RegistrationRequest,
+            r#"DELETE FROM registration_requests WHERE id = ? RETURNING id as "id!", telegram_id as "telegram_id!", server_key as "server_key!", username as "username!""#,
+            id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(request)
+    }
+}
+
+#[cfg(test)]
+#[path = "../../../tests/unit/infra_db_registrations.rs"]
+mod tests;
@@ -0,0 +1,61 @@
+use anyhow::Result;
+use chrono::NaiveDateTime;
+
+use super::{Database, types::ReportSession};
+
+impl Database {
+    pub async fn get_sessions_between(
+        &self,
+        start: NaiveDateTime,
+        end: NaiveDateTime,
+    ) -> Result<Vec<ReportSession>> {
+        let rows = sqlx::query_as!(
+            ReportSession,
+            r#"
+            SELECT
+                username as "username!",
+                nickname as "nickname!",
+                channel,
+                login_time as "login_time!",
+                logout_time
+            FROM user_sessions
+            WHERE login_time < ? AND (logout_time IS NULL OR logout_time > ?)
+            "#,
+            end,
+            start
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows)
+    }
+
+    pub async fn count_new_subscribers(
+        &self,
+        start: NaiveDateTime,
+        end: NaiveDateTime,
+    ) -> Result<i64> {
+        let count = sqlx::query_scalar!(
+            r#"SELECT COUNT(*) as "count!: i64" FROM subscribed_users WHERE subscribed_at >= ? AND subscribed_at < ?"#,
+            start,
+            end
+        )
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(count)
+    }
+
+    pub async fn count_bans_issued(&self, start: NaiveDateTime, end: NaiveDateTime) -> Result<i64> {
+        let count = sqlx::query_scalar!(
+            r#"SELECT COUNT(*) as "count!: i64" FROM ban_list WHERE banned_at >= ? AND banned_at < ?"#,
+            start,
+            end
+        )
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+#[path = "../../../tests/unit/infra_db_reports.rs"]
+mod tests;
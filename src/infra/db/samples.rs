@@ -0,0 +1,59 @@
+use anyhow::Result;
+use chrono::NaiveDateTime;
+
+use super::{Database, types::OnlineSample};
+
+impl Database {
+    pub async fn add_online_sample(
+        &self,
+        server_key: &str,
+        sampled_at: NaiveDateTime,
+        user_count: i64,
+    ) -> Result<()> {
+        sqlx::query!(
+            "INSERT INTO online_samples (server_key, sampled_at, user_count) VALUES (?, ?, ?)",
+            server_key,
+            sampled_at,
+            user_count
+        )
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn get_online_samples_since(
+        &self,
+        since: NaiveDateTime,
+    ) -> Result<Vec<OnlineSample>> {
+        let rows = sqlx::query_as!(
+            OnlineSample,
+            r#"
+            SELECT
+                server_key as "server_key!",
+                sampled_at as "sampled_at!",
+                user_count as "user_count!"
+            FROM online_samples
+            WHERE sampled_at >= ?
+            ORDER BY sampled_at
+            "#,
+            since
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows)
+    }
+
+    pub async fn cleanup_online_samples(&self, older_than: NaiveDateTime) -> Result<u64> {
+        let res = sqlx::query!(
+            "DELETE FROM online_samples WHERE sampled_at < ?",
+            older_than
+        )
+        .execute(&self.pool)
+        .await?;
+        Ok(res.rows_affected())
+    }
+}
+
+#[cfg(test)]
+#[path = "../../../tests/unit/infra_db_samples.rs"]
+mod tests;
@@ -0,0 +1,44 @@
+use anyhow::Result;
+
+use super::Database;
+
+impl Database {
+    pub async fn get_ignored_servers(&self, telegram_id: i64) -> Result<Vec<String>> {
+        let rows = sqlx::query_scalar!(
+            "SELECT server_key FROM ignored_servers WHERE telegram_id = ?",
+            telegram_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows)
+    }
+
+    pub async fn toggle_ignored_server(&self, telegram_id: i64, server_key: &str) -> Result<bool> {
+        let count: i32 = sqlx::query_scalar(
+            "SELECT count(*) FROM ignored_servers WHERE telegram_id = ? AND server_key = ?",
+        )
+        .bind(telegram_id)
+        .bind(server_key)
+        .fetch_one(&self.pool)
+        .await?;
+        let is_ignored = count > 0;
+
+        let query = if is_ignored {
+            "DELETE FROM ignored_servers WHERE telegram_id = ? AND server_key = ?"
+        } else {
+            "INSERT INTO ignored_servers (telegram_id, server_key) VALUES (?, ?)"
+        };
+
+        sqlx::query(query)
+            .bind(telegram_id)
+            .bind(server_key)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(!is_ignored)
+    }
+}
+
+#[cfg(test)]
+#[path = "../../../tests/unit/infra_db_servers.rs"]
+mod tests;
@@ -0,0 +1,103 @@
+use anyhow::Result;
+use chrono::Utc;
+
+use super::{Database, types::UserSession};
+
+impl Database {
+    pub async fn open_session(
+        &self,
+        server_key: &str,
+        username: &str,
+        nickname: &str,
+    ) -> Result<()> {
+        let now = Utc::now().naive_utc();
+        let mut tx = self.pool.begin().await?;
+        sqlx::query!(
+            "UPDATE user_sessions SET logout_time = ? WHERE server_key = ? AND username = ? AND logout_time IS NULL",
+            now,
+            server_key,
+            username
+        )
+        .execute(&mut *tx)
+        .await?;
+        sqlx::query!(
+            "INSERT INTO user_sessions (server_key, username, nickname, login_time) VALUES (?, ?, ?, ?)",
+            server_key,
+            username,
+            nickname,
+            now
+        )
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    pub async fn update_session_channel(
+        &self,
+        server_key: &str,
+        username: &str,
+        channel: &str,
+    ) -> Result<()> {
+        sqlx::query!(
+            "UPDATE user_sessions SET channel = ? WHERE server_key = ? AND username = ? AND logout_time IS NULL",
+            channel,
+            server_key,
+            username
+        )
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn close_session(&self, server_key: &str, username: &str) -> Result<()> {
+        let now = Utc::now().naive_utc();
+        sqlx::query!(
+            "UPDATE user_sessions SET logout_time = ? WHERE server_key = ? AND username = ? AND logout_time IS NULL",
+            now,
+            server_key,
+            username
+        )
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn close_open_sessions(&self, server_key: &str) -> Result<u64> {
+        let now = Utc::now().naive_utc();
+        let res = sqlx::query!(
+            "UPDATE user_sessions SET logout_time = ? WHERE server_key = ? AND logout_time IS NULL",
+            now,
+            server_key
+        )
+        .execute(&self.pool)
+        .await?;
+        Ok(res.rows_affected())
+    }
+
+    pub async fn get_last_session(&self, username: &str) -> Result<Option<UserSession>> {
+        let session = sqlx::query_as!(
+            UserSession,
+            r#"
+            SELECT
+                server_key as "server_key!",
+                nickname as "nickname!",
+                channel,
+                login_time as "login_time!",
+                logout_time
+            FROM user_sessions
+            WHERE username = ?
+            ORDER BY login_time DESC, id DESC
+            LIMIT 1
+            "#,
+            username
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(session)
+    }
+}
+
+#[cfg(test)]
+#[path = "../../../tests/unit/infra_db_sessions.rs"]
+mod tests;
@@ -0,0 +1,64 @@
+use anyhow::Result;
+
+use super::{Database, types::QueuedStream};
+
+impl Database {
+    pub async fn replace_stream_queue(
+        &self,
+        server_key: &str,
+        items: &[QueuedStream],
+    ) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+        sqlx::query!("DELETE FROM stream_queue WHERE server_key = ?", server_key)
+            .execute(&mut *tx)
+            .await?;
+        for (position, item) in (0_i64..).zip(items) {
+            sqlx::query!(
+                "INSERT INTO stream_queue (server_key, position, channel_id, file_path, duration_ms, announce_text, title) VALUES (?, ?, ?, ?, ?, ?, ?)",
+                server_key,
+                position,
+                item.channel_id,
+                item.file_path,
+                item.duration_ms,
+                item.announce_text,
+                item.title
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    pub async fn get_stream_queue(&self, server_key: &str) -> Result<Vec<QueuedStream>> {
+        let rows = sqlx::query_as!(
+            QueuedStream,
+            r#"
+            SELECT
+                channel_id as "channel_id!",
+                file_path as "file_path!",
+                duration_ms as "duration_ms!",
+                announce_text,
+                title
+            FROM stream_queue
+            WHERE server_key = ?
+            ORDER BY position
+            "#,
+            server_key
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows)
+    }
+
+    pub async fn get_stream_queue_paths(&self) -> Result<Vec<String>> {
+        let rows = sqlx::query_scalar!("SELECT file_path FROM stream_queue")
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(rows)
+    }
+}
+
+#[cfg(test)]
+#[path = "../../../tests/unit/infra_db_stream_queue.rs"]
+mod tests;
@@ -1,16 +1,19 @@
 use crate::core::types::NotificationType;
 use anyhow::Result;
+use chrono::Utc;
 
 use super::{
     Database,
-    types::{SubscriberInfo, UserSettings},
+    types::{HealthRecipient, SubscriberInfo, UserSettings},
 };
 
 impl Database {
     pub async fn add_subscriber(&self, telegram_id: i64) -> Result<()> {
+        let now = Utc::now().naive_utc();
         sqlx::query!(
-            "INSERT OR IGNORE INTO subscribed_users (telegram_id) VALUES (?)",
-            telegram_id
+            "INSERT OR IGNORE INTO subscribed_users (telegram_id, subscribed_at) VALUES (?, ?)",
+            telegram_id,
+            now
         )
         .execute(&self.pool)
         .await?;
@@ -47,10 +50,16 @@ impl Database {
         &self,
         tt_username: &str,
         event_type: NotificationType,
+        server_key: &str,
     ) -> Result<Vec<UserSettings>> {
         let event_tag = match event_type {
             NotificationType::Join => "join",
             NotificationType::Leave => "leave",
+            NotificationType::ChannelJoin | NotificationType::ChannelLeave => "channel",
+            NotificationType::NickChange => "nick",
+            NotificationType::StatusChange => "status",
+            NotificationType::ServerBroadcast => "broadcast",
+            NotificationType::FileUpload => "file",
         };
 
         let recipients = sqlx::query_as!(
@@ -63,7 +72,14 @@ impl Database {
                 us.mute_list_mode as "mute_list_mode!",
                 us.teamtalk_username,
                 us.not_on_online_enabled as "not_on_online_enabled!",
-                us.not_on_online_confirmed as "not_on_online_confirmed!"
+                us.not_on_online_confirmed as "not_on_online_confirmed!",
+                us.channel_notifications_enabled as "channel_notifications_enabled!",
+                us.nick_notifications_enabled as "nick_notifications_enabled!",
+                us.status_notifications_enabled as "status_notifications_enabled!",
+                us.broadcast_notifications_enabled as "broadcast_notifications_enabled!",
+                us.file_notifications_enabled as "file_notifications_enabled!",
+                us.health_notifications_enabled as "health_notifications_enabled!",
+                us.delivery_mode as "delivery_mode!"
             FROM user_settings us
             JOIN subscribed_users su ON us.telegram_id = su.telegram_id
             LEFT JOIN muted_users mu ON us.telegram_id = mu.user_settings_telegram_id
@@ -74,22 +90,68 @@ impl Database {
                 (? = 'join' AND us.notification_settings != 'join_off')
                 OR
                 (? = 'leave' AND us.notification_settings != 'leave_off')
+                OR
+                (? = 'channel' AND us.channel_notifications_enabled = 1)
+                OR
+                (? = 'nick' AND us.nick_notifications_enabled = 1)
+                OR
+                (? = 'status' AND us.status_notifications_enabled = 1)
+                OR
+                (? = 'broadcast' AND us.broadcast_notifications_enabled = 1)
+                OR
+                (? = 'file' AND us.file_notifications_enabled = 1)
             )
             AND (
+                ? = 'broadcast'
+                OR
                 (us.mute_list_mode = 'blacklist' AND mu.id IS NULL)
                 OR
                 (us.mute_list_mode = 'whitelist' AND mu.id IS NOT NULL)
             )
+            AND NOT EXISTS (
+                SELECT 1 FROM ignored_servers ig
+                WHERE ig.telegram_id = us.telegram_id AND ig.server_key = ?
+            )
             "#,
             tt_username,
             event_tag,
-            event_tag
+            event_tag,
+            event_tag,
+            event_tag,
+            event_tag,
+            event_tag,
+            event_tag,
+            event_tag,
+            server_key
         )
         .fetch_all(&self.pool)
         .await?;
 
         Ok(recipients)
     }
+
+    pub async fn get_health_recipients(&self, server_key: &str) -> Result<Vec<HealthRecipient>> {
+        let rows = sqlx::query_as!(
+            HealthRecipient,
+            r#"
+            SELECT
+                us.telegram_id as "telegram_id!",
+                us.language_code as "language_code!"
+            FROM user_settings us
+            JOIN subscribed_users su ON us.telegram_id = su.telegram_id
+            WHERE us.health_notifications_enabled = 1
+            AND us.notification_settings != 'none'
+            AND NOT EXISTS (
+                SELECT 1 FROM ignored_servers ig
+                WHERE ig.telegram_id = us.telegram_id AND ig.server_key = ?
+            )
+            "#,
+            server_key
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows)
+    }
 }
 
 #[cfg(test)]
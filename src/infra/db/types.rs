@@ -1,6 +1,7 @@
 use chrono::NaiveDateTime;
 
-#[derive(sqlx::FromRow, Debug)]
+#[allow(clippy::struct_excessive_bools)]
+#[derive(sqlx::FromRow, Debug, Clone)]
 pub struct UserSettings {
     pub telegram_id: i64,
     pub language_code: String,
@@ -9,6 +10,13 @@ pub struct UserSettings {
     pub teamtalk_username: Option<String>,
     pub not_on_online_enabled: bool,
     pub not_on_online_confirmed: bool,
+    pub channel_notifications_enabled: bool,
+    pub nick_notifications_enabled: bool,
+    pub status_notifications_enabled: bool,
+    pub broadcast_notifications_enabled: bool,
+    pub file_notifications_enabled: bool,
+    pub health_notifications_enabled: bool,
+    pub delivery_mode: String,
 }
 
 #[derive(sqlx::FromRow, Debug)]
@@ -24,6 +32,27 @@ pub struct BanEntry {
     pub id: i64,
     pub telegram_id: Option<i64>,
     pub teamtalk_username: Option<String>,
+    pub ban_reason: Option<String>,
+    pub expires_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct NewBan {
+    pub telegram_id: Option<i64>,
+    pub teamtalk_username: Option<String>,
+    pub reason: Option<String>,
+    pub expires_at: Option<NaiveDateTime>,
+    pub server_key: Option<String>,
+    pub ip_address: Option<String>,
+}
+
+#[derive(sqlx::FromRow, Debug, Clone, PartialEq, Eq)]
+pub struct LiftedBan {
+    pub id: i64,
+    pub teamtalk_username: Option<String>,
+    pub server_key: Option<String>,
+    pub ip_address: Option<String>,
+    pub channel_id: Option<i64>,
 }
 
 #[derive(sqlx::FromRow, Debug)]
@@ -31,3 +60,66 @@ pub struct SubscriberInfo {
     pub telegram_id: i64,
     pub teamtalk_username: Option<String>,
 }
+
+#[derive(sqlx::FromRow, Debug)]
+pub struct HealthRecipient {
+    pub telegram_id: i64,
+    pub language_code: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingChannelReply {
+    pub server_key: String,
+    pub channel_id: i32,
+    pub channel_name: String,
+    pub server_name: String,
+    pub original_text: String,
+}
+
+#[derive(sqlx::FromRow, Debug, Clone)]
+pub struct UserSession {
+    pub server_key: String,
+    pub nickname: String,
+    pub channel: Option<String>,
+    pub login_time: NaiveDateTime,
+    pub logout_time: Option<NaiveDateTime>,
+}
+
+#[derive(sqlx::FromRow, Debug, Clone)]
+pub struct ReportSession {
+    pub username: String,
+    pub nickname: String,
+    pub channel: Option<String>,
+    pub login_time: NaiveDateTime,
+    pub logout_time: Option<NaiveDateTime>,
+}
+
+#[derive(sqlx::FromRow, Debug, Clone)]
+pub struct OnlineSample {
+    pub server_key: String,
+    pub sampled_at: NaiveDateTime,
+    pub user_count: i64,
+}
+
+#[derive(sqlx::FromRow, Debug, Clone, PartialEq, Eq)]
+pub struct QueuedStream {
+    pub channel_id: i64,
+    pub file_path: String,
+    pub duration_ms: i64,
+    pub announce_text: Option<String>,
+    pub title: Option<String>,
+}
+
+#[derive(sqlx::FromRow, Debug, Clone, PartialEq, Eq)]
+pub struct PlaylistSummary {
+    pub name: String,
+    pub track_count: i64,
+}
+
+#[derive(sqlx::FromRow, Debug, Clone, PartialEq, Eq)]
+pub struct RegistrationRequest {
+    pub id: i64,
+    pub telegram_id: i64,
+    pub server_key: String,
+    pub username: String,
+}
@@ -1,4 +1,4 @@
-use crate::core::types::{LanguageCode, NotificationSetting};
+use crate::core::types::{DeliveryMode, EventOptIn, LanguageCode, NotificationSetting};
 use anyhow::Result;
 use sqlx::Row;
 use std::collections::HashMap;
@@ -21,7 +21,14 @@ impl Database {
                 mute_list_mode as "mute_list_mode!",
                 teamtalk_username,
                 not_on_online_enabled as "not_on_online_enabled!",
-                not_on_online_confirmed as "not_on_online_confirmed!"
+                not_on_online_confirmed as "not_on_online_confirmed!",
+                channel_notifications_enabled as "channel_notifications_enabled!",
+                nick_notifications_enabled as "nick_notifications_enabled!",
+                status_notifications_enabled as "status_notifications_enabled!",
+                broadcast_notifications_enabled as "broadcast_notifications_enabled!",
+                file_notifications_enabled as "file_notifications_enabled!",
+                health_notifications_enabled as "health_notifications_enabled!",
+                delivery_mode as "delivery_mode!"
             FROM user_settings
             WHERE telegram_id = ?
             "#,
@@ -52,7 +59,14 @@ impl Database {
                     mute_list_mode as "mute_list_mode!",
                     teamtalk_username,
                     not_on_online_enabled as "not_on_online_enabled!",
-                    not_on_online_confirmed as "not_on_online_confirmed!"
+                    not_on_online_confirmed as "not_on_online_confirmed!",
+                    channel_notifications_enabled as "channel_notifications_enabled!",
+                    nick_notifications_enabled as "nick_notifications_enabled!",
+                    status_notifications_enabled as "status_notifications_enabled!",
+                    broadcast_notifications_enabled as "broadcast_notifications_enabled!",
+                    file_notifications_enabled as "file_notifications_enabled!",
+                    health_notifications_enabled as "health_notifications_enabled!",
+                    delivery_mode as "delivery_mode!"
                 FROM user_settings
                 WHERE telegram_id = ?
                 "#,
@@ -87,6 +101,24 @@ impl Database {
         res.and_then(|lang| LanguageCode::try_from(lang.as_str()).ok())
     }
 
+    pub async fn get_user_lang(&self, telegram_id: i64) -> Option<LanguageCode> {
+        let res: Option<String> = match sqlx::query_scalar!(
+            "SELECT language_code FROM user_settings WHERE telegram_id = ?",
+            telegram_id
+        )
+        .fetch_optional(&self.pool)
+        .await
+        {
+            Ok(res) => res,
+            Err(e) => {
+                tracing::error!(telegram_id, error = %e, "Failed to get user language");
+                None
+            }
+        };
+
+        res.and_then(|lang| LanguageCode::try_from(lang.as_str()).ok())
+    }
+
     pub async fn get_telegram_id_by_tt_user(&self, tt_username: &str) -> Option<i64> {
         match sqlx::query_scalar!(
             r#"
@@ -195,6 +227,18 @@ impl Database {
         Ok(())
     }
 
+    pub async fn update_delivery_mode(&self, telegram_id: i64, mode: DeliveryMode) -> Result<()> {
+        let mode_str = mode.to_string();
+        sqlx::query!(
+            "UPDATE user_settings SET delivery_mode = ? WHERE telegram_id = ?",
+            mode_str,
+            telegram_id
+        )
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
     pub async fn update_language(&self, telegram_id: i64, lang: LanguageCode) -> Result<()> {
         let lang_code = lang.as_str();
         sqlx::query!(
@@ -250,6 +294,54 @@ impl Database {
         Ok(new_bool)
     }
 
+    pub async fn toggle_event_opt_in(&self, telegram_id: i64, opt_in: EventOptIn) -> Result<bool> {
+        let (select_query, update_query) = match opt_in {
+            EventOptIn::ChannelMoves => (
+                "SELECT CAST(channel_notifications_enabled AS INTEGER) FROM user_settings WHERE telegram_id = ?",
+                "UPDATE user_settings SET channel_notifications_enabled = ? WHERE telegram_id = ?",
+            ),
+            EventOptIn::NickChanges => (
+                "SELECT CAST(nick_notifications_enabled AS INTEGER) FROM user_settings WHERE telegram_id = ?",
+                "UPDATE user_settings SET nick_notifications_enabled = ? WHERE telegram_id = ?",
+            ),
+            EventOptIn::StatusChanges => (
+                "SELECT CAST(status_notifications_enabled AS INTEGER) FROM user_settings WHERE telegram_id = ?",
+                "UPDATE user_settings SET status_notifications_enabled = ? WHERE telegram_id = ?",
+            ),
+            EventOptIn::ServerBroadcasts => (
+                "SELECT CAST(broadcast_notifications_enabled AS INTEGER) FROM user_settings WHERE telegram_id = ?",
+                "UPDATE user_settings SET broadcast_notifications_enabled = ? WHERE telegram_id = ?",
+            ),
+            EventOptIn::FileUploads => (
+                "SELECT CAST(file_notifications_enabled AS INTEGER) FROM user_settings WHERE telegram_id = ?",
+                "UPDATE user_settings SET file_notifications_enabled = ? WHERE telegram_id = ?",
+            ),
+            EventOptIn::ServerHealth => (
+                "SELECT CAST(health_notifications_enabled AS INTEGER) FROM user_settings WHERE telegram_id = ?",
+                "UPDATE user_settings SET health_notifications_enabled = ? WHERE telegram_id = ?",
+            ),
+        };
+
+        let mut tx = self.pool.begin().await?;
+
+        let current_val: i64 = sqlx::query_scalar(select_query)
+            .bind(telegram_id)
+            .fetch_optional(&mut *tx)
+            .await?
+            .unwrap_or(0);
+
+        let new_bool = current_val == 0;
+
+        sqlx::query(update_query)
+            .bind(i64::from(new_bool))
+            .bind(telegram_id)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+        Ok(new_bool)
+    }
+
     pub async fn link_tt_account(&self, telegram_id: i64, tt_username: &str) -> Result<()> {
         sqlx::query!("UPDATE user_settings SET teamtalk_username = ?, not_on_online_confirmed = 1 WHERE telegram_id = ?", tt_username, telegram_id)
             .execute(&self.pool)
@@ -284,6 +376,18 @@ impl Database {
         )
         .execute(&mut *tx)
         .await?;
+        sqlx::query!(
+            "DELETE FROM ignored_servers WHERE telegram_id = ?",
+            telegram_id
+        )
+        .execute(&mut *tx)
+        .await?;
+        sqlx::query!(
+            "DELETE FROM registration_requests WHERE telegram_id = ?",
+            telegram_id
+        )
+        .execute(&mut *tx)
+        .await?;
         sqlx::query!(
             "DELETE FROM user_settings WHERE telegram_id = ?",
             telegram_id
@@ -0,0 +1,118 @@
+use super::*;
+
+fn sub(telegram_id: i64) -> UserSettings {
+    UserSettings {
+        telegram_id,
+        language_code: "en".to_string(),
+        notification_settings: "all".to_string(),
+        mute_list_mode: "blacklist".to_string(),
+        teamtalk_username: None,
+        not_on_online_enabled: false,
+        not_on_online_confirmed: false,
+        channel_notifications_enabled: false,
+        nick_notifications_enabled: false,
+        status_notifications_enabled: false,
+        broadcast_notifications_enabled: true,
+        file_notifications_enabled: true,
+        health_notifications_enabled: false,
+        delivery_mode: "batched".to_string(),
+    }
+}
+
+#[test]
+fn batched_digest_is_released_after_window() {
+    let buffer = DigestBuffer::new(Duration::from_mins(1));
+    let start = Instant::now();
+    buffer.push(
+        sub(1),
+        ServerId(0),
+        DeliveryMode::Batched,
+        LanguageCode::En,
+        "alice joined".to_string(),
+        start,
+    );
+    buffer.push(
+        sub(1),
+        ServerId(0),
+        DeliveryMode::Batched,
+        LanguageCode::En,
+        "alice left".to_string(),
+        start + Duration::from_secs(10),
+    );
+
+    assert!(buffer.take_due(start + Duration::from_secs(30)).is_empty());
+
+    let ready = buffer.take_due(start + Duration::from_mins(1));
+    assert_eq!(ready.len(), 1);
+    assert_eq!(ready[0].sub.telegram_id, 1);
+    assert!(ready[0].text.contains("alice joined\nalice left"));
+    assert_eq!(ready[0].servers, vec![ServerId(0)]);
+    assert!(buffer.take_all().is_empty());
+}
+
+#[test]
+fn hourly_digest_waits_longer_than_batch() {
+    let buffer = DigestBuffer::new(Duration::from_mins(1));
+    let start = Instant::now();
+    buffer.push(
+        sub(1),
+        ServerId(0),
+        DeliveryMode::Batched,
+        LanguageCode::En,
+        "a".to_string(),
+        start,
+    );
+    buffer.push(
+        sub(2),
+        ServerId(0),
+        DeliveryMode::Hourly,
+        LanguageCode::En,
+        "b".to_string(),
+        start,
+    );
+
+    let ready = buffer.take_due(start + Duration::from_mins(2));
+    assert_eq!(ready.len(), 1);
+    assert_eq!(ready[0].sub.telegram_id, 1);
+
+    let rest = buffer.take_all();
+    assert_eq!(rest.len(), 1);
+    assert_eq!(rest[0].sub.telegram_id, 2);
+}
+
+#[test]
+fn digest_remembers_every_server_it_covers() {
+    let buffer = DigestBuffer::new(Duration::ZERO);
+    let start = Instant::now();
+    for server_id in [ServerId(1), ServerId(0), ServerId(1)] {
+        buffer.push(
+            sub(1),
+            server_id,
+            DeliveryMode::Batched,
+            LanguageCode::En,
+            "event".to_string(),
+            start,
+        );
+    }
+    let ready = buffer.take_all();
+    assert_eq!(ready[0].servers, vec![ServerId(1), ServerId(0)]);
+}
+
+#[test]
+fn long_digest_is_truncated() {
+    let buffer = DigestBuffer::new(Duration::ZERO);
+    let start = Instant::now();
+    for i in 0..(MAX_DIGEST_LINES + 5) {
+        buffer.push(
+            sub(1),
+            ServerId(0),
+            DeliveryMode::Batched,
+            LanguageCode::En,
+            format!("line {i}"),
+            start,
+        );
+    }
+    let ready = buffer.take_all();
+    assert_eq!(ready.len(), 1);
+    assert!(!ready[0].text.contains(&format!("line {MAX_DIGEST_LINES}")));
+}
@@ -0,0 +1,46 @@
+use super::*;
+
+#[test]
+fn parse_new_account_requires_username_and_password() {
+    assert_eq!(
+        parse_new_account("alice | s3cret | Front desk"),
+        Some(NewAccount {
+            username: "alice".to_string(),
+            password: "s3cret".to_string(),
+            note: "Front desk".to_string(),
+        })
+    );
+    assert_eq!(
+        parse_new_account("bob|pw"),
+        Some(NewAccount {
+            username: "bob".to_string(),
+            password: "pw".to_string(),
+            ..NewAccount::default()
+        })
+    );
+    assert_eq!(parse_new_account("alice"), None);
+    assert_eq!(parse_new_account(" | pw"), None);
+    assert_eq!(parse_new_account("alice | | note"), None);
+    assert_eq!(parse_new_account("a | b | c | d"), None);
+}
+
+#[test]
+fn parse_account_edit_rejects_empty_password() {
+    assert_eq!(
+        parse_account_edit(AccountField::Password, " new "),
+        Some(AccountEdit::Password("new".to_string()))
+    );
+    assert_eq!(parse_account_edit(AccountField::Password, "-"), None);
+    assert_eq!(
+        parse_account_edit(AccountField::Note, "-"),
+        Some(AccountEdit::Note(String::new()))
+    );
+}
+
+#[test]
+fn matches_query_checks_username_and_note() {
+    assert!(matches_query("Alice", "", "ali"));
+    assert!(matches_query("bob", "Front Desk", "desk"));
+    assert!(matches_query("bob", "", " "));
+    assert!(!matches_query("bob", "", "carol"));
+}
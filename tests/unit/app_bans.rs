@@ -0,0 +1,127 @@
+use super::*;
+use crate::core::types::ServerId;
+
+fn config() -> Config {
+    toml::from_str(
+        r#"
+            [general]
+            admin_username = "admin"
+
+            [database]
+            db_file = "test.db"
+
+            [telegram]
+            event_token = "t"
+            admin_chat_id = 1
+
+            [[teamtalk]]
+            id = "main"
+            host_name = "main"
+            port = 1
+            encrypted = false
+            user_name = "u"
+            password = "p"
+            channel = "/"
+            nick_name = "n"
+            client_name = "c"
+
+            [[teamtalk]]
+            id = "backup"
+            host_name = "backup"
+            port = 1
+            encrypted = false
+            user_name = "u"
+            password = "p"
+            channel = "/"
+            nick_name = "n"
+            client_name = "c"
+            "#,
+    )
+    .unwrap()
+}
+
+fn entry(reason: Option<&str>, expires_at: Option<NaiveDateTime>) -> BanEntry {
+    BanEntry {
+        id: 1,
+        telegram_id: None,
+        teamtalk_username: Some("eve".to_string()),
+        ban_reason: reason.map(str::to_string),
+        expires_at,
+    }
+}
+
+#[test]
+fn expires_at_follows_term() {
+    let now = chrono::Utc::now().naive_utc();
+    assert_eq!(expires_at(BanTerm::Day, now), Some(now + Duration::days(1)));
+    assert_eq!(expires_at(BanTerm::Permanent, now), None);
+}
+
+#[test]
+fn ban_label_shows_remaining_time_and_reason() {
+    let now = chrono::Utc::now().naive_utc();
+    let timed = entry(Some("spam"), Some(now + Duration::minutes(90)));
+    assert_eq!(
+        ban_label(LanguageCode::En, &timed, now),
+        "eve · 1h 30m left · spam"
+    );
+    assert_eq!(
+        ban_label(LanguageCode::En, &entry(None, None), now),
+        "eve · permanent"
+    );
+    let linked = BanEntry {
+        telegram_id: Some(42),
+        ..entry(None, None)
+    };
+    assert_eq!(ban_label(LanguageCode::En, &linked, now), "eve · permanent");
+}
+
+#[test]
+fn unban_command_targets_the_banning_server() {
+    let cfg = config();
+    let ban = LiftedBan {
+        id: 7,
+        teamtalk_username: Some("eve".to_string()),
+        server_key: Some("backup".to_string()),
+        ip_address: Some("10.0.0.1".to_string()),
+        channel_id: Some(4),
+    };
+    assert!(matches!(
+        unban_command(&cfg, &ban, Some((42, LanguageCode::En))),
+        Some(TtCommand::UnbanUser {
+            server_id: ServerId(1),
+            ban_id: 7,
+            ref ip_address,
+            channel_id: Some(4),
+            reply_to: Some((42, LanguageCode::En)),
+        }) if ip_address == "10.0.0.1"
+    ));
+    let unknown = LiftedBan {
+        server_key: Some("gone".to_string()),
+        ..ban.clone()
+    };
+    assert!(unban_command(&cfg, &unknown, None).is_none());
+    let no_ip = LiftedBan {
+        ip_address: None,
+        ..ban
+    };
+    assert!(unban_command(&cfg, &no_ip, None).is_none());
+}
+
+#[test]
+fn parse_ban_target_tells_addresses_from_usernames() {
+    assert_eq!(
+        parse_ban_target(" 10.0.0.7 "),
+        Some(BanTarget::IpAddress("10.0.0.7".to_string()))
+    );
+    assert_eq!(
+        parse_ban_target("::1"),
+        Some(BanTarget::IpAddress("::1".to_string()))
+    );
+    assert_eq!(
+        parse_ban_target("eve"),
+        Some(BanTarget::Username("eve".to_string()))
+    );
+    assert_eq!(parse_ban_target(""), None);
+    assert_eq!(parse_ban_target("two words"), None);
+}
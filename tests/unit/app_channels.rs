@@ -0,0 +1,87 @@
+use super::*;
+
+fn channel(id: i32, parent_id: i32, path: &str) -> LiteChannel {
+    LiteChannel {
+        id,
+        parent_id,
+        path: path.to_string(),
+        topic: String::new(),
+        password_protected: false,
+        max_users: 100,
+    }
+}
+
+#[test]
+fn parse_new_channel_reads_optional_fields() {
+    assert_eq!(
+        parse_new_channel("Meeting | secret | Weekly sync | 12"),
+        Some(NewChannel {
+            name: "Meeting".to_string(),
+            password: "secret".to_string(),
+            topic: "Weekly sync".to_string(),
+            max_users: Some(12),
+        })
+    );
+    assert_eq!(
+        parse_new_channel(" Lounge "),
+        Some(NewChannel {
+            name: "Lounge".to_string(),
+            ..NewChannel::default()
+        })
+    );
+    assert_eq!(
+        parse_new_channel("Lounge || Chat"),
+        Some(NewChannel {
+            name: "Lounge".to_string(),
+            topic: "Chat".to_string(),
+            ..NewChannel::default()
+        })
+    );
+}
+
+#[test]
+fn parse_new_channel_rejects_bad_input() {
+    assert_eq!(parse_new_channel(""), None);
+    assert_eq!(parse_new_channel(" | pass"), None);
+    assert_eq!(parse_new_channel("a/b"), None);
+    assert_eq!(parse_new_channel("Room | | | many"), None);
+    assert_eq!(parse_new_channel("Room | | | 0"), None);
+    assert_eq!(parse_new_channel("Room | | | 5 | extra"), None);
+}
+
+#[test]
+fn parse_channel_edit_clears_with_dash() {
+    assert_eq!(
+        parse_channel_edit(ChannelField::Topic, " News "),
+        Some(ChannelEdit::Topic("News".to_string()))
+    );
+    assert_eq!(
+        parse_channel_edit(ChannelField::Password, "-"),
+        Some(ChannelEdit::Password(String::new()))
+    );
+    assert_eq!(parse_channel_edit(ChannelField::SubChannel, "x"), None);
+}
+
+#[test]
+fn occupants_include_sub_channels() {
+    let channels = [
+        channel(1, 0, "/"),
+        channel(2, 1, "/Lobby/"),
+        channel(3, 2, "/Lobby/Quiet/"),
+        channel(4, 1, "/Music/"),
+    ];
+    assert_eq!(occupants(2, &channels, [1, 3, 4]), 1);
+    assert_eq!(occupants(4, &channels, [1, 3]), 0);
+    assert_eq!(occupants(1, &channels, [1, 3, 4]), 3);
+}
+
+#[test]
+fn channel_details_lists_settings() {
+    let mut lobby = channel(2, 1, "/Lobby/");
+    lobby.password_protected = true;
+    let text = channel_details(LanguageCode::En, &lobby, 3);
+    assert!(text.contains("/Lobby/"));
+    assert!(text.contains("Topic: none"));
+    assert!(text.contains("Password: set"));
+    assert!(text.contains("Users now: 3"));
+}
@@ -50,3 +50,34 @@ async fn resolve_for_user_honors_expected_id() {
     db.close().await;
     let _ = std::fs::remove_file(db_path);
 }
+
+#[tokio::test]
+async fn resolve_for_chat_skips_account_binding() {
+    let (db, db_path) = setup_db().await;
+
+    db.create_deeplink(
+        "chat-token",
+        DeeplinkAction::Subscribe,
+        Some("payload"),
+        Some(42),
+        60,
+    )
+    .await
+    .expect("insert deeplink");
+
+    let resolved = resolve_for_chat(&db, "chat-token")
+        .await
+        .expect("resolve")
+        .expect("expected deeplink");
+    assert_eq!(resolved.action, DeeplinkAction::Subscribe);
+    assert!(resolved.payload.is_none());
+    assert!(
+        resolve_for_chat(&db, "chat-token")
+            .await
+            .expect("resolve")
+            .is_none()
+    );
+
+    db.close().await;
+    let _ = std::fs::remove_file(db_path);
+}
@@ -0,0 +1,45 @@
+use super::*;
+
+#[test]
+fn format_file_size_picks_unit() {
+    assert_eq!(format_file_size(0), "0 B");
+    assert_eq!(format_file_size(1023), "1023 B");
+    assert_eq!(format_file_size(1536), "1.5 KB");
+    assert_eq!(format_file_size(4_404_019), "4.2 MB");
+    assert_eq!(format_file_size(3 * 1024 * 1024 * 1024), "3.0 GB");
+}
+
+#[test]
+fn download_limit_is_inclusive() {
+    assert!(fits_download_limit(50 * 1024 * 1024, 50));
+    assert!(!fits_download_limit(50 * 1024 * 1024 + 1, 50));
+    assert!(!fits_download_limit(1, 0));
+    assert!(!fits_download_limit(-1, 50));
+}
+
+#[test]
+fn channel_quota_counts_existing_files() {
+    assert!(fits_channel_quota(0, 100, 100));
+    assert!(fits_channel_quota(60, 40, 100));
+    assert!(!fits_channel_quota(61, 40, 100));
+    assert!(!fits_channel_quota(0, 1, 0));
+}
+
+#[test]
+fn upload_file_name_strips_directories() {
+    assert_eq!(upload_file_name(Some("song.mp3"), "file"), "song.mp3");
+    assert_eq!(upload_file_name(Some("../../etc/passwd"), "file"), "passwd");
+    assert_eq!(upload_file_name(Some("dir/"), "file"), "dir");
+    assert_eq!(upload_file_name(Some(".."), "file"), "file");
+    assert_eq!(upload_file_name(Some("  "), "file"), "file");
+    assert_eq!(upload_file_name(None, "file"), "file");
+}
+
+#[test]
+fn transfer_percent_is_clamped() {
+    assert_eq!(transfer_percent(0, 200), 0);
+    assert_eq!(transfer_percent(50, 200), 25);
+    assert_eq!(transfer_percent(300, 200), 100);
+    assert_eq!(transfer_percent(-5, 200), 0);
+    assert_eq!(transfer_percent(10, 0), 0);
+}
@@ -0,0 +1,49 @@
+use super::*;
+use chrono::DateTime;
+
+fn sample(server: &str, ts: i64, count: i64) -> OnlineSample {
+    OnlineSample {
+        server_key: server.to_string(),
+        sampled_at: DateTime::from_timestamp(ts, 0).unwrap().naive_utc(),
+        user_count: count,
+    }
+}
+
+#[test]
+fn parse_range_defaults_to_day() {
+    assert_eq!(GraphRange::parse(""), Some(GraphRange::Day));
+    assert_eq!(GraphRange::parse(" Week "), Some(GraphRange::Week));
+    assert_eq!(GraphRange::parse("month"), Some(GraphRange::Month));
+    assert_eq!(GraphRange::parse("year"), None);
+}
+
+#[test]
+fn summarize_sums_servers_per_bucket() {
+    let samples = vec![
+        sample("main", 0, 3),
+        sample("backup", 10, 2),
+        sample("main", 200, 4),
+        sample("main", 3600, 1),
+    ];
+    let graph = summarize_samples(&samples, 3600).unwrap();
+    assert_eq!(graph.points, vec![(0, 6), (3600, 1)]);
+    assert_eq!(graph.peak, 6);
+    assert_eq!(graph.average, 4);
+}
+
+#[test]
+fn summarize_empty_is_none() {
+    assert!(summarize_samples(&[], 300).is_none());
+}
+
+#[test]
+fn caption_mentions_period_and_stats() {
+    let graph = OnlineGraph {
+        points: vec![(0, 2)],
+        peak: 7,
+        average: 3,
+    };
+    let caption = render_caption(LanguageCode::En, GraphRange::Week, &graph);
+    assert!(caption.contains('7'));
+    assert!(caption.contains('3'));
+}
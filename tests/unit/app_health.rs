@@ -0,0 +1,140 @@
+use super::*;
+
+const COOLDOWN: Duration = Duration::from_secs(300);
+
+fn tracker() -> HealthTracker {
+    HealthTracker::new(COOLDOWN, 3)
+}
+
+#[test]
+fn first_login_is_not_a_recovery() {
+    let mut health = tracker();
+    assert_eq!(health.logged_in(Instant::now()), None);
+}
+
+#[test]
+fn outage_alerts_once_and_reports_downtime() {
+    let mut health = tracker();
+    let start = Instant::now();
+    assert_eq!(health.disconnected(start), Some(HealthAlert::Down));
+    assert_eq!(health.disconnected(start + Duration::from_secs(10)), None);
+    assert_eq!(
+        health.logged_in(start + Duration::from_secs(90)),
+        Some(HealthAlert::Restored {
+            downtime: Duration::from_secs(90)
+        })
+    );
+}
+
+#[test]
+fn login_failures_alert_at_threshold() {
+    let mut health = tracker();
+    let start = Instant::now();
+    assert_eq!(health.login_failed(start), None);
+    assert_eq!(health.login_failed(start), None);
+    assert_eq!(
+        health.login_failed(start),
+        Some(HealthAlert::LoginFailing { attempts: 3 })
+    );
+    assert_eq!(health.login_failed(start), None);
+    assert_eq!(health.disconnected(start), None);
+}
+
+#[test]
+fn subscribers_hear_of_an_outage_once() {
+    let mut health = HealthTracker::new(Duration::ZERO, 2);
+    let start = Instant::now();
+    let down = health.disconnected(start).unwrap();
+    assert!(health.notifies_subscribers(down));
+    assert_eq!(health.login_failed(start), None);
+    let failing = health.login_failed(start).unwrap();
+    assert_eq!(failing, HealthAlert::LoginFailing { attempts: 2 });
+    assert!(!health.notifies_subscribers(failing));
+    let restored = health.logged_in(start + Duration::from_secs(30)).unwrap();
+    assert!(health.notifies_subscribers(restored));
+}
+
+#[test]
+fn login_failure_is_the_subscriber_alert_without_a_down_alert() {
+    let mut health = tracker();
+    let start = Instant::now();
+    health.login_failed(start);
+    health.login_failed(start);
+    let failing = health.login_failed(start).unwrap();
+    assert!(health.notifies_subscribers(failing));
+}
+
+#[test]
+fn flapping_link_is_rate_limited() {
+    let mut health = tracker();
+    let start = Instant::now();
+    assert_eq!(health.disconnected(start), Some(HealthAlert::Down));
+    assert!(health.logged_in(start + Duration::from_secs(5)).is_some());
+
+    let flap = start + Duration::from_secs(20);
+    assert_eq!(health.disconnected(flap), None);
+    assert_eq!(health.logged_in(flap + Duration::from_secs(5)), None);
+
+    assert_eq!(health.disconnected(flap + Duration::from_secs(60)), None);
+    assert_eq!(
+        health.disconnected(start + COOLDOWN + Duration::from_secs(5)),
+        Some(HealthAlert::Down)
+    );
+}
+
+#[test]
+fn outage_during_cooldown_alerts_once_cooldown_ends() {
+    let mut health = tracker();
+    let start = Instant::now();
+    assert_eq!(health.disconnected(start), Some(HealthAlert::Down));
+    assert!(health.logged_in(start + Duration::from_secs(5)).is_some());
+
+    let outage = start + Duration::from_secs(60);
+    assert_eq!(health.disconnected(outage), None);
+    assert_eq!(
+        health.deferred_alert(outage + Duration::from_secs(30)),
+        None
+    );
+    assert_eq!(
+        health.deferred_alert(start + COOLDOWN + Duration::from_secs(5)),
+        Some(HealthAlert::Down)
+    );
+    assert_eq!(health.deferred_alert(start + COOLDOWN * 3), None);
+}
+
+#[test]
+fn deferred_alert_is_dropped_after_recovery() {
+    let mut health = tracker();
+    let start = Instant::now();
+    assert_eq!(health.disconnected(start), Some(HealthAlert::Down));
+    assert!(health.logged_in(start + Duration::from_secs(5)).is_some());
+    assert_eq!(health.disconnected(start + Duration::from_secs(20)), None);
+    assert_eq!(health.logged_in(start + Duration::from_secs(25)), None);
+    assert_eq!(health.deferred_alert(start + COOLDOWN), None);
+}
+
+#[test]
+fn short_blip_without_alert_stays_silent() {
+    let mut health = tracker();
+    let start = Instant::now();
+    assert_eq!(health.login_failed(start), None);
+    assert_eq!(health.logged_in(start + Duration::from_secs(2)), None);
+    assert_eq!(health.login_failed(start + Duration::from_secs(3)), None);
+    assert_eq!(health.login_failed(start + Duration::from_secs(4)), None);
+}
+
+#[test]
+fn alert_texts_name_server_and_downtime() {
+    let restored = HealthAlert::Restored {
+        downtime: Duration::from_secs(3 * 3600 + 5 * 60),
+    };
+    let admin = admin_alert_text(LanguageCode::En, "Lounge", restored);
+    assert!(admin.contains("Lounge") && admin.contains("3h 5m"));
+
+    let failing = HealthAlert::LoginFailing { attempts: 4 };
+    assert!(admin_alert_text(LanguageCode::En, "Lounge", failing).contains('4'));
+    assert_eq!(
+        subscriber_notice_text(LanguageCode::En, "Lounge", failing),
+        subscriber_notice_text(LanguageCode::En, "Lounge", HealthAlert::Down)
+    );
+}
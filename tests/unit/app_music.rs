@@ -0,0 +1,95 @@
+use super::*;
+
+fn track(name: &str) -> Track {
+    Track {
+        path: PathBuf::from("/music").join(name),
+        name: name.to_string(),
+    }
+}
+
+#[test]
+fn parse_music_command_recovers_the_original_request() {
+    assert_eq!(
+        parse_music_command("/play  daft punk "),
+        Some(MusicCommand::Play { query: "daft punk" })
+    );
+    assert_eq!(
+        parse_music_command("/Playlist@sender_bot play road"),
+        Some(MusicCommand::Playlist { args: "play road" })
+    );
+    assert_eq!(
+        parse_music_command("/play"),
+        Some(MusicCommand::Play { query: "" })
+    );
+    assert_eq!(parse_music_command("/queue"), None);
+    assert_eq!(parse_music_command("play road"), None);
+}
+
+#[test]
+fn parse_playlist_command_handles_verbs() {
+    assert_eq!(parse_playlist_command(""), Some(PlaylistCommand::List));
+    assert_eq!(
+        parse_playlist_command(" list "),
+        Some(PlaylistCommand::List)
+    );
+    assert_eq!(
+        parse_playlist_command("add chill  night drive"),
+        Some(PlaylistCommand::Add {
+            name: "chill",
+            query: "night drive"
+        })
+    );
+    assert_eq!(
+        parse_playlist_command("PLAY chill"),
+        Some(PlaylistCommand::Play { name: "chill" })
+    );
+    assert_eq!(parse_playlist_command("add chill"), None);
+    assert_eq!(parse_playlist_command("delete"), None);
+    assert_eq!(parse_playlist_command("rename a b"), None);
+}
+
+#[test]
+fn match_score_ranks_phrase_words_then_subsequence() {
+    let phrase = match_score("night drive", "Artist/Night Drive.mp3").unwrap();
+    let words = match_score("drive night", "Artist/Night Drive.mp3").unwrap();
+    let fuzzy = match_score("ngtdrv", "Artist/Night Drive.mp3").unwrap();
+    assert!(phrase < words);
+    assert!(words < fuzzy);
+    assert_eq!(match_score("zzz", "Artist/Night Drive.mp3"), None);
+    assert_eq!(match_score("  ", "Artist/Night Drive.mp3"), None);
+}
+
+#[test]
+fn search_orders_and_limits_results() {
+    let tracks = vec![
+        track("b/Rain.mp3"),
+        track("a/Rainbow.flac"),
+        track("Sun.ogg"),
+        track("Train.mp3"),
+    ];
+    let found = search(tracks, "rain", 2);
+    let names: Vec<_> = found.iter().map(|t| t.name.as_str()).collect();
+    assert_eq!(names, vec!["b/Rain.mp3", "a/Rainbow.flac"]);
+    assert_eq!(found[0].title(), "Rain");
+}
+
+#[test]
+fn resolve_track_rejects_paths_outside_library() {
+    let root = std::env::temp_dir().join(format!("tt_tg_music_{}", uuid::Uuid::now_v7()));
+    std::fs::create_dir_all(root.join("album")).unwrap();
+    std::fs::write(root.join("album/song.mp3"), b"x").unwrap();
+    std::fs::write(root.join("notes.txt"), b"x").unwrap();
+
+    assert_eq!(
+        scan_library(&root)
+            .into_iter()
+            .map(|t| t.name)
+            .collect::<Vec<_>>(),
+        vec!["album/song.mp3".to_string()]
+    );
+    assert!(resolve_track(&root, "album/song.mp3").is_some());
+    assert!(resolve_track(&root, "../album/song.mp3").is_none());
+    assert!(resolve_track(&root, "album/missing.mp3").is_none());
+
+    let _ = std::fs::remove_dir_all(root);
+}
@@ -0,0 +1,80 @@
+use super::*;
+use crate::core::types::NowPlaying;
+
+fn entry(stream_id: u64, title: Option<&str>, duration_ms: u32) -> QueueEntry {
+    QueueEntry {
+        stream_id,
+        title: title.map(str::to_string),
+        duration_ms,
+    }
+}
+
+#[test]
+fn format_clock_adds_hours_only_when_needed() {
+    assert_eq!(format_clock(0), "00:00");
+    assert_eq!(format_clock(65_999), "01:05");
+    assert_eq!(format_clock(3_725_000), "1:02:05");
+}
+
+#[test]
+fn render_queue_shows_now_playing_and_pending() {
+    let snapshot = QueueSnapshot {
+        now_playing: Some(NowPlaying {
+            entry: entry(1, Some("Intro"), 180_000),
+            elapsed_ms: 200_000,
+            paused: true,
+        }),
+        pending: vec![entry(2, None, 5_000)],
+        ..QueueSnapshot::default()
+    };
+    let text = render_queue(LanguageCode::En, "Main", &snapshot);
+    assert!(text.contains("Main"));
+    assert!(text.contains("Intro — 03:00 / 03:00"));
+    assert!(text.contains("1. Voice message (00:05)"));
+}
+
+#[test]
+fn render_queue_truncates_long_queues() {
+    let snapshot = QueueSnapshot {
+        now_playing: None,
+        pending: (0..13).map(|i| entry(i, Some("t"), 1000)).collect(),
+        ..QueueSnapshot::default()
+    };
+    let text = render_queue(LanguageCode::En, "Main", &snapshot);
+    assert!(text.contains("10. t"));
+    assert!(!text.contains("11. t"));
+    assert!(text.contains("3 more"));
+}
+
+#[test]
+fn is_audio_file_checks_mime_then_extension() {
+    assert!(is_audio_file(Some("audio/flac"), None));
+    assert!(is_audio_file(None, Some("Song.MP3")));
+    assert!(is_audio_file(
+        Some("application/octet-stream"),
+        Some("a.m4a")
+    ));
+    assert!(!is_audio_file(Some("application/pdf"), Some("doc.pdf")));
+    assert!(!is_audio_file(None, None));
+}
+
+#[test]
+fn track_title_prefers_tags_over_file_name() {
+    assert_eq!(
+        track_title(Some("Artist"), Some("Song"), Some("x.mp3")).as_deref(),
+        Some("Artist — Song")
+    );
+    assert_eq!(
+        track_title(None, Some("Song"), None).as_deref(),
+        Some("Song")
+    );
+    assert_eq!(
+        track_title(Some(" "), None, Some("01 Track.flac")).as_deref(),
+        Some("01 Track")
+    );
+    assert_eq!(
+        track_title(Some("Artist"), None, None).as_deref(),
+        Some("Artist")
+    );
+    assert_eq!(track_title(None, None, None), None);
+}
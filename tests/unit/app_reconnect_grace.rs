@@ -0,0 +1,136 @@
+use super::*;
+
+const WINDOW: Duration = Duration::from_secs(30);
+
+#[test]
+fn login_within_window_swallows_the_leave() {
+    let mut grace = ReconnectGrace::new(WINDOW);
+    let start = Instant::now();
+    assert_eq!(grace.hold_leave("alice", start, "left"), None);
+    assert!(grace.logged_in("alice", start));
+    assert!(grace.take_expired(start + WINDOW).is_empty());
+    assert_eq!(grace.next_deadline(), None);
+}
+
+#[test]
+fn login_after_window_is_a_normal_join() {
+    let mut grace = ReconnectGrace::new(WINDOW);
+    let start = Instant::now();
+    grace.hold_leave("alice", start, "left");
+    assert!(
+        grace
+            .take_expired(start + Duration::from_secs(29))
+            .is_empty()
+    );
+    assert_eq!(grace.take_expired(start + WINDOW), vec!["left"]);
+    assert!(!grace.logged_in("alice", start + WINDOW));
+}
+
+#[test]
+fn expired_leaves_come_out_in_order() {
+    let mut grace = ReconnectGrace::new(WINDOW);
+    let start = Instant::now();
+    grace.hold_leave("bob", start + Duration::from_secs(5), "bob left");
+    grace.hold_leave("alice", start, "alice left");
+    assert_eq!(grace.next_deadline(), Some(start + WINDOW));
+    assert_eq!(
+        grace.take_expired(start + Duration::from_secs(60)),
+        vec!["alice left", "bob left"]
+    );
+}
+
+#[test]
+fn other_users_do_not_cancel_a_leave() {
+    let mut grace = ReconnectGrace::new(WINDOW);
+    let start = Instant::now();
+    grace.hold_leave("alice", start, "left");
+    assert!(!grace.logged_in("bob", start));
+    assert_eq!(grace.take_expired(start + WINDOW), vec!["left"]);
+}
+
+#[test]
+fn zero_window_or_anonymous_user_is_sent_at_once() {
+    let start = Instant::now();
+    let mut off = ReconnectGrace::new(Duration::ZERO);
+    assert_eq!(off.hold_leave("alice", start, "left"), Some("left"));
+    assert!(!off.logged_in("alice", start));
+
+    let mut grace = ReconnectGrace::new(WINDOW);
+    assert_eq!(grace.hold_leave("", start, "left"), Some("left"));
+}
+
+#[test]
+fn reconnect_swallows_channel_leave_and_rejoin() {
+    let mut grace = ReconnectGrace::new(WINDOW);
+    let start = Instant::now();
+    assert_eq!(grace.hold_channel_leave("alice", start, "left lobby"), None);
+    assert_eq!(grace.hold_leave("alice", start, "logged out"), None);
+    assert!(
+        grace
+            .take_expired(start + Duration::from_secs(10))
+            .is_empty()
+    );
+    let back = start + Duration::from_secs(12);
+    assert!(grace.logged_in("alice", back));
+    assert!(
+        grace
+            .channel_joined("alice", back, "joined lobby")
+            .is_empty()
+    );
+    assert!(grace.take_expired(start + WINDOW).is_empty());
+    assert_eq!(
+        grace.channel_joined("alice", back + Duration::from_secs(1), "joined music"),
+        vec!["joined music"]
+    );
+}
+
+#[test]
+fn channel_move_keeps_leave_before_join() {
+    let mut grace = ReconnectGrace::new(WINDOW);
+    let start = Instant::now();
+    assert_eq!(grace.hold_channel_leave("alice", start, "left lobby"), None);
+    assert_eq!(
+        grace.channel_joined("alice", start, "joined music"),
+        vec!["left lobby", "joined music"]
+    );
+    assert_eq!(grace.next_deadline(), None);
+}
+
+#[test]
+fn channel_leave_without_logout_is_sent_after_settling() {
+    let mut grace = ReconnectGrace::new(WINDOW);
+    let start = Instant::now();
+    assert_eq!(grace.hold_channel_leave("alice", start, "left lobby"), None);
+    assert_eq!(
+        grace.take_expired(start + Duration::from_secs(5)),
+        vec!["left lobby"]
+    );
+
+    let mut off = ReconnectGrace::new(Duration::ZERO);
+    assert_eq!(
+        off.hold_channel_leave("alice", start, "left lobby"),
+        Some("left lobby")
+    );
+    assert_eq!(
+        off.channel_joined("alice", start, "joined lobby"),
+        vec!["joined lobby"]
+    );
+}
+
+#[test]
+fn reconnect_after_window_reports_everything() {
+    let mut grace = ReconnectGrace::new(WINDOW);
+    let start = Instant::now();
+    grace.hold_channel_leave("alice", start, "left lobby");
+    grace.hold_leave("alice", start, "logged out");
+    assert_eq!(
+        grace.take_expired(start + WINDOW),
+        vec!["left lobby", "logged out"]
+    );
+    let back = start + WINDOW + Duration::from_secs(5);
+    assert!(!grace.logged_in("alice", back));
+    assert_eq!(
+        grace.channel_joined("alice", back, "joined lobby"),
+        vec!["joined lobby"]
+    );
+}
@@ -0,0 +1,52 @@
+use super::*;
+
+#[test]
+fn normalize_username_accepts_plain_names() {
+    assert_eq!(normalize_username(" alice "), Some("alice".to_string()));
+    assert_eq!(
+        normalize_username("Bob_the.builder-2"),
+        Some("Bob_the.builder-2".to_string())
+    );
+}
+
+#[test]
+fn normalize_username_rejects_bad_names() {
+    assert_eq!(normalize_username(""), None);
+    assert_eq!(normalize_username("a"), None);
+    assert_eq!(normalize_username("two words"), None);
+    assert_eq!(normalize_username("имя"), None);
+    assert_eq!(normalize_username(&"x".repeat(33)), None);
+}
+
+#[test]
+fn generate_password_is_random_alphanumeric() {
+    let first = generate_password();
+    assert_eq!(first.len(), PASSWORD_LENGTH);
+    assert!(first.chars().all(|c| c.is_ascii_alphanumeric()));
+    assert_ne!(first, generate_password());
+}
+
+#[tokio::test]
+async fn submit_refuses_duplicates() {
+    let mut path = std::env::temp_dir();
+    path.push(format!("tt_tg_registration_{}.db", uuid::Uuid::now_v7()));
+    let db = Database::new(path.to_str().unwrap()).await.unwrap();
+
+    let outcome = submit(&db, 1, "main", "alice").await.unwrap();
+    assert!(matches!(outcome, SubmitOutcome::Filed(_)));
+    assert_eq!(
+        submit(&db, 1, "main", "carol").await.unwrap(),
+        SubmitOutcome::AlreadyPending
+    );
+    assert_eq!(
+        submit(&db, 2, "main", "alice").await.unwrap(),
+        SubmitOutcome::UsernameTaken
+    );
+    assert!(matches!(
+        submit(&db, 2, "other", "alice").await.unwrap(),
+        SubmitOutcome::Filed(_)
+    ));
+
+    db.close().await;
+    let _ = std::fs::remove_file(path);
+}
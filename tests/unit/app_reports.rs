@@ -0,0 +1,86 @@
+use super::*;
+use chrono::{NaiveDate, NaiveTime, Weekday};
+
+fn at(hour: u32, minute: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(2026, 10, 14)
+        .unwrap()
+        .and_hms_opt(hour, minute, 0)
+        .unwrap()
+}
+
+fn session(
+    username: &str,
+    channel: Option<&str>,
+    login: NaiveDateTime,
+    logout: Option<NaiveDateTime>,
+) -> ReportSession {
+    ReportSession {
+        username: username.to_string(),
+        nickname: username.to_uppercase(),
+        channel: channel.map(str::to_string),
+        login_time: login,
+        logout_time: logout,
+    }
+}
+
+#[test]
+fn summarize_counts_peak_and_uniques() {
+    let sessions = vec![
+        session("alice", Some("/Lobby/"), at(10, 0), Some(at(12, 0))),
+        session("bob", Some("/Lobby/"), at(11, 0), Some(at(11, 30))),
+        session("alice", Some("/Music/"), at(13, 0), None),
+        session("carol", None, at(12, 0), Some(at(12, 10))),
+    ];
+    let report = summarize_sessions(&sessions, at(0, 0), at(14, 0));
+
+    assert_eq!(report.peak_users, 2);
+    assert_eq!(report.unique_visitors, 3);
+    assert_eq!(report.longest_sessions[0], ("ALICE".to_string(), 7200));
+    assert_eq!(report.longest_sessions.len(), 3);
+    assert_eq!(report.final_channels[0], ("/Lobby/".to_string(), 2));
+}
+
+#[test]
+fn touching_sessions_do_not_overlap() {
+    let sessions = vec![
+        session("alice", None, at(10, 0), Some(at(11, 0))),
+        session("bob", None, at(11, 0), Some(at(12, 0))),
+    ];
+    let report = summarize_sessions(&sessions, at(0, 0), at(14, 0));
+    assert_eq!(report.peak_users, 1);
+}
+
+fn reports(schedule: ReportSchedule, offset: &str) -> ReportsConfig {
+    ReportsConfig {
+        schedule,
+        time: NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+        weekday: Weekday::Mon,
+        utc_offset: offset.parse().unwrap(),
+    }
+}
+
+#[test]
+fn next_run_daily_respects_offset() {
+    let cfg = reports(ReportSchedule::Daily, "+03:00");
+    let now = Utc.from_utc_datetime(&at(5, 0));
+    assert_eq!(next_run(now, &cfg), Some(Utc.from_utc_datetime(&at(6, 0))));
+
+    let now = Utc.from_utc_datetime(&at(6, 0));
+    assert_eq!(
+        next_run(now, &cfg),
+        Some(Utc.from_utc_datetime(&(at(6, 0) + Duration::days(1))))
+    );
+}
+
+#[test]
+fn next_run_weekly_picks_weekday() {
+    let cfg = reports(ReportSchedule::Weekly, "+00:00");
+    let now = Utc.from_utc_datetime(&at(10, 0));
+    let expected = NaiveDate::from_ymd_opt(2026, 10, 19)
+        .unwrap()
+        .and_hms_opt(9, 0, 0)
+        .unwrap();
+    assert_eq!(next_run(now, &cfg), Some(Utc.from_utc_datetime(&expected)));
+
+    assert_eq!(next_run(now, &reports(ReportSchedule::Off, "+00:00")), None);
+}
@@ -0,0 +1,17 @@
+use super::*;
+
+#[test]
+fn format_duration_uses_two_largest_units() {
+    assert_eq!(format_duration(LanguageCode::En, 42), "42s");
+    assert_eq!(format_duration(LanguageCode::En, 45 * 60), "45m");
+    assert_eq!(
+        format_duration(LanguageCode::En, 3 * 3600 + 5 * 60),
+        "3h 5m"
+    );
+    assert_eq!(
+        format_duration(LanguageCode::En, 2 * 86_400 + 7 * 3600 + 59),
+        "2d 7h"
+    );
+    assert_eq!(format_duration(LanguageCode::En, 86_400 + 30 * 60), "1d");
+    assert_eq!(format_duration(LanguageCode::En, -5), "0s");
+}
@@ -0,0 +1,61 @@
+use super::*;
+
+fn info() -> LiteServerInfo {
+    LiteServerInfo {
+        name: "Lounge".to_string(),
+        motd: "Welcome".to_string(),
+        max_users: 100,
+        login_delay_ms: 500,
+        version: "5.17".to_string(),
+    }
+}
+
+#[test]
+fn parse_server_edit_requires_a_name() {
+    assert_eq!(
+        parse_server_edit(ServerField::Name, " Hangout "),
+        Some(ServerEdit::Name("Hangout".to_string()))
+    );
+    assert_eq!(parse_server_edit(ServerField::Name, "  "), None);
+    assert_eq!(parse_server_edit(ServerField::Name, "-"), None);
+}
+
+#[test]
+fn parse_server_edit_clears_motd_with_dash() {
+    assert_eq!(
+        parse_server_edit(ServerField::Motd, " Be nice "),
+        Some(ServerEdit::Motd("Be nice".to_string()))
+    );
+    assert_eq!(
+        parse_server_edit(ServerField::Motd, "-"),
+        Some(ServerEdit::Motd(String::new()))
+    );
+}
+
+#[test]
+fn server_details_lists_properties() {
+    let mut server = info();
+    server.motd.clear();
+    let text = server_details(LanguageCode::En, &server);
+    assert!(text.contains("Lounge"));
+    assert!(text.contains("MOTD: none"));
+    assert!(text.contains("Max users: 100"));
+    assert!(text.contains("500 ms"));
+    assert!(text.contains("5.17"));
+}
+
+#[test]
+fn describe_changes_reports_only_changed_fields() {
+    let previous = info();
+    assert!(describe_changes(LanguageCode::En, &previous, &previous).is_empty());
+
+    let current = LiteServerInfo {
+        name: "Hangout".to_string(),
+        max_users: 50,
+        ..info()
+    };
+    let changes = describe_changes(LanguageCode::En, &previous, &current);
+    assert_eq!(changes.len(), 2);
+    assert!(changes[0].contains("Lounge") && changes[0].contains("Hangout"));
+    assert!(changes[1].contains("100") && changes[1].contains("50"));
+}
@@ -14,3 +14,10 @@ fn parse_mute_list_mode_fallback() {
     assert_eq!(parse_mute_list_mode(""), MuteListMode::Blacklist);
     assert_eq!(parse_mute_list_mode("unknown"), MuteListMode::Blacklist);
 }
+
+#[test]
+fn parse_delivery_mode_fallback() {
+    assert_eq!(parse_delivery_mode("hourly"), DeliveryMode::Hourly);
+    assert_eq!(parse_delivery_mode(""), DeliveryMode::Instant);
+    assert_eq!(parse_delivery_mode("unknown"), DeliveryMode::Instant);
+}
@@ -32,7 +32,7 @@ fn display_name_prefers_server_name() {
             "#,
     );
 
-    assert_eq!(cfg.teamtalk.display_name(), "srv");
+    assert_eq!(cfg.teamtalk[0].display_name(), "srv");
 }
 
 #[test]
@@ -63,7 +63,7 @@ fn display_name_falls_back_to_host() {
             "#,
     );
 
-    assert_eq!(cfg.teamtalk.display_name(), "host");
+    assert_eq!(cfg.teamtalk[0].display_name(), "host");
 }
 
 #[test]
@@ -130,6 +130,12 @@ fn operational_parameters_override() {
             tt_reconnect_retry_seconds = 22
             deeplink_cleanup_interval_seconds = 33
             tt_reconnect_check_interval_seconds = 44
+            reconnect_grace_seconds = 55
+            digest_batch_minutes = 5
+            online_sample_interval_seconds = 66
+            file_download_max_mb = 77
+            health_alert_cooldown_seconds = 88
+            login_failure_alert_threshold = 4
             "#,
     );
 
@@ -137,4 +143,279 @@ fn operational_parameters_override() {
     assert_eq!(cfg.operational_parameters.tt_reconnect_retry, 22);
     assert_eq!(cfg.operational_parameters.deeplink_cleanup_interval, 33);
     assert_eq!(cfg.operational_parameters.tt_reconnect_check_interval, 44);
+    assert_eq!(cfg.operational_parameters.reconnect_grace, 55);
+    assert_eq!(cfg.operational_parameters.digest_batch, 5);
+    assert_eq!(cfg.operational_parameters.online_sample_interval, 66);
+    assert_eq!(cfg.operational_parameters.file_download_max, 77);
+    assert_eq!(cfg.operational_parameters.health_alert_cooldown, 88);
+    assert_eq!(cfg.operational_parameters.login_failure_alert_threshold, 4);
+}
+
+#[test]
+fn single_teamtalk_table_is_one_server() {
+    let cfg = parse_config(
+        r#"
+            [general]
+            admin_username = "admin"
+
+            [database]
+            db_file = "test.db"
+
+            [telegram]
+            admin_chat_id = 1
+
+            [teamtalk]
+            host_name = "host"
+            port = 10333
+            encrypted = false
+            user_name = "u"
+            password = "p"
+            channel = "/"
+            nick_name = "n"
+            client_name = "c"
+            "#,
+    );
+
+    assert_eq!(cfg.teamtalk.len(), 1);
+    assert!(!cfg.is_multi_server());
+    assert_eq!(cfg.teamtalk[0].key(), "host:10333");
+}
+
+#[test]
+fn multiple_teamtalk_servers_are_parsed_in_order() {
+    let cfg = parse_config(
+        r#"
+            [general]
+            admin_username = "admin"
+
+            [database]
+            db_file = "test.db"
+
+            [telegram]
+            admin_chat_id = 1
+
+            [[teamtalk]]
+            id = "main"
+            host_name = "one"
+            port = 1
+            encrypted = false
+            user_name = "u"
+            password = "p"
+            channel = "/"
+            nick_name = "n"
+            client_name = "c"
+            server_name = "First"
+
+            [[teamtalk]]
+            host_name = "two"
+            port = 2
+            encrypted = true
+            user_name = "u"
+            password = "p"
+            channel = "/"
+            nick_name = "n"
+            client_name = "c"
+            guest_username = "guest"
+            "#,
+    );
+
+    assert_eq!(cfg.teamtalk.len(), 2);
+    assert!(cfg.is_multi_server());
+    assert_eq!(cfg.teamtalk[0].key(), "main");
+    assert_eq!(cfg.teamtalk[1].key(), "two:2");
+    assert_eq!(cfg.server_display_name(ServerId(0)), "First");
+    assert_eq!(cfg.server_display_name(ServerId(1)), "two");
+    assert!(cfg.server(ServerId(2)).is_none());
+    assert_eq!(cfg.server_ids().count(), 2);
+    assert_eq!(cfg.guest_usernames(), vec!["guest".to_string()]);
+}
+
+#[test]
+fn duplicate_server_ids_are_rejected() {
+    let res = toml::from_str::<Config>(
+        r#"
+            [general]
+            admin_username = "admin"
+
+            [database]
+            db_file = "test.db"
+
+            [telegram]
+            admin_chat_id = 1
+
+            [[teamtalk]]
+            host_name = "same"
+            port = 1
+            encrypted = false
+            user_name = "u"
+            password = "p"
+            channel = "/"
+            nick_name = "n"
+            client_name = "c"
+
+            [[teamtalk]]
+            host_name = "same"
+            port = 1
+            encrypted = false
+            user_name = "u2"
+            password = "p"
+            channel = "/"
+            nick_name = "n"
+            client_name = "c"
+            "#,
+    );
+
+    assert!(res.is_err());
+}
+
+const REPORTS_BASE: &str = r#"
+    [general]
+    admin_username = "admin"
+
+    [database]
+    db_file = "test.db"
+
+    [telegram]
+    admin_chat_id = 1
+
+    [teamtalk]
+    host_name = "host"
+    port = 1
+    encrypted = false
+    user_name = "u"
+    password = "p"
+    channel = "/"
+    nick_name = "n"
+    client_name = "c"
+"#;
+
+#[test]
+fn reports_default_to_off() {
+    let cfg = parse_config(REPORTS_BASE);
+    assert_eq!(cfg.reports.schedule, ReportSchedule::Off);
+}
+
+#[test]
+fn reports_schedule_parses() {
+    let cfg = parse_config(&format!(
+        r#"{REPORTS_BASE}
+            [reports]
+            schedule = "weekly"
+            time = "21:30"
+            weekday = "fri"
+            utc_offset = "+03:00"
+        "#
+    ));
+    assert_eq!(cfg.reports.schedule, ReportSchedule::Weekly);
+    assert_eq!(
+        cfg.reports.time,
+        NaiveTime::from_hms_opt(21, 30, 0).unwrap()
+    );
+    assert_eq!(cfg.reports.weekday, Weekday::Fri);
+    assert_eq!(cfg.reports.utc_offset.local_minus_utc(), 3 * 3600);
+}
+
+#[test]
+fn reports_reject_zone_name_offset() {
+    let res = toml::from_str::<Config>(&format!(
+        r#"{REPORTS_BASE}
+            [reports]
+            schedule = "daily"
+            utc_offset = "Europe/Berlin"
+        "#
+    ));
+    let err = res.err().unwrap().to_string();
+    assert!(err.contains("fixed offset"), "{err}");
+}
+
+#[test]
+fn reports_reject_bad_time() {
+    let res = toml::from_str::<Config>(&format!(
+        r#"{REPORTS_BASE}
+            [reports]
+            schedule = "daily"
+            time = "25:00"
+        "#
+    ));
+    assert!(res.is_err());
+}
+
+#[test]
+fn chat_bridges_resolve_by_channel_and_chat() {
+    let cfg = parse_config(&format!(
+        r#"{REPORTS_BASE}
+            [[chat_bridge]]
+            channel = "/Lobby/"
+            chat_id = -100
+
+            [[chat_bridge]]
+            channel = "/Quiet"
+            chat_id = -200
+            enabled = false
+        "#
+    ));
+    assert!(cfg.check_chat_bridges().is_ok());
+    assert_eq!(
+        cfg.chat_bridge_for_channel(ServerId(0), "/Lobby")
+            .map(|b| b.chat_id),
+        Some(-100)
+    );
+    assert!(
+        cfg.chat_bridge_for_channel(ServerId(0), "/Quiet/")
+            .is_none()
+    );
+    assert_eq!(
+        cfg.chat_bridge_for_chat(-100)
+            .map(|(server_id, b)| (server_id, b.channel.as_str())),
+        Some((ServerId(0), "/Lobby/"))
+    );
+    assert!(cfg.chat_bridge_for_chat(-200).is_none());
+}
+
+#[test]
+fn chat_bridges_reject_unknown_server_and_duplicate_chat() {
+    let unknown = parse_config(&format!(
+        r#"{REPORTS_BASE}
+            [[chat_bridge]]
+            server = "missing"
+            channel = "/"
+            chat_id = -100
+        "#
+    ));
+    assert!(unknown.check_chat_bridges().is_err());
+
+    let duplicate = parse_config(&format!(
+        r#"{REPORTS_BASE}
+            [[chat_bridge]]
+            channel = "/"
+            chat_id = -100
+
+            [[chat_bridge]]
+            channel = "/Other"
+            chat_id = -100
+        "#
+    ));
+    assert!(duplicate.check_chat_bridges().is_err());
+}
+
+#[test]
+fn spool_dir_sits_next_to_database() {
+    let db = DatabaseConfig {
+        db_file: "/var/lib/bot/bot_data.db".to_string(),
+    };
+    assert_eq!(db.spool_dir(), PathBuf::from("/var/lib/bot/spool"));
+}
+
+#[test]
+fn music_library_is_optional() {
+    let cfg = parse_config(REPORTS_BASE);
+    assert!(cfg.music.library_dir().is_none());
+
+    let cfg = parse_config(&format!(
+        r#"{REPORTS_BASE}
+            [music]
+            library_dir = "/srv/music"
+        "#
+    ));
+    assert_eq!(cfg.music.library_dir(), Some(Path::new("/srv/music")));
 }
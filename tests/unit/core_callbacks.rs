@@ -1,5 +1,8 @@
 use super::*;
-use crate::core::types::{MuteListMode, NotificationSetting, TtUsername};
+use crate::core::types::{
+    AccountField, AccountType, BanTerm, ChannelField, MuteListMode, NotificationSetting,
+    ServerField, ServerId, TtUsername,
+};
 
 #[test]
 fn callback_roundtrip_menu() {
@@ -19,6 +22,50 @@ fn callback_roundtrip_nested() {
     assert_eq!(decoded, action);
 }
 
+#[test]
+fn callback_roundtrip_file_download_fits() {
+    let action = CallbackAction::File(FileAction::Download {
+        server_id: ServerId(3),
+        channel_id: i32::MAX,
+        file_id: i32::MAX,
+    });
+    let encoded = encode_callback(&action);
+    assert_ne!(encoded, "noop");
+    let decoded = CallbackAction::from_str(&encoded).unwrap();
+    assert_eq!(decoded, action);
+}
+
+#[test]
+fn callback_roundtrip_queue_control_fits() {
+    let action = CallbackAction::Queue(QueueAction::Control {
+        server_id: ServerId(3),
+        op: crate::core::types::QueueOp::MoveTop {
+            stream_id: u64::MAX,
+        },
+    });
+    let encoded = encode_callback(&action);
+    assert_ne!(encoded, "noop");
+    let decoded = CallbackAction::from_str(&encoded).unwrap();
+    assert_eq!(decoded, action);
+}
+
+#[test]
+fn callback_released_variants_keep_their_index() {
+    let decode = |bytes: &[u8]| CallbackAction::from_str(&z85::encode(bytes)).unwrap();
+    assert_eq!(decode(&[0, 3]), CallbackAction::Menu(MenuAction::Unsub));
+    assert_eq!(
+        decode(&[1, 5, 14, 2]),
+        CallbackAction::Admin(AdminAction::UnbanPerform {
+            ban_db_id: 7,
+            page: 2,
+        })
+    );
+    assert_eq!(
+        decode(&[1, 6, 1]),
+        CallbackAction::Admin(AdminAction::SubsList { page: 1 })
+    );
+}
+
 #[test]
 fn callback_noop_roundtrip() {
     let decoded = CallbackAction::from_str("noop").unwrap();
@@ -110,3 +157,84 @@ fn callback_oversized_payload_encodes_as_noop() {
     let encoded = encode_callback(&action);
     assert_eq!(encoded, "noop");
 }
+
+#[test]
+fn callback_roundtrip_server_scoped_actions() {
+    for action in [
+        CallbackAction::Menu(MenuAction::WhoServer {
+            server_id: ServerId(3),
+        }),
+        CallbackAction::Admin(AdminAction::BanPerform {
+            server_id: ServerId(1),
+            user_id: 4242,
+        }),
+        CallbackAction::Admin(AdminAction::BanConfirm {
+            server_id: ServerId(1),
+            user_id: 4242,
+            term: BanTerm::Week,
+        }),
+        CallbackAction::Admin(AdminAction::MovePerform {
+            server_id: ServerId(1),
+            user_id: 4242,
+            channel_id: i32::MAX,
+        }),
+        CallbackAction::Admin(AdminAction::ChannelPrompt {
+            server_id: ServerId(1),
+            channel_id: 77,
+            field: ChannelField::Password,
+        }),
+        CallbackAction::Admin(AdminAction::AccountPrompt {
+            server_id: ServerId(1),
+            username: TtUsername::from("operator"),
+            field: AccountField::Note,
+        }),
+        CallbackAction::Admin(AdminAction::AccountSetType {
+            server_id: ServerId(1),
+            username: TtUsername::from("operator"),
+            account_type: AccountType::Admin,
+        }),
+        CallbackAction::Admin(AdminAction::ServerPrompt {
+            server_id: ServerId(1),
+            field: ServerField::Motd,
+        }),
+        CallbackAction::Admin(AdminAction::ServerUnban {
+            server_id: ServerId(1),
+            page: 3,
+            ban_key: u64::MAX,
+        }),
+        CallbackAction::Settings(SettingsAction::ServerToggle {
+            server_id: ServerId(2),
+        }),
+        CallbackAction::Menu(MenuAction::RegisterServer {
+            server_id: ServerId(2),
+            username: TtUsername::from("new.user_32"),
+        }),
+        CallbackAction::Admin(AdminAction::RegistrationApprove {
+            request_id: i64::MAX,
+        }),
+    ] {
+        let encoded = encode_callback(&action);
+        let decoded = CallbackAction::from_str(&encoded).unwrap();
+        assert_eq!(decoded, action);
+    }
+}
+
+#[test]
+fn feed_buttons_act_for_the_group_chat() {
+    let download = CallbackAction::File(FileAction::Download {
+        server_id: ServerId(0),
+        channel_id: 1,
+        file_id: 2,
+    });
+    let menu = CallbackAction::Menu(MenuAction::Who);
+    assert!(download.acts_for_chat(false));
+    assert!(menu.acts_for_chat(false));
+    assert!(!download.acts_for_chat(true));
+    assert!(!menu.acts_for_chat(true));
+    assert!(!download.changes_chat_settings());
+
+    let mute = CallbackAction::Mute(MuteAction::Menu {
+        mode: MuteListMode::Blacklist,
+    });
+    assert!(mute.acts_for_chat(true) && mute.acts_for_chat(false));
+}
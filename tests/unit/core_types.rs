@@ -70,3 +70,18 @@ fn tt_username_helpers() {
     let from_string: TtUsername = String::from("bob").into();
     assert_eq!(from_string.as_ref(), "bob");
 }
+
+#[test]
+fn tt_command_server_id_routing() {
+    assert_eq!(TtCommand::Shutdown.server_id(), None);
+    let cmd = TtCommand::KickUser {
+        server_id: ServerId(2),
+        user_id: 7,
+    };
+    assert_eq!(cmd.server_id(), Some(ServerId(2)));
+    let cmd = TtCommand::LoadAccounts {
+        server_id: ServerId(1),
+    };
+    assert_eq!(cmd.server_id(), Some(ServerId(1)));
+    assert_eq!(ServerId(3).to_string(), "3");
+}
@@ -0,0 +1,25 @@
+use super::*;
+
+const LABELS: AxisLabels<'static> = AxisLabels {
+    x: "Time (UTC)",
+    y: "Users online",
+};
+
+#[test]
+fn renders_png() {
+    let points = vec![(0, 1), (300, 4), (600, 2), (900, 0)];
+    let png = render_line_chart(&points, &LABELS, 320, 200).unwrap();
+    assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+}
+
+#[test]
+fn rejects_empty_series() {
+    assert!(render_line_chart(&[], &LABELS, 320, 200).is_err());
+}
+
+#[test]
+fn ticks_show_time_for_short_spans_and_date_for_long_ones() {
+    let noon = 1_760_702_400;
+    assert_eq!(format_tick(noon, 24 * 60 * 60), "12:00");
+    assert_eq!(format_tick(noon, 30 * 24 * 60 * 60), "17.10");
+}
@@ -1,4 +1,6 @@
 use super::Database;
+use crate::infra::db::types::{LiftedBan, NewBan};
+use chrono::{Duration, Utc};
 
 #[tokio::test]
 async fn ban_lifecycle() {
@@ -26,6 +28,72 @@ async fn ban_lifecycle() {
     let _ = std::fs::remove_file(path);
 }
 
+#[tokio::test]
+async fn expired_bans_are_ignored_and_lifted() {
+    let (db, path) = setup_db().await;
+    let now = Utc::now().naive_utc();
+    db.add_ban_entry(&NewBan {
+        teamtalk_username: Some("expired".to_string()),
+        reason: Some("spam".to_string()),
+        expires_at: Some(now - Duration::minutes(1)),
+        server_key: Some("main".to_string()),
+        ip_address: Some("10.0.0.1".to_string()),
+        ..NewBan::default()
+    })
+    .await
+    .unwrap();
+    db.add_ban_entry(&NewBan {
+        teamtalk_username: Some("timed".to_string()),
+        reason: Some("flood".to_string()),
+        expires_at: Some(now + Duration::hours(1)),
+        ..NewBan::default()
+    })
+    .await
+    .unwrap();
+
+    assert!(!db.is_teamtalk_username_banned("expired").await.unwrap());
+    assert!(db.is_teamtalk_username_banned("timed").await.unwrap());
+    let list = db.get_banned_users().await.unwrap();
+    assert_eq!(list.len(), 1);
+    assert_eq!(list[0].ban_reason.as_deref(), Some("flood"));
+    assert!(list[0].expires_at.is_some());
+
+    db.set_ban_channel("main", "10.0.0.1", 3).await.unwrap();
+    let expired = db.get_expired_bans(now).await.unwrap();
+    assert_eq!(
+        expired,
+        vec![LiftedBan {
+            id: expired[0].id,
+            teamtalk_username: Some("expired".to_string()),
+            server_key: Some("main".to_string()),
+            ip_address: Some("10.0.0.1".to_string()),
+            channel_id: Some(3),
+        }]
+    );
+    assert_eq!(
+        db.get_expired_bans(now).await.unwrap(),
+        expired,
+        "expired bans stay until the unban is confirmed"
+    );
+    assert_eq!(
+        db.get_ban_by_id(expired[0].id).await.unwrap().as_ref(),
+        expired.first()
+    );
+    db.remove_ban_by_id(expired[0].id).await.unwrap();
+    assert!(db.get_expired_bans(now).await.unwrap().is_empty());
+    assert!(db.get_ban_by_id(expired[0].id).await.unwrap().is_none());
+
+    let removed = db.remove_ban_by_id(list[0].id).await.unwrap();
+    assert_eq!(
+        removed.and_then(|b| b.teamtalk_username),
+        Some("timed".to_string())
+    );
+    assert!(db.remove_ban_by_id(list[0].id).await.unwrap().is_none());
+
+    db.close().await;
+    let _ = std::fs::remove_file(path);
+}
+
 #[tokio::test]
 async fn ban_checks_false_for_unknown() {
     let (db, path) = setup_db().await;
@@ -3,14 +3,15 @@ use super::Database;
 #[tokio::test]
 async fn pending_channel_reply_roundtrip() {
     let (db, path) = setup_db().await;
-    db.add_pending_channel_reply(10, 1, "chan", "srv", "text")
+    db.add_pending_channel_reply(10, "backup", 1, "chan", "srv", "text")
         .await
         .unwrap();
     let data = db.get_pending_channel_reply(10).await.unwrap().unwrap();
-    assert_eq!(data.0, 1);
-    assert_eq!(data.1, "chan");
-    assert_eq!(data.2, "srv");
-    assert_eq!(data.3, "text");
+    assert_eq!(data.server_key, "backup");
+    assert_eq!(data.channel_id, 1);
+    assert_eq!(data.channel_name, "chan");
+    assert_eq!(data.server_name, "srv");
+    assert_eq!(data.original_text, "text");
 
     db.touch_pending_channel_reply(10).await.unwrap();
     let removed = db.cleanup_pending_channel_replies(0).await.unwrap();
@@ -23,7 +24,7 @@ async fn pending_channel_reply_roundtrip() {
 #[tokio::test]
 async fn cleanup_keeps_recent_when_ttl_large() {
     let (db, path) = setup_db().await;
-    db.add_pending_channel_reply(11, 2, "chan2", "srv2", "text2")
+    db.add_pending_channel_reply(11, "", 2, "chan2", "srv2", "text2")
         .await
         .unwrap();
     let removed = db.cleanup_pending_channel_replies(10_000).await.unwrap();
@@ -0,0 +1,52 @@
+use super::Database;
+use crate::infra::db::types::PlaylistSummary;
+
+#[tokio::test]
+async fn playlists_roundtrip() {
+    let (db, path) = setup_db().await;
+
+    assert_eq!(
+        db.add_playlist_track("chill", "a/one.mp3").await.unwrap(),
+        1
+    );
+    assert_eq!(
+        db.add_playlist_track("Chill", "b/two.mp3").await.unwrap(),
+        2
+    );
+    assert_eq!(
+        db.add_playlist_track("party", "three.mp3").await.unwrap(),
+        1
+    );
+
+    assert_eq!(
+        db.get_playlist_tracks("CHILL").await.unwrap(),
+        vec!["a/one.mp3".to_string(), "b/two.mp3".to_string()]
+    );
+    assert_eq!(
+        db.list_playlists().await.unwrap(),
+        vec![
+            PlaylistSummary {
+                name: "chill".to_string(),
+                track_count: 2,
+            },
+            PlaylistSummary {
+                name: "party".to_string(),
+                track_count: 1,
+            },
+        ]
+    );
+
+    assert!(db.delete_playlist("chill").await.unwrap());
+    assert!(!db.delete_playlist("chill").await.unwrap());
+    assert!(db.get_playlist_tracks("chill").await.unwrap().is_empty());
+
+    db.close().await;
+    let _ = std::fs::remove_file(path);
+}
+
+async fn setup_db() -> (Database, std::path::PathBuf) {
+    let mut path = std::env::temp_dir();
+    path.push(format!("tt_tg_playlists_{}.db", uuid::Uuid::now_v7()));
+    let db = Database::new(path.to_str().unwrap()).await.unwrap();
+    (db, path)
+}
@@ -0,0 +1,84 @@
+use super::Database;
+use crate::infra::db::types::RegistrationRequest;
+
+#[tokio::test]
+async fn registration_requests_roundtrip() {
+    let (db, path) = setup_db().await;
+
+    let id = db
+        .add_registration_request(42, "main", "alice")
+        .await
+        .unwrap();
+    let expected = RegistrationRequest {
+        id,
+        telegram_id: 42,
+        server_key: "main".to_string(),
+        username: "alice".to_string(),
+    };
+    assert!(
+        db.add_registration_request(42, "main", "bob")
+            .await
+            .is_err()
+    );
+    assert_eq!(
+        db.get_registration_request(id).await.unwrap(),
+        Some(expected.clone())
+    );
+    assert_eq!(
+        db.get_registration_by_telegram_id(42).await.unwrap(),
+        Some(expected.clone())
+    );
+    assert!(db.is_username_requested("main", "alice").await.unwrap());
+    assert!(!db.is_username_requested("other", "alice").await.unwrap());
+
+    assert_eq!(
+        db.take_registration_request(id).await.unwrap(),
+        Some(expected)
+    );
+    assert_eq!(db.take_registration_request(id).await.unwrap(), None);
+    assert_eq!(db.get_registration_by_telegram_id(42).await.unwrap(), None);
+
+    db.close().await;
+    let _ = std::fs::remove_file(path);
+}
+
+#[tokio::test]
+async fn registration_request_is_claimed_once() {
+    let (db, path) = setup_db().await;
+
+    let id = db
+        .add_registration_request(42, "main", "alice")
+        .await
+        .unwrap();
+    assert_eq!(
+        db.claim_registration_request(id)
+            .await
+            .unwrap()
+            .map(|request| request.username),
+        Some("alice".to_string())
+    );
+    assert_eq!(db.claim_registration_request(id).await.unwrap(), None);
+    assert_eq!(db.withdraw_registration_request(id).await.unwrap(), None);
+    assert!(db.get_registration_request(id).await.unwrap().is_some());
+
+    db.release_registration_request(id).await.unwrap();
+    assert!(db.claim_registration_request(id).await.unwrap().is_some());
+    db.release_registration_request(id).await.unwrap();
+    assert!(
+        db.withdraw_registration_request(id)
+            .await
+            .unwrap()
+            .is_some()
+    );
+    assert_eq!(db.claim_registration_request(id).await.unwrap(), None);
+
+    db.close().await;
+    let _ = std::fs::remove_file(path);
+}
+
+async fn setup_db() -> (Database, std::path::PathBuf) {
+    let mut path = std::env::temp_dir();
+    path.push(format!("tt_tg_registrations_{}.db", uuid::Uuid::now_v7()));
+    let db = Database::new(path.to_str().unwrap()).await.unwrap();
+    (db, path)
+}
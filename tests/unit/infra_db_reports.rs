@@ -0,0 +1,36 @@
+use super::Database;
+use chrono::{Duration, Utc};
+
+#[tokio::test]
+async fn report_queries_respect_window() {
+    let (db, path) = setup_db().await;
+    let start = Utc::now().naive_utc() - Duration::hours(1);
+
+    db.open_session("main", "alice", "Alice").await.unwrap();
+    db.close_session("main", "alice").await.unwrap();
+    db.open_session("main", "bob", "Bob").await.unwrap();
+    db.add_subscriber(1).await.unwrap();
+    db.add_ban(None, Some("eve".to_string()), None)
+        .await
+        .unwrap();
+
+    let end = Utc::now().naive_utc() + Duration::seconds(1);
+    let sessions = db.get_sessions_between(start, end).await.unwrap();
+    assert_eq!(sessions.len(), 2);
+    assert_eq!(db.count_new_subscribers(start, end).await.unwrap(), 1);
+    assert_eq!(db.count_bans_issued(start, end).await.unwrap(), 1);
+
+    let later = end + Duration::hours(1);
+    assert_eq!(db.count_new_subscribers(end, later).await.unwrap(), 0);
+    assert_eq!(db.get_sessions_between(end, later).await.unwrap().len(), 1);
+
+    db.close().await;
+    let _ = std::fs::remove_file(path);
+}
+
+async fn setup_db() -> (Database, std::path::PathBuf) {
+    let mut path = std::env::temp_dir();
+    path.push(format!("tt_tg_reports_{}.db", uuid::Uuid::now_v7()));
+    let db = Database::new(path.to_str().unwrap()).await.unwrap();
+    (db, path)
+}
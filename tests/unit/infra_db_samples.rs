@@ -0,0 +1,48 @@
+use super::Database;
+use chrono::{Duration, Utc};
+
+#[tokio::test]
+async fn samples_roundtrip_and_cleanup() {
+    let (db, path) = setup_db().await;
+    let now = Utc::now().naive_utc();
+
+    db.add_online_sample("main", now - Duration::days(40), 3)
+        .await
+        .unwrap();
+    db.add_online_sample("main", now - Duration::hours(2), 5)
+        .await
+        .unwrap();
+    db.add_online_sample("backup", now - Duration::hours(1), 2)
+        .await
+        .unwrap();
+
+    let recent = db
+        .get_online_samples_since(now - Duration::days(1))
+        .await
+        .unwrap();
+    assert_eq!(recent.len(), 2);
+    assert_eq!(recent[0].server_key, "main");
+    assert_eq!(recent[0].user_count, 5);
+    assert_eq!(recent[1].server_key, "backup");
+
+    let removed = db
+        .cleanup_online_samples(now - Duration::days(35))
+        .await
+        .unwrap();
+    assert_eq!(removed, 1);
+    let all = db
+        .get_online_samples_since(now - Duration::days(365))
+        .await
+        .unwrap();
+    assert_eq!(all.len(), 2);
+
+    db.close().await;
+    let _ = std::fs::remove_file(path);
+}
+
+async fn setup_db() -> (Database, std::path::PathBuf) {
+    let mut path = std::env::temp_dir();
+    path.push(format!("tt_tg_samples_{}.db", uuid::Uuid::now_v7()));
+    let db = Database::new(path.to_str().unwrap()).await.unwrap();
+    (db, path)
+}
@@ -0,0 +1,61 @@
+use super::Database;
+use crate::core::types::{LanguageCode, NotificationType};
+
+#[tokio::test]
+async fn toggle_ignored_server() {
+    let (db, path) = setup_db().await;
+    db.get_or_create_user(1, LanguageCode::En).await.unwrap();
+
+    assert!(db.toggle_ignored_server(1, "main").await.unwrap());
+    assert_eq!(
+        db.get_ignored_servers(1).await.unwrap(),
+        vec!["main".to_string()]
+    );
+
+    assert!(!db.toggle_ignored_server(1, "main").await.unwrap());
+    assert!(db.get_ignored_servers(1).await.unwrap().is_empty());
+
+    db.close().await;
+    let _ = std::fs::remove_file(path);
+}
+
+#[tokio::test]
+async fn ignored_server_filters_recipients() {
+    let (db, path) = setup_db().await;
+    db.get_or_create_user(2, LanguageCode::En).await.unwrap();
+    db.add_subscriber(2).await.unwrap();
+    db.toggle_ignored_server(2, "main").await.unwrap();
+
+    let ignored = db
+        .get_recipients_for_event("bob", NotificationType::Join, "main")
+        .await
+        .unwrap();
+    assert!(ignored.is_empty());
+
+    let other = db
+        .get_recipients_for_event("bob", NotificationType::Join, "backup")
+        .await
+        .unwrap();
+    assert_eq!(other.len(), 1);
+
+    db.close().await;
+    let _ = std::fs::remove_file(path);
+}
+
+#[tokio::test]
+async fn delete_profile_clears_ignored_servers() {
+    let (db, path) = setup_db().await;
+    db.get_or_create_user(3, LanguageCode::En).await.unwrap();
+    db.toggle_ignored_server(3, "main").await.unwrap();
+    db.delete_user_profile(3).await.unwrap();
+    assert!(db.get_ignored_servers(3).await.unwrap().is_empty());
+    db.close().await;
+    let _ = std::fs::remove_file(path);
+}
+
+async fn setup_db() -> (Database, std::path::PathBuf) {
+    let mut path = std::env::temp_dir();
+    path.push(format!("tt_tg_servers_{}.db", uuid::Uuid::now_v7()));
+    let db = Database::new(path.to_str().unwrap()).await.unwrap();
+    (db, path)
+}
@@ -0,0 +1,51 @@
+use super::Database;
+
+#[tokio::test]
+async fn session_lifecycle() {
+    let (db, path) = setup_db().await;
+
+    assert!(db.get_last_session("alice").await.unwrap().is_none());
+
+    db.open_session("main", "alice", "Alice").await.unwrap();
+    db.update_session_channel("main", "alice", "/Lobby/")
+        .await
+        .unwrap();
+    let open = db.get_last_session("alice").await.unwrap().unwrap();
+    assert_eq!(open.nickname, "Alice");
+    assert_eq!(open.channel.as_deref(), Some("/Lobby/"));
+    assert!(open.logout_time.is_none());
+
+    db.close_session("main", "alice").await.unwrap();
+    let closed = db.get_last_session("alice").await.unwrap().unwrap();
+    assert!(closed.logout_time.is_some());
+
+    db.close().await;
+    let _ = std::fs::remove_file(path);
+}
+
+#[tokio::test]
+async fn reopening_closes_stale_session() {
+    let (db, path) = setup_db().await;
+
+    db.open_session("main", "bob", "Bob").await.unwrap();
+    db.open_session("main", "bob", "Bobby").await.unwrap();
+    db.open_session("other", "carol", "Carol").await.unwrap();
+
+    let last = db.get_last_session("bob").await.unwrap().unwrap();
+    assert_eq!(last.nickname, "Bobby");
+    assert!(last.logout_time.is_none());
+
+    assert_eq!(db.close_open_sessions("main").await.unwrap(), 1);
+    let carol = db.get_last_session("carol").await.unwrap().unwrap();
+    assert!(carol.logout_time.is_none());
+
+    db.close().await;
+    let _ = std::fs::remove_file(path);
+}
+
+async fn setup_db() -> (Database, std::path::PathBuf) {
+    let mut path = std::env::temp_dir();
+    path.push(format!("tt_tg_sessions_{}.db", uuid::Uuid::now_v7()));
+    let db = Database::new(path.to_str().unwrap()).await.unwrap();
+    (db, path)
+}
@@ -0,0 +1,49 @@
+use super::Database;
+use crate::infra::db::types::QueuedStream;
+
+fn item(path: &str, title: Option<&str>) -> QueuedStream {
+    QueuedStream {
+        channel_id: 0,
+        file_path: path.to_string(),
+        duration_ms: 1000,
+        announce_text: None,
+        title: title.map(str::to_string),
+    }
+}
+
+#[tokio::test]
+async fn stream_queue_is_replaced_per_server() {
+    let (db, path) = setup_db().await;
+
+    db.replace_stream_queue("main", &[item("/a", Some("A")), item("/b", None)])
+        .await
+        .unwrap();
+    db.replace_stream_queue("backup", &[item("/c", None)])
+        .await
+        .unwrap();
+    assert_eq!(
+        db.get_stream_queue("main").await.unwrap(),
+        vec![item("/a", Some("A")), item("/b", None)]
+    );
+
+    db.replace_stream_queue("main", &[item("/b", None)])
+        .await
+        .unwrap();
+    assert_eq!(
+        db.get_stream_queue("main").await.unwrap(),
+        vec![item("/b", None)]
+    );
+    let mut paths = db.get_stream_queue_paths().await.unwrap();
+    paths.sort();
+    assert_eq!(paths, vec!["/b".to_string(), "/c".to_string()]);
+
+    db.close().await;
+    let _ = std::fs::remove_file(path);
+}
+
+async fn setup_db() -> (Database, std::path::PathBuf) {
+    let mut path = std::env::temp_dir();
+    path.push(format!("tt_tg_stream_queue_{}.db", uuid::Uuid::now_v7()));
+    let db = Database::new(path.to_str().unwrap()).await.unwrap();
+    (db, path)
+}
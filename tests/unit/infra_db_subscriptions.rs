@@ -1,5 +1,5 @@
 use super::Database;
-use crate::core::types::{LanguageCode, MuteListMode, NotificationSetting};
+use crate::core::types::{EventOptIn, LanguageCode, MuteListMode, NotificationSetting};
 
 #[tokio::test]
 async fn subscriptions_basic_flow() {
@@ -36,13 +36,13 @@ async fn recipients_respect_notification_settings() {
         .await
         .unwrap();
     let join = db
-        .get_recipients_for_event("user", crate::core::types::NotificationType::Join)
+        .get_recipients_for_event("user", crate::core::types::NotificationType::Join, "srv")
         .await
         .unwrap();
     assert!(join.is_empty());
 
     let leave = db
-        .get_recipients_for_event("user", crate::core::types::NotificationType::Leave)
+        .get_recipients_for_event("user", crate::core::types::NotificationType::Leave, "srv")
         .await
         .unwrap();
     assert_eq!(leave.len(), 1);
@@ -66,7 +66,7 @@ async fn recipients_respect_mute_modes() {
         .await
         .unwrap();
     let join = db
-        .get_recipients_for_event("bob", crate::core::types::NotificationType::Join)
+        .get_recipients_for_event("bob", crate::core::types::NotificationType::Join, "srv")
         .await
         .unwrap();
     assert!(join.is_empty());
@@ -79,7 +79,7 @@ async fn recipients_respect_mute_modes() {
         .await
         .unwrap();
     let join = db
-        .get_recipients_for_event("bob", crate::core::types::NotificationType::Join)
+        .get_recipients_for_event("bob", crate::core::types::NotificationType::Join, "srv")
         .await
         .unwrap();
     assert_eq!(join.len(), 1);
@@ -98,7 +98,7 @@ async fn recipients_are_empty_when_notifications_disabled() {
         .unwrap();
 
     let join = db
-        .get_recipients_for_event("user", crate::core::types::NotificationType::Join)
+        .get_recipients_for_event("user", crate::core::types::NotificationType::Join, "srv")
         .await
         .unwrap();
     assert!(join.is_empty());
@@ -107,6 +107,188 @@ async fn recipients_are_empty_when_notifications_disabled() {
     let _ = std::fs::remove_file(path);
 }
 
+#[tokio::test]
+async fn channel_recipients_require_opt_in() {
+    let (db, path) = setup_db().await;
+    db.get_or_create_user(50, LanguageCode::En).await.unwrap();
+    db.add_subscriber(50).await.unwrap();
+
+    let moves = db
+        .get_recipients_for_event(
+            "bob",
+            crate::core::types::NotificationType::ChannelJoin,
+            "srv",
+        )
+        .await
+        .unwrap();
+    assert!(moves.is_empty());
+
+    assert!(
+        db.toggle_event_opt_in(50, EventOptIn::ChannelMoves)
+            .await
+            .unwrap()
+    );
+    let moves = db
+        .get_recipients_for_event(
+            "bob",
+            crate::core::types::NotificationType::ChannelLeave,
+            "srv",
+        )
+        .await
+        .unwrap();
+    assert_eq!(moves.len(), 1);
+    assert!(moves[0].channel_notifications_enabled);
+
+    db.toggle_muted_user(50, MuteListMode::Blacklist, "bob")
+        .await
+        .unwrap();
+    let moves = db
+        .get_recipients_for_event(
+            "bob",
+            crate::core::types::NotificationType::ChannelJoin,
+            "srv",
+        )
+        .await
+        .unwrap();
+    assert!(moves.is_empty());
+
+    db.close().await;
+    let _ = std::fs::remove_file(path);
+}
+
+#[tokio::test]
+async fn nick_and_status_recipients_are_independent() {
+    let (db, path) = setup_db().await;
+    db.get_or_create_user(60, LanguageCode::En).await.unwrap();
+    db.add_subscriber(60).await.unwrap();
+    db.toggle_event_opt_in(60, EventOptIn::NickChanges)
+        .await
+        .unwrap();
+
+    let nick = db
+        .get_recipients_for_event(
+            "bob",
+            crate::core::types::NotificationType::NickChange,
+            "srv",
+        )
+        .await
+        .unwrap();
+    assert_eq!(nick.len(), 1);
+
+    let status = db
+        .get_recipients_for_event(
+            "bob",
+            crate::core::types::NotificationType::StatusChange,
+            "srv",
+        )
+        .await
+        .unwrap();
+    assert!(status.is_empty());
+
+    assert!(
+        !db.toggle_event_opt_in(60, EventOptIn::NickChanges)
+            .await
+            .unwrap()
+    );
+
+    db.close().await;
+    let _ = std::fs::remove_file(path);
+}
+
+#[tokio::test]
+async fn server_broadcasts_ignore_mute_list_but_respect_opt_out() {
+    let (db, path) = setup_db().await;
+    db.get_or_create_user(70, LanguageCode::En).await.unwrap();
+    db.add_subscriber(70).await.unwrap();
+    db.toggle_muted_user(70, MuteListMode::Blacklist, "operator")
+        .await
+        .unwrap();
+
+    let kind = crate::core::types::NotificationType::ServerBroadcast;
+    let recipients = db
+        .get_recipients_for_event("operator", kind, "srv")
+        .await
+        .unwrap();
+    assert_eq!(recipients.len(), 1);
+
+    assert!(
+        !db.toggle_event_opt_in(70, EventOptIn::ServerBroadcasts)
+            .await
+            .unwrap()
+    );
+    let recipients = db
+        .get_recipients_for_event("operator", kind, "srv")
+        .await
+        .unwrap();
+    assert!(recipients.is_empty());
+
+    db.close().await;
+    let _ = std::fs::remove_file(path);
+}
+
+#[tokio::test]
+async fn file_uploads_respect_mute_list_and_opt_out() {
+    let (db, path) = setup_db().await;
+    db.get_or_create_user(80, LanguageCode::En).await.unwrap();
+    db.add_subscriber(80).await.unwrap();
+    db.get_or_create_user(81, LanguageCode::En).await.unwrap();
+    db.add_subscriber(81).await.unwrap();
+    db.toggle_muted_user(81, MuteListMode::Blacklist, "uploader")
+        .await
+        .unwrap();
+
+    let kind = crate::core::types::NotificationType::FileUpload;
+    let recipients = db
+        .get_recipients_for_event("uploader", kind, "srv")
+        .await
+        .unwrap();
+    assert_eq!(
+        recipients.iter().map(|r| r.telegram_id).collect::<Vec<_>>(),
+        vec![80]
+    );
+
+    assert!(
+        !db.toggle_event_opt_in(80, EventOptIn::FileUploads)
+            .await
+            .unwrap()
+    );
+    let recipients = db
+        .get_recipients_for_event("uploader", kind, "srv")
+        .await
+        .unwrap();
+    assert!(recipients.is_empty());
+
+    db.close().await;
+    let _ = std::fs::remove_file(path);
+}
+
+#[tokio::test]
+async fn health_notices_are_opt_in_per_server() {
+    let (db, path) = setup_db().await;
+    db.get_or_create_user(90, LanguageCode::En).await.unwrap();
+    db.add_subscriber(90).await.unwrap();
+    db.get_or_create_user(91, LanguageCode::Ru).await.unwrap();
+    db.add_subscriber(91).await.unwrap();
+    assert!(db.get_health_recipients("srv").await.unwrap().is_empty());
+
+    assert!(
+        db.toggle_event_opt_in(90, EventOptIn::ServerHealth)
+            .await
+            .unwrap()
+    );
+    let recipients = db.get_health_recipients("srv").await.unwrap();
+    assert_eq!(recipients.len(), 1);
+    assert_eq!(recipients[0].telegram_id, 90);
+    assert_eq!(recipients[0].language_code, "en");
+
+    db.toggle_ignored_server(90, "srv").await.unwrap();
+    assert!(db.get_health_recipients("srv").await.unwrap().is_empty());
+    assert_eq!(db.get_health_recipients("other").await.unwrap().len(), 1);
+
+    db.close().await;
+    let _ = std::fs::remove_file(path);
+}
+
 async fn setup_db() -> (Database, std::path::PathBuf) {
     let mut path = std::env::temp_dir();
     path.push(format!("tt_tg_subs_{}.db", uuid::Uuid::now_v7()));
@@ -10,6 +10,8 @@ async fn get_or_create_and_updates() {
     db.update_language(1, LanguageCode::Ru).await.unwrap();
     let lang = db.get_user_lang_by_tt_user("missing").await;
     assert!(lang.is_none());
+    assert_eq!(db.get_user_lang(1).await, Some(LanguageCode::Ru));
+    assert!(db.get_user_lang(404).await.is_none());
 
     db.update_notification_setting(1, NotificationSetting::LeaveOff)
         .await
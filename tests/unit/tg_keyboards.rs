@@ -1,5 +1,6 @@
 use super::*;
 use crate::core::callbacks::MenuAction;
+use crate::core::types::{ServerId, TtUsername};
 
 #[test]
 fn create_main_menu_keyboard_has_expected_rows_for_user() {
@@ -10,7 +11,7 @@ fn create_main_menu_keyboard_has_expected_rows_for_user() {
 #[test]
 fn create_main_menu_keyboard_has_expected_rows_for_admin() {
     let kb = create_main_menu_keyboard(LanguageCode::En, true);
-    assert_eq!(kb.inline_keyboard.len(), 8);
+    assert_eq!(kb.inline_keyboard.len(), 12);
 }
 
 #[test]
@@ -90,3 +91,76 @@ fn create_pagination_keyboard_without_back_or_nav_is_empty() {
     );
     assert!(kb.inline_keyboard.is_empty());
 }
+
+#[test]
+fn create_server_picker_keyboard_has_row_per_server() {
+    let servers = vec![
+        (ServerId(0), "Main".to_string()),
+        (ServerId(1), "Backup".to_string()),
+    ];
+    let kb = create_server_picker_keyboard(&servers, |server_id| {
+        CallbackAction::Menu(MenuAction::WhoServer { server_id })
+    });
+    assert_eq!(kb.inline_keyboard.len(), 2);
+    assert_eq!(kb.inline_keyboard[1][0].text, "Backup");
+}
+
+#[test]
+fn create_queue_keyboard_has_row_per_item_and_controls() {
+    use crate::core::types::{NowPlaying, QueueEntry, QueueSnapshot, RepeatMode};
+
+    let entry = |stream_id| QueueEntry {
+        stream_id,
+        title: None,
+        duration_ms: 1000,
+    };
+    let idle = create_queue_keyboard(LanguageCode::En, ServerId(0), &QueueSnapshot::default());
+    assert_eq!(idle.inline_keyboard.len(), 2);
+    assert_eq!(idle.inline_keyboard[0][0].text, "🔀 Shuffle: off");
+    assert_eq!(idle.inline_keyboard[1].len(), 1);
+
+    let snapshot = QueueSnapshot {
+        now_playing: Some(NowPlaying {
+            entry: entry(1),
+            elapsed_ms: 0,
+            paused: true,
+        }),
+        pending: (2..20).map(entry).collect(),
+        shuffle: true,
+        repeat: RepeatMode::One,
+    };
+    let kb = create_queue_keyboard(LanguageCode::En, ServerId(0), &snapshot);
+    assert_eq!(kb.inline_keyboard.len(), 13);
+    assert_eq!(kb.inline_keyboard[10][0].text, "▶️ Resume");
+    assert_eq!(kb.inline_keyboard[11][0].text, "🔀 Shuffle: on");
+    assert_eq!(kb.inline_keyboard[11][1].text, "🔂 Repeat: one");
+    assert_eq!(kb.inline_keyboard[12].len(), 2);
+}
+
+#[test]
+fn create_ban_term_keyboard_offers_every_term() {
+    let kb = create_ban_term_keyboard(LanguageCode::En, ServerId(0), 7);
+    assert_eq!(kb.inline_keyboard.len(), 2);
+    assert_eq!(kb.inline_keyboard[0][0].text, "⏱ 1 hour");
+    assert_eq!(kb.inline_keyboard[1][1].text, "♾ Permanent");
+}
+
+#[test]
+fn create_channel_keyboard_hides_delete_for_occupied_channels() {
+    let kb = create_channel_keyboard(LanguageCode::En, ServerId(0), 5, true);
+    assert_eq!(kb.inline_keyboard.len(), 4);
+    assert_eq!(kb.inline_keyboard[1].len(), 2);
+    let kb = create_channel_keyboard(LanguageCode::En, ServerId(0), 5, false);
+    assert_eq!(kb.inline_keyboard.len(), 3);
+}
+
+#[test]
+fn create_account_keyboard_offers_the_other_type() {
+    let username = TtUsername::from("operator");
+    let kb = create_account_keyboard(LanguageCode::En, ServerId(0), &username, true);
+    assert_eq!(kb.inline_keyboard.len(), 4);
+    assert_eq!(kb.inline_keyboard[0].len(), 2);
+    assert_eq!(kb.inline_keyboard[1][0].text, "👤 Make regular user");
+    let kb = create_account_keyboard(LanguageCode::En, ServerId(0), &username, false);
+    assert_eq!(kb.inline_keyboard[1][0].text, "🛡 Make admin");
+}
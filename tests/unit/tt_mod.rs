@@ -3,6 +3,7 @@ use crate::bootstrap::config::TeamTalkConfig;
 
 fn base_config() -> TeamTalkConfig {
     TeamTalkConfig {
+        id: None,
         host_name: "host".to_string(),
         port: 1,
         encrypted: false,
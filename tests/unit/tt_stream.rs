@@ -0,0 +1,153 @@
+use super::*;
+
+fn queue_with(count: u32) -> StreamQueue {
+    let mut queue = StreamQueue::default();
+    for i in 0..count {
+        queue.push(0, format!("/tmp/{i}.ogg"), 1000 * (i + 1), None, None);
+    }
+    queue
+}
+
+fn pending_ids(queue: &StreamQueue) -> Vec<u64> {
+    queue
+        .snapshot(Instant::now())
+        .pending
+        .iter()
+        .map(|e| e.stream_id)
+        .collect()
+}
+
+#[test]
+fn move_to_top_and_remove_reorder_pending() {
+    let mut queue = queue_with(3);
+    assert!(queue.move_to_top(3));
+    assert_eq!(pending_ids(&queue), vec![3, 1, 2]);
+    assert_eq!(
+        queue.remove(1).map(|i| i.file_path),
+        Some("/tmp/0.ogg".into())
+    );
+    assert_eq!(pending_ids(&queue), vec![3, 2]);
+    assert!(!queue.move_to_top(42));
+    assert!(queue.remove(42).is_none());
+    assert_eq!(queue.clear().len(), 2);
+    assert!(queue.is_idle());
+}
+
+#[test]
+fn pause_freezes_elapsed_time() {
+    let mut queue = queue_with(1);
+    let t0 = Instant::now();
+    let item = queue.pop_next().unwrap();
+    queue.start(item, t0);
+
+    assert!(queue.pause(t0 + Duration::from_millis(300)));
+    assert!(!queue.pause(t0 + Duration::from_millis(400)));
+    let snap = queue.snapshot(t0 + Duration::from_secs(5));
+    let playing = snap.now_playing.unwrap();
+    assert!(playing.paused);
+    assert_eq!(playing.elapsed_ms, 300);
+
+    assert!(queue.resume(t0 + Duration::from_secs(5)));
+    assert!(!queue.resume(t0 + Duration::from_secs(5)));
+    assert_eq!(
+        queue.remaining(t0 + Duration::from_millis(5_500)),
+        Some(Duration::from_millis(200))
+    );
+    assert_eq!(
+        queue.remaining(t0 + Duration::from_secs(10)),
+        Some(Duration::ZERO)
+    );
+}
+
+#[test]
+fn finish_current_clears_playing_item() {
+    let mut queue = queue_with(1);
+    let item = queue.pop_next().unwrap();
+    queue.start(item, Instant::now());
+    assert_eq!(queue.current_id(), Some(1));
+    assert_eq!(queue.finish_current().map(|i| i.stream_id), Some(1));
+    assert!(queue.is_idle());
+}
+
+#[test]
+fn resolve_duration_prefers_probed_length() {
+    assert_eq!(resolve_duration(Some(183_000), 180_000), Some(183_000));
+    assert_eq!(resolve_duration(Some(0), 4_000), Some(4_000));
+    assert_eq!(resolve_duration(None, 4_000), Some(4_000));
+    assert_eq!(resolve_duration(None, 0), None);
+}
+
+#[test]
+fn interrupt_requeues_current_item_first() {
+    let mut queue = queue_with(2);
+    let item = queue.pop_next().unwrap();
+    queue.start(item, Instant::now());
+    assert!(queue.interrupt());
+    assert!(!queue.is_active());
+    assert_eq!(pending_ids(&queue), vec![1, 2]);
+    assert!(!queue.interrupt());
+
+    let records = queue.records();
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].file_path, "/tmp/0.ogg");
+    assert_eq!(records[1].duration_ms, 2000);
+}
+
+#[tokio::test]
+async fn sweep_spool_keeps_queued_files() {
+    let base = std::env::temp_dir().join(format!("tt_tg_spool_{}", uuid::Uuid::now_v7()));
+    let spool = base.join("spool");
+    std::fs::create_dir_all(&spool).unwrap();
+    let db = Database::new(base.join("bot.db").to_str().unwrap())
+        .await
+        .unwrap();
+    let kept = spool.join("kept.ogg");
+    let orphan = spool.join("orphan.ogg");
+    std::fs::write(&kept, b"a").unwrap();
+    std::fs::write(&orphan, b"b").unwrap();
+    db.replace_stream_queue(
+        "main",
+        &[QueuedStream {
+            channel_id: 0,
+            file_path: kept.to_string_lossy().to_string(),
+            duration_ms: 1000,
+            announce_text: None,
+            title: None,
+        }],
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(sweep_spool(&db, &spool).await.unwrap(), 1);
+    assert!(kept.exists());
+    assert!(!orphan.exists());
+
+    db.close().await;
+    let _ = std::fs::remove_dir_all(base);
+}
+
+#[test]
+fn repeat_modes_requeue_finished_items() {
+    let mut queue = queue_with(2);
+    assert_eq!(queue.cycle_repeat(), RepeatMode::All);
+    let item = queue.pop_next().unwrap();
+    queue.start(item, Instant::now());
+    assert!(queue.complete_current(false).is_none());
+    assert_eq!(pending_ids(&queue), vec![2, 1]);
+
+    assert_eq!(queue.cycle_repeat(), RepeatMode::One);
+    let item = queue.pop_next().unwrap();
+    queue.start(item, Instant::now());
+    assert!(queue.complete_current(false).is_none());
+    assert_eq!(pending_ids(&queue), vec![2, 1]);
+
+    let item = queue.pop_next().unwrap();
+    queue.start(item, Instant::now());
+    assert_eq!(queue.complete_current(true).map(|i| i.stream_id), Some(2));
+
+    assert_eq!(queue.cycle_repeat(), RepeatMode::Off);
+    assert!(queue.toggle_shuffle());
+    assert!(queue.snapshot(Instant::now()).shuffle);
+    assert_eq!(queue.pop_next().map(|i| i.stream_id), Some(1));
+    assert!(queue.pop_next().is_none());
+}